@@ -85,7 +85,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         // Если props_id >= 20, это font atlas
         if (in.props_id >= 20u) {
             // Font atlas имеет сетку 16x16 спрайтов (16 колонок, 16 строк)
-            // Каждый спрайт 4x6 пикселей, текстура 64x96 пикселей
+            // Каждая ячейка 6x6 пикселей, текстура 96x96 пикселей (см. font.rs)
             let sprites_per_row = 16.0;
             let sprites_per_col = 16.0;
             
@@ -104,10 +104,10 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             let base_v = sprite_row * sprite_size_v;
             
             // Добавляем смещение внутри спрайта
-            // Глиф занимает 3x5 пикселей из ячейки 4x6, поэтому масштабируем UV
-            // Глиф начинается в левом верхнем углу ячейки
-            let glyph_u_scale = 3.0 / 4.0; // Глиф 3 пикселя из 4
-            let glyph_v_scale = 5.0 / 6.0; // Глиф 5 пикселей из 6
+            // Квад глифа покрывает 5x5 пикселей из ячейки 6x6, поэтому масштабируем UV
+            // Глиф начинается в левом верхнем углу ячейки, узкие глифы дополнены прозрачным
+            let glyph_u_scale = 5.0 / 6.0; // 5 пикселей из 6
+            let glyph_v_scale = 5.0 / 6.0; // 5 пикселей из 6
             atlas_uv = vec2<f32>(
                 base_u + in.uv.x * sprite_size_u * glyph_u_scale,
                 base_v + (1.0 - in.uv.y) * sprite_size_v * glyph_v_scale
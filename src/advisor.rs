@@ -0,0 +1,355 @@
+use glam::IVec2;
+use serde::{Serialize, Deserialize};
+
+use crate::building_production::create_production_strategy;
+use crate::notifications::NotificationKind;
+use crate::types::{Building, BuildingKind, Citizen, ResourceKind, Resources, WarehouseStore};
+use crate::world::World;
+use crate::i18n::{tr, tr_fmt};
+
+// сколько зданий перечислять в одном предупреждении
+const MAX_LISTED: usize = 3;
+
+/// Проблема, о которой предупреждает советник
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Issue {
+    Hungry,
+    NoWorkers,
+    NoInput,
+    GoldShortfall,
+    NoTrees,
+}
+
+impl Issue {
+    /// Начало текста предупреждения
+    pub fn message(self) -> &'static str {
+        match self {
+            Issue::Hungry => tr("advisor.hungry"),
+            Issue::NoWorkers => tr("advisor.no_workers"),
+            Issue::NoInput => tr("advisor.no_input"),
+            Issue::GoldShortfall => tr("advisor.gold_shortfall"),
+            Issue::NoTrees => tr("advisor.no_trees"),
+        }
+    }
+
+    /// Цвет значка над зданием
+    pub fn color(self) -> [f32; 4] {
+        match self {
+            Issue::Hungry => [0.95, 0.3, 0.2, 1.0],
+            Issue::NoWorkers => [0.95, 0.75, 0.2, 1.0],
+            Issue::NoInput => [0.9, 0.5, 0.15, 1.0],
+            Issue::GoldShortfall => [1.0, 0.85, 0.3, 1.0],
+            Issue::NoTrees => [0.45, 0.8, 0.3, 1.0],
+        }
+    }
+}
+
+/// Проблема и здание, к которому она относится (None — поселение в целом)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Problem {
+    pub issue: Issue,
+    pub pos: Option<IVec2>,
+}
+
+/// Что видят правила советника
+pub struct AdvisorContext<'a> {
+    pub citizens: &'a [Citizen],
+    pub buildings: &'a [Building],
+    pub resources: &'a Resources,
+    pub warehouses: &'a [WarehouseStore],
+    pub world: &'a World,
+    pub research: &'a crate::research::ResearchModifiers,
+    pub config: &'a crate::input::Config,
+    pub tax_rate: f32,
+}
+
+/// Правило: находит проблемы одного вида
+trait Rule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>);
+}
+
+// жители, не поевшие сегодня, — по их домам
+struct HungryRule;
+
+impl Rule for HungryRule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>) {
+        for c in ctx.citizens.iter().filter(|c| !c.fed_today) {
+            let problem = Problem { issue: Issue::Hungry, pos: Some(c.home) };
+            if !out.contains(&problem) { out.push(problem); }
+        }
+    }
+}
+
+// здание ждёт рабочих, а не пришёл никто
+struct NoWorkersRule;
+
+impl Rule for NoWorkersRule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>) {
+        for b in ctx.buildings.iter().filter(|b| b.workers_target > 0) {
+            if !ctx.citizens.iter().any(|c| c.workplace == Some(b.pos)) {
+                out.push(Problem { issue: Issue::NoWorkers, pos: Some(b.pos) });
+            }
+        }
+    }
+}
+
+// переработчику нечего перерабатывать: сырья нет ни на складах, ни в руках
+struct NoInputRule;
+
+impl Rule for NoInputRule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>) {
+        for b in ctx.buildings.iter().filter(|b| b.workers_target > 0) {
+            let Some(input) = create_production_strategy(b.kind).required_input_resource() else { continue; };
+            if crate::trade::stock_of(ctx.resources, ctx.warehouses, input) > 0 { continue; }
+            if ctx.citizens.iter().any(|c| c.carrying.is_some_and(|(kind, n)| kind == input && n > 0)) { continue; }
+            out.push(Problem { issue: Issue::NoInput, pos: Some(b.pos) });
+        }
+    }
+}
+
+// казна с завтрашними налогами не покроет содержание зданий
+struct GoldShortfallRule;
+
+impl Rule for GoldShortfallRule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>) {
+        let pop = ctx.citizens.len() as i32;
+        let happiness_avg = if pop > 0 {
+            ctx.citizens.iter().map(|c| c.happiness as f32).sum::<f32>() / pop as f32
+        } else {
+            50.0
+        };
+        let income = crate::game::tax_income(pop, happiness_avg, ctx.tax_rate, ctx.config).max(0);
+        let taxes = income + ctx.research.tax_bonus(income);
+        let gold = crate::trade::stock_of(ctx.resources, ctx.warehouses, ResourceKind::Gold);
+        if gold + taxes - crate::game::daily_upkeep(ctx.buildings, ctx.config) < 0 {
+            out.push(Problem { issue: Issue::GoldShortfall, pos: None });
+        }
+    }
+}
+
+// лесорубу не дотянуться ни до одного взрослого дерева
+struct NoTreesRule;
+
+impl Rule for NoTreesRule {
+    fn check(&self, ctx: &AdvisorContext, out: &mut Vec<Problem>) {
+        let r = crate::game_loop::LUMBERJACK_RANGE;
+        for b in ctx.buildings.iter().filter(|b| b.kind == BuildingKind::Lumberjack && b.workers_target > 0) {
+            let has_tree = (-r..=r).any(|dy| (-r..=r).any(|dx| {
+                matches!(ctx.world.tree_stage(b.pos + IVec2::new(dx, dy)), Some(1 | 2))
+            }));
+            if !has_tree {
+                out.push(Problem { issue: Issue::NoTrees, pos: Some(b.pos) });
+            }
+        }
+    }
+}
+
+const RULES: &[&dyn Rule] = &[&HungryRule, &NoWorkersRule, &NoInputRule, &GoldShortfallRule, &NoTreesRule];
+
+/// Советник: раз в день проверяет правила и предупреждает о новых проблемах
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Advisor {
+    // проблемы последней проверки (по ним рисуются значки)
+    #[serde(default)]
+    pub problems: Vec<Problem>,
+    // день последней проверки
+    #[serde(default)]
+    pub last_day: i32,
+}
+
+impl Advisor {
+    /// Проверить правила; предупреждения только о проблемах, которых вчера не было.
+    /// Одно предупреждение на вид проблемы: текст, список зданий и место первого из них
+    pub fn evaluate(&mut self, day: i32, ctx: &AdvisorContext) -> Vec<(NotificationKind, Option<IVec2>)> {
+        self.last_day = day;
+        let mut found = Vec::new();
+        for rule in RULES {
+            rule.check(ctx, &mut found);
+        }
+        let fresh: Vec<Problem> = found.iter().copied().filter(|p| !self.problems.contains(p)).collect();
+        self.problems = found;
+
+        let mut warnings = Vec::new();
+        let mut issues: Vec<Issue> = Vec::new();
+        for p in &fresh {
+            if !issues.contains(&p.issue) { issues.push(p.issue); }
+        }
+        for issue in issues {
+            let places: Vec<IVec2> = fresh.iter().filter(|p| p.issue == issue).filter_map(|p| p.pos).collect();
+            let mut names: Vec<String> = places.iter().take(MAX_LISTED).map(|&pos| {
+                let kind = ctx.buildings.iter().find(|b| b.pos == pos).map(|b| b.kind);
+                match kind {
+                    Some(kind) => format!("{} ({}, {})", crate::ui::building_label(kind), pos.x, pos.y),
+                    None => format!("({}, {})", pos.x, pos.y),
+                }
+            }).collect();
+            if places.len() > MAX_LISTED {
+                names.push(tr_fmt("advisor.more", &[&(places.len() - MAX_LISTED)]));
+            }
+            let message = if names.is_empty() {
+                issue.message().to_string()
+            } else {
+                format!("{}: {}", issue.message(), names.join(", "))
+            };
+            warnings.push((NotificationKind::Warning { message }, places.first().copied()));
+        }
+        warnings
+    }
+
+    /// Забыть проблемы снесённых зданий
+    pub fn forget_missing(&mut self, buildings: &[Building]) {
+        self.problems.retain(|p| p.pos.is_none_or(|pos| buildings.iter().any(|b| b.pos == pos)));
+    }
+
+    /// Проблемы здания (для значков над ним)
+    pub fn issues_at(&self, pos: IVec2) -> impl Iterator<Item = Issue> + '_ {
+        self.problems.iter().filter(move |p| p.pos == Some(pos)).map(|p| p.issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::research::ResearchModifiers;
+    use crate::types::CitizenState;
+    use crate::ui_interaction::new_building;
+    use crate::world::Tree;
+
+    // поселение для проверки правил
+    struct Town {
+        citizens: Vec<Citizen>,
+        buildings: Vec<Building>,
+        resources: Resources,
+        warehouses: Vec<WarehouseStore>,
+        world: World,
+        research: ResearchModifiers,
+        config: crate::input::Config,
+    }
+
+    impl Town {
+        fn new() -> Self {
+            Self {
+                citizens: Vec::new(),
+                buildings: Vec::new(),
+                resources: Resources::default(),
+                warehouses: Vec::new(),
+                world: World::new(1, 1),
+                research: ResearchModifiers::default(),
+                config: crate::input::Config::default(),
+            }
+        }
+
+        fn ctx(&self) -> AdvisorContext<'_> {
+            AdvisorContext {
+                citizens: &self.citizens,
+                buildings: &self.buildings,
+                resources: &self.resources,
+                warehouses: &self.warehouses,
+                world: &self.world,
+                research: &self.research,
+                config: &self.config,
+                tax_rate: 1.0,
+            }
+        }
+
+        // проблемы одного вида по итогам проверки
+        fn found(&self, issue: Issue) -> Vec<Problem> {
+            let mut out = Vec::new();
+            for rule in RULES { rule.check(&self.ctx(), &mut out); }
+            out.retain(|p| p.issue == issue);
+            out
+        }
+    }
+
+    fn citizen(home: IVec2, workplace: Option<IVec2>) -> Citizen {
+        Citizen {
+            pos: home, target: home, moving: false, progress: 0.0, carrying_log: false, assigned_job: None,
+            idle_timer_ms: 0, home, workplace, state: CitizenState::Idle, work_timer_ms: 0,
+            carrying: None, pending_input: None, path: Vec::new(), path_index: 0, fed_today: true, manual_workplace: false,
+            happiness: 50, last_food_mask: 0, cold_today: false, health: 100, tool_wear: 0.0,
+        }
+    }
+
+    #[test]
+    fn unfed_citizens_are_reported_once_per_home() {
+        let mut town = Town::new();
+        let home = IVec2::new(2, 3);
+        town.citizens = vec![citizen(home, None), citizen(home, None), citizen(IVec2::new(9, 9), None)];
+        assert!(town.found(Issue::Hungry).is_empty());
+        town.citizens[0].fed_today = false;
+        town.citizens[1].fed_today = false;
+        assert_eq!(town.found(Issue::Hungry), [Problem { issue: Issue::Hungry, pos: Some(home) }]);
+    }
+
+    #[test]
+    fn building_without_workers_is_reported() {
+        let mut town = Town::new();
+        let pos = IVec2::new(4, 4);
+        town.buildings.push(new_building(BuildingKind::Lumberjack, pos));
+        assert_eq!(town.found(Issue::NoWorkers), [Problem { issue: Issue::NoWorkers, pos: Some(pos) }]);
+        town.citizens.push(citizen(IVec2::ZERO, Some(pos)));
+        assert!(town.found(Issue::NoWorkers).is_empty());
+        // здание без рабочих мест рабочих не ждёт
+        town.buildings.push(new_building(BuildingKind::Warehouse, IVec2::new(8, 8)));
+        assert!(town.found(Issue::NoWorkers).is_empty());
+    }
+
+    #[test]
+    fn processor_without_input_is_reported() {
+        let mut town = Town::new();
+        let pos = IVec2::new(5, 5);
+        town.buildings.push(new_building(BuildingKind::Bakery, pos));
+        assert_eq!(town.found(Issue::NoInput), [Problem { issue: Issue::NoInput, pos: Some(pos) }]);
+        // мука в руках у носильщика тоже считается
+        town.citizens.push(citizen(IVec2::ZERO, None));
+        town.citizens[0].carrying = Some((ResourceKind::Flour, 2));
+        assert!(town.found(Issue::NoInput).is_empty());
+        town.citizens.clear();
+        town.warehouses.push(WarehouseStore { pos: IVec2::ZERO, flour: 1, ..Default::default() });
+        assert!(town.found(Issue::NoInput).is_empty());
+    }
+
+    #[test]
+    fn gold_below_upkeep_is_reported() {
+        let mut town = Town::new();
+        town.config.upkeep_house = 10;
+        town.buildings.push(new_building(BuildingKind::House, IVec2::ZERO));
+        town.resources.gold = 9;
+        assert_eq!(town.found(Issue::GoldShortfall), [Problem { issue: Issue::GoldShortfall, pos: None }]);
+        town.resources.gold = 10;
+        assert!(town.found(Issue::GoldShortfall).is_empty());
+    }
+
+    #[test]
+    fn lumberjack_without_grown_trees_is_reported() {
+        let mut town = Town::new();
+        let pos = IVec2::new(8, 8);
+        town.world.get_tile(pos.x, pos.y);
+        while town.world.loaded_tile(pos).is_none() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            town.world.integrate_ready_chunks();
+        }
+        town.buildings.push(new_building(BuildingKind::Lumberjack, pos));
+        // только саженец — рубить нечего
+        town.world.restore_trees([((pos.x + 2, pos.y), Tree { stage: 0, age_ms: 0 })]);
+        assert_eq!(town.found(Issue::NoTrees), [Problem { issue: Issue::NoTrees, pos: Some(pos) }]);
+        town.world.restore_trees([((pos.x + 2, pos.y), Tree { stage: 2, age_ms: 0 })]);
+        assert!(town.found(Issue::NoTrees).is_empty());
+    }
+
+    #[test]
+    fn lasting_problem_is_not_reported_again() {
+        let mut town = Town::new();
+        town.citizens.push(citizen(IVec2::ZERO, None));
+        town.citizens[0].fed_today = false;
+        let mut advisor = Advisor::default();
+        assert_eq!(advisor.evaluate(1, &town.ctx()).len(), 1);
+        assert!(advisor.evaluate(2, &town.ctx()).is_empty());
+        assert_eq!(advisor.problems.len(), 1);
+        // проблема ушла и вернулась — снова предупреждение
+        town.citizens[0].fed_today = true;
+        assert!(advisor.evaluate(3, &town.ctx()).is_empty());
+        town.citizens[0].fed_today = false;
+        assert_eq!(advisor.evaluate(4, &town.ctx()).len(), 1);
+    }
+}
@@ -1,7 +1,17 @@
 use glam::IVec2;
 use crate::types::{Building, BuildingKind, Citizen, ResourceKind, WarehouseStore};
-use crate::world::World;
+use crate::world::{DepositKind, World};
 use crate::input::Config;
+use crate::research::ResearchModifiers;
+
+/// Условия производства на шаге симуляции
+pub struct ProductionContext<'a> {
+    pub config: &'a Config,
+    // эффекты завершённых исследований
+    pub research: &'a ResearchModifiers,
+    // в поселении есть кузница: без инструментов работа замедляется
+    pub tools_demand: bool,
+}
 
 /// Trait для стратегии производства здания
 /// Каждый тип здания реализует свою стратегию производства
@@ -14,7 +24,7 @@ pub trait ProductionStrategy {
         building: &Building,
         warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         step_ms: f32,
     ) -> bool;
@@ -33,18 +43,68 @@ pub trait ProductionStrategy {
     /// Может быть полезно для отладки и UI
     #[allow(dead_code)]
     fn output_resource(&self) -> Option<ResourceKind>;
+
+    /// Откуда здание в `pos` берёт сырьё из мира: центр и радиус в тайлах
+    fn work_area(&self, _pos: IVec2) -> Option<(IVec2, i32)> {
+        None
+    }
+}
+
+/// Множитель времени цикла от инструментов: с ними работа быстрее;
+/// когда в поселении есть кузница, а инструменты кончились, — медленнее
+pub fn tools_multiplier(warehouses: &[WarehouseStore], ctx: &ProductionContext) -> f32 {
+    if crate::resource_visitor::sum_warehouses_resource(warehouses, ResourceKind::Tools) > 0 {
+        ctx.config.tools_speed_mul
+    } else if ctx.tools_demand {
+        ctx.config.no_tools_speed_mul
+    } else {
+        1.0
+    }
+}
+
+/// Износ инструментов: цикл добавляет рабочему долю износа (tool_wear_chance),
+/// набралась единица — списываем один инструмент со склада
+fn wear_tools(citizen: &mut Citizen, warehouses: &mut [WarehouseStore], config: &Config) {
+    let Some(w) = warehouses.iter_mut().find(|w| w.tools > 0) else { return; };
+    citizen.tool_wear += config.tool_wear_chance.clamp(0.0, 1.0);
+    if citizen.tool_wear >= 1.0 {
+        citizen.tool_wear -= 1.0;
+        w.tools -= 1;
+    }
+}
+
+/// Добытое за цикл — в ношу. На склад рабочий идёт, когда ноша полна:
+/// исследования (тачки) позволяют уносить несколько единиц за ходку
+fn load_output(citizen: &mut Citizen, world: &mut World, ctx: &ProductionContext, dst: IVec2, output: ResourceKind) {
+    let amount = citizen.carrying.map_or(0, |(_, n)| n) + 1;
+    citizen.carrying = Some((output, amount));
+    if amount > ctx.research.carry_bonus {
+        crate::game::plan_path(world, citizen, dst);
+        citizen.state = crate::types::CitizenState::GoingToDeposit;
+    }
 }
 
 /// Простая стратегия для зданий, которые добывают ресурсы напрямую
-/// (StoneQuarry, ClayPit, IronMine, WheatField, Fishery)
+/// (StoneQuarry, ClayPit, IronMine, CoalMine, GoldMine, GemMine, SaltWorks, SheepFarm, HopFarm)
 pub struct ExtractionStrategy {
     output: ResourceKind,
     base_time_ms: i32,
+    // месторождение, из которого берётся сырьё (None — неисчерпаемый источник)
+    deposit: Option<DepositKind>,
 }
 
+/// Радиус поиска сырья вокруг центра добывающего здания
+pub const DEPOSIT_RADIUS: i32 = 2;
+
 impl ExtractionStrategy {
     pub fn new(output: ResourceKind, base_time_ms: i32) -> Self {
-        Self { output, base_time_ms }
+        Self { output, base_time_ms, deposit: None }
+    }
+
+    /// Builder: добыча расходует запас месторождения
+    pub fn with_deposit(mut self, kind: DepositKind) -> Self {
+        self.deposit = Some(kind);
+        self
     }
 }
 
@@ -55,21 +115,25 @@ impl ProductionStrategy for ExtractionStrategy {
         building: &Building,
         warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        _config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
-        if citizen.carrying.is_some() {
+        if citizen.carrying.is_some_and(|(r, _)| r != self.output) {
             return false;
         }
         
-        let production_time = (self.base_time_ms as f32 * weather_multiplier) as i32;
+        let production_time = (self.base_time_ms as f32 * weather_multiplier * tools_multiplier(warehouses, ctx)) as i32;
         if citizen.work_timer_ms >= production_time {
             citizen.work_timer_ms = 0;
-            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, building.pos) {
-                citizen.carrying = Some((self.output, 1));
-                crate::game::plan_path(world, citizen, dst);
-                citizen.state = crate::types::CitizenState::GoingToDeposit;
+            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, building.pos) {
+                // месторождение выработано — производство стоит
+                if let Some(kind) = self.deposit
+                    && !world.extract_deposit(building.pos + IVec2::new(1, 1), DEPOSIT_RADIUS, kind) {
+                    return false;
+                }
+                wear_tools(citizen, warehouses, ctx.config);
+                load_output(citizen, world, ctx, dst, self.output);
                 return true;
             }
         }
@@ -87,16 +151,24 @@ impl ProductionStrategy for ExtractionStrategy {
     fn output_resource(&self) -> Option<ResourceKind> {
         Some(self.output)
     }
+
+    fn work_area(&self, pos: IVec2) -> Option<(IVec2, i32)> {
+        self.deposit.map(|_| (pos + IVec2::new(1, 1), DEPOSIT_RADIUS))
+    }
 }
 
 /// Стратегия для зданий, которые перерабатывают ресурсы
-/// (Mill, Bakery, Kiln, Smelter)
+/// (Mill, Bakery, Kiln, Smelter, Mint, Jeweler, CharcoalBurner, Blacksmith, Weaver, Tailor, Brewery)
 pub struct ProcessingStrategy {
     input: ResourceKind,
     output: ResourceKind,
     base_time_ms: i32,
     /// Дополнительные ресурсы, которые нужно списать (например, wood для Kiln)
     additional_cost: Option<(ResourceKind, i32)>,
+    /// Топливо: списывается 1 единица первого доступного вида (по порядку предпочтения)
+    fuel: &'static [ResourceKind],
+    /// Сколько единиц продукта даёт один цикл
+    output_amount: i32,
 }
 
 impl ProcessingStrategy {
@@ -106,6 +178,8 @@ impl ProcessingStrategy {
             output,
             base_time_ms,
             additional_cost: None,
+            fuel: &[],
+            output_amount: 1,
         }
     }
     
@@ -113,6 +187,16 @@ impl ProcessingStrategy {
         self.additional_cost = Some((resource, amount));
         self
     }
+
+    pub fn with_fuel(mut self, fuel: &'static [ResourceKind]) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    pub fn with_output_amount(mut self, amount: i32) -> Self {
+        self.output_amount = amount;
+        self
+    }
 }
 
 impl ProductionStrategy for ProcessingStrategy {
@@ -122,7 +206,7 @@ impl ProductionStrategy for ProcessingStrategy {
         building: &Building,
         warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        _config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
@@ -139,7 +223,7 @@ impl ProductionStrategy for ProcessingStrategy {
             });
             
             if have_any {
-                if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, building.pos) {
+                if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, building.pos) {
                     citizen.pending_input = Some(self.input);
                     citizen.state = crate::types::CitizenState::GoingToFetch;
                     crate::game::plan_path(world, citizen, dst);
@@ -150,14 +234,24 @@ impl ProductionStrategy for ProcessingStrategy {
         }
         
         // Есть входной ресурс, производим
-        let production_time = (self.base_time_ms as f32 * weather_multiplier) as i32;
+        let production_time = (self.base_time_ms as f32 * weather_multiplier * tools_multiplier(warehouses, ctx)) as i32;
         if citizen.work_timer_ms >= production_time {
             citizen.work_timer_ms = 0;
             
+            // Топливо: первый вид, который есть хоть на одном складе
+            use crate::resource_visitor::{ResourceVisitable, CheckEnoughVisitor, SpendVisitor};
+            let fuel = self.fuel.iter().copied().find(|&kind| warehouses.iter().any(|w| {
+                let mut visitor = CheckEnoughVisitor::new(1);
+                w.accept(&mut visitor, kind);
+                visitor.result
+            }));
+            if !self.fuel.is_empty() && fuel.is_none() {
+                return false;
+            }
+            
             // Списываем дополнительные ресурсы (например, wood для Kiln)
             // Используем Visitor Pattern для списания
             if let Some((res_kind, amount)) = self.additional_cost {
-                use crate::resource_visitor::{ResourceVisitable, CheckEnoughVisitor, SpendVisitor};
                 let mut found = false;
                 for w in warehouses.iter_mut() {
                     let mut check_visitor = CheckEnoughVisitor::new(amount);
@@ -173,10 +267,19 @@ impl ProductionStrategy for ProcessingStrategy {
                     return false;
                 }
             }
+            if let Some(kind) = fuel
+                && let Some(w) = warehouses.iter_mut().find(|w| {
+                    let mut visitor = CheckEnoughVisitor::new(1);
+                    w.accept(&mut visitor, kind);
+                    visitor.result
+                }) {
+                w.accept_mut(&mut SpendVisitor::new(1), kind);
+            }
             
             citizen.carrying = None; // Потратили входной ресурс
-            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, building.pos) {
-                citizen.carrying = Some((self.output, 1));
+            wear_tools(citizen, warehouses, ctx.config);
+            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, building.pos) {
+                citizen.carrying = Some((self.output, self.output_amount));
                 crate::game::plan_path(world, citizen, dst);
                 citizen.state = crate::types::CitizenState::GoingToDeposit;
                 return true;
@@ -206,15 +309,16 @@ impl ProductionStrategy for ForesterStrategy {
         &self,
         citizen: &mut Citizen,
         building: &Building,
-        _warehouses: &mut Vec<WarehouseStore>,
+        warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        _config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
-        let production_time = (4000.0 * weather_multiplier) as i32;
+        let production_time = (4000.0 * weather_multiplier * tools_multiplier(warehouses, ctx)) as i32;
         if citizen.work_timer_ms >= production_time {
             citizen.work_timer_ms = 0;
+            wear_tools(citizen, warehouses, ctx.config);
             
             const R: i32 = 6;
             let mut best: Option<(i32, IVec2)> = None;
@@ -222,7 +326,7 @@ impl ProductionStrategy for ForesterStrategy {
                 for dx in -R..=R {
                     let p = IVec2::new(building.pos.x + dx, building.pos.y + dy);
                     let tk = world.get_tile(p.x, p.y);
-                    if tk != crate::types::TileKind::Water
+                    if matches!(tk, crate::types::TileKind::Grass | crate::types::TileKind::Forest | crate::types::TileKind::Hills)
                         && !world.has_tree(p)
                         && !world.is_occupied(p)
                         && !world.is_road(p)
@@ -266,21 +370,20 @@ impl ProductionStrategy for FisheryStrategy {
         building: &Building,
         warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        _config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
-        if citizen.carrying.is_some() {
+        if citizen.carrying.is_some_and(|(r, _)| r != ResourceKind::Fish) {
             return false;
         }
         
-        let production_time = (5000.0 * weather_multiplier) as i32;
+        let production_time = (5000.0 * weather_multiplier * tools_multiplier(warehouses, ctx)) as i32;
         if citizen.work_timer_ms >= production_time {
             citizen.work_timer_ms = 0;
-            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, building.pos) {
-                citizen.carrying = Some((ResourceKind::Fish, 1));
-                crate::game::plan_path(world, citizen, dst);
-                citizen.state = crate::types::CitizenState::GoingToDeposit;
+            wear_tools(citizen, warehouses, ctx.config);
+            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, building.pos) {
+                load_output(citizen, world, ctx, dst, ResourceKind::Fish);
                 return true;
             }
         }
@@ -310,11 +413,11 @@ impl ProductionStrategy for WheatFieldStrategy {
         building: &Building,
         warehouses: &mut Vec<WarehouseStore>,
         world: &mut World,
-        config: &Config,
+        ctx: &ProductionContext,
         weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
-        if citizen.carrying.is_some() {
+        if citizen.carrying.is_some_and(|(r, _)| r != ResourceKind::Wheat) {
             return false;
         }
         
@@ -322,19 +425,18 @@ impl ProductionStrategy for WheatFieldStrategy {
         let biome_multiplier = {
             use crate::types::BiomeKind::*;
             match world.biome(building.pos) {
-                Meadow => config.biome_meadow_wheat_wmul,
-                Swamp => config.biome_swamp_wheat_wmul,
+                Meadow => ctx.config.biome_meadow_wheat_wmul,
+                Swamp => ctx.config.biome_swamp_wheat_wmul,
                 _ => 1.0,
             }
         };
         
-        let production_time = (6000.0 * weather_multiplier * biome_multiplier) as i32;
+        let production_time = (6000.0 * weather_multiplier * biome_multiplier * tools_multiplier(warehouses, ctx)) as i32;
         if citizen.work_timer_ms >= production_time {
             citizen.work_timer_ms = 0;
-            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, building.pos) {
-                citizen.carrying = Some((ResourceKind::Wheat, 1));
-                crate::game::plan_path(world, citizen, dst);
-                citizen.state = crate::types::CitizenState::GoingToDeposit;
+            wear_tools(citizen, warehouses, ctx.config);
+            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, building.pos) {
+                load_output(citizen, world, ctx, dst, ResourceKind::Wheat);
                 return true;
             }
         }
@@ -360,9 +462,9 @@ pub fn create_production_strategy(kind: BuildingKind) -> Box<dyn ProductionStrat
     use crate::types::ResourceKind::*;
     
     match kind {
-        StoneQuarry => Box::new(ExtractionStrategy::new(Stone, 4000)),
-        ClayPit => Box::new(ExtractionStrategy::new(Clay, 4000)),
-        IronMine => Box::new(ExtractionStrategy::new(IronOre, 5000)),
+        StoneQuarry => Box::new(ExtractionStrategy::new(Stone, 4000).with_deposit(DepositKind::Stone)),
+        ClayPit => Box::new(ExtractionStrategy::new(Clay, 4000).with_deposit(DepositKind::Clay)),
+        IronMine => Box::new(ExtractionStrategy::new(IronOre, 5000).with_deposit(DepositKind::Iron)),
         WheatField => Box::new(WheatFieldStrategy),
         Fishery => Box::new(FisheryStrategy),
         Mill => Box::new(ProcessingStrategy::new(Wheat, Flour, 5000)),
@@ -371,10 +473,30 @@ pub fn create_production_strategy(kind: BuildingKind) -> Box<dyn ProductionStrat
             ProcessingStrategy::new(Clay, Bricks, 5000)
                 .with_additional_cost(Wood, 1)
         ),
-        Smelter => Box::new(ProcessingStrategy::new(IronOre, IronIngot, 6000)),
+        // плавильня топится углём, затем древесным углём, в крайнем случае дровами
+        Smelter => Box::new(ProcessingStrategy::new(IronOre, IronIngot, 6000).with_fuel(&[Coal, Charcoal, Wood])),
+        CharcoalBurner => Box::new(ProcessingStrategy::new(Wood, Charcoal, 6000)),
+        // кузница: слиток → два инструмента, нужен жар горна
+        Blacksmith => Box::new(ProcessingStrategy::new(IronIngot, Tools, 8000).with_fuel(&[Coal, Charcoal, Wood]).with_output_amount(2)),
+        // цепочки удобств: шерсть → ткань → одежда, хмель → пиво
+        SheepFarm => Box::new(ExtractionStrategy::new(Wool, 6000)),
+        Weaver => Box::new(ProcessingStrategy::new(Wool, Cloth, 5000)),
+        Tailor => Box::new(ProcessingStrategy::new(Cloth, Clothes, 6000)),
+        HopFarm => Box::new(ExtractionStrategy::new(Hops, 6000)),
+        Brewery => Box::new(ProcessingStrategy::new(Hops, Ale, 6000).with_fuel(&[Wood])),
         Forester => Box::new(ForesterStrategy),
+        CoalMine => Box::new(ExtractionStrategy::new(Coal, 5000).with_deposit(DepositKind::Coal)),
+        GoldMine => Box::new(ExtractionStrategy::new(GoldOre, 8000).with_deposit(DepositKind::Gold)),
+        GemMine => Box::new(ExtractionStrategy::new(Gems, 10000).with_deposit(DepositKind::Gems)),
+        SaltWorks => Box::new(ExtractionStrategy::new(Salt, 6000).with_deposit(DepositKind::Salt)),
+        // монетный двор: одна порция руды даёт несколько монет
+        Mint => Box::new(ProcessingStrategy::new(GoldOre, Gold, 6000).with_fuel(&[Coal, Charcoal, Wood]).with_output_amount(8)),
+        Jeweler => Box::new(
+            ProcessingStrategy::new(Gems, Jewelry, 8000)
+                .with_additional_cost(GoldOre, 1)
+        ),
         // Здания без производства
-        Lumberjack | House | Warehouse | ResearchLab => {
+        Lumberjack | House | Warehouse | ResearchLab | ScoutTower | Dock | Tavern | TradingPost => {
             // Возвращаем пустую стратегию (или можно сделать NoOpStrategy)
             Box::new(NoOpStrategy)
         }
@@ -391,7 +513,7 @@ impl ProductionStrategy for NoOpStrategy {
         _building: &Building,
         _warehouses: &mut Vec<WarehouseStore>,
         _world: &mut World,
-        _config: &Config,
+        _ctx: &ProductionContext,
         _weather_multiplier: f32,
         _step_ms: f32,
     ) -> bool {
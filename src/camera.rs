@@ -29,6 +29,11 @@ impl Camera {
         self.pos.y += dy;
     }
 
+    /// Навести камеру на центр тайла (обратное к screen_to_tile_px)
+    pub fn center_on_tile(&mut self, tile: IVec2, atlas: &TileAtlas) {
+        self.pos = tile_center_px(tile, atlas.half_w, atlas.half_h);
+    }
+
     /// Установить зум с ограничениями
     pub fn set_zoom(&mut self, new_zoom: f32, min: f32, max: f32) {
         self.zoom = new_zoom.clamp(min, max);
@@ -41,6 +46,18 @@ impl Camera {
 
 }
 
+// центр тайла в мировых пикселях (обратное к screen_to_tile_px)
+fn tile_center_px(tile: IVec2, half_w: i32, half_h: i32) -> Vec2 {
+    let (a, b) = (half_w as f32, half_h as f32);
+    Vec2::new(a * (tile.x - tile.y) as f32, b * (tile.x + tile.y - 1) as f32)
+}
+
+/// Экранные координаты центра тайла
+pub fn tile_to_screen_px(tile: IVec2, sw: i32, sh: i32, cam_px: Vec2, half_w: i32, half_h: i32, zoom: f32) -> Vec2 {
+    let half_screen = Vec2::new((sw / 2) as f32, (sh / 2) as f32);
+    (tile_center_px(tile, half_w, half_h) - cam_px) * zoom + half_screen
+}
+
 /// Преобразовать экранные координаты в координаты тайла в мире
 pub fn screen_to_tile_px(mx: i32, my: i32, sw: i32, sh: i32, cam_px: Vec2, half_w: i32, half_h: i32, zoom: f32) -> Option<IVec2> {
     // экран -> мир (с учетом zoom и камеры)
@@ -6,6 +6,7 @@ use crate::input::ResolvedInput;
 use crate::input::Config;
 use crate::save;
 use crate::gpu_renderer::GpuRenderer;
+use crate::i18n::{tr, tr_fmt};
 
 /// Trait для команд - инкапсулирует действие, которое можно выполнить
 pub trait Command {
@@ -147,6 +148,44 @@ impl Command for ChangeTaxCommand {
     }
 }
 
+/// Команда отправки разведчиков к тайлу под курсором
+pub struct SendExpeditionCommand;
+
+impl Command for SendExpeditionCommand {
+    fn execute(
+        &self,
+        game_state: &mut GameState,
+        _camera: &mut Camera,
+        _elwt: &EventLoopWindowTarget<()>,
+        _input: &ResolvedInput,
+        _config: &Config,
+        _gpu_renderer: &mut GpuRenderer,
+    ) -> bool {
+        use crate::notifications::{LogCategory, NotificationKind};
+        use crate::scouting;
+        let Some(target) = game_state.hovered_tile else { return false; };
+        let Some(tower) = scouting::free_tower_for(&game_state.buildings, &game_state.expeditions, target) else {
+            game_state.notification_system.add(NotificationKind::Warning {
+                message: tr("notify.no_scout_tower").to_string(),
+            });
+            return true;
+        };
+        let cost = scouting::expedition_cost();
+        if !crate::types::spend_building_cost(&mut game_state.warehouses, &mut game_state.resources, &cost) {
+            game_state.notification_system.add(NotificationKind::Warning {
+                message: tr_fmt("notify.expedition_cost", &[&cost.gold]),
+            });
+            return true;
+        }
+        let expedition = scouting::launch(tower, target);
+        game_state.notification_system.add_event(LogCategory::World, NotificationKind::Info {
+            message: tr_fmt("notify.scouts_sent", &[&expedition.target.x, &expedition.target.y]),
+        }, Some(expedition.target));
+        game_state.expeditions.push(expedition);
+        true
+    }
+}
+
 /// Команда сохранения игры
 pub struct SaveGameCommand;
 
@@ -162,6 +201,7 @@ impl Command for SaveGameCommand {
     ) -> bool {
         match save::save_game(&save::SaveData::from_runtime(
             game_state.seed,
+            game_state.setup,
             &game_state.resources,
             &game_state.buildings,
             camera.pos,
@@ -171,6 +211,13 @@ impl Command for SaveGameCommand {
             &game_state.notification_system,
             &game_state.quest_system,
             &game_state.tutorial_system,
+            &game_state.poi_system,
+            &game_state.expeditions,
+            &game_state.shipping,
+            &game_state.trade,
+            &game_state.contracts,
+            &game_state.scenario,
+            &game_state.advisor,
             &game_state.citizens,
             &game_state.jobs,
             game_state.next_job_id,
@@ -178,6 +225,7 @@ impl Command for SaveGameCommand {
             &game_state.warehouses,
             game_state.population,
             game_state.world_clock_ms,
+            game_state.weather_system.day(),
             game_state.tax_rate,
             game_state.speed_mult,
             game_state.food_policy,
@@ -185,14 +233,14 @@ impl Command for SaveGameCommand {
             Ok(_) => {
                 game_state.notification_system.add(
                     crate::notifications::NotificationKind::Info {
-                        message: "Game saved".to_string(),
+                        message: tr("menu.game_saved").to_string(),
                     }
                 );
             }
             Err(e) => {
                 game_state.notification_system.add(
                     crate::notifications::NotificationKind::Warning {
-                        message: format!("Save error: {}", e),
+                        message: format!("{}: {}", tr("menu.save_error"), e),
                     }
                 );
             }
@@ -215,80 +263,7 @@ impl Command for LoadGameCommand {
         _gpu_renderer: &mut GpuRenderer,
     ) -> bool {
         if let Ok(save) = save::load_game() {
-            game_state.seed = save.seed;
-            game_state.world.reset_noise(game_state.seed);
-            game_state.buildings = save.to_buildings();
-            game_state.buildings_dirty = true;
-            game_state.resources = save.resources;
-            camera.pos = glam::Vec2::new(save.cam_x, save.cam_y);
-            camera.zoom = save.zoom;
-            
-            // Восстанавливаем граждан
-            game_state.citizens = save.citizens;
-            
-            // Восстанавливаем работы
-            game_state.jobs = save.jobs;
-            game_state.next_job_id = save.next_job_id;
-            
-            // Восстанавливаем поленья на земле
-            game_state.logs_on_ground = save.logs_on_ground;
-            
-            // Восстанавливаем склады
-            game_state.warehouses = save.warehouses;
-            
-            // Восстанавливаем население
-            game_state.population = save.population;
-            
-            // Восстанавливаем время игры
-            game_state.world_clock_ms = save.world_clock_ms;
-            
-            // Восстанавливаем экономические параметры
-            game_state.tax_rate = save.tax_rate;
-            game_state.speed_mult = save.speed_mult;
-            game_state.food_policy = save.food_policy;
-            
-            // Восстанавливаем отметку occupied
-            game_state.world.occupied.clear();
-            for b in &game_state.buildings {
-                game_state.world.occupy(b.pos);
-            }
-            
-            // Восстанавливаем деревья
-            game_state.world.trees.clear();
-            game_state.world.removed_trees.clear();
-            for t in &save.trees {
-                game_state.world.trees.insert((t.x, t.y), crate::world::Tree {
-                    stage: t.stage,
-                    age_ms: t.age_ms,
-                });
-            }
-            
-            // Восстанавливаем туман войны (разведанные тайлы)
-            game_state.world.explored_tiles.clear();
-            for &(x, y) in &save.explored_tiles {
-                game_state.world.explored_tiles.insert((x, y));
-            }
-            
-            // Восстанавливаем дороги
-            game_state.world.roads.clear();
-            for &(x, y) in &save.roads {
-                game_state.world.roads.insert((x, y));
-            }
-            
-            // Восстанавливаем системы исследований и уведомлений
-            if let Some(research_system) = save.research_system {
-                game_state.research_system = research_system;
-            } else {
-                // Для старых сохранений создаем новую систему исследований
-                game_state.research_system = crate::research::ResearchSystem::new();
-            }
-            
-            if let Some(notification_system) = save.notification_system {
-                game_state.notification_system = notification_system;
-            } else {
-                // Для старых сохранений создаем новую систему уведомлений
-                game_state.notification_system = crate::notifications::NotificationSystem::new();
-            }
+            save::apply_save(game_state, camera, save);
         }
         false
     }
@@ -348,6 +323,8 @@ impl CommandManager {
         manager.register(input.save_game, SaveGameCommand);
         manager.register(input.load_game, LoadGameCommand);
         
+        manager.register(input.send_expedition, SendExpeditionCommand);
+        
         manager
     }
 }
@@ -17,6 +17,17 @@ pub fn defaults() -> (input::Config, input::InputConfig) {
         happy_variety_bonus: 5,
         happy_house_bonus: 10,
         happy_starving_penalty: -25,
+        happy_salt_bonus: 3,
+        happy_jewelry_bonus: 6,
+        happy_cold_penalty: -12,
+        firewood_per_house: 1,
+        happy_comfort_bonus: 5,
+        health_cold_loss: 20,
+        health_recovery: 10,
+        happy_sick_penalty: -10,
+        tools_speed_mul: 0.8,
+        no_tools_speed_mul: 1.25,
+        tool_wear_chance: 0.1,
         migration_join_threshold: 65.0,
         migration_leave_threshold: 35.0,
         tax_income_base: 0.5,
@@ -35,6 +46,23 @@ pub fn defaults() -> (input::Config, input::InputConfig) {
         upkeep_kiln: 1,
         upkeep_fishery: 1,
         upkeep_smelter: 2,
+        upkeep_coal_mine: 2,
+        upkeep_gold_mine: 3,
+        upkeep_gem_mine: 3,
+        upkeep_salt_works: 2,
+        upkeep_mint: 4,
+        upkeep_jeweler: 3,
+        upkeep_scout_tower: 2,
+        upkeep_dock: 2,
+        upkeep_charcoal_burner: 2,
+        upkeep_blacksmith: 3,
+        upkeep_sheep_farm: 1,
+        upkeep_weaver: 2,
+        upkeep_tailor: 2,
+        upkeep_hop_farm: 1,
+        upkeep_brewery: 2,
+        upkeep_tavern: 3,
+        upkeep_trading_post: 3,
         biome_swamp_thr: 0.10,
         biome_rocky_thr: 0.10,
         biome_swamp_lumberjack_wmul: 1.10,
@@ -43,6 +71,8 @@ pub fn defaults() -> (input::Config, input::InputConfig) {
         biome_rocky_tree_growth_wmul: 1.20,
         biome_meadow_wheat_wmul: 0.95,
         biome_swamp_wheat_wmul: 1.15,
+        chunk_workers: 0,
+        language: "en".into(),
     };
     let input = input::InputConfig {
         move_up: "W".into(),
@@ -63,6 +93,7 @@ pub fn defaults() -> (input::Config, input::InputConfig) {
         load_game: "F9".into(),
         tax_up: "]".into(),
         tax_down: "[".into(),
+        send_expedition: "V".into(),
     };
     (config, input)
 }
@@ -87,8 +118,10 @@ pub fn load_or_create(path: &str) -> Result<(input::Config, input::InputConfig)>
         }
         let parsed: FileCfg = toml::from_str(&data)?;
         // Мягкая миграция старых конфигов: дополним отсутствующие поля значениями по умолчанию
-        let (def_cfg, _def_input) = defaults();
+        let (def_cfg, def_input) = defaults();
         let mut cfg = parsed.config.clone();
+        let mut input = parsed.input.clone();
+        if input.send_expedition.is_empty() { input.send_expedition = def_input.send_expedition; }
         // если новые поля остались нулевыми — подставим дефолты
         if cfg.tax_max <= 0.0 { cfg.tax_max = def_cfg.tax_max; }
         if cfg.tax_step <= 0.0 { cfg.tax_step = def_cfg.tax_step; }
@@ -96,6 +129,17 @@ pub fn load_or_create(path: &str) -> Result<(input::Config, input::InputConfig)>
         if cfg.happy_variety_bonus == 0 { cfg.happy_variety_bonus = def_cfg.happy_variety_bonus; }
         if cfg.happy_house_bonus == 0 { cfg.happy_house_bonus = def_cfg.happy_house_bonus; }
         if cfg.happy_starving_penalty == 0 { cfg.happy_starving_penalty = def_cfg.happy_starving_penalty; }
+        if cfg.happy_salt_bonus == 0 { cfg.happy_salt_bonus = def_cfg.happy_salt_bonus; }
+        if cfg.happy_jewelry_bonus == 0 { cfg.happy_jewelry_bonus = def_cfg.happy_jewelry_bonus; }
+        if cfg.happy_cold_penalty == 0 { cfg.happy_cold_penalty = def_cfg.happy_cold_penalty; }
+        if cfg.firewood_per_house == 0 { cfg.firewood_per_house = def_cfg.firewood_per_house; }
+        if cfg.happy_comfort_bonus == 0 { cfg.happy_comfort_bonus = def_cfg.happy_comfort_bonus; }
+        if cfg.health_cold_loss == 0 { cfg.health_cold_loss = def_cfg.health_cold_loss; }
+        if cfg.health_recovery == 0 { cfg.health_recovery = def_cfg.health_recovery; }
+        if cfg.happy_sick_penalty == 0 { cfg.happy_sick_penalty = def_cfg.happy_sick_penalty; }
+        if cfg.tools_speed_mul <= 0.0 { cfg.tools_speed_mul = def_cfg.tools_speed_mul; }
+        if cfg.no_tools_speed_mul <= 0.0 { cfg.no_tools_speed_mul = def_cfg.no_tools_speed_mul; }
+        if cfg.tool_wear_chance <= 0.0 { cfg.tool_wear_chance = def_cfg.tool_wear_chance; }
         if cfg.migration_join_threshold == 0.0 { cfg.migration_join_threshold = def_cfg.migration_join_threshold; }
         if cfg.migration_leave_threshold == 0.0 { cfg.migration_leave_threshold = def_cfg.migration_leave_threshold; }
         if cfg.tax_income_base == 0.0 { cfg.tax_income_base = def_cfg.tax_income_base; }
@@ -115,6 +159,23 @@ pub fn load_or_create(path: &str) -> Result<(input::Config, input::InputConfig)>
         if cfg.upkeep_kiln == 0 { cfg.upkeep_kiln = def_cfg.upkeep_kiln; }
         if cfg.upkeep_fishery == 0 { cfg.upkeep_fishery = def_cfg.upkeep_fishery; }
         if cfg.upkeep_smelter == 0 { cfg.upkeep_smelter = def_cfg.upkeep_smelter; }
+        if cfg.upkeep_coal_mine == 0 { cfg.upkeep_coal_mine = def_cfg.upkeep_coal_mine; }
+        if cfg.upkeep_gold_mine == 0 { cfg.upkeep_gold_mine = def_cfg.upkeep_gold_mine; }
+        if cfg.upkeep_gem_mine == 0 { cfg.upkeep_gem_mine = def_cfg.upkeep_gem_mine; }
+        if cfg.upkeep_salt_works == 0 { cfg.upkeep_salt_works = def_cfg.upkeep_salt_works; }
+        if cfg.upkeep_mint == 0 { cfg.upkeep_mint = def_cfg.upkeep_mint; }
+        if cfg.upkeep_jeweler == 0 { cfg.upkeep_jeweler = def_cfg.upkeep_jeweler; }
+        if cfg.upkeep_scout_tower == 0 { cfg.upkeep_scout_tower = def_cfg.upkeep_scout_tower; }
+        if cfg.upkeep_dock == 0 { cfg.upkeep_dock = def_cfg.upkeep_dock; }
+        if cfg.upkeep_charcoal_burner == 0 { cfg.upkeep_charcoal_burner = def_cfg.upkeep_charcoal_burner; }
+        if cfg.upkeep_blacksmith == 0 { cfg.upkeep_blacksmith = def_cfg.upkeep_blacksmith; }
+        if cfg.upkeep_sheep_farm == 0 { cfg.upkeep_sheep_farm = def_cfg.upkeep_sheep_farm; }
+        if cfg.upkeep_weaver == 0 { cfg.upkeep_weaver = def_cfg.upkeep_weaver; }
+        if cfg.upkeep_tailor == 0 { cfg.upkeep_tailor = def_cfg.upkeep_tailor; }
+        if cfg.upkeep_hop_farm == 0 { cfg.upkeep_hop_farm = def_cfg.upkeep_hop_farm; }
+        if cfg.upkeep_brewery == 0 { cfg.upkeep_brewery = def_cfg.upkeep_brewery; }
+        if cfg.upkeep_tavern == 0 { cfg.upkeep_tavern = def_cfg.upkeep_tavern; }
+        if cfg.upkeep_trading_post == 0 { cfg.upkeep_trading_post = def_cfg.upkeep_trading_post; }
         // биомы — мягкие дефолты
         if cfg.biome_swamp_thr == 0.0 { cfg.biome_swamp_thr = def_cfg.biome_swamp_thr; }
         if cfg.biome_rocky_thr == 0.0 { cfg.biome_rocky_thr = def_cfg.biome_rocky_thr; }
@@ -124,21 +185,26 @@ pub fn load_or_create(path: &str) -> Result<(input::Config, input::InputConfig)>
         if cfg.biome_rocky_tree_growth_wmul == 0.0 { cfg.biome_rocky_tree_growth_wmul = def_cfg.biome_rocky_tree_growth_wmul; }
         if cfg.biome_meadow_wheat_wmul == 0.0 { cfg.biome_meadow_wheat_wmul = def_cfg.biome_meadow_wheat_wmul; }
         if cfg.biome_swamp_wheat_wmul == 0.0 { cfg.biome_swamp_wheat_wmul = def_cfg.biome_swamp_wheat_wmul; }
-        Ok((cfg, parsed.input))
+        if cfg.language.is_empty() { cfg.language = def_cfg.language; }
+        Ok((cfg, input))
     } else {
         let (config, input) = defaults();
-        #[derive(Serialize)]
-        struct FileCfg<'a> {
-            config: &'a input::Config,
-            input: &'a input::InputConfig,
-        }
-        let toml_text = toml::to_string_pretty(&FileCfg { config: &config, input: &input })?;
-        // Сохраняем в пользовательскую директорию, а не в bundle
-        use crate::resource_path;
-        let user_config_path = resource_path::user_data_dir().join("config.toml");
-        fs::write(&user_config_path, toml_text)?;
+        save(&config, &input)?;
         Ok((config, input))
     }
 }
 
+/// Записать конфиг в config.toml пользовательской директории (не в bundle)
+pub fn save(config: &input::Config, input: &input::InputConfig) -> Result<()> {
+    #[derive(Serialize)]
+    struct FileCfg<'a> {
+        config: &'a input::Config,
+        input: &'a input::InputConfig,
+    }
+    let toml_text = toml::to_string_pretty(&FileCfg { config, input })?;
+    let user_config_path = crate::resource_path::user_data_dir().join("config.toml");
+    fs::write(&user_config_path, toml_text)?;
+    Ok(())
+}
+
 
@@ -289,6 +289,150 @@ impl DeveloperConsole {
                     self.log.push("ERR: usage iron_ingots <±N>".to_string());
                 }
             }
+            "gold_ore" | "goldore" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.gold_ore = resources.gold_ore.saturating_add(delta);
+                        self.log.push(format!("OK: gold_ore += {} -> {}", delta, resources.gold_ore));
+                    } else {
+                        self.log.push("ERR: usage gold_ore <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage gold_ore <±N>".to_string());
+                }
+            }
+            "coal" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.coal = resources.coal.saturating_add(delta);
+                        self.log.push(format!("OK: coal += {} -> {}", delta, resources.coal));
+                    } else {
+                        self.log.push("ERR: usage coal <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage coal <±N>".to_string());
+                }
+            }
+            "salt" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.salt = resources.salt.saturating_add(delta);
+                        self.log.push(format!("OK: salt += {} -> {}", delta, resources.salt));
+                    } else {
+                        self.log.push("ERR: usage salt <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage salt <±N>".to_string());
+                }
+            }
+            "gems" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.gems = resources.gems.saturating_add(delta);
+                        self.log.push(format!("OK: gems += {} -> {}", delta, resources.gems));
+                    } else {
+                        self.log.push("ERR: usage gems <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage gems <±N>".to_string());
+                }
+            }
+            "jewelry" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.jewelry = resources.jewelry.saturating_add(delta);
+                        self.log.push(format!("OK: jewelry += {} -> {}", delta, resources.jewelry));
+                    } else {
+                        self.log.push("ERR: usage jewelry <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage jewelry <±N>".to_string());
+                }
+            }
+            "charcoal" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.charcoal = resources.charcoal.saturating_add(delta);
+                        self.log.push(format!("OK: charcoal += {} -> {}", delta, resources.charcoal));
+                    } else {
+                        self.log.push("ERR: usage charcoal <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage charcoal <±N>".to_string());
+                }
+            }
+            "tools" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.tools = resources.tools.saturating_add(delta);
+                        self.log.push(format!("OK: tools += {} -> {}", delta, resources.tools));
+                    } else {
+                        self.log.push("ERR: usage tools <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage tools <±N>".to_string());
+                }
+            }
+            "wool" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.wool = resources.wool.saturating_add(delta);
+                        self.log.push(format!("OK: wool += {} -> {}", delta, resources.wool));
+                    } else {
+                        self.log.push("ERR: usage wool <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage wool <±N>".to_string());
+                }
+            }
+            "cloth" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.cloth = resources.cloth.saturating_add(delta);
+                        self.log.push(format!("OK: cloth += {} -> {}", delta, resources.cloth));
+                    } else {
+                        self.log.push("ERR: usage cloth <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage cloth <±N>".to_string());
+                }
+            }
+            "clothes" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.clothes = resources.clothes.saturating_add(delta);
+                        self.log.push(format!("OK: clothes += {} -> {}", delta, resources.clothes));
+                    } else {
+                        self.log.push("ERR: usage clothes <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage clothes <±N>".to_string());
+                }
+            }
+            "hops" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.hops = resources.hops.saturating_add(delta);
+                        self.log.push(format!("OK: hops += {} -> {}", delta, resources.hops));
+                    } else {
+                        self.log.push("ERR: usage hops <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage hops <±N>".to_string());
+                }
+            }
+            "ale" => {
+                if let Some(arg) = parts.next() {
+                    if let Ok(delta) = arg.parse::<i32>() {
+                        resources.ale = resources.ale.saturating_add(delta);
+                        self.log.push(format!("OK: ale += {} -> {}", delta, resources.ale));
+                    } else {
+                        self.log.push("ERR: usage ale <±N>".to_string());
+                    }
+                } else {
+                    self.log.push("ERR: usage ale <±N>".to_string());
+                }
+            }
             "set" => {
                 let Some(what) = parts.next() else {
                     self.log.push("ERR: usage set <resource> <N>".to_string());
@@ -427,7 +571,151 @@ impl DeveloperConsole {
                             self.log.push("ERR: usage set iron_ingots <N>".to_string());
                         }
                     }
-                    _ => self.log.push("ERR: unknown resource. Available: wood, gold, stone, clay, bricks, wheat, flour, bread, fish, iron_ore, iron_ingots".to_string()),
+                    "gold_ore" | "goldore" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.gold_ore = val;
+                                self.log.push(format!("OK: gold_ore = {}", resources.gold_ore));
+                            } else {
+                                self.log.push("ERR: usage set gold_ore <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set gold_ore <N>".to_string());
+                        }
+                    }
+                    "coal" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.coal = val;
+                                self.log.push(format!("OK: coal = {}", resources.coal));
+                            } else {
+                                self.log.push("ERR: usage set coal <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set coal <N>".to_string());
+                        }
+                    }
+                    "salt" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.salt = val;
+                                self.log.push(format!("OK: salt = {}", resources.salt));
+                            } else {
+                                self.log.push("ERR: usage set salt <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set salt <N>".to_string());
+                        }
+                    }
+                    "gems" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.gems = val;
+                                self.log.push(format!("OK: gems = {}", resources.gems));
+                            } else {
+                                self.log.push("ERR: usage set gems <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set gems <N>".to_string());
+                        }
+                    }
+                    "jewelry" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.jewelry = val;
+                                self.log.push(format!("OK: jewelry = {}", resources.jewelry));
+                            } else {
+                                self.log.push("ERR: usage set jewelry <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set jewelry <N>".to_string());
+                        }
+                    }
+                    "charcoal" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.charcoal = val;
+                                self.log.push(format!("OK: charcoal = {}", resources.charcoal));
+                            } else {
+                                self.log.push("ERR: usage set charcoal <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set charcoal <N>".to_string());
+                        }
+                    }
+                    "tools" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.tools = val;
+                                self.log.push(format!("OK: tools = {}", resources.tools));
+                            } else {
+                                self.log.push("ERR: usage set tools <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set tools <N>".to_string());
+                        }
+                    }
+                    "wool" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.wool = val;
+                                self.log.push(format!("OK: wool = {}", resources.wool));
+                            } else {
+                                self.log.push("ERR: usage set wool <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set wool <N>".to_string());
+                        }
+                    }
+                    "cloth" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.cloth = val;
+                                self.log.push(format!("OK: cloth = {}", resources.cloth));
+                            } else {
+                                self.log.push("ERR: usage set cloth <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set cloth <N>".to_string());
+                        }
+                    }
+                    "clothes" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.clothes = val;
+                                self.log.push(format!("OK: clothes = {}", resources.clothes));
+                            } else {
+                                self.log.push("ERR: usage set clothes <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set clothes <N>".to_string());
+                        }
+                    }
+                    "hops" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.hops = val;
+                                self.log.push(format!("OK: hops = {}", resources.hops));
+                            } else {
+                                self.log.push("ERR: usage set hops <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set hops <N>".to_string());
+                        }
+                    }
+                    "ale" => {
+                        if let Some(arg) = parts.next() {
+                            if let Ok(val) = arg.parse::<i32>() {
+                                resources.ale = val;
+                                self.log.push(format!("OK: ale = {}", resources.ale));
+                            } else {
+                                self.log.push("ERR: usage set ale <N>".to_string());
+                            }
+                        } else {
+                            self.log.push("ERR: usage set ale <N>".to_string());
+                        }
+                    }
+                    _ => self.log.push("ERR: unknown resource. Available: wood, gold, stone, clay, bricks, wheat, flour, bread, fish, iron_ore, iron_ingots, gold_ore, coal, salt, gems, jewelry, charcoal, tools, wool, cloth, clothes, hops, ale".to_string()),
                 }
             }
             "time" => {
@@ -459,7 +747,7 @@ impl DeveloperConsole {
                         if let (Ok(sw), Ok(rk)) = (arg.parse::<f32>(), arg2.parse::<f32>()) {
                             world.biome_swamp_thr = sw;
                             world.biome_rocky_thr = rk;
-                            world.biomes.clear(); // сбросим кэш
+                            world.recompute_biomes(); // пересчитаем слой биомов
                             self.log.push(format!("OK: biome thresholds set swamp_thr={:.2} rocky_thr={:.2}", sw, rk));
                         } else {
                             self.log.push("ERR: usage biome <swamp_thr rocky_thr|overlay>".to_string());
@@ -0,0 +1,201 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::i18n::tr;
+use crate::quests::QuestSystem;
+use crate::types::{ResourceKind, Resources, WarehouseStore};
+
+// как часто соседи присылают новые предложения (дней) и сколько предложение ждёт ответа
+const OFFER_INTERVAL_DAYS: i32 = 3;
+const OFFER_EXPIRY_DAYS: i32 = 5;
+// репутация у каждого поселения: от вражды до союза
+pub const REPUTATION_MIN: i32 = -100;
+pub const REPUTATION_MAX: i32 = 100;
+// срыв срока бьёт по репутации вдвое сильнее награды и стоит четверть суммы контракта
+const MISSED_REP_MUL: i32 = 2;
+const MISSED_FINE_DIV: i32 = 4;
+
+/// Соседнее NPC-поселение: с ним торгуют по контрактам
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Settlement {
+    pub name: String,
+    pub reputation: i32,
+    // товары, которые поселение заказывает
+    pub wants: Vec<ResourceKind>,
+}
+
+impl Settlement {
+    /// Отношение поселения к игроку по репутации
+    pub fn standing(&self) -> &'static str {
+        match self.reputation {
+            i32::MIN..=-50 => tr("contracts.hostile"),
+            -49..=-10 => tr("contracts.cool"),
+            -9..=19 => tr("contracts.neutral"),
+            20..=59 => tr("contracts.friendly"),
+            _ => tr("contracts.allied"),
+        }
+    }
+}
+
+/// Контракт на поставку: сначала предложение, после принятия — обязательство со сроком
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Contract {
+    pub id: u32,
+    pub settlement: usize,
+    pub kind: ResourceKind,
+    pub amount: i32,
+    // срок поставки после принятия
+    pub days: i32,
+    // для предложения — сколько оно ещё ждёт, для принятого — сколько дней до срока
+    pub days_left: i32,
+    pub reward_gold: i32,
+    pub reward_rep: i32,
+    pub accepted: bool,
+}
+
+/// Что произошло с контрактами за день
+pub enum ContractEvent {
+    Offered(Contract),
+    Fulfilled(Contract),
+    Missed(Contract, i32),
+}
+
+/// Соседние поселения, их репутация и контракты на поставку
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContractSystem {
+    pub settlements: Vec<Settlement>,
+    pub contracts: Vec<Contract>,
+    next_id: u32,
+    offer_timer_days: i32,
+}
+
+impl Default for ContractSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractSystem {
+    pub fn new() -> Self {
+        use ResourceKind::*;
+        let town = |name: &str, wants: &[ResourceKind]| Settlement { name: name.to_string(), reputation: 0, wants: wants.to_vec() };
+        Self {
+            settlements: vec![
+                town("Millhaven", &[Bread, Fish, Ale, Clothes]),
+                town("Stonewick", &[Bricks, Stone, Tools, IronIngot]),
+                town("Eastbrook", &[Wood, Charcoal, Cloth, Salt]),
+            ],
+            contracts: Vec::new(),
+            next_id: 1,
+            offer_timer_days: 1,
+        }
+    }
+
+    /// Надбавка к ценам купца от средней репутации (от -0.1 до +0.1)
+    pub fn price_bonus(&self) -> f32 {
+        if self.settlements.is_empty() { return 0.0; }
+        let avg = self.settlements.iter().map(|s| s.reputation).sum::<i32>() as f32 / self.settlements.len() as f32;
+        avg / 1000.0
+    }
+
+    /// Принять предложение: контракт появляется среди квестов и начинается отсчёт срока
+    pub fn accept(&mut self, id: u32, quest_system: &mut QuestSystem) {
+        let Some(c) = self.contracts.iter_mut().find(|c| c.id == id && !c.accepted) else { return };
+        c.accepted = true;
+        c.days_left = c.days;
+        let town = self.settlements.get(c.settlement).map_or("", |s| s.name.as_str());
+        quest_system.add_contract(c, town);
+    }
+
+    pub fn decline(&mut self, id: u32) {
+        self.contracts.retain(|c| c.id != id || c.accepted);
+    }
+
+    /// Рассвет: поставки по принятым контрактам, сроки, новые предложения.
+    /// Без торгового поста связи с соседями нет
+    pub fn new_day(
+        &mut self,
+        has_post: bool,
+        resources: &mut Resources,
+        warehouses: &mut [WarehouseStore],
+        quest_system: &mut QuestSystem,
+        rng: &mut impl Rng,
+    ) -> Vec<ContractEvent> {
+        let mut events = Vec::new();
+        if !has_post { return events; }
+
+        // обиды понемногу забываются
+        for s in &mut self.settlements {
+            if s.reputation < 0 { s.reputation += 1; }
+        }
+
+        let mut i = 0;
+        while i < self.contracts.len() {
+            let c = &mut self.contracts[i];
+            if !c.accepted {
+                c.days_left -= 1;
+                if c.days_left <= 0 { self.contracts.remove(i); } else { i += 1; }
+                continue;
+            }
+            // поставка целиком, как только товар есть на складах
+            if crate::trade::stock_of(resources, warehouses, c.kind) >= c.amount {
+                crate::trade::take(resources, warehouses, c.kind, c.amount);
+                let c = self.contracts.remove(i);
+                if let Some(s) = self.settlements.get_mut(c.settlement) {
+                    s.reputation = (s.reputation + c.reward_rep).min(REPUTATION_MAX);
+                }
+                quest_system.fulfill_contract(c.id);
+                events.push(ContractEvent::Fulfilled(c));
+                continue;
+            }
+            c.days_left -= 1;
+            quest_system.set_contract_days(c.id, c.days_left);
+            if c.days_left <= 0 {
+                let c = self.contracts.remove(i);
+                let fine = c.reward_gold / MISSED_FINE_DIV;
+                resources.gold -= fine;
+                if let Some(s) = self.settlements.get_mut(c.settlement) {
+                    s.reputation = (s.reputation - c.reward_rep * MISSED_REP_MUL).max(REPUTATION_MIN);
+                }
+                quest_system.fail_contract(c.id);
+                events.push(ContractEvent::Missed(c, fine));
+                continue;
+            }
+            i += 1;
+        }
+
+        self.offer_timer_days -= 1;
+        if self.offer_timer_days <= 0 {
+            self.offer_timer_days = OFFER_INTERVAL_DAYS;
+            if let Some(c) = self.generate_offer(rng) {
+                events.push(ContractEvent::Offered(c.clone()));
+                self.contracts.push(c);
+            }
+        }
+        events
+    }
+
+    // предложение от поселения без текущих контрактов; с ростом репутации заказы крупнее и щедрее
+    fn generate_offer(&mut self, rng: &mut impl Rng) -> Option<Contract> {
+        let free: Vec<usize> = (0..self.settlements.len())
+            .filter(|&i| !self.contracts.iter().any(|c| c.settlement == i))
+            .filter(|&i| self.settlements[i].reputation > REPUTATION_MIN / 2)
+            .collect();
+        if free.is_empty() { return None; }
+        let settlement = free[rng.random_range(0..free.len())];
+        let town = &self.settlements[settlement];
+        if town.wants.is_empty() { return None; }
+        let kind = town.wants[rng.random_range(0..town.wants.len())];
+        let rep = town.reputation.max(0);
+        let amount = rng.random_range(3..=8) * 10 * (100 + rep) / 100;
+        let days = rng.random_range(8..=14);
+        let base = crate::trade::base_price(kind);
+        let reward_gold = amount * base * (150 + rep) / 100;
+        let reward_rep = 5 + amount / 20;
+        let id = self.next_id;
+        self.next_id += 1;
+        Some(Contract { id, settlement, kind, amount, days, days_left: OFFER_EXPIRY_DAYS, reward_gold, reward_rep, accepted: false })
+    }
+}
+
@@ -61,11 +61,37 @@ pub fn handle_keyboard_input(
     if let PhysicalKey::Code(key_code) = key {
         // Escape: отменить выбор здания, закрыть окно исследований или открыть меню паузы
         if key_code == KeyCode::Escape {
+            // Закрыть экран итога сценария и играть дальше
+            if let Some(scenario) = game_state.scenario.as_mut().filter(|sc| sc.result_shown()) {
+                scenario.dismissed = true;
+                game_state.paused = false;
+                return true;
+            }
             // Закрыть окно исследований, если оно открыто
             if game_state.show_research_tree {
                 game_state.show_research_tree = false;
                 return true;
             }
+            // Закрыть окно торговли
+            if game_state.show_trade_panel {
+                game_state.show_trade_panel = false;
+                return true;
+            }
+            // Закрыть окно контрактов
+            if game_state.show_contracts_panel {
+                game_state.show_contracts_panel = false;
+                return true;
+            }
+            // Закрыть журнал квестов
+            if game_state.show_quest_journal {
+                game_state.show_quest_journal = false;
+                return true;
+            }
+            // Закрыть журнал событий
+            if game_state.show_event_log {
+                game_state.show_event_log = false;
+                return true;
+            }
             // Если консоль закрыта и выбрано здание - отменяем выбор
             if !game_state.console.open && game_state.selected_building.is_some() {
                 game_state.selected_building = None;
@@ -85,6 +111,10 @@ pub fn handle_keyboard_input(
         if key_code == KeyCode::KeyT && !game_state.console.open {
             if game_state.research_system.has_research_lab {
                 game_state.show_research_tree = !game_state.show_research_tree;
+                game_state.show_trade_panel = false;
+                game_state.show_contracts_panel = false;
+                game_state.show_quest_journal = false;
+                game_state.show_event_log = false;
                 // Сбрасываем скролл при открытии
                 if game_state.show_research_tree {
                     game_state.research_tree_scroll = 0.0;
@@ -93,8 +123,51 @@ pub fn handle_keyboard_input(
             }
         }
         
+        // Y: открыть/закрыть окно торговли (только если есть торговый пост)
+        if key_code == KeyCode::KeyY && !game_state.console.open
+            && game_state.buildings.iter().any(|b| b.kind == crate::types::BuildingKind::TradingPost) {
+            game_state.show_trade_panel = !game_state.show_trade_panel;
+            game_state.show_research_tree = false;
+            game_state.show_contracts_panel = false;
+            game_state.show_quest_journal = false;
+            game_state.show_event_log = false;
+            return true;
+        }
+        
+        // K: открыть/закрыть окно контрактов (тоже через торговый пост)
+        if key_code == KeyCode::KeyK && !game_state.console.open
+            && game_state.buildings.iter().any(|b| b.kind == crate::types::BuildingKind::TradingPost) {
+            game_state.show_contracts_panel = !game_state.show_contracts_panel;
+            game_state.show_research_tree = false;
+            game_state.show_trade_panel = false;
+            game_state.show_quest_journal = false;
+            game_state.show_event_log = false;
+            return true;
+        }
+        
+        // L: открыть/закрыть журнал квестов
+        if key_code == KeyCode::KeyL && !game_state.console.open {
+            game_state.show_quest_journal = !game_state.show_quest_journal;
+            game_state.show_research_tree = false;
+            game_state.show_trade_panel = false;
+            game_state.show_contracts_panel = false;
+            game_state.show_event_log = false;
+            return true;
+        }
+        
+        // O: открыть/закрыть журнал событий
+        if key_code == KeyCode::KeyO && !game_state.console.open {
+            game_state.show_event_log = !game_state.show_event_log;
+            game_state.show_research_tree = false;
+            game_state.show_trade_panel = false;
+            game_state.show_contracts_panel = false;
+            game_state.show_quest_journal = false;
+            game_state.event_log_view.scroll = 0;
+            return true;
+        }
+        
         // Space: продолжить туториал (если туториал активен и ожидает нажатия)
-        if key_code == KeyCode::Space && !game_state.console.open && game_state.tutorial_system.active {
+        if key_code == KeyCode::Space && !game_state.console.open && game_state.tutorial_system.requires_space() {
             game_state.tutorial_system.handle_space();
             return true;
         }
@@ -187,6 +260,7 @@ pub fn handle_mouse_input(
     button: winit::event::MouseButton,
     state: ElementState,
     game_state: &mut GameState,
+    camera: &mut Camera,
     config: &crate::input::Config,
     gpu_renderer: &mut GpuRenderer,
 ) -> bool {
@@ -196,6 +270,26 @@ pub fn handle_mouse_input(
         if state == ElementState::Pressed {
             game_state.left_mouse_down = true;
             
+            // Экран итога сценария перекрывает всё остальное
+            if game_state.scenario.as_ref().is_some_and(|sc| sc.result_shown()) {
+                match crate::ui_interaction::handle_scenario_result_click(
+                    game_state.cursor_xy,
+                    game_state.width_i32,
+                    game_state.height_i32,
+                    config.ui_scale_base,
+                ) {
+                    Some(crate::ui_interaction::ScenarioResultAction::Continue) => {
+                        if let Some(scenario) = game_state.scenario.as_mut() { scenario.dismissed = true; }
+                        game_state.paused = false;
+                    }
+                    Some(crate::ui_interaction::ScenarioResultAction::MainMenu) => {
+                        game_state.app_state = crate::game_state::AppState::MainMenu;
+                    }
+                    None => {}
+                }
+                return true;
+            }
+            
             // Обработка кликов в окне исследований
             if game_state.show_research_tree {
                 // Обрабатываем клики в дереве исследований
@@ -218,6 +312,84 @@ pub fn handle_mouse_input(
                 return true; // Поглощаем клик, если он в окне исследований
             }
             
+            // Клики в окне торговли
+            if game_state.show_trade_panel {
+                let visible = crate::types::total_resources(&game_state.warehouses, &game_state.resources);
+                let should_close = crate::ui_interaction::handle_trade_panel_click(
+                    game_state.cursor_xy,
+                    game_state.width_i32,
+                    game_state.height_i32,
+                    config.ui_scale_base,
+                    &mut game_state.trade,
+                    &visible,
+                );
+                if should_close {
+                    game_state.show_trade_panel = false;
+                }
+                return true;
+            }
+            
+            // Клики в окне контрактов
+            if game_state.show_contracts_panel {
+                let should_close = crate::ui_interaction::handle_contracts_panel_click(
+                    game_state.cursor_xy,
+                    game_state.width_i32,
+                    game_state.height_i32,
+                    config.ui_scale_base,
+                    &mut game_state.contracts,
+                    &mut game_state.quest_system,
+                );
+                if should_close {
+                    game_state.show_contracts_panel = false;
+                }
+                return true;
+            }
+            
+            // Клики в журнале событий: фильтр разделов и переход к месту события
+            if game_state.show_event_log {
+                let action = crate::ui_interaction::handle_event_log_click(
+                    game_state.cursor_xy,
+                    game_state.width_i32,
+                    game_state.height_i32,
+                    config.ui_scale_base,
+                    &game_state.notification_system,
+                    game_state.event_log_view,
+                );
+                match action {
+                    Some(crate::ui_interaction::EventLogAction::Close) => game_state.show_event_log = false,
+                    Some(crate::ui_interaction::EventLogAction::Filter(filter)) => {
+                        game_state.event_log_view = crate::notifications::LogView { filter, scroll: 0 };
+                    }
+                    Some(crate::ui_interaction::EventLogAction::GoTo(pos)) => {
+                        camera.center_on_tile(pos, &game_state.atlas);
+                        game_state.show_event_log = false;
+                    }
+                    None => {}
+                }
+                return true;
+            }
+            
+            // Клики в журнале квестов: получение награды
+            if game_state.show_quest_journal {
+                let (should_close, claim) = crate::ui_interaction::handle_quest_journal_click(
+                    game_state.cursor_xy,
+                    game_state.width_i32,
+                    game_state.height_i32,
+                    config.ui_scale_base,
+                    &game_state.quest_system,
+                );
+                if let Some(quest) = claim.and_then(|id| game_state.quest_system.claim(id)) {
+                    crate::quests::grant_rewards(&quest.rewards, &mut game_state.resources, &mut game_state.research_system);
+                    game_state.notification_system.add_event(crate::notifications::LogCategory::Quest, crate::notifications::NotificationKind::Info {
+                        message: crate::i18n::tr_fmt("notify.reward_claimed", &[&crate::quests::rewards_label(&quest.rewards)]),
+                    }, None);
+                }
+                if should_close {
+                    game_state.show_quest_journal = false;
+                }
+                return true;
+            }
+            
             if game_state.road_mode {
                 if let Some(tp) = game_state.hovered_tile {
                     let on = !game_state.world.is_road(tp);
@@ -258,6 +430,10 @@ pub fn handle_mouse_input(
                     &mut game_state.show_deposits,
                     &mut game_state.research_system,
                     &mut game_state.show_research_tree,
+                    &mut game_state.show_trade_panel,
+                    &mut game_state.show_contracts_panel,
+                    &mut game_state.show_quest_journal,
+                    &mut game_state.show_event_log,
                 ) {
                     return true;
                 }
@@ -323,6 +499,20 @@ pub fn handle_mouse_wheel(
     game_state: &mut GameState,
     base_scale_k: f32,
 ) {
+    // Журнал событий прокручивается по записям
+    if game_state.show_event_log {
+        let up = match delta {
+            MouseScrollDelta::LineDelta(_, y) => y > 0.0,
+            MouseScrollDelta::PixelDelta(p) => p.y > 0.0,
+        };
+        let s = crate::ui::ui_scale(game_state.height_i32, base_scale_k);
+        let rows = crate::ui::layout_event_log(game_state.width_i32, game_state.height_i32, s).max_rows.max(0) as usize;
+        let view = &mut game_state.event_log_view;
+        let total = game_state.notification_system.entries(view.filter).count();
+        view.scroll = if up { view.scroll.saturating_sub(1) } else { (view.scroll + 1).min(total.saturating_sub(rows)) };
+        return;
+    }
+    
     // Если открыто окно исследований, скроллим его
     if game_state.show_research_tree {
         let scroll_amount = match delta {
@@ -341,14 +531,7 @@ pub fn handle_mouse_wheel(
         let gap_y = (45 * s) as f32;
         
         // Вычисляем максимальный ряд динамически (как в draw_research_tree_gpu)
-        use crate::research::ResearchKind;
-        let mut max_row = 0;
-        for &research_kind in ResearchKind::all() {
-            let (_, row) = research_kind.tree_position();
-            if row > max_row {
-                max_row = row;
-            }
-        }
+        let max_row = crate::research::tree().iter().map(|d| d.row).max().unwrap_or(0);
         // Высота дерева: позиция верхней границы последнего узла + высота узла
         // Позиция последнего узла: max_row * (node_h + gap_y)
         // Нижняя граница последнего узла: max_row * (node_h + gap_y) + node_h
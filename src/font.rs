@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+// Пиксельный шрифт интерфейса: только заглавные (строчные рисуются заглавными),
+// высота 5 пикселей, ширина глифа 1..5. Текст — UTF-8, латиница и кириллица.
+// Все размеры ниже — в пикселях глифа; на экране пиксель глифа = 2 * scale.
+
+/// Высота глифа
+pub const GLYPH_H: i32 = 5;
+/// Наибольшая ширина глифа
+pub const GLYPH_MAX_W: i32 = 5;
+/// Промежуток между глифами
+pub const SPACING: i32 = 1;
+
+/// Ячейка атласа (глиф в левом верхнем углу, остаток прозрачный)
+pub const CELL_W: u32 = 6;
+pub const CELL_H: u32 = 6;
+/// Сетка атласа: ячеек в строке и строк
+pub const ATLAS_COLS: u32 = 16;
+pub const ATLAS_ROWS: u32 = 16;
+
+/// Глифы: строки сверху вниз, '#' — закрашенный пиксель; ширина — длина строки
+const GLYPHS: &[(char, [&str; 5])] = &[
+    (' ', ["...", "...", "...", "...", "..."]),
+    ('0', ["###", "#.#", "#.#", "#.#", "###"]),
+    ('1', [".#.", "##.", ".#.", ".#.", "###"]),
+    ('2', ["###", "..#", "###", "#..", "###"]),
+    ('3', ["###", "..#", ".##", "..#", "###"]),
+    ('4', ["#.#", "#.#", "###", "..#", "..#"]),
+    ('5', ["###", "#..", "###", "..#", "###"]),
+    ('6', ["###", "#..", "###", "#.#", "###"]),
+    ('7', ["###", "..#", ".#.", ".#.", ".#."]),
+    ('8', ["###", "#.#", "###", "#.#", "###"]),
+    ('9', ["###", "#.#", "###", "..#", "###"]),
+    ('A', [".#.", "#.#", "###", "#.#", "#.#"]),
+    ('B', ["##.", "#.#", "##.", "#.#", "##."]),
+    ('C', [".##", "#..", "#..", "#..", ".##"]),
+    ('D', ["##.", "#.#", "#.#", "#.#", "##."]),
+    ('E', ["###", "#..", "##.", "#..", "###"]),
+    ('F', ["###", "#..", "##.", "#..", "#.."]),
+    ('G', [".##", "#..", "#.#", "#.#", ".##"]),
+    ('H', ["#.#", "#.#", "###", "#.#", "#.#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..#", "..#", "..#", "#.#", ".#."]),
+    ('K', ["#.#", "##.", "#..", "##.", "#.#"]),
+    ('L', ["#..", "#..", "#..", "#..", "###"]),
+    ('M', ["#...#", "##.##", "#.#.#", "#...#", "#...#"]),
+    ('N', ["#..#", "##.#", "#.##", "#..#", "#..#"]),
+    ('O', [".#.", "#.#", "#.#", "#.#", ".#."]),
+    ('P', ["##.", "#.#", "##.", "#..", "#.."]),
+    ('Q', [".#.", "#.#", "#.#", "##.", ".##"]),
+    ('R', ["##.", "#.#", "##.", "#.#", "#.#"]),
+    ('S', [".##", "#..", ".#.", "..#", "##."]),
+    ('T', ["###", ".#.", ".#.", ".#.", ".#."]),
+    ('U', ["#.#", "#.#", "#.#", "#.#", ".#."]),
+    ('V', ["#.#", "#.#", "#.#", "#.#", ".#."]),
+    ('W', ["#...#", "#...#", "#.#.#", "##.##", "#...#"]),
+    ('X', ["#.#", "#.#", ".#.", "#.#", "#.#"]),
+    ('Y', ["#.#", "#.#", ".#.", ".#.", ".#."]),
+    ('Z', ["###", "..#", ".#.", "#..", "###"]),
+    ('А', [".#.", "#.#", "###", "#.#", "#.#"]),
+    ('Б', ["###", "#..", "##.", "#.#", "##."]),
+    ('В', ["##.", "#.#", "##.", "#.#", "##."]),
+    ('Г', ["###", "#..", "#..", "#..", "#.."]),
+    ('Д', [".###.", ".#.#.", ".#.#.", "#####", "#...#"]),
+    ('Е', ["###", "#..", "##.", "#..", "###"]),
+    ('Ё', ["#.#", "###", "##.", "#..", "###"]),
+    ('Ж', ["#.#.#", "#.#.#", ".###.", "#.#.#", "#.#.#"]),
+    ('З', ["##.", "..#", ".#.", "..#", "##."]),
+    ('И', ["#..#", "#..#", "#.##", "##.#", "#..#"]),
+    ('Й', [".##.", "#..#", "#.##", "##.#", "#..#"]),
+    ('К', ["#.#", "##.", "#..", "##.", "#.#"]),
+    ('Л', [".##", "#.#", "#.#", "#.#", "#.#"]),
+    ('М', ["#...#", "##.##", "#.#.#", "#...#", "#...#"]),
+    ('Н', ["#.#", "#.#", "###", "#.#", "#.#"]),
+    ('О', [".#.", "#.#", "#.#", "#.#", ".#."]),
+    ('П', ["###", "#.#", "#.#", "#.#", "#.#"]),
+    ('Р', ["##.", "#.#", "##.", "#..", "#.."]),
+    ('С', [".##", "#..", "#..", "#..", ".##"]),
+    ('Т', ["###", ".#.", ".#.", ".#.", ".#."]),
+    ('У', ["#.#", "#.#", ".##", "..#", "##."]),
+    ('Ф', [".###.", "#.#.#", "#.#.#", ".###.", "..#.."]),
+    ('Х', ["#.#", "#.#", ".#.", "#.#", "#.#"]),
+    ('Ц', ["#.#.", "#.#.", "#.#.", "####", "...#"]),
+    ('Ч', ["#.#", "#.#", ".##", "..#", "..#"]),
+    ('Ш', ["#.#.#", "#.#.#", "#.#.#", "#.#.#", "#####"]),
+    ('Щ', ["#.#.#", "#.#.#", "#.#.#", "#####", "....#"]),
+    ('Ъ', ["##..", ".#..", ".##.", ".#.#", ".##."]),
+    ('Ы', ["#...#", "#...#", "##..#", "#.#.#", "##..#"]),
+    ('Ь', ["#..", "#..", "##.", "#.#", "##."]),
+    ('Э', ["##.", "..#", ".##", "..#", "##."]),
+    ('Ю', ["#..#.", "#.#.#", "###.#", "#.#.#", "#..#."]),
+    ('Я', [".##", "#.#", ".##", "#.#", "#.#"]),
+    ('.', [".", ".", ".", ".", "#"]),
+    (',', ["..", "..", "..", ".#", "#."]),
+    (':', [".", "#", ".", "#", "."]),
+    (';', ["..", ".#", "..", ".#", "#."]),
+    ('!', ["#", "#", "#", ".", "#"]),
+    ('?', ["###", "..#", ".##", "...", ".#."]),
+    ('\'', ["#", "#", ".", ".", "."]),
+    ('"', ["#.#", "#.#", "...", "...", "..."]),
+    ('/', ["..#", "..#", ".#.", "#..", "#.."]),
+    ('%', ["##.", "..#", ".#.", "#..", ".##"]),
+    ('*', [".#.", "###", ".#.", "#.#", "..."]),
+    ('-', ["...", "...", "###", "...", "..."]),
+    ('+', ["...", ".#.", "###", ".#.", "..."]),
+    ('=', ["...", "###", "...", "###", "..."]),
+    ('_', ["...", "...", "...", "...", "###"]),
+    ('(', [".#", "#.", "#.", "#.", ".#"]),
+    (')', ["#.", ".#", ".#", ".#", "#."]),
+    ('[', ["##", "#.", "#.", "#.", "##"]),
+    (']', ["##", ".#", ".#", ".#", "##"]),
+    ('<', ["..#", ".#.", "#..", ".#.", "..#"]),
+    ('>', ["#..", ".#.", "..#", ".#.", "#.."]),
+    ('#', ["#.#", "###", "#.#", "###", "#.#"]),
+    ('—', ["....", "....", "####", "....", "...."]),
+];
+
+/// Кернинг: пары, которые сдвигаются на пиксель ближе
+const KERNING: &[(char, char, i32)] = &[
+    ('L', 'T', -1), ('L', 'Y', -1), ('L', 'V', -1), ('L', '\'', -1),
+    ('T', 'A', -1), ('A', 'T', -1), ('Y', 'A', -1), ('A', 'Y', -1), ('V', 'A', -1), ('A', 'V', -1),
+    ('T', '.', -1), ('T', ',', -1), ('F', '.', -1), ('F', ',', -1), ('P', '.', -1), ('Y', '.', -1),
+    ('Г', 'А', -1), ('Т', 'А', -1), ('А', 'Т', -1), ('Г', '.', -1), ('Г', ',', -1), ('Т', '.', -1),
+    ('Р', '.', -1), ('Ь', 'Т', -1), ('Ъ', 'Т', -1),
+];
+
+/// Символ, которым рисуются отсутствующие в шрифте
+const MISSING: char = '?';
+
+/// Символ в том виде, в каком он есть в шрифте (строчные — заглавные,
+/// управляющие — пробел, прочие отсутствующие — '?')
+fn normalize(ch: char) -> char {
+    if ch.is_control() { return ' '; }
+    let upper = ch.to_uppercase().next().unwrap_or(ch);
+    if glyph(upper).is_some() { upper } else { MISSING }
+}
+
+type Rows = [&'static str; 5];
+
+/// Глифы и пары кернинга по символам; индекс строится один раз за запуск
+fn glyph(ch: char) -> Option<&'static Rows> {
+    static INDEX: OnceLock<HashMap<char, &'static Rows>> = OnceLock::new();
+    INDEX.get_or_init(|| GLYPHS.iter().map(|(ch, rows)| (*ch, rows)).collect()).get(&ch).copied()
+}
+
+/// Ширина глифа без промежутка
+pub fn glyph_width(ch: char) -> i32 {
+    glyph(normalize(ch)).map_or(0, |rows| rows[0].len() as i32)
+}
+
+fn kerning(a: char, b: char) -> i32 {
+    static INDEX: OnceLock<HashMap<(char, char), i32>> = OnceLock::new();
+    INDEX.get_or_init(|| KERNING.iter().map(|&(a, b, dx)| ((a, b), dx)).collect()).get(&(a, b)).copied().unwrap_or(0)
+}
+
+/// Позиции глифов строки: символ шрифта и его x (в пикселях глифа)
+pub fn layout(text: &str) -> impl Iterator<Item = (char, i32)> + '_ {
+    let mut x = 0;
+    let mut prev: Option<char> = None;
+    text.chars().map(normalize).map(move |ch| {
+        if let Some(p) = prev { x += kerning(p, ch); }
+        let pos = x;
+        x += glyph_width(ch) + SPACING;
+        prev = Some(ch);
+        (ch, pos)
+    })
+}
+
+/// Ширина строки в пикселях глифа (с промежутком после последнего глифа)
+pub fn measure(text: &str) -> i32 {
+    let mut width = 0;
+    let mut prev: Option<char> = None;
+    for ch in text.chars().map(normalize) {
+        if let Some(p) = prev { width += kerning(p, ch); }
+        width += glyph_width(ch) + SPACING;
+        prev = Some(ch);
+    }
+    width
+}
+
+/// Ширина текста (как в draw_text) на экране при заданном масштабе
+pub fn text_width(text: &str, scale: f32) -> f32 {
+    measure(text) as f32 * 2.0 * scale
+}
+
+/// Разбить текст на строки не шире max_w (экранные пиксели): по словам,
+/// '\n' — принудительный перенос, слишком длинное слово режется по символам
+pub fn wrap(text: &str, max_w: f32, scale: f32) -> Vec<String> {
+    let max_units = ((max_w / (2.0 * scale)) as i32).max(GLYPH_MAX_W + SPACING);
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ').filter(|w| !w.is_empty()) {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if measure(&candidate) <= max_units {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() { lines.push(std::mem::take(&mut line)); }
+            // слово не влезает и в пустую строку — режем
+            for ch in word.chars() {
+                line.push(ch);
+                if measure(&line) > max_units {
+                    line.pop();
+                    lines.push(std::mem::take(&mut line));
+                    line.push(ch);
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Атлас шрифта: RGBA-пиксели и индексы ячеек символов
+pub struct FontAtlas {
+    pub pixels: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub index: HashMap<char, u32>,
+}
+
+/// Нарисовать все глифы в атлас (белым, фон прозрачный)
+pub fn build_atlas() -> FontAtlas {
+    let width = CELL_W * ATLAS_COLS;
+    let height = CELL_H * ATLAS_ROWS;
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+    let mut index = HashMap::new();
+    for (i, (ch, rows)) in GLYPHS.iter().enumerate().take((ATLAS_COLS * ATLAS_ROWS) as usize) {
+        let i = i as u32;
+        let x0 = (i % ATLAS_COLS) * CELL_W;
+        let y0 = (i / ATLAS_COLS) * CELL_H;
+        for (py, row) in rows.iter().enumerate() {
+            for (px, cell) in row.bytes().enumerate() {
+                if cell != b'#' { continue; }
+                let idx = (((y0 + py as u32) * width + x0 + px as u32) * 4) as usize;
+                pixels[idx..idx + 4].copy_from_slice(&[255, 255, 255, 255]);
+            }
+        }
+        index.insert(*ch, i);
+    }
+    FontAtlas { pixels, width, height, index }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // экранная ширина строки при scale = 1
+    fn px(text: &str) -> f32 {
+        text_width(text, 1.0)
+    }
+
+    #[test]
+    fn wrap_breaks_between_words_and_on_newlines() {
+        assert_eq!(wrap("AB CD EF", px("AB CD"), 1.0), ["AB CD", "EF"]);
+        assert_eq!(wrap("AB  CD", px("AB CD"), 1.0), ["AB CD"]);
+        assert_eq!(wrap("AB\nCD", 1000.0, 1.0), ["AB", "CD"]);
+        assert_eq!(wrap("", 1000.0, 1.0), [""]);
+    }
+
+    #[test]
+    fn wrap_cuts_a_word_longer_than_the_line() {
+        let lines = wrap("ABCDEFGH", px("ABC"), 1.0);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| px(l) <= px("ABC")), "{:?}", lines);
+        assert_eq!(lines.concat(), "ABCDEFGH");
+    }
+
+    #[test]
+    fn measure_uses_font_glyphs_and_kerning() {
+        assert_eq!(measure("привет"), measure("ПРИВЕТ"));
+        assert_eq!(measure("@"), measure("?"));
+        assert_eq!(measure("LT"), measure("L") + measure("T") - 1);
+        assert_eq!(measure("\t"), measure(" "));
+    }
+}
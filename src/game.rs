@@ -37,6 +37,23 @@ pub fn simulate(
             BuildingKind::IronMine => {}
             BuildingKind::Smelter => {}
             BuildingKind::ResearchLab => {}
+            BuildingKind::CoalMine => {}
+            BuildingKind::GoldMine => {}
+            BuildingKind::GemMine => {}
+            BuildingKind::SaltWorks => {}
+            BuildingKind::Mint => {}
+            BuildingKind::Jeweler => {}
+            BuildingKind::ScoutTower => {}
+            BuildingKind::Dock => {}
+            BuildingKind::CharcoalBurner => {}
+            BuildingKind::Blacksmith => {}
+            BuildingKind::SheepFarm => {}
+            BuildingKind::Weaver => {}
+            BuildingKind::Tailor => {}
+            BuildingKind::HopFarm => {}
+            BuildingKind::Brewery => {}
+            BuildingKind::Tavern => {}
+            BuildingKind::TradingPost => {}
         }
         // при желании можно применить biome_mod к таймерам производства (пока заглушка)
         let _ = biome_mod;
@@ -52,21 +69,24 @@ pub fn production_weather_wmul(weather: crate::types::WeatherKind, building: Bui
         Clear => 1.0,
         Rain => match building {
             Fishery => 0.85,          // рыбаки быстрее в дождь
-            WheatField => 1.10,       // поле страдает от дождя
-            Lumberjack | StoneQuarry | ClayPit | IronMine => 1.05,
+            WheatField | HopFarm => 1.10, // поля страдают от дождя
+            Lumberjack | StoneQuarry | ClayPit | IronMine | CoalMine | GoldMine | GemMine => 1.05,
+            SaltWorks => 1.20,        // соль не выпаривается в дождь
             Forester => 1.00,         // лесник почти без изменений
-            Mill | Bakery | Kiln | Smelter | House | Warehouse | ResearchLab => 1.00,
+            Mill | Bakery | Kiln | Smelter | House | Warehouse | ResearchLab | Mint | Jeweler | ScoutTower | Dock | CharcoalBurner | Blacksmith
+            | SheepFarm | Weaver | Tailor | Brewery | Tavern | TradingPost => 1.00,
         },
         Fog => match building {
             Forester => 1.02,         // туман мешает меньше
             _ => 1.05,
         },
         Snow => match building {
-            WheatField => 1.30,       // снег сильно бьёт по полю
+            WheatField | HopFarm => 1.30, // снег сильно бьёт по полям
             Fishery => 1.10,
             Forester => 1.15,
-            Lumberjack | StoneQuarry | ClayPit | IronMine => 1.15,
-            Mill | Bakery | Kiln | Smelter | House | Warehouse | ResearchLab => 1.10,
+            Lumberjack | StoneQuarry | ClayPit | IronMine | CoalMine | GoldMine | GemMine | SaltWorks => 1.15,
+            Mill | Bakery | Kiln | Smelter | House | Warehouse | ResearchLab | Mint | Jeweler | ScoutTower | Dock | CharcoalBurner | Blacksmith
+            | SheepFarm | Weaver | Tailor | Brewery | Tavern | TradingPost => 1.10,
         },
     }
 }
@@ -99,35 +119,81 @@ pub fn new_day_feed_and_income(citizens: &mut [Citizen], resources: &mut Resourc
     }
 }
 
-pub fn economy_new_day(citizens: &mut Vec<Citizen>, resources: &mut Resources, warehouses: &mut [WarehouseStore], buildings: &[Building], tax_rate: f32, cfg: &crate::input::Config, policy: FoodPolicy) -> (i32, i32) {
-    // 1) Кормление и фиксация типов еды
-    new_day_feed_and_income(citizens, resources, warehouses, policy);
-    // 2) Пересчёт счастья
-    let has_house_at = |pos: IVec2| -> bool { buildings.iter().any(|b| b.kind == BuildingKind::House && b.pos == pos) };
-    let mut happiness_sum: i32 = 0;
+// Списать единицу товара: сначала со складов, затем из общих запасов
+fn take_one(warehouses: &mut [WarehouseStore], stock: &mut i32, field: fn(&mut WarehouseStore) -> &mut i32) -> bool {
+    if let Some(v) = warehouses.iter_mut().map(field).find(|v| **v > 0) { *v -= 1; return true; }
+    if *stock > 0 { *stock -= 1; return true; }
+    false
+}
+
+// ниже этого здоровья житель болеет: работает вполсилы и недоволен
+pub const SICK_HEALTH: u8 = 50;
+
+/// Отопление на рассвете (до кормления): в холод (зима или снегопад) каждый заселённый дом
+/// сжигает дрова. Жильцы домов, которым не хватило дров, мёрзнут.
+/// Возвращает число нетопленых домов
+pub fn heating_new_day(citizens: &mut [Citizen], resources: &mut Resources, warehouses: &mut [WarehouseStore], buildings: &[Building], cold: bool, cfg: &crate::input::Config) -> i32 {
+    for c in citizens.iter_mut() { c.cold_today = false; }
+    if !cold { return 0; }
+    let mut heated: Vec<IVec2> = Vec::new();
+    let mut unheated = 0;
+    for b in buildings.iter().filter(|b| b.kind == BuildingKind::House) {
+        if !citizens.iter().any(|c| c.home == b.pos) { continue; }
+        let have = warehouses.iter().map(|w| w.wood).sum::<i32>() + resources.wood;
+        if have >= cfg.firewood_per_house {
+            for _ in 0..cfg.firewood_per_house { take_one(warehouses, &mut resources.wood, |w| &mut w.wood); }
+            heated.push(b.pos);
+        } else {
+            unheated += 1;
+        }
+    }
     for c in citizens.iter_mut() {
-        let mut h: i32 = 50;
-        if c.fed_today { h += cfg.happy_feed_bonus; } else { h += cfg.happy_starving_penalty; }
-        if c.last_food_mask & 0b11 == 0b11 { h += cfg.happy_variety_bonus; }
-        // Бонус за дом только если житель сегодня поел — иначе голод нивелирует комфорт жилья
-        if has_house_at(c.home) && c.fed_today { h += cfg.happy_house_bonus; }
-        // простой штраф за высокие налоги перенесём в доход
-        c.happiness = h.clamp(0, 100) as u8;
-        happiness_sum += c.happiness as i32;
-        // обнулим маску раз в день (считаем только последний день)
-        c.last_food_mask = 0;
+        c.cold_today = !heated.contains(&c.home);
     }
-    let pop = citizens.len() as i32;
-    let happiness_avg = if pop > 0 { happiness_sum as f32 / pop as f32 } else { 50.0 };
-    // 3) Налоги (простая формула)
+    unheated
+}
+
+/// Вместимость дома без удобств; каждый недельный товар (одежда, пиво) добавляет место
+pub const HOUSE_BASE_CAPACITY: i32 = 2;
+/// Дни между недельными поставками удобств
+pub const DAYS_PER_WEEK: i32 = 7;
+/// Как далеко от дома может быть таверна, чтобы жильцы ходили туда за пивом
+pub const TAVERN_RADIUS: i32 = 12;
+
+/// Недельное потребление удобств: каждый заселённый дом берёт комплект одежды,
+/// а дома у таверны — ещё и бочонок пива. Уровень удобств задаёт вместимость дома и бонус счастья
+pub fn comfort_new_week(citizens: &[Citizen], resources: &mut Resources, warehouses: &mut [WarehouseStore], buildings: &mut [Building]) {
+    let taverns: Vec<IVec2> = buildings.iter().filter(|b| b.kind == BuildingKind::Tavern).map(|b| b.pos).collect();
+    for b in buildings.iter_mut().filter(|b| b.kind == BuildingKind::House) {
+        let mut comfort = 0;
+        if citizens.iter().any(|c| c.home == b.pos) {
+            if take_one(warehouses, &mut resources.clothes, |w| &mut w.clothes) { comfort += 1; }
+            let near_tavern = taverns.iter().any(|&t| (t - b.pos).abs().max_element() <= TAVERN_RADIUS);
+            if near_tavern && take_one(warehouses, &mut resources.ale, |w| &mut w.ale) { comfort += 1; }
+        }
+        b.comfort = comfort;
+    }
+}
+
+/// Вместимость домов: база, удобства и места от исследований.
+/// Лишних жильцов не выселяем — просто новые не въедут, пока места не освободятся
+pub fn update_house_capacity(buildings: &mut [Building], research_bonus: i32) {
+    for b in buildings.iter_mut().filter(|b| b.kind == BuildingKind::House) {
+        b.capacity = HOUSE_BASE_CAPACITY + b.comfort as i32 + research_bonus;
+    }
+}
+
+/// Налоги за день: монеты с жителя (tax_rate) растут со средним счастьем
+pub fn tax_income(population: i32, happiness_avg: f32, tax_rate: f32, cfg: &crate::input::Config) -> i32 {
     let base = cfg.tax_income_base;
     let scale = cfg.tax_income_happy_scale;
     // Налог теперь в монетах на жителя в день: tax_rate — уже монеты/чел
     let per_cap = tax_rate.max(0.0);
-    let income = (per_cap * (citizens.len() as f32) * (base + scale * (happiness_avg / 100.0))).round() as i32;
-    resources.gold += income.max(0);
+    (per_cap * (population as f32) * (base + scale * (happiness_avg / 100.0))).round() as i32
+}
 
-    // 4) Апкип зданий (простая модель — золотом)
+/// Содержание всех зданий за день (золотом)
+pub fn daily_upkeep(buildings: &[Building], cfg: &crate::input::Config) -> i32 {
     let mut upkeep: i32 = 0;
     for b in buildings.iter() {
         use BuildingKind::*;
@@ -146,12 +212,78 @@ pub fn economy_new_day(citizens: &mut Vec<Citizen>, resources: &mut Resources, w
             Fishery => cfg.upkeep_fishery,
             Smelter => cfg.upkeep_smelter,
             ResearchLab => 10, // Небольшое содержание для лаборатории
+            CoalMine => cfg.upkeep_coal_mine,
+            GoldMine => cfg.upkeep_gold_mine,
+            GemMine => cfg.upkeep_gem_mine,
+            SaltWorks => cfg.upkeep_salt_works,
+            Mint => cfg.upkeep_mint,
+            Jeweler => cfg.upkeep_jeweler,
+            ScoutTower => cfg.upkeep_scout_tower,
+            Dock => cfg.upkeep_dock,
+            CharcoalBurner => cfg.upkeep_charcoal_burner,
+            Blacksmith => cfg.upkeep_blacksmith,
+            SheepFarm => cfg.upkeep_sheep_farm,
+            Weaver => cfg.upkeep_weaver,
+            Tailor => cfg.upkeep_tailor,
+            HopFarm => cfg.upkeep_hop_farm,
+            Brewery => cfg.upkeep_brewery,
+            Tavern => cfg.upkeep_tavern,
+            TradingPost => cfg.upkeep_trading_post,
         };
         upkeep += u;
     }
+    upkeep
+}
+
+pub fn economy_new_day(citizens: &mut Vec<Citizen>, resources: &mut Resources, warehouses: &mut [WarehouseStore], buildings: &[Building], tax_rate: f32, cfg: &crate::input::Config, policy: FoodPolicy) -> (i32, i32) {
+    // 1) Кормление и фиксация типов еды
+    new_day_feed_and_income(citizens, resources, warehouses, policy);
+    // 2) Роскошь: каждый заселённый дом получает за день по единице соли и украшений, если они есть
+    let mut salted: Vec<IVec2> = Vec::new();
+    let mut adorned: Vec<IVec2> = Vec::new();
+    for b in buildings.iter().filter(|b| b.kind == BuildingKind::House) {
+        if !citizens.iter().any(|c| c.home == b.pos) { continue; }
+        if take_one(warehouses, &mut resources.salt, |w| &mut w.salt) { salted.push(b.pos); }
+        if take_one(warehouses, &mut resources.jewelry, |w| &mut w.jewelry) { adorned.push(b.pos); }
+    }
+    // 3) Пересчёт счастья
+    let has_house_at = |pos: IVec2| -> bool { buildings.iter().any(|b| b.kind == BuildingKind::House && b.pos == pos) };
+    let comfort_at = |pos: IVec2| -> u8 { buildings.iter().find(|b| b.kind == BuildingKind::House && b.pos == pos).map_or(0, |b| b.comfort) };
+    let mut happiness_sum: i32 = 0;
+    for c in citizens.iter_mut() {
+        let mut h: i32 = 50;
+        if c.fed_today { h += cfg.happy_feed_bonus; } else { h += cfg.happy_starving_penalty; }
+        if c.last_food_mask & 0b11 == 0b11 { h += cfg.happy_variety_bonus; }
+        // Бонус за дом только если житель сегодня поел — иначе голод нивелирует комфорт жилья
+        if has_house_at(c.home) && c.fed_today { h += cfg.happy_house_bonus; }
+        if c.fed_today && salted.contains(&c.home) { h += cfg.happy_salt_bonus; }
+        if adorned.contains(&c.home) { h += cfg.happy_jewelry_bonus; }
+        if c.cold_today { h += cfg.happy_cold_penalty; }
+        h += cfg.happy_comfort_bonus * comfort_at(c.home) as i32;
+        // здоровье: холод подрывает, тепло и сытость восстанавливают
+        if c.cold_today {
+            c.health = c.health.saturating_sub(cfg.health_cold_loss.clamp(0, 100) as u8);
+        } else if c.fed_today {
+            c.health = (c.health + cfg.health_recovery.clamp(0, 100) as u8).min(100);
+        }
+        if c.health < SICK_HEALTH { h += cfg.happy_sick_penalty; }
+        // простой штраф за высокие налоги перенесём в доход
+        c.happiness = h.clamp(0, 100) as u8;
+        happiness_sum += c.happiness as i32;
+        // обнулим маску раз в день (считаем только последний день)
+        c.last_food_mask = 0;
+    }
+    let pop = citizens.len() as i32;
+    let happiness_avg = if pop > 0 { happiness_sum as f32 / pop as f32 } else { 50.0 };
+    // 4) Налоги (простая формула)
+    let income = tax_income(pop, happiness_avg, tax_rate, cfg);
+    resources.gold += income.max(0);
+
+    // 5) Апкип зданий (простая модель — золотом)
+    let upkeep = daily_upkeep(buildings, cfg);
     resources.gold -= upkeep.max(0);
 
-    // 5) Простая миграция: если достаточно счастья и есть свободные места в домах — прибывает 1 житель.
+    // 6) Простая миграция: если достаточно счастья и есть свободные места в домах — прибывает 1 житель.
     // Если очень низкое счастье — уходит 1 житель.
     use std::collections::HashMap;
     let mut occ: HashMap<IVec2, i32> = HashMap::new();
@@ -183,6 +315,9 @@ pub fn economy_new_day(citizens: &mut Vec<Citizen>, resources: &mut Resources, w
                 manual_workplace: false,
                 happiness: 55,
                 last_food_mask: 0,
+                cold_today: false,
+                health: 100,
+                tool_wear: 0.0,
             });
         }
     } else if happiness_avg < cfg.migration_leave_threshold {
@@ -1,5 +1,5 @@
 use glam::{IVec2, Vec2};
-use rand::Rng;
+use rand::{Rng, rngs::StdRng};
 use crate::types::{
     Building, BuildingKind, Citizen, CitizenState, Job, JobKind, LogItem,
     WarehouseStore,
@@ -7,15 +7,33 @@ use crate::types::{
 use crate::world::World;
 use crate::game;
 use crate::jobs;
-use crate::weather::WeatherSystem;
+use crate::weather::{Season, WeatherSystem};
 use crate::game_state::{GameState, Firefly};
 use crate::building_production;
 use crate::citizen_state;
 use crate::research::ResearchSystem;
-use crate::notifications::{NotificationSystem, NotificationKind};
+use crate::notifications::{LogCategory, NotificationSystem, NotificationKind};
+use crate::poi::{PoiSystem, PoiEvent, PoiKind};
+use crate::scouting::{self, Expedition};
+use crate::shipping::ShippingSystem;
+use crate::trade::TradeSystem;
+use crate::contracts::{ContractEvent, ContractSystem};
+use crate::quests::{QuestEvent, QuestSystem};
+use crate::scenario::ScenarioOutcome;
 use crate::tutorial::TutorialContext;
+use crate::i18n::{tr, tr_fmt};
 
 pub const DAY_LENGTH_MS: f32 = 120_000.0;
+// дальше этого лесоруб деревья не ищет
+pub const LUMBERJACK_RANGE: i32 = 64;
+
+/// Рабочие области зданий: где лесорубы ищут деревья, а добытчики — месторождения
+pub fn work_areas(buildings: &[Building]) -> Vec<(IVec2, i32)> {
+    buildings.iter().filter_map(|b| match b.kind {
+        BuildingKind::Lumberjack => Some((b.pos, LUMBERJACK_RANGE)),
+        kind => building_production::create_production_strategy(kind).work_area(b.pos),
+    }).collect()
+}
 
 /// Главная функция обновления игрового состояния
 pub fn update_game_state(game_state: &mut GameState, frame_ms: f32, config: &crate::input::Config) {
@@ -59,9 +77,16 @@ pub fn update_game_state(game_state: &mut GameState, frame_ms: f32, config: &cra
                 game_state.tax_rate,
                 game_state.food_policy,
                 config,
-                &game_state.weather_system,
+                &mut game_state.weather_system,
                 &mut game_state.research_system,
                 &mut game_state.notification_system,
+                &mut game_state.poi_system,
+                &mut game_state.expeditions,
+                &mut game_state.shipping,
+                &mut game_state.trade,
+                &mut game_state.contracts,
+                &mut game_state.quest_system,
+                &mut game_state.rng,
             );
             accelerated_accumulator -= step_ms;
             if accelerated_accumulator > 10.0 * step_ms {
@@ -77,23 +102,25 @@ pub fn update_game_state(game_state: &mut GameState, frame_ms: f32, config: &cra
     // Обновление уведомлений (используем реальное время, чтобы они не исчезали слишком быстро)
     game_state.notification_system.update(frame_ms);
     
-    // Обновление системы квестов
-    let completed_quests = game_state.quest_system.update(
-        frame_ms,
-        &mut game_state.rng,
-        &game_state.resources,
-        &game_state.warehouses,
-        &game_state.buildings,
-        game_state.population,
-    );
-    
-    // Выдаем награды за выполненные квесты
-    for quest in completed_quests {
-        game_state.resources.gold += quest.reward_gold;
-        game_state.notification_system.add(NotificationKind::Info {
-            message: format!("Quest completed! +{} gold", quest.reward_gold),
-        });
-    }
+    // Обновление системы квестов (награды игрок забирает в журнале квестов)
+    let total_res = crate::types::total_resources(&game_state.warehouses, &game_state.resources);
+    let happiness = if game_state.citizens.is_empty() {
+        0
+    } else {
+        game_state.citizens.iter().map(|c| c.happiness as i32).sum::<i32>() / game_state.citizens.len() as i32
+    };
+    let quest_ctx = crate::quests::QuestContext {
+        resources: &total_res,
+        warehouses: &game_state.warehouses,
+        buildings: &game_state.buildings,
+        population: game_state.population,
+        happiness,
+        world: &game_state.world,
+    };
+    let quest_events = game_state.quest_system.update(frame_ms, &mut game_state.rng, &quest_ctx);
+    apply_quest_events(quest_events, &mut game_state.notification_system);
+    update_scenario(game_state, total_res.gold);
+    update_advisor(game_state, config);
     
     // Обновление погоды и светлячков (используем ускоренное время)
     game_state.weather_system.update(accelerated_frame_ms, &mut game_state.rng);
@@ -111,10 +138,15 @@ pub fn update_game_state(game_state: &mut GameState, frame_ms: f32, config: &cra
     
     // Обновление туториала
     if game_state.tutorial_system.active {
-        let tutorial_context = TutorialContext::from_game_state(
-            game_state.ui_category,
-            &game_state.buildings,
-        );
+        let tutorial_context = TutorialContext {
+            current_category: game_state.ui_category,
+            buildings: &game_state.buildings,
+            warehouses: &game_state.warehouses,
+            resources: &total_res,
+            research: &game_state.research_system,
+            world: &game_state.world,
+            population: game_state.citizens.len() as i32,
+        };
         game_state.tutorial_system.update(frame_ms, &tutorial_context);
     }
 }
@@ -206,25 +238,65 @@ pub fn update_game_simulation(
     tax_rate: f32,
     food_policy: crate::types::FoodPolicy,
     config: &crate::input::Config,
-    weather_system: &WeatherSystem,
+    weather_system: &mut WeatherSystem,
     research_system: &mut ResearchSystem,
     notification_system: &mut NotificationSystem,
+    poi_system: &mut PoiSystem,
+    expeditions: &mut Vec<Expedition>,
+    shipping: &mut ShippingSystem,
+    trade: &mut TradeSystem,
+    contracts: &mut ContractSystem,
+    quest_system: &mut QuestSystem,
+    rng: &mut StdRng,
 ) {
-    // Подтянем готовые чанки перед генерацией задач
+    // Подтянем готовые чанки перед генерацией задач (рабочие области зданий не выгружаются)
+    world.work_areas = work_areas(buildings);
     world.integrate_ready_chunks();
     game::simulate(buildings, world, resources, warehouses, step_ms as i32);
-    world.grow_trees(step_ms as i32);
+    // деревья растут быстрее весной и почти стоят зимой
+    world.grow_trees((step_ms * weather_system.season().tree_growth_mul() * research_system.modifiers.tree_growth_mul()) as i32);
     *world_clock_ms = (*world_clock_ms + step_ms) % DAY_LENGTH_MS;
+    notification_system.set_time(weather_system.day(), hour_of(*world_clock_ms));
     
-    // Обновляем область строительства на основе населения
-    world.update_exploration_by_population(buildings, *_population);
+    // Разведчики и туман войны: видимость от зданий и отрядов
+    for target in scouting::update_expeditions(expeditions, buildings, world, step_ms) {
+        notification_system.add_event(LogCategory::World, NotificationKind::Info {
+            message: tr_fmt("notify.scouts_back", &[&target.x, &target.y]),
+        }, Some(target));
+    }
+    let scouts: Vec<IVec2> = expeditions.iter().map(|e| e.tile()).collect();
+    world.update_vision(step_ms as i32, buildings, &scouts);
 
     // День/ночь
     let is_day = is_daytime(*world_clock_ms);
     
     // На рассвете (переход ночь→день) — кормление и доход
     if !*prev_is_day_flag && is_day {
-        let _ = game::economy_new_day(
+        let season_changed = weather_system.new_day();
+        notification_system.set_time(weather_system.day(), hour_of(*world_clock_ms));
+        if season_changed {
+            let season = weather_system.season();
+            let hint = match season {
+                Season::Spring => tr("notify.spring"),
+                Season::Summer => tr("notify.summer"),
+                Season::Autumn => tr("notify.autumn"),
+                Season::Winter => tr("notify.winter"),
+            };
+            notification_system.add_event(LogCategory::World, NotificationKind::Info { message: hint.to_string() }, None);
+        }
+        let unheated = game::heating_new_day(citizens, resources, warehouses, buildings, weather_system.is_cold(), config);
+        if unheated > 0 {
+            notification_system.add(NotificationKind::Warning {
+                message: tr_fmt("notify.unheated", &[&unheated]),
+            });
+        }
+        // раз в неделю дома запасаются одеждой и пивом
+        if weather_system.day() % game::DAYS_PER_WEEK == 0 {
+            game::comfort_new_week(citizens, resources, warehouses, buildings);
+        }
+        game::update_house_capacity(buildings, research_system.modifiers.house_capacity);
+        let population_before = citizens.len();
+        let (income, _upkeep) = game::economy_new_day(
             citizens,
             resources,
             warehouses,
@@ -233,6 +305,29 @@ pub fn update_game_simulation(
             config,
             food_policy,
         );
+        log_population(citizens, population_before, notification_system);
+        // бухгалтерия и прочие исследования увеличивают сбор налогов
+        resources.gold += research_system.modifiers.tax_bonus(income);
+        // купец у торгового поста исполняет приказы на покупку и продажу; репутация у соседей влияет на цены
+        trade.reputation_bonus = contracts.price_bonus();
+        if let Some(report) = trade.new_day(buildings, resources, warehouses, world, rng)
+            && (report.sold > 0 || report.bought > 0) {
+            let post = buildings.iter().find(|b| b.kind == BuildingKind::TradingPost).map(|b| b.pos);
+            notification_system.add_event(LogCategory::Trade, NotificationKind::Info {
+                message: tr_fmt("notify.merchant", &[&report.sold, &report.gold_in, &report.bought, &report.gold_out]),
+            }, post);
+        }
+        // контракты соседних поселений: поставки, сроки, новые предложения
+        let has_post = buildings.iter().any(|b| b.kind == BuildingKind::TradingPost);
+        let events = contracts.new_day(has_post, resources, warehouses, quest_system, rng);
+        apply_contract_events(events, contracts, notification_system);
+        // квесты: суточный прирост и сроки
+        let total_res = crate::types::total_resources(warehouses, resources);
+        let events = quest_system.new_day(&total_res);
+        apply_quest_events(events, notification_system);
+        // волнения после набега бандитов: часть налогов не собрана
+        resources.gold -= poi_system.unrest_tax_loss(income);
+        poi_system.new_day();
 
         citizen_state::handle_dawn_routine_with_states(
             citizens,
@@ -241,45 +336,15 @@ pub fn update_game_simulation(
             jobs,
             is_day,
         );
+
+        // Глина в разработанных карьерах понемногу восстанавливается
+        world.regenerate_clay();
         
-        // Обновление исследований (каждый день)
-        // Проверяем наличие лаборатории
+        // Лаборатории: итог очков за вчера; очередь могла дождаться ресурсов
         research_system.has_research_lab = buildings.iter().any(|b| b.kind == BuildingKind::ResearchLab);
-        
-        // Обновляем прогресс исследования (только если есть лаборатория)
-        if research_system.has_research_lab {
-            if let Some(completed_research) = research_system.update_daily() {
-                let info = completed_research.info();
-                
-                // Добавляем уведомление о завершении исследования
-                notification_system.add(NotificationKind::ResearchCompleted {
-                    name: info.name.to_string(),
-                });
-                
-                // Добавляем уведомления о разблокированных зданиях
-                for &building in info.unlocks_buildings {
-                    let building_name = match building {
-                        BuildingKind::Lumberjack => "Lumberjack",
-                        BuildingKind::House => "House",
-                        BuildingKind::Warehouse => "Warehouse",
-                        BuildingKind::Forester => "Forester",
-                        BuildingKind::StoneQuarry => "Quarry",
-                        BuildingKind::ClayPit => "Clay Pit",
-                        BuildingKind::Kiln => "Kiln",
-                        BuildingKind::WheatField => "Wheat Field",
-                        BuildingKind::Mill => "Mill",
-                        BuildingKind::Bakery => "Bakery",
-                        BuildingKind::Fishery => "Fishery",
-                        BuildingKind::IronMine => "Iron Mine",
-                        BuildingKind::Smelter => "Smelter",
-                        BuildingKind::ResearchLab => "Laboratory",
-                    };
-                    
-                    notification_system.add(NotificationKind::BuildingUnlocked {
-                        name: building_name.to_string(),
-                    });
-                }
-            }
+        research_system.new_day();
+        if let Some(started) = research_system.start_next(resources, warehouses) {
+            notify_research_started(&started, notification_system);
         }
     }
     *prev_is_day_flag = is_day;
@@ -302,10 +367,15 @@ pub fn update_game_simulation(
         adjust_workers_count(citizens, buildings);
     }
 
+    // Учёные в лабораториях набирают очки исследования
+    if is_day {
+        update_research(step_ms, citizens, buildings, research_system, notification_system, resources, warehouses);
+    }
+
     // Генерация и обработка задач
     if is_day {
         generate_lumberjack_jobs(buildings, jobs, next_job_id, world, citizens);
-        generate_haul_jobs(jobs, logs_on_ground, warehouses, next_job_id);
+        generate_haul_jobs(jobs, logs_on_ground, warehouses, world, next_job_id);
         jobs::assign_jobs_nearest_worker(citizens, jobs, world, buildings);
         jobs::process_jobs(
             citizens,
@@ -319,21 +389,193 @@ pub fn update_game_simulation(
         );
     }
 
-    update_citizen_movement(step_ms, citizens, world, warehouses, buildings);
+    update_citizen_movement(step_ms, citizens, world, warehouses, buildings, research_system.modifiers.road_step_mul());
 
     if is_day {
-        update_production(
-            step_ms,
-            citizens,
-            buildings,
-            warehouses,
-            world,
-            weather_system,
+        let ctx = building_production::ProductionContext {
             config,
-        );
+            research: &research_system.modifiers,
+            tools_demand: buildings.iter().any(|b| b.kind == BuildingKind::Blacksmith),
+        };
+        update_production(step_ms, citizens, buildings, warehouses, world, weather_system, &ctx);
+        for (pos, kind) in world.depleted_events.drain(..) {
+            notification_system.add_event(LogCategory::World, NotificationKind::Warning {
+                message: tr_fmt("notify.deposit_exhausted", &[&kind.label(), &pos.x, &pos.y]),
+            }, Some(pos));
+        }
+    }
+
+    // Точки интереса: караваны идут к складу (или первому дому)
+    let home = buildings.iter().find(|b| b.kind == BuildingKind::Warehouse)
+        .or_else(|| buildings.iter().find(|b| b.kind == BuildingKind::House))
+        .map(|b| b.pos)
+        .unwrap_or(IVec2::ZERO);
+    let events = poi_system.update(step_ms as i32, world, home);
+    apply_poi_events(events, resources, research_system, poi_system, notification_system);
+
+    // Паромы: линии между причалами и подвоз запасов между островами
+    shipping.update(step_ms, buildings, warehouses, world);
+}
+
+/// Уведомления о контрактах (золото за выполненный выдаётся как награда квеста)
+fn apply_contract_events(events: Vec<ContractEvent>, contracts: &ContractSystem, notification_system: &mut NotificationSystem) {
+    let town = |i: usize| contracts.settlements.get(i).map_or("?", |s| s.name.as_str()).to_string();
+    for ev in events {
+        match ev {
+            ContractEvent::Offered(c) => notification_system.add_event(LogCategory::Trade, NotificationKind::Info {
+                message: tr_fmt("notify.contract_offered", &[&town(c.settlement), &c.amount, &crate::trade::display_label(c.kind), &c.days, &c.reward_gold]),
+            }, None),
+            ContractEvent::Fulfilled(c) => notification_system.add_event(LogCategory::Trade, NotificationKind::Info {
+                message: tr_fmt("notify.contract_fulfilled", &[&town(c.settlement), &c.reward_rep]),
+            }, None),
+            ContractEvent::Missed(c, fine) => notification_system.add_event(LogCategory::Trade, NotificationKind::Warning {
+                message: tr_fmt("notify.contract_missed", &[&town(c.settlement), &(c.reward_rep * 2), &fine]),
+            }, None),
+        }
+    }
+}
+
+/// Уведомления о квестах
+fn apply_quest_events(events: Vec<QuestEvent>, notification_system: &mut NotificationSystem) {
+    for ev in events {
+        match ev {
+            QuestEvent::New(q) => notification_system.add_event(LogCategory::Quest, NotificationKind::Info {
+                message: tr_fmt("notify.quest_new", &[&q.label.title()]),
+            }, None),
+            QuestEvent::Completed(q) => notification_system.add_event(LogCategory::Quest, NotificationKind::Info {
+                message: tr_fmt("notify.quest_completed", &[&q.label.title(), &crate::quests::rewards_label(&q.rewards)]),
+            }, None),
+            QuestEvent::Failed(q) => notification_system.add_event(LogCategory::Quest, NotificationKind::Warning {
+                message: tr_fmt("notify.quest_failed", &[&q.label.title()]),
+            }, None),
+        }
+    }
+}
+
+/// Сценарий: скриптовые события и проверка победы/поражения (итог ставит игру на паузу)
+fn update_scenario(game_state: &mut GameState, gold: i32) {
+    let day = game_state.weather_system.day();
+    let Some(scenario) = game_state.scenario.as_mut() else { return; };
+    for ev in scenario.due_events(day) {
+        crate::quests::grant_rewards(&ev.rewards, &mut game_state.resources, &mut game_state.research_system);
+        let message = if ev.rewards.is_empty() {
+            ev.display_message().to_string()
+        } else {
+            format!("{} ({})", ev.display_message(), crate::quests::rewards_label(&ev.rewards))
+        };
+        game_state.notification_system.add_event(LogCategory::Quest, NotificationKind::Info { message }, None);
+    }
+    match scenario.check(day, &game_state.quest_system, gold) {
+        Some(ScenarioOutcome::Victory) => {
+            game_state.paused = true;
+            game_state.notification_system.add_event(LogCategory::Quest, NotificationKind::Info {
+                message: tr_fmt("notify.scenario_won", &[&scenario.display_name()]),
+            }, None);
+        }
+        Some(ScenarioOutcome::Defeat(reason)) => {
+            game_state.paused = true;
+            game_state.notification_system.add_event(LogCategory::Quest, NotificationKind::Warning {
+                message: tr_fmt("notify.scenario_lost", &[&reason]),
+            }, None);
+        }
+        None => {}
+    }
+}
+
+/// Советник: раз в день, к полудню, когда рабочие уже на местах, проверяет поселение
+/// и предупреждает о новых проблемах
+fn update_advisor(game_state: &mut GameState, config: &crate::input::Config) {
+    game_state.advisor.forget_missing(&game_state.buildings);
+    let day = game_state.weather_system.day();
+    let clock = game_state.world_clock_ms;
+    if game_state.advisor.last_day == day || !is_daytime(clock) || hour_of(clock) < 12 {
+        return;
+    }
+    let ctx = crate::advisor::AdvisorContext {
+        citizens: &game_state.citizens,
+        buildings: &game_state.buildings,
+        resources: &game_state.resources,
+        warehouses: &game_state.warehouses,
+        world: &game_state.world,
+        research: &game_state.research_system.modifiers,
+        config,
+        tax_rate: game_state.tax_rate,
+    };
+    for (kind, pos) in game_state.advisor.evaluate(day, &ctx) {
+        game_state.notification_system.add_event(LogCategory::Warning, kind, pos);
     }
 }
 
+/// Награды и потери от точек интереса, до которых дошла разведка
+fn apply_poi_events(
+    events: Vec<PoiEvent>,
+    resources: &mut crate::types::Resources,
+    research_system: &mut ResearchSystem,
+    poi_system: &mut PoiSystem,
+    notification_system: &mut NotificationSystem,
+) {
+    for ev in events {
+        let pos = match &ev {
+            PoiEvent::Revealed(p) | PoiEvent::Reached(p) | PoiEvent::CaravanLeft(p) => Some(p.pos),
+        };
+        let message = match ev {
+            PoiEvent::Revealed(p) => match p.kind {
+                PoiKind::BanditCamp => {
+                    notification_system.add_event(LogCategory::Warning, NotificationKind::Warning {
+                        message: tr_fmt("notify.bandits_spotted", &[&p.pos.x, &p.pos.y]),
+                    }, pos);
+                    continue;
+                }
+                _ => tr_fmt("notify.poi_spotted", &[&p.kind.label(), &p.pos.x, &p.pos.y]),
+            },
+            PoiEvent::CaravanLeft(p) => tr_fmt("notify.caravan_left", &[&p.pos.x, &p.pos.y]),
+            PoiEvent::Reached(p) => match p.kind {
+                PoiKind::Cache => {
+                    resources.wood += p.amount;
+                    resources.stone += p.amount / 2;
+                    resources.gold += p.amount;
+                    tr_fmt("notify.cache_found", &[&p.amount, &(p.amount / 2), &p.amount])
+                }
+                PoiKind::Ruins => {
+                    if research_system.advance(p.amount) {
+                        tr_fmt("notify.ruins_scrolls", &[&p.amount])
+                    } else {
+                        // без текущего исследования находки идут на продажу
+                        resources.gold += p.amount * 25;
+                        tr_fmt("notify.ruins_relics", &[&(p.amount * 25)])
+                    }
+                }
+                PoiKind::BanditCamp => {
+                    let stolen = resources.gold.max(0) * p.amount / 100;
+                    resources.gold -= stolen;
+                    poi_system.start_unrest();
+                    notification_system.add_event(LogCategory::Warning, NotificationKind::Warning {
+                        message: tr_fmt("notify.bandit_raid", &[&stolen]),
+                    }, pos);
+                    continue;
+                }
+                PoiKind::Caravan => {
+                    // караван продаёт хлеб по 2 золота, сколько хватит казны
+                    let bread = p.amount.min(resources.gold.max(0) / 2);
+                    if bread > 0 {
+                        resources.gold -= bread * 2;
+                        resources.bread += bread;
+                        tr_fmt("notify.caravan_bread", &[&bread, &(bread * 2)])
+                    } else {
+                        tr("notify.caravan_no_gold").to_string()
+                    }
+                }
+            },
+        };
+        notification_system.add_event(LogCategory::World, NotificationKind::Info { message }, pos);
+    }
+}
+
+/// Час игровых суток (0..24) для отметок в журнале
+fn hour_of(world_clock_ms: f32) -> i32 {
+    (world_clock_ms / DAY_LENGTH_MS * 24.0) as i32 % 24
+}
+
 /// Проверить, сейчас день или ночь
 fn is_daytime(world_clock_ms: f32) -> bool {
     let t = (world_clock_ms / DAY_LENGTH_MS).clamp(0.0, 1.0);
@@ -343,6 +585,72 @@ fn is_daytime(world_clock_ms: f32) -> bool {
 }
 
 
+/// Работа учёных: каждый сытый учёный на месте в лаборатории даёт очки исследования.
+/// Вклад растёт со счастьем и припасами лаборатории, больной работает вполсилы.
+fn update_research(
+    step_ms: f32,
+    citizens: &[Citizen],
+    buildings: &[Building],
+    research_system: &mut ResearchSystem,
+    notification_system: &mut NotificationSystem,
+    resources: &mut crate::types::Resources,
+    warehouses: &mut [WarehouseStore],
+) {
+    if research_system.active_research.is_none() {
+        return;
+    }
+    let mut work_ms = 0.0;
+    for lab in buildings.iter().filter(|b| b.kind == BuildingKind::ResearchLab) {
+        let scholars: Vec<&Citizen> = citizens.iter()
+            .filter(|c| c.workplace == Some(lab.pos) && c.pos == lab.pos && c.state == CitizenState::Working && c.fed_today)
+            .collect();
+        if scholars.is_empty() {
+            continue;
+        }
+        let supplies = 1.0 + research_system.lab_bonus(lab.pos, resources, warehouses) as f32 / 100.0;
+        for c in scholars {
+            let sick = if c.health < game::SICK_HEALTH { 0.5 } else { 1.0 };
+            work_ms += step_ms * (0.5 + c.happiness as f32 / 100.0) * supplies * sick;
+        }
+    }
+    let Some(completed) = research_system.add_work(work_ms) else { return; };
+    notify_research_completed(&completed, notification_system);
+    if let Some(started) = research_system.start_next(resources, warehouses) {
+        notify_research_started(&started, notification_system);
+    }
+}
+
+fn notify_research_started(key: &str, notification_system: &mut NotificationSystem) {
+    notification_system.add_event(LogCategory::Research, NotificationKind::Info {
+        message: tr_fmt("notify.research_started", &[&crate::research::name(key)]),
+    }, None);
+}
+
+/// Миграция за прошедший день — в журнал событий (о голоде предупреждает советник)
+fn log_population(citizens: &[Citizen], before: usize, notification_system: &mut NotificationSystem) {
+    if citizens.len() > before {
+        notification_system.add_event(LogCategory::Population, NotificationKind::Info {
+            message: tr("notify.settler_arrived").to_string(),
+        }, citizens.last().map(|c| c.home));
+    } else if citizens.len() < before {
+        notification_system.add_event(LogCategory::Population, NotificationKind::Warning {
+            message: tr("notify.citizen_left").to_string(),
+        }, None);
+    }
+}
+
+/// Уведомления о завершённом исследовании и открытых им зданиях
+fn notify_research_completed(key: &str, notification_system: &mut NotificationSystem) {
+    notification_system.add(NotificationKind::ResearchCompleted {
+        name: crate::research::name(key).to_string(),
+    });
+    for &building in crate::research::def(key).map_or(&[][..], |d| &d.unlocks_buildings) {
+        notification_system.add(NotificationKind::BuildingUnlocked {
+            name: crate::ui::building_label(building).to_string(),
+        });
+    }
+}
+
 /// Назначить рабочих на здания
 fn assign_workers_to_buildings(
     citizens: &mut Vec<Citizen>,
@@ -485,7 +793,7 @@ fn generate_lumberjack_jobs(
         if let Some(np) = search(24)
             .or_else(|| search(32))
             .or_else(|| search(48))
-            .or_else(|| search(64))
+            .or_else(|| search(LUMBERJACK_RANGE))
         {
             let already = jobs.iter().any(|j| {
                 match j.kind {
@@ -512,6 +820,7 @@ fn generate_haul_jobs(
     jobs: &mut Vec<Job>,
     logs_on_ground: &Vec<LogItem>,
     warehouses: &Vec<WarehouseStore>,
+    world: &World,
     next_job_id: &mut u64,
 ) {
     if warehouses.is_empty() {
@@ -528,7 +837,7 @@ fn generate_haul_jobs(
             }
         });
         if !already {
-            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, li.pos) {
+            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, li.pos) {
                 let id = *next_job_id;
                 *next_job_id += 1;
                 jobs.push(Job {
@@ -543,7 +852,7 @@ fn generate_haul_jobs(
 }
 
 /// Обновить движение граждан
-fn update_citizen_movement(step_ms: f32, citizens: &mut Vec<Citizen>, world: &mut World, warehouses: &mut Vec<WarehouseStore>, buildings: &Vec<Building>) {
+fn update_citizen_movement(step_ms: f32, citizens: &mut Vec<Citizen>, world: &mut World, warehouses: &mut Vec<WarehouseStore>, buildings: &Vec<Building>, road_step_mul: f32) {
     for c in citizens.iter_mut() {
         if !c.moving {
             c.idle_timer_ms += step_ms as i32;
@@ -558,7 +867,7 @@ fn update_citizen_movement(step_ms: f32, citizens: &mut Vec<Citizen>, world: &mu
             handle_arrival_state(c, world, warehouses, buildings);
         } else {
             c.idle_timer_ms = 0;
-            update_movement(step_ms, c, world);
+            update_movement(step_ms, c, world, road_step_mul);
         }
     }
 }
@@ -579,7 +888,7 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
                     crate::game::plan_path(world, c, workplace);
                     c.state = CitizenState::GoingToWork;
                 } else if c.carrying.is_some() {
-                    if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, c.pos) {
+                    if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, c.pos) {
                         crate::game::plan_path(world, c, dst);
                         c.state = CitizenState::GoingToDeposit;
                     }
@@ -626,6 +935,18 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
                         crate::types::ResourceKind::Gold => warehouse.gold += amount,
                         crate::types::ResourceKind::IronOre => warehouse.iron_ore += amount,
                         crate::types::ResourceKind::IronIngot => warehouse.iron_ingots += amount,
+                        crate::types::ResourceKind::GoldOre => warehouse.gold_ore += amount,
+                        crate::types::ResourceKind::Coal => warehouse.coal += amount,
+                        crate::types::ResourceKind::Salt => warehouse.salt += amount,
+                        crate::types::ResourceKind::Gems => warehouse.gems += amount,
+                        crate::types::ResourceKind::Jewelry => warehouse.jewelry += amount,
+                        crate::types::ResourceKind::Charcoal => warehouse.charcoal += amount,
+                        crate::types::ResourceKind::Tools => warehouse.tools += amount,
+                        crate::types::ResourceKind::Wool => warehouse.wool += amount,
+                        crate::types::ResourceKind::Cloth => warehouse.cloth += amount,
+                        crate::types::ResourceKind::Clothes => warehouse.clothes += amount,
+                        crate::types::ResourceKind::Hops => warehouse.hops += amount,
+                        crate::types::ResourceKind::Ale => warehouse.ale += amount,
                     }
                     c.carrying = None;
                     
@@ -639,7 +960,7 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
                 } else {
                     // Если не нашли склад, но гражданин не двигается, попробуем найти склад снова
                     if !c.moving {
-                        if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, c.pos) {
+                        if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, c.pos) {
                             crate::game::plan_path(world, c, dst);
                         } else {
                         }
@@ -693,7 +1014,7 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
                     }
                 } else if !c.moving {
                     // Если не нашли склад, но гражданин не двигается, попробуем найти склад снова
-                    if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, c.pos) {
+                    if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, c.pos) {
                         crate::game::plan_path(world, c, dst);
                     } else {
                         // Если складов нет, возвращаемся на рабочее место
@@ -731,7 +1052,7 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
                             // Ничего не делаем, производство обработается в update_production
                         } else {
                             // Это выходной ресурс или работник не на рабочем месте - отправляем на склад
-                            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, c.pos) {
+                            if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, c.pos) {
                                 crate::game::plan_path(world, c, dst);
                                 c.state = CitizenState::GoingToDeposit;
                                 return;
@@ -763,7 +1084,7 @@ fn handle_arrival_state(c: &mut Citizen, world: &mut World, warehouses: &mut Vec
 }
 
 /// Обновить движение гражданина по пути
-fn update_movement(step_ms: f32, c: &mut Citizen, world: &mut World) {
+fn update_movement(step_ms: f32, c: &mut Citizen, world: &mut World, road_step_mul: f32) {
     // если дорога пустая — идём к следующей точке пути
     if c.pos == c.target {
         // достигнута вершина пути
@@ -776,11 +1097,21 @@ fn update_movement(step_ms: f32, c: &mut Citizen, world: &mut World) {
             c.progress = 0.0;
         }
     } else {
-        // запрет: без моста нельзя идти в воду
+        // переправа паромом: от причала прямо к причалу другого берега
+        if world.ferry_partner(c.pos) == Some(c.target) {
+            let len = (c.target - c.pos).as_vec2().length().max(1.0);
+            c.progress += step_ms / (len * crate::shipping::FERRY_MS_PER_TILE);
+            if c.progress >= 1.0 {
+                c.pos = c.target;
+                c.progress = 0.0;
+            }
+            return;
+        }
+        // запрет: без моста нельзя идти в глубокую воду и в горы
         {
             use crate::types::TileKind::*;
             let k = world.get_tile(c.target.x, c.target.y);
-            if matches!(k, Water) && !world.is_road(c.target) {
+            if matches!(k, Water | Mountain) && !world.is_road(c.target) {
                 c.moving = false;
                 c.progress = 0.0;
                 c.path.clear();
@@ -789,14 +1120,18 @@ fn update_movement(step_ms: f32, c: &mut Citizen, world: &mut World) {
         }
         // скорость шага зависит от целевой клетки
         let step_time_ms: f32 = if world.is_road(c.target) {
-            300.0
+            300.0 * road_step_mul
         } else {
             use crate::types::TileKind::*;
-            match world.get_tile(c.target.x, c.target.y) {
+            let base = match world.get_tile(c.target.x, c.target.y) {
                 Grass => 450.0,
+                Sand => 500.0,
                 Forest => 600.0,
-                Water => 300.0,
-            }
+                Hills => 650.0,
+                ShallowWater => 800.0,
+                Water | Mountain => 300.0,
+            };
+            base + world.slope(c.target) as f32 * 60.0
         };
         c.progress += (step_ms / step_time_ms) as f32;
         if c.progress >= 1.0 {
@@ -814,7 +1149,7 @@ fn update_production(
     warehouses: &mut Vec<WarehouseStore>,
     world: &mut World,
     weather_system: &WeatherSystem,
-    config: &crate::input::Config,
+    ctx: &building_production::ProductionContext,
 ) {
     for c in citizens.iter_mut() {
         if !matches!(c.state, CitizenState::Working) {
@@ -843,12 +1178,23 @@ fn update_production(
             true // Если не несет ресурс, разрешаем производство
         };
         
+        // добытчик с неполной ношей (тачки из исследований) продолжает работать
+        let partial_load = strategy.required_input_resource().is_none()
+            && c.carrying.is_some_and(|(r, _)| strategy.output_resource() == Some(r));
+        
         // Пропускаем граждан, которые несут выходной ресурс (они должны доставить его на склад)
-        if c.carrying.is_some() && !is_input_resource {
+        if c.carrying.is_some() && !is_input_resource && !partial_load {
             continue;
         }
 
-        c.work_timer_ms += step_ms as i32;
+        // зимой поля не растут
+        if matches!(b.kind, BuildingKind::WheatField | BuildingKind::HopFarm) && !weather_system.season().crops_grow() {
+            continue;
+        }
+
+        // больные работают вполсилы
+        let work_ms = if c.health < game::SICK_HEALTH { step_ms * 0.5 } else { step_ms };
+        c.work_timer_ms += work_ms as i32;
         
         // модификатор погоды на скорость циклов производства
         let wmul = {
@@ -856,14 +1202,14 @@ fn update_production(
             use crate::types::BiomeKind::*;
             let bm = world.biome(b.pos);
             let bmul = match (bm, b.kind) {
-                (Swamp, BuildingKind::Lumberjack) => config.biome_swamp_lumberjack_wmul,
-                (Rocky, BuildingKind::StoneQuarry) => config.biome_rocky_stone_wmul,
+                (Swamp, BuildingKind::Lumberjack) => ctx.config.biome_swamp_lumberjack_wmul,
+                (Rocky, BuildingKind::StoneQuarry) => ctx.config.biome_rocky_stone_wmul,
                 _ => 1.00,
             };
-            w * bmul
+            w * bmul * ctx.research.production_time_mul(b.kind)
         };
 
-        handle_building_production(c, b, warehouses, world, config, wmul, step_ms);
+        handle_building_production(c, b, warehouses, world, ctx, wmul, step_ms);
     }
 }
 
@@ -874,11 +1220,11 @@ fn handle_building_production(
     b: &Building,
     warehouses: &mut Vec<WarehouseStore>,
     world: &mut World,
-    config: &crate::input::Config,
+    ctx: &building_production::ProductionContext,
     wmul: f32,
     step_ms: f32,
 ) {
     let strategy = building_production::create_production_strategy(b.kind);
-    strategy.process_production(c, b, warehouses, world, config, wmul, step_ms);
+    strategy.process_production(c, b, warehouses, world, ctx, wmul, step_ms);
 }
 
@@ -0,0 +1,144 @@
+use serde::{Serialize, Deserialize};
+
+use crate::i18n::tr;
+use crate::input::Config;
+use crate::types::Resources;
+use crate::world::MapGenParams;
+
+/// Пресет генерации карты
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MapPreset {
+    #[default]
+    Continental, // материк с озёрами и реками
+    Islands,     // архипелаг, старт на большом острове
+    RiverValley, // мало моря, широкие реки
+    Forested,    // сплошные леса
+}
+
+impl MapPreset {
+    pub const ALL: [MapPreset; 4] = [MapPreset::Continental, MapPreset::Islands, MapPreset::RiverValley, MapPreset::Forested];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MapPreset::Continental => tr("setup.continental"),
+            MapPreset::Islands => tr("setup.islands"),
+            MapPreset::RiverValley => tr("setup.river_valley"),
+            MapPreset::Forested => tr("setup.forests"),
+        }
+    }
+
+    pub fn gen_params(self) -> MapGenParams {
+        let base = MapGenParams::default();
+        match self {
+            MapPreset::Continental => base,
+            MapPreset::Islands => MapGenParams { sea_level: 0.02, land_scale: 1.6, river_width: 0.0, start_lift: 0.35, ..base },
+            MapPreset::RiverValley => MapGenParams { sea_level: -0.3, forest_level: 0.25, river_width: 2.2, ..base },
+            MapPreset::Forested => MapGenParams { sea_level: -0.22, forest_level: 0.02, ..base },
+        }
+    }
+}
+
+/// Обилие месторождений
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Abundance {
+    Poor,
+    #[default]
+    Normal,
+    Rich,
+}
+
+impl Abundance {
+    pub const ALL: [Abundance; 3] = [Abundance::Poor, Abundance::Normal, Abundance::Rich];
+
+    pub fn label(self) -> &'static str {
+        match self { Abundance::Poor => tr("setup.poor"), Abundance::Normal => tr("setup.normal"), Abundance::Rich => tr("setup.rich") }
+    }
+
+    // сдвиг порогов шума месторождений
+    fn deposit_bonus(self) -> f32 {
+        match self { Abundance::Poor => -0.06, Abundance::Normal => 0.0, Abundance::Rich => 0.08 }
+    }
+}
+
+/// Стартовые запасы
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum StartResources {
+    Scarce,
+    #[default]
+    Standard,
+    Plentiful,
+}
+
+impl StartResources {
+    pub const ALL: [StartResources; 3] = [StartResources::Scarce, StartResources::Standard, StartResources::Plentiful];
+
+    pub fn label(self) -> &'static str {
+        match self { StartResources::Scarce => tr("setup.scarce"), StartResources::Standard => tr("setup.standard"), StartResources::Plentiful => tr("setup.plentiful") }
+    }
+
+    pub fn resources(self) -> Resources {
+        match self {
+            StartResources::Scarce => Resources { wood: 30, gold: 100, bread: 5, fish: 5, ..Default::default() },
+            StartResources::Standard => Resources { wood: 60, gold: 200, bread: 10, fish: 10, ..Default::default() },
+            StartResources::Plentiful => Resources { wood: 120, gold: 400, stone: 20, bread: 20, fish: 20, ..Default::default() },
+        }
+    }
+}
+
+/// Сложность: правит экономические параметры конфига
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self { Difficulty::Easy => tr("setup.easy"), Difficulty::Normal => tr("setup.normal"), Difficulty::Hard => tr("setup.hard") }
+    }
+}
+
+/// Настройки новой игры; сохраняются вместе с игрой
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct GameSetup {
+    pub map: MapPreset,
+    pub abundance: Abundance,
+    pub start: StartResources,
+    pub difficulty: Difficulty,
+}
+
+impl GameSetup {
+    pub fn map_params(&self) -> MapGenParams {
+        MapGenParams { deposit_bonus: self.abundance.deposit_bonus(), ..self.map.gen_params() }
+    }
+
+    /// Конфиг с поправками сложности поверх базового (из config.toml)
+    pub fn config(&self, base: &Config) -> Config {
+        let mut cfg = base.clone();
+        match self.difficulty {
+            Difficulty::Easy => {
+                cfg.tax_income_base *= 1.3;
+                cfg.migration_join_threshold -= 10.0;
+                cfg.migration_leave_threshold -= 10.0;
+            }
+            Difficulty::Normal => {}
+            Difficulty::Hard => {
+                cfg.tax_income_base *= 0.75;
+                cfg.migration_join_threshold += 10.0;
+                cfg.migration_leave_threshold += 5.0;
+            }
+        }
+        cfg
+    }
+}
+
+/// Следующее (dir > 0) или предыдущее значение из списка по кругу
+pub fn cycle<T: Copy + PartialEq>(all: &[T], cur: T, dir: i32) -> T {
+    let i = all.iter().position(|&v| v == cur).unwrap_or(0) as i32;
+    all[(i + dir).rem_euclid(all.len() as i32) as usize]
+}
@@ -1,6 +1,6 @@
 use glam::{IVec2, Vec2};
 use crate::types::{
-    Building, BuildingKind, Citizen, Job, LogItem, Resources, WarehouseStore,
+    Building, BuildingKind, Citizen, CitizenState, Job, LogItem, Resources, WarehouseStore,
     FoodPolicy,
 };
 use crate::ui::{UICategory, UITab};
@@ -13,7 +13,15 @@ use crate::music::MusicManager;
 use crate::research::ResearchSystem;
 use crate::notifications::NotificationSystem;
 use crate::quests::QuestSystem;
+use crate::poi::PoiSystem;
+use crate::scouting::Expedition;
+use crate::shipping::ShippingSystem;
+use crate::trade::TradeSystem;
+use crate::contracts::ContractSystem;
+use crate::scenario::{Scenario, ScenarioState};
+use crate::advisor::Advisor;
 use crate::tutorial::TutorialSystem;
+use crate::game_setup::GameSetup;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::time::Instant;
 
@@ -30,6 +38,7 @@ pub struct Firefly {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AppState {
     MainMenu,
+    NewGameSetup,
     Playing,
     Paused,
 }
@@ -50,6 +59,7 @@ pub struct GameState {
     pub population: i32,
     pub world: World,
     pub seed: u64,
+    pub setup: GameSetup, // пресет карты, сложность и пр. (выбраны при создании игры)
     
     // === Экономика ===
     pub tax_rate: f32,
@@ -73,6 +83,11 @@ pub struct GameState {
     pub active_building_panel: Option<IVec2>,
     pub show_research_tree: bool,  // Показать окно дерева исследований
     pub research_tree_scroll: f32, // Позиция скролла для дерева исследований
+    pub show_trade_panel: bool,    // Показать окно торговли
+    pub show_contracts_panel: bool, // Показать окно контрактов
+    pub show_quest_journal: bool,  // Показать журнал квестов
+    pub show_event_log: bool,      // Показать журнал событий
+    pub event_log_view: crate::notifications::LogView, // Раздел и прокрутка журнала событий
     
     // === Дороги ===
     pub road_mode: bool,
@@ -114,6 +129,15 @@ pub struct GameState {
     pub notification_system: NotificationSystem,
     pub quest_system: QuestSystem,
     pub tutorial_system: TutorialSystem,
+    pub poi_system: PoiSystem,
+    pub expeditions: Vec<Expedition>,
+    pub shipping: ShippingSystem,
+    pub trade: TradeSystem,
+    pub contracts: ContractSystem,
+    // сценарий (None — свободная игра)
+    pub scenario: Option<ScenarioState>,
+    // советник: проблемы поселения за последнюю проверку
+    pub advisor: Advisor,
     
     // === Размеры окна ===
     pub width_i32: i32,
@@ -127,8 +151,14 @@ impl GameState {
     /// Создать новое состояние игры с начальными значениями
     pub fn new(rng: &mut StdRng, config: &Config) -> Self {
         let seed = rng.random();
-        let mut world = World::new(seed);
+        Self::with_setup(rng, config, seed, GameSetup::default())
+    }
+    
+    /// Новая игра с заданным seed и настройками из экрана создания игры
+    pub fn with_setup(rng: &mut StdRng, config: &Config, seed: u64, setup: GameSetup) -> Self {
+        let mut world = World::new(seed, crate::world::chunk_worker_threads(config.chunk_workers));
         world.apply_biome_config(config);
+        world.map_gen = setup.map_params();
         
         // Инициализируем начальную область строительства (небольшой радиус вокруг центра)
         // Это позволяет игроку начать строить с самого начала
@@ -151,13 +181,7 @@ impl GameState {
             app_state: AppState::MainMenu,
             
             // Игровое состояние
-            resources: Resources {
-                wood: 60,
-                gold: 200,
-                bread: 10,
-                fish: 10,
-                ..Default::default()
-            },
+            resources: setup.start.resources(),
             buildings: Vec::new(),
             buildings_dirty: true,
             citizens: Vec::new(),
@@ -168,6 +192,7 @@ impl GameState {
             population: 0,
             world,
             seed,
+            setup,
             
             // Экономика
             tax_rate: 2.0,
@@ -191,6 +216,11 @@ impl GameState {
             active_building_panel: None,
             show_research_tree: false,
             research_tree_scroll: 0.0,
+            show_trade_panel: false,
+            show_contracts_panel: false,
+            show_quest_journal: false,
+            show_event_log: false,
+            event_log_view: crate::notifications::LogView::default(),
             
             // Дороги
             road_mode: false,
@@ -232,6 +262,13 @@ impl GameState {
             notification_system: NotificationSystem::new(),
             quest_system: QuestSystem::new(),
             tutorial_system: TutorialSystem::new(),
+            poi_system: PoiSystem::new(),
+            expeditions: Vec::new(),
+            shipping: ShippingSystem::new(),
+            trade: TradeSystem::new(),
+            contracts: ContractSystem::new(),
+            scenario: None,
+            advisor: Advisor::default(),
             
             // Размеры
             width_i32: 1280,
@@ -241,5 +278,38 @@ impl GameState {
             fps_ema: 60.0,
         }
     }
+    
+    /// Применить сценарий к только что созданной игре: запасы, стартовые здания, квесты и цели
+    pub fn start_scenario(&mut self, scenario: &Scenario) {
+        if let Some(resources) = scenario.resources {
+            self.resources = resources;
+        }
+        for sb in &scenario.buildings {
+            let pos = IVec2::new(sb.x, sb.y);
+            if !crate::ui_interaction::building_allowed_at(&mut self.world, sb.kind, pos) {
+                log::warn!("Сценарий {}: {:?} нельзя поставить в ({}, {})", scenario.name, sb.kind, sb.x, sb.y);
+                continue;
+            }
+            self.world.occupy(pos);
+            self.buildings.push(crate::ui_interaction::new_building(sb.kind, pos));
+            match sb.kind {
+                BuildingKind::Warehouse => self.warehouses.push(WarehouseStore { pos, ..Default::default() }),
+                BuildingKind::ResearchLab => self.research_system.has_research_lab = true,
+                BuildingKind::House => {
+                    self.citizens.push(Citizen {
+                        pos, target: pos, moving: false, progress: 0.0, carrying_log: false, assigned_job: None,
+                        idle_timer_ms: 0, home: pos, workplace: None, state: CitizenState::Idle, work_timer_ms: 0,
+                        carrying: None, pending_input: None, path: Vec::new(), path_index: 0, fed_today: true, manual_workplace: false,
+                        happiness: 50, last_food_mask: 0, cold_today: false, health: 100, tool_wear: 0.0,
+                    });
+                    self.population += 1;
+                }
+                _ => {}
+            }
+        }
+        self.buildings_dirty = true;
+        self.quest_system.chain = scenario.quest.clone();
+        self.quest_system.random_quests = scenario.random_quests;
+        self.scenario = Some(ScenarioState::new(scenario, self.weather_system.day()));
+    }
 }
-
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use wgpu::util::DeviceExt;
 use winit::window::Window;
@@ -5,7 +6,7 @@ use glam::{Mat4, Vec2, Vec3};
 use bytemuck::{Pod, Zeroable};
 use anyhow::Result;
 
-use crate::world::World;
+use crate::world::{DepositKind, World, DEPOSIT_MAX};
 use crate::types::{TileKind, WeatherKind, BuildingKind, BiomeKind};
 
 // Структуры для передачи данных в GPU
@@ -762,7 +763,7 @@ pub struct GpuRenderer {
     font_instance_buffer: wgpu::Buffer, // Буфер для font instances
     props_texture_bind_group: Option<wgpu::BindGroup>, // Bind group для props текстуры
     font_texture_bind_group: Option<wgpu::BindGroup>, // Bind group для font текстуры
-    font_char_to_index: [u32; 256], // Маппинг символов на индексы в font atlas
+    font_char_to_index: HashMap<char, u32>, // Маппинг символов на ячейки font atlas
     
     // Клиппинг для UI (x, y, width, height)
     ui_clip_rect: Option<(f32, f32, f32, f32)>,
@@ -1159,74 +1160,15 @@ impl GpuRenderer {
             ],
         }));
         
-        // Создаём bitmap font atlas
-        // Размер глифа: 3x5 пикселей, но мы создадим 4x6 для удобства (с отступом)
-        const GLYPH_WIDTH: u32 = 4;
-        const GLYPH_HEIGHT: u32 = 6;
-        const CHARS_PER_ROW: u32 = 16; // 16 символов в строке
-        const NUM_ROWS: u32 = 16; // 16 строк
-        const FONT_ATLAS_WIDTH: u32 = GLYPH_WIDTH * CHARS_PER_ROW;
-        const FONT_ATLAS_HEIGHT: u32 = GLYPH_HEIGHT * NUM_ROWS;
-        
-        let mut font_atlas_data = vec![0u8; (FONT_ATLAS_WIDTH * FONT_ATLAS_HEIGHT * 4) as usize];
-        
-        // Маппинг символов на индексы в атласе
-        // Используем 0xFFFFFFFF для "не найден", чтобы индекс 0 был валидным
-        let mut font_char_to_index = [0xFFFFFFFFu32; 256];
-        let mut current_index = 0u32;
-        
-        // Функция для добавления глифа в атлас
-        let mut add_glyph = |ch: u8, pattern: [u8; 15]| {
-            if current_index >= (CHARS_PER_ROW * NUM_ROWS) {
-                return;
-            }
-            
-            let row = current_index / CHARS_PER_ROW;
-            let col = current_index % CHARS_PER_ROW;
-            let start_x = col * GLYPH_WIDTH;
-            let start_y = row * GLYPH_HEIGHT;
-            
-            // Рисуем глиф в атласе (масштабируем 3x5 до 4x6 для лучшей читаемости)
-            for py in 0..5 {
-                for px in 0..3 {
-                    if pattern[py * 3 + px] == 1 {
-                        // Рисуем пиксель (белый цвет)
-                        let x = start_x + px as u32;
-                        let y = start_y + py as u32;
-                        let idx = ((y * FONT_ATLAS_WIDTH + x) * 4) as usize;
-                        if idx + 3 < font_atlas_data.len() {
-                            font_atlas_data[idx] = 255; // R
-                            font_atlas_data[idx + 1] = 255; // G
-                            font_atlas_data[idx + 2] = 255; // B
-                            font_atlas_data[idx + 3] = 255; // A
-                        }
-                    }
-                }
-            }
-            
-            font_char_to_index[ch as usize] = current_index;
-            
-            // Также маппим нижний регистр на тот же индекс (если это буква)
-            if ch.is_ascii_uppercase() {
-                font_char_to_index[ch.to_ascii_lowercase() as usize] = current_index;
-            }
-            
-            current_index += 1;
-        };
-        
-        // Добавляем все символы в атлас
-        let chars = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ.,!?'\":/%* ";
-        for &ch in chars.iter() {
-            let pattern = Self::get_glyph_pattern(ch);
-            add_glyph(ch, pattern);
-        }
+        // Атлас пиксельного шрифта (глифы — в font.rs)
+        let font_atlas = crate::font::build_atlas();
         
         // Создаём текстуру шрифта
         let font_texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("Font Atlas Texture"),
             size: wgpu::Extent3d {
-                width: FONT_ATLAS_WIDTH,
-                height: FONT_ATLAS_HEIGHT,
+                width: font_atlas.width,
+                height: font_atlas.height,
                 depth_or_array_layers: 1,
             },
             mip_level_count: 1,
@@ -1244,15 +1186,15 @@ impl GpuRenderer {
                 origin: wgpu::Origin3d::ZERO,
                 aspect: wgpu::TextureAspect::All,
             },
-            &font_atlas_data,
+            &font_atlas.pixels,
             wgpu::ImageDataLayout {
                 offset: 0,
-                bytes_per_row: Some(4 * FONT_ATLAS_WIDTH),
-                rows_per_image: Some(FONT_ATLAS_HEIGHT),
+                bytes_per_row: Some(4 * font_atlas.width),
+                rows_per_image: Some(font_atlas.height),
             },
             wgpu::Extent3d {
-                width: FONT_ATLAS_WIDTH,
-                height: FONT_ATLAS_HEIGHT,
+                width: font_atlas.width,
+                height: font_atlas.height,
                 depth_or_array_layers: 1,
             },
         );
@@ -2230,7 +2172,7 @@ impl GpuRenderer {
             font_instance_buffer,
             props_texture_bind_group,
             font_texture_bind_group,
-            font_char_to_index,
+            font_char_to_index: font_atlas.index,
             menu_background_textures: None,
             ui_clip_rect: None,
         })
@@ -2418,10 +2360,11 @@ impl GpuRenderer {
                 
                 // Проверяем наличие депозитов ресурсов
                 let pos = glam::IVec2::new(mx, my);
-                let has_clay = world.has_clay_deposit(pos);
-                let has_stone = world.has_stone_deposit(pos);
-                let has_iron = world.has_iron_deposit(pos);
+                let deposit = visible_deposit(world, pos);
                 let is_road = world.is_road(pos);
+                // выработанное месторождение (ни в одном слое не осталось запаса)
+                let depleted = deposit.is_none()
+                    && DepositKind::ALL.iter().any(|&k| world.is_depleted(pos, k));
                 
                 let (tile_id, tint) = if is_road {
                     // Дорога - используем спрайт 1:10 из spritesheet.png
@@ -2430,39 +2373,54 @@ impl GpuRenderer {
                     // Но если спрайтшит 11 колонок: 0 * 11 + 9 = 9 (тот же результат)
                     // Используем 9 как базовый индекс для первого спрайта в строке 1, колонка 10
                     (9u32, [1.0, 1.0, 1.0, 1.0]) // белый цвет для дороги
-                } else if show_deposits && (has_clay || has_stone || has_iron) {
+                } else if show_deposits && let Some(kind) = deposit {
                     // Депозит ресурса - используем тайл (6, 5) из spritesheet.png
                     let deposit_tile_id = 61; // тайл (6, 1) - попробуем другой
                     
-                    // Определяем цвет депозита (приоритет: железо > камень > глина)
-                    let deposit_tint = if has_iron {
-                        [0.3, 0.3, 0.3, 1.0] // темно-серый для железа
-                    } else if has_stone {
-                        [0.6, 0.6, 0.6, 1.0] // серый для камня
-                    } else {
-                        [0.8, 0.6, 0.4, 1.0] // коричневый для глины
-                    };
+                    let deposit_tint = deposit_color(kind);
+                    // разведка: чем меньше осталось, тем тусклее
+                    let frac = world.deposit_left(pos, kind) as f32 / DEPOSIT_MAX as f32;
+                    let k = 0.5 + 0.7 * frac.min(1.0);
+                    let deposit_tint = [deposit_tint[0] * k, deposit_tint[1] * k, deposit_tint[2] * k, 1.0];
                     
                     (deposit_tile_id, deposit_tint)
+                } else if depleted && show_deposits {
+                    // выработанное месторождение в режиме разведки - красноватый тон
+                    (61, [0.7, 0.3, 0.25, 1.0])
+                } else if depleted {
+                    // выработка - голая земля
+                    (1, [0.75, 0.65, 0.55, 1.0])
                 } else {
                     // Обычный тайл
+                    // Определяем биом для тинтинга
+                    let biome = world.biome(glam::IVec2::new(mx, my));
+                    
                     let tile_id = match kind {
                         TileKind::Grass => 22,
                         TileKind::Forest => 40, 
                         TileKind::Water => 110,
+                        TileKind::ShallowWater => 110,
+                        TileKind::Sand => 0,
+                        TileKind::Hills if biome == BiomeKind::Rocky => 60, // каменистые холмы
+                        TileKind::Hills => 23,
+                        TileKind::Mountain => 62,
                     };
                     
-                    // Определяем биом для тинтинга
-                    let biome = world.biome(glam::IVec2::new(mx, my));
-                    
                     // Применяем тинт биома (более яркий)
                     let biome_tint = match (kind, biome) {
                         (TileKind::Grass, BiomeKind::Swamp) => [0.4, 0.3, 0.2, 1.0],   // темный коричневый оттенок
                         (TileKind::Grass, BiomeKind::Rocky) => [0.8, 0.8, 0.8, 1.0],   // светлый серый оттенок
                         (TileKind::Forest, BiomeKind::Swamp) => [0.4, 0.3, 0.2, 1.0],  // темный коричневый оттенок для леса
                         (TileKind::Forest, BiomeKind::Rocky) => [0.8, 0.8, 0.8, 1.0],  // светлый серый оттенок для леса
+                        (TileKind::ShallowWater, _) => [1.15, 1.2, 1.1, 1.0],          // мелководье светлее
+                        (TileKind::Sand, _) => [1.3, 1.15, 0.75, 1.0],                 // песок
+                        (TileKind::Hills, BiomeKind::Rocky) => [0.9, 0.9, 0.9, 1.0],
+                        (TileKind::Hills, _) => [0.8, 0.8, 0.55, 1.0],                 // выжженная трава холмов
                         _ => [1.0, 1.0, 1.0, 1.0], // без тинтинга для лугов и воды
                     };
+                    // рельеф: чем выше клетка, тем светлее
+                    let shade = if kind.is_water() { 1.0 } else { 0.85 + world.height(glam::IVec2::new(mx, my)) as f32 * 0.01 };
+                    let biome_tint = [biome_tint[0] * shade, biome_tint[1] * shade, biome_tint[2] * shade, biome_tint[3]];
                     
                     // Подсветка при наведении - желтый тинт поверх биомного
                     let base_tint = if hovered_tile == Some(glam::IVec2::new(mx, my)) {
@@ -2554,11 +2512,17 @@ impl GpuRenderer {
         
         for my in min_ty..=max_ty {
             for mx in min_tx..=max_tx {
-                // Проверяем, разблокирован ли тайл
-                let is_explored = world.is_explored(glam::IVec2::new(mx, my));
+                // Неразведанное — плотный туман, разведанное вне обзора — лёгкая дымка
+                let p = glam::IVec2::new(mx, my);
+                let fog_alpha = if !world.is_explored(p) {
+                    0.75
+                } else if !world.is_visible(p) {
+                    0.25
+                } else {
+                    0.0
+                };
                 
-                // Добавляем туман только для неисследованных тайлов
-                if !is_explored {
+                if fog_alpha > 0.0 {
                     // ИЗОМЕТРИЧЕСКАЯ проекция В ПИКСЕЛЯХ
                     let iso_x = (mx - my) as f32 * half_w;
                     let iso_y = (mx + my) as f32 * half_h;
@@ -2572,7 +2536,7 @@ impl GpuRenderer {
                     self.fog_instances.push(FogInstance {
                         model_matrix: model_matrix.to_cols_array_2d(),
                         fog_id: 0, // всегда 0 для тумана
-                        tint_color: [1.0, 1.0, 1.0, 0.4], // белый цвет с небольшой прозрачностью
+                        tint_color: [1.0, 1.0, 1.0, fog_alpha],
                         padding: [0; 3],
                     });
                     
@@ -2764,6 +2728,11 @@ impl GpuRenderer {
     // Рисует спрайт из props.png по индексу (col + row * cols)
     // props.png имеет сетку спрайтов 5x4 (5 колонок, 4 строки), каждый спрайт 16x16 пикселей
     pub fn draw_ui_props_icon(&mut self, x: f32, y: f32, size: f32, props_index: u32) {
+        self.draw_ui_props_icon_tinted(x, y, size, props_index, [1.0, 1.0, 1.0, 1.0]);
+    }
+
+    // То же с тинтом (перекрашенные иконки для ресурсов без своего спрайта)
+    pub fn draw_ui_props_icon_tinted(&mut self, x: f32, y: f32, size: f32, props_index: u32, tint_color: [f32; 4]) {
         use glam::{Mat4, Vec3};
         
         // Учитываем клиппинг UI: не добавляем инстанс, если он вне видимой области
@@ -2781,101 +2750,33 @@ impl GpuRenderer {
         let instance = UIPropsInstance {
             model_matrix: model_matrix.to_cols_array_2d(),
             props_id: props_index,
-            tint_color: [1.0, 1.0, 1.0, 1.0], // белый цвет по умолчанию
+            tint_color,
             padding: [0; 3],
         };
         
         self.ui_props_instances.push(instance);
     }
     
-    // Bitmap шрифт 3x5 для цифр и букв
-    fn get_glyph_pattern(ch: u8) -> [u8; 15] {
-        // Обрабатываем специальные символы, которые не меняются при to_ascii_uppercase
-        match ch {
-            b'.' => return [0,0,0, 0,0,0, 0,0,0, 0,0,0, 0,1,0],
-            b',' => return [0,0,0, 0,0,0, 0,0,0, 0,1,0, 1,0,0], // запятая (точка + хвостик)
-            b'!' => return [0,1,0, 0,1,0, 0,1,0, 0,0,0, 0,1,0], // вертикальная линия + точка
-            b'?' => return [1,1,1, 0,0,1, 0,1,1, 0,0,0, 0,1,0], // вопросительный знак + точка
-            b'\'' => return [0,1,0, 0,1,0, 0,0,0, 0,0,0, 0,0,0], // одинарная кавычка (верх)
-            b'"' => return [1,0,1, 1,0,1, 0,0,0, 0,0,0, 0,0,0], // двойная кавычка (верх)
-            _ => {}
-        }
-        
-        match ch.to_ascii_uppercase() {
-            b'0' => [1,1,1, 1,0,1, 1,0,1, 1,0,1, 1,1,1],
-            b'1' => [0,1,0, 1,1,0, 0,1,0, 0,1,0, 1,1,1],
-            b'2' => [1,1,1, 0,0,1, 1,1,1, 1,0,0, 1,1,1],
-            b'3' => [1,1,1, 0,0,1, 0,1,1, 0,0,1, 1,1,1],
-            b'4' => [1,0,1, 1,0,1, 1,1,1, 0,0,1, 0,0,1],
-            b'5' => [1,1,1, 1,0,0, 1,1,1, 0,0,1, 1,1,1],
-            b'6' => [1,1,1, 1,0,0, 1,1,1, 1,0,1, 1,1,1],
-            b'7' => [1,1,1, 0,0,1, 0,1,0, 0,1,0, 0,1,0],
-            b'8' => [1,1,1, 1,0,1, 1,1,1, 1,0,1, 1,1,1],
-            b'9' => [1,1,1, 1,0,1, 1,1,1, 0,0,1, 1,1,1],
-            b'A' => [0,1,0, 1,0,1, 1,1,1, 1,0,1, 1,0,1],
-            b'B' => [1,1,0, 1,0,1, 1,1,0, 1,0,1, 1,1,0],
-            b'C' => [0,1,1, 1,0,0, 1,0,0, 1,0,0, 0,1,1],
-            b'D' => [1,1,0, 1,0,1, 1,0,1, 1,0,1, 1,1,0],
-            b'E' => [1,1,1, 1,0,0, 1,1,0, 1,0,0, 1,1,1],
-            b'F' => [1,1,1, 1,0,0, 1,1,0, 1,0,0, 1,0,0],
-            b'G' => [0,1,1, 1,0,0, 1,0,1, 1,0,1, 0,1,1],
-            b'H' => [1,0,1, 1,0,1, 1,1,1, 1,0,1, 1,0,1],
-            b'I' => [1,1,1, 0,1,0, 0,1,0, 0,1,0, 1,1,1],
-            b'J' => [0,0,1, 0,0,1, 0,0,1, 1,0,1, 0,1,0],
-            b'K' => [1,0,1, 1,1,0, 1,0,0, 1,1,0, 1,0,1],
-            b'L' => [1,0,0, 1,0,0, 1,0,0, 1,0,0, 1,1,1],
-            b'M' => [1,0,1, 1,1,1, 1,0,1, 1,0,1, 1,0,1],
-            b'N' => [1,0,1, 1,1,1, 1,1,1, 1,0,1, 1,0,1],
-            b'O' => [0,1,0, 1,0,1, 1,0,1, 1,0,1, 0,1,0],
-            b'P' => [1,1,0, 1,0,1, 1,1,0, 1,0,0, 1,0,0],
-            b'Q' => [0,1,0, 1,0,1, 1,0,1, 1,1,0, 0,1,1],
-            b'R' => [1,1,0, 1,0,1, 1,1,0, 1,0,1, 1,0,1],
-            b'S' => [0,1,1, 1,0,0, 0,1,0, 0,0,1, 1,1,0],
-            b'T' => [1,1,1, 0,1,0, 0,1,0, 0,1,0, 0,1,0],
-            b'U' => [1,0,1, 1,0,1, 1,0,1, 1,0,1, 0,1,0],
-            b'V' => [1,0,1, 1,0,1, 1,0,1, 1,0,1, 0,1,0],
-            b'W' => [1,0,1, 1,0,1, 1,0,1, 1,1,1, 1,0,1],
-            b'X' => [1,0,1, 1,0,1, 0,1,0, 1,0,1, 1,0,1],
-            b'Y' => [1,0,1, 1,0,1, 0,1,0, 0,1,0, 0,1,0],
-            b'Z' => [1,1,1, 0,0,1, 0,1,0, 1,0,0, 1,1,1],
-            b'/' => [0,0,1, 0,0,1, 0,1,0, 1,0,0, 1,0,0],
-            b'%' => [1,1,0, 0,0,1, 0,1,0, 1,0,0, 0,1,1],
-            b':' => [0,0,0, 0,1,0, 0,0,0, 0,1,0, 0,0,0],
-            b'*' => [0,1,0, 1,1,1, 0,1,0, 1,0,1, 0,0,0],
-            _ => [0,0,0, 0,0,0, 0,0,0, 0,0,0, 0,0,0],
-        }
-    }
-    
-    // Рисует один глиф bitmap шрифта 3x5 используя font atlas
-    fn draw_glyph(&mut self, x: f32, y: f32, ch: u8, color: [f32; 4], scale: f32) {
+    // Рисует один глиф пиксельного шрифта из font atlas
+    fn draw_glyph(&mut self, x: f32, y: f32, ch: char, color: [f32; 4], scale: f32) {
         let px = 2.0 * scale; // размер одного пикселя глифа
-        let glyph_width = 3.0 * px;
-        let glyph_height = 5.0 * px;
+        // квад на всю ширину ячейки: узкий глиф дополняется прозрачными пикселями
+        let glyph_width = crate::font::GLYPH_MAX_W as f32 * px;
+        let glyph_height = crate::font::GLYPH_H as f32 * px;
         
         // Быстрая проверка видимости всего глифа
-        if !self.is_rect_visible(x, y, glyph_width, glyph_height) {
-            return;
-        }
-        
-        // Используем font atlas вместо множества прямоугольников
-        // Получаем индекс символа в font atlas
-        let font_index = self.font_char_to_index[ch as usize];
-        
-        // Если символ не найден в атласе (0xFFFFFFFF означает "не найден"), пропускаем
-        if font_index == 0xFFFFFFFF {
+        if ch == ' ' || !self.is_rect_visible(x, y, glyph_width, glyph_height) {
             return;
         }
         
-        // Для пробела пропускаем (не рисуем ничего)
-        if ch == b' ' {
+        // Символы вне атласа пропускаем (font::layout уже заменил их на '?')
+        let Some(&font_index) = self.font_char_to_index.get(&ch) else {
             return;
-        }
+        };
         
         // Используем font atlas - один инстанс вместо множества прямоугольников
         use glam::{Mat4, Vec3};
         
-        // Создаем матрицу трансформации для глифа
-        // Используем реальные размеры глифа (glyph_width x glyph_height), а не квадрат
         let model_matrix = Mat4::from_scale_rotation_translation(
             Vec3::new(glyph_width, glyph_height, 1.0),
             glam::Quat::IDENTITY,
@@ -2893,50 +2794,20 @@ impl GpuRenderer {
     }
     
     // Рисует число (как draw_number в CPU версии)
-    pub fn draw_number(&mut self, x: f32, y: f32, mut n: u32, color: [f32; 4], scale: f32) {
-        let mut digits: [u8; 12] = [0; 12];
-        let mut len = 0;
-        
-        if n == 0 {
-            digits[0] = b'0';
-            len = 1;
-        } else {
-            while n > 0 && len < digits.len() {
-                let d = (n % 10) as u8;
-                n /= 10;
-                digits[len] = b'0' + d;
-                len += 1;
-            }
-        }
-        
-        let px = 2.0 * scale;
-        let char_width = 4.0 * px; // ширина символа с отступом
-        let mut current_x = x;
-        
-        for i in (0..len).rev() {
-            self.draw_glyph(current_x, y, digits[i], color, scale);
-            current_x += char_width;
-        }
+    pub fn draw_number(&mut self, x: f32, y: f32, n: u32, color: [f32; 4], scale: f32) {
+        self.draw_text(x, y, &n.to_string(), color, scale);
     }
     
-    // Рисует текст
-    pub fn draw_text(&mut self, x: f32, y: f32, text: &[u8], color: [f32; 4], scale: f32) {
+    // Рисует текст (символы, которых нет в шрифте, рисуются как '?')
+    pub fn draw_text(&mut self, x: f32, y: f32, text: &str, color: [f32; 4], scale: f32) {
         let px = 2.0 * scale;
-        let char_width = 4.0 * px;
-        let mut current_x = x;
-        
-        for &ch in text {
-            if ch == b' ' {
-                current_x += char_width;
-                continue;
-            }
-            self.draw_glyph(current_x, y, ch, color, scale);
-            current_x += char_width;
+        for (ch, gx) in crate::font::layout(text) {
+            self.draw_glyph(x + gx as f32 * px, y, ch, color, scale);
         }
     }
     
     /// Рисует текст с обводкой (outline)
-    pub fn draw_text_outlined(&mut self, x: f32, y: f32, text: &[u8], outline_color: [f32; 4], fill_color: [f32; 4], scale: f32) {
+    pub fn draw_text_outlined(&mut self, x: f32, y: f32, text: &str, outline_color: [f32; 4], fill_color: [f32; 4], scale: f32) {
         let outline_offset = 1.0 * scale;
         
         // Рисуем обводку (8 направлений)
@@ -2960,12 +2831,12 @@ impl GpuRenderer {
     }
     
     // Рисует кнопку (прямоугольник с текстом)
-    pub fn draw_button(&mut self, x: f32, y: f32, w: f32, h: f32, text: &[u8], active: bool, scale: f32) {
+    pub fn draw_button(&mut self, x: f32, y: f32, w: f32, h: f32, text: &str, active: bool, scale: f32) {
         self.draw_button_disabled(x, y, w, h, text, active, false, scale);
     }
     
     // Рисует кнопку с поддержкой disabled состояния
-    pub fn draw_button_disabled(&mut self, x: f32, y: f32, w: f32, h: f32, text: &[u8], active: bool, disabled: bool, scale: f32) {
+    pub fn draw_button_disabled(&mut self, x: f32, y: f32, w: f32, h: f32, text: &str, active: bool, disabled: bool, scale: f32) {
         // Цвета кнопки
         let bg_color = if disabled {
             // Серый цвет для неактивных кнопок
@@ -2990,7 +2861,7 @@ impl GpuRenderer {
         
         // Текст по центру
         let px = 2.0 * scale;
-        let text_w = text.len() as f32 * 4.0 * px;
+        let text_w = crate::font::text_width(text, scale);
         let text_h = 5.0 * px;
         let text_x = x + (w - text_w) / 2.0;
         let text_y = y + (h - text_h) / 2.0;
@@ -3018,7 +2889,6 @@ impl GpuRenderer {
     ) {
         use crate::types::BuildingKind;
         use glam::{Mat4, IVec2};
-        use std::collections::HashMap;
         
         self.building_instances.clear();
         
@@ -3136,6 +3006,22 @@ impl GpuRenderer {
                         BuildingKind::IronMine => 11,
                         BuildingKind::Smelter => 12,
                         BuildingKind::ResearchLab => 13,
+                        // своих спрайтов пока нет — берём похожие
+                        BuildingKind::CoalMine | BuildingKind::GoldMine | BuildingKind::GemMine => 11,
+                        BuildingKind::SaltWorks => 5,
+                        BuildingKind::Mint => 12,
+                        BuildingKind::Jeweler => 9,
+                        BuildingKind::ScoutTower => 2,
+                        BuildingKind::Dock => 10,
+                        BuildingKind::CharcoalBurner => 6,
+                        BuildingKind::Blacksmith => 12,
+                        BuildingKind::SheepFarm => 7,
+                        BuildingKind::Weaver => 8,
+                        BuildingKind::Tailor => 9,
+                        BuildingKind::HopFarm => 7,
+                        BuildingKind::Brewery => 6,
+                        BuildingKind::Tavern => 0,
+                        BuildingKind::TradingPost => 2,
                     };
                     
                     // Подсветка здания при наведении
@@ -3209,7 +3095,7 @@ impl GpuRenderer {
             let y_offset = -half_h / 3.0;
             
             // Цвет гражданина зависит от места работы
-            let mut col = [255.0/255.0, 230.0/255.0, 120.0/255.0, 1.0]; // желтоватый по умолчанию
+            let mut col = [1.0, 230.0/255.0, 120.0/255.0, 1.0]; // желтоватый по умолчанию
             if let Some(wp) = c.workplace {
                 if let Some(b) = buildings.iter().find(|b| b.pos == wp) {
                     let bcol = building_color(b.kind);
@@ -3346,6 +3232,22 @@ impl GpuRenderer {
             BuildingKind::IronMine => 11,
             BuildingKind::Smelter => 12,
             BuildingKind::ResearchLab => 13,
+            // своих спрайтов пока нет — берём похожие
+            BuildingKind::CoalMine | BuildingKind::GoldMine | BuildingKind::GemMine => 11,
+            BuildingKind::SaltWorks => 5,
+            BuildingKind::Mint => 12,
+            BuildingKind::Jeweler => 9,
+            BuildingKind::ScoutTower => 2,
+            BuildingKind::Dock => 10,
+            BuildingKind::CharcoalBurner => 6,
+            BuildingKind::Blacksmith => 12,
+            BuildingKind::SheepFarm => 7,
+            BuildingKind::Weaver => 8,
+            BuildingKind::Tailor => 9,
+            BuildingKind::HopFarm => 7,
+            BuildingKind::Brewery => 6,
+            BuildingKind::Tavern => 0,
+            BuildingKind::TradingPost => 2,
         };
         
         // Цвет предпросмотра: зеленоватый если можно построить, красноватый если нельзя
@@ -3390,6 +3292,8 @@ impl GpuRenderer {
         &mut self,
         world: &mut crate::world::World,
         buildings: &[crate::types::Building],
+        pois: &[crate::poi::Poi],
+        ships: &[crate::shipping::Ship],
         _cam_x: f32,
         _cam_y: f32,
         minimap_x: i32,
@@ -3441,6 +3345,10 @@ impl GpuRenderer {
                     TileKind::Grass => [0.2, 0.6, 0.2, 1.0], // зеленый
                     TileKind::Forest => [0.1, 0.4, 0.1, 1.0], // темно-зеленый
                     TileKind::Water => [0.2, 0.4, 0.8, 1.0], // синий
+                    TileKind::ShallowWater => [0.3, 0.55, 0.85, 1.0], // голубой
+                    TileKind::Sand => [0.85, 0.8, 0.5, 1.0], // песочный
+                    TileKind::Hills => [0.45, 0.5, 0.3, 1.0], // оливковый
+                    TileKind::Mountain => [0.55, 0.55, 0.55, 1.0], // серый
                 };
                 
                 // Применяем тинт биома (те же значения, что и в основном рендерере)
@@ -3460,8 +3368,9 @@ impl GpuRenderer {
                     base_color[3] * biome_tint[3]
                 ];
                 
-                // Проверяем, разблокирован ли тайл для строительства
+                // Проверяем, разведан ли тайл и виден ли он сейчас
                 let is_explored = world.is_explored(glam::IVec2::new(tx, ty));
+                let is_visible = world.is_visible(glam::IVec2::new(tx, ty));
                 
                 // Затемнение для недоступных областей (fog of war)
                 let color = if !is_explored {
@@ -3472,6 +3381,14 @@ impl GpuRenderer {
                         base_final_color[2] * 0.2,
                         base_final_color[3]
                     ]
+                } else if !is_visible {
+                    // разведано, но сейчас вне обзора
+                    [
+                        base_final_color[0] * 0.7,
+                        base_final_color[1] * 0.7,
+                        base_final_color[2] * 0.7,
+                        base_final_color[3]
+                    ]
                 } else {
                     base_final_color
                 };
@@ -3508,11 +3425,7 @@ impl GpuRenderer {
                 let pos = glam::IVec2::new(tx, ty);
                 
                 // Проверяем наличие депозитов
-                let has_clay = world.has_clay_deposit(pos);
-                let has_stone = world.has_stone_deposit(pos);
-                let has_iron = world.has_iron_deposit(pos);
-                
-                if has_clay || has_stone || has_iron {
+                if let Some(kind) = visible_deposit(world, pos) {
                     let x = minimap_x + (tx - min_tx) * cell_size;
                     let y = minimap_y + (ty - min_ty) * cell_size;
                     
@@ -3520,14 +3433,8 @@ impl GpuRenderer {
                     if x >= minimap_x && x < minimap_x + minimap_w && 
                        y >= minimap_y && y < minimap_y + minimap_h {
                         
-                        // Определяем цвет депозита (приоритет: железо > камень > глина)
-                        let deposit_color = if has_iron {
-                            [0.3, 0.3, 0.3, 0.8] // темно-серый для железа
-                        } else if has_stone {
-                            [0.6, 0.6, 0.6, 0.8] // серый для камня
-                        } else {
-                            [0.8, 0.6, 0.4, 0.8] // коричневый для глины
-                        };
+                        let [r, g, b, _] = deposit_color(kind);
+                        let deposit_color = [r, g, b, 0.8];
                         
                         // Поворачиваем относительно центра миникарты
                         let local_pos = glam::Vec3::new(x as f32, y as f32, 0.0) - center_vec;
@@ -3612,6 +3519,23 @@ impl GpuRenderer {
                     crate::types::BuildingKind::Smelter => [0.4, 0.4, 0.6, 1.0], // сине-серый
                     crate::types::BuildingKind::Fishery => [0.2, 0.6, 0.8, 1.0], // голубой
                     crate::types::BuildingKind::ResearchLab => [0.4, 0.4, 1.0, 1.0], // синий
+                    crate::types::BuildingKind::CoalMine => [0.2, 0.2, 0.22, 1.0],
+                    crate::types::BuildingKind::GoldMine => [0.85, 0.7, 0.2, 1.0],
+                    crate::types::BuildingKind::GemMine => [0.45, 0.8, 0.75, 1.0],
+                    crate::types::BuildingKind::SaltWorks => [0.95, 0.95, 0.9, 1.0],
+                    crate::types::BuildingKind::Mint => [0.9, 0.8, 0.35, 1.0],
+                    crate::types::BuildingKind::Jeweler => [0.8, 0.45, 0.8, 1.0],
+                    crate::types::BuildingKind::ScoutTower => [0.6, 0.5, 0.35, 1.0],
+                    crate::types::BuildingKind::Dock => [0.45, 0.35, 0.25, 1.0],
+                    crate::types::BuildingKind::CharcoalBurner => [0.3, 0.25, 0.22, 1.0],
+                    crate::types::BuildingKind::Blacksmith => [0.5, 0.5, 0.55, 1.0],
+                    crate::types::BuildingKind::SheepFarm => [0.8, 0.8, 0.7, 1.0],
+                    crate::types::BuildingKind::Weaver => [0.65, 0.55, 0.75, 1.0],
+                    crate::types::BuildingKind::Tailor => [0.4, 0.5, 0.8, 1.0],
+                    crate::types::BuildingKind::HopFarm => [0.45, 0.7, 0.3, 1.0],
+                    crate::types::BuildingKind::Brewery => [0.7, 0.45, 0.2, 1.0],
+                    crate::types::BuildingKind::Tavern => [0.8, 0.55, 0.35, 1.0],
+                    crate::types::BuildingKind::TradingPost => [0.85, 0.7, 0.3, 1.0],
                 };
                 
                 // Поворачиваем относительно центра миникарты
@@ -3632,6 +3556,62 @@ impl GpuRenderer {
                 });
             }
         }
+
+        // Замеченные точки интереса (тайники, руины, бандиты, караваны)
+        for poi in pois.iter().filter(|p| p.revealed) {
+            if poi.pos.x < min_tx || poi.pos.x > max_tx || poi.pos.y < min_ty || poi.pos.y > max_ty {
+                continue;
+            }
+            let map_x = minimap_x + (poi.pos.x - min_tx) * cell_size;
+            let map_y = minimap_y + (poi.pos.y - min_ty) * cell_size;
+            let local_pos = glam::Vec3::new(map_x as f32, map_y as f32, 0.0) - center_vec;
+            let final_pos = rotation_45 * local_pos + center_vec;
+            // чуть крупнее здания, чтобы метка не терялась
+            let size = (cell_size * 3 / 2).max(2) as f32;
+            let transform = glam::Mat4::from_scale_rotation_translation(
+                glam::Vec3::new(size, size, 1.0),
+                rotation_45,
+                final_pos,
+            );
+            self.minimap_instances.push(UIRect {
+                model_matrix: transform.to_cols_array_2d(),
+                color: poi.kind.color(),
+            });
+        }
+
+        // Паромные линии (пунктир) и корабли на них
+        let mut marks: Vec<(glam::Vec2, f32, [f32; 4])> = Vec::new();
+        for &(a, b) in &world.ferries {
+            let len = (b - a).as_vec2().length();
+            let dots = (len / 3.0) as i32;
+            for i in 1..dots {
+                let p = a.as_vec2().lerp(b.as_vec2(), i as f32 / dots as f32);
+                marks.push((p, 1.0, [0.85, 0.85, 0.95, 0.6]));
+            }
+        }
+        for ship in ships {
+            let color = if ship.cargo.is_some() { [0.95, 0.75, 0.3, 1.0] } else { [0.95, 0.95, 0.95, 1.0] };
+            marks.push((ship.pos(), (cell_size * 2).max(3) as f32, color));
+        }
+        for (p, size, color) in marks {
+            let (tx, ty) = (p.x.round() as i32, p.y.round() as i32);
+            if tx < min_tx || tx > max_tx || ty < min_ty || ty > max_ty {
+                continue;
+            }
+            let map_x = minimap_x as f32 + (p.x - min_tx as f32) * cell_size as f32;
+            let map_y = minimap_y as f32 + (p.y - min_ty as f32) * cell_size as f32;
+            let local_pos = glam::Vec3::new(map_x, map_y, 0.0) - center_vec;
+            let final_pos = rotation_45 * local_pos + center_vec;
+            let transform = glam::Mat4::from_scale_rotation_translation(
+                glam::Vec3::new(size, size, 1.0),
+                rotation_45,
+                final_pos,
+            );
+            self.minimap_instances.push(UIRect {
+                model_matrix: transform.to_cols_array_2d(),
+                color,
+            });
+        }
         
         // Показываем рамку видимой области на миникарте
         // Вычисляем границы видимой области в координатах миникарты
@@ -4644,7 +4624,7 @@ impl GpuRenderer {
             self.light_instances.push(LightInstance {
                 model_matrix: model_matrix1.to_cols_array_2d(),
                 radius: radius1,
-                color: [255.0/255.0, 200.0/255.0, 120.0/255.0, a * 0.65],
+                color: [1.0, 200.0/255.0, 120.0/255.0, a * 0.65],
                 padding: [0.0; 3],
             });
             
@@ -4655,9 +4635,30 @@ impl GpuRenderer {
             self.light_instances.push(LightInstance {
                 model_matrix: model_matrix2.to_cols_array_2d(),
                 radius: radius2,
-                color: [255.0/255.0, 240.0/255.0, 180.0/255.0, a],
+                color: [1.0, 240.0/255.0, 180.0/255.0, a],
                 padding: [0.0; 3],
             });
         }
     }
 }
+
+// Месторождение, которое показываем в клетке (приоритет: редкие > железо > камень > глина)
+fn visible_deposit(world: &World, pos: glam::IVec2) -> Option<DepositKind> {
+    const ORDER: [DepositKind; 7] = [
+        DepositKind::Gems, DepositKind::Gold, DepositKind::Salt, DepositKind::Coal,
+        DepositKind::Iron, DepositKind::Stone, DepositKind::Clay,
+    ];
+    ORDER.into_iter().find(|&k| world.deposit_left(pos, k) > 0)
+}
+
+fn deposit_color(kind: DepositKind) -> [f32; 4] {
+    match kind {
+        DepositKind::Iron => [0.3, 0.3, 0.3, 1.0],   // темно-серый
+        DepositKind::Stone => [0.6, 0.6, 0.6, 1.0],  // серый
+        DepositKind::Clay => [0.8, 0.6, 0.4, 1.0],   // коричневый
+        DepositKind::Coal => [0.12, 0.12, 0.14, 1.0], // почти чёрный
+        DepositKind::Gold => [1.0, 0.8, 0.2, 1.0],   // золотой
+        DepositKind::Gems => [0.4, 0.9, 0.85, 1.0],  // бирюзовый
+        DepositKind::Salt => [1.0, 1.0, 0.95, 1.0],  // белый
+    }
+}
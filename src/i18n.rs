@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Поставляемые языки: код (имя файла в assets/lang) и название на самом языке.
+/// Первый — английский: из него берутся строки, которых нет в переводе
+pub const LANGUAGES: [(&str, &str); 2] = [("en", "English"), ("ru", "Русский")];
+
+/// Таблицы по умолчанию, вшитые в бинарник (в порядке LANGUAGES)
+const DEFAULT_TABLES: [&str; 2] = [
+    include_str!("../assets/lang/en.toml"),
+    include_str!("../assets/lang/ru.toml"),
+];
+
+type Table = HashMap<String, String>;
+
+/// Таблицы строк загружаются один раз за запуск
+static TABLES: OnceLock<Vec<Table>> = OnceLock::new();
+
+/// Индекс текущего языка в LANGUAGES
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+fn tables() -> &'static [Table] {
+    TABLES.get_or_init(load_tables)
+}
+
+/// Таблицы из assets/lang/<код>.toml; если файла нет или он с ошибками — встроенная
+fn load_tables() -> Vec<Table> {
+    let dir = crate::resource_path::assets_path().join("lang");
+    LANGUAGES.iter().zip(DEFAULT_TABLES).map(|(&(code, _), default)| {
+        let path = dir.join(format!("{}.toml", code));
+        let from_file = std::fs::read_to_string(&path).ok().and_then(|text| match parse_table(&text) {
+            Ok(table) => Some(table),
+            Err(e) => { log::warn!("Язык {:?} не загружен: {}", path, e); None }
+        });
+        from_file.unwrap_or_else(|| parse_table(default).expect("встроенная таблица строк должна быть корректной"))
+    }).collect()
+}
+
+/// Разобрать таблицу: разделы TOML дают ключи через точку ([menu] quit → "menu.quit")
+pub fn parse_table(text: &str) -> Result<Table, String> {
+    let root: toml::Table = toml::from_str(text).map_err(|e| e.to_string())?;
+    let mut table = Table::new();
+    flatten("", &root, &mut table)?;
+    Ok(table)
+}
+
+fn flatten(prefix: &str, section: &toml::Table, out: &mut Table) -> Result<(), String> {
+    for (name, value) in section {
+        let key = if prefix.is_empty() { name.clone() } else { format!("{}.{}", prefix, name) };
+        match value {
+            toml::Value::String(s) => { out.insert(key, s.clone()); }
+            toml::Value::Table(t) => flatten(&key, t, out)?,
+            _ => return Err(format!("'{}' is not a string", key)),
+        }
+    }
+    Ok(())
+}
+
+/// Строка на текущем языке; нет перевода — английская, нет и её — сам ключ
+pub fn tr(key: &'static str) -> &'static str {
+    tr_opt(key).unwrap_or(key)
+}
+
+/// Строка на текущем языке или английская; None, если ключа нет нигде
+pub fn tr_opt(key: &str) -> Option<&'static str> {
+    let tables = tables();
+    tables.get(CURRENT.load(Ordering::Relaxed))
+        .and_then(|t| t.get(key))
+        .or_else(|| tables[0].get(key))
+        .map(|s| s.as_str())
+}
+
+/// Строка с подстановкой: {0}, {1}… заменяются аргументами по номеру
+/// (в переводе порядок может отличаться от английского)
+pub fn tr_fmt(key: &'static str, args: &[&dyn std::fmt::Display]) -> String {
+    fill(tr(key), args)
+}
+
+/// Подставить аргументы в {0}, {1}… готового текста
+pub fn fill(text: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut text = text.to_string();
+    for (i, arg) in args.iter().enumerate() {
+        text = text.replace(&format!("{{{}}}", i), &arg.to_string());
+    }
+    text
+}
+
+/// Код текущего языка
+pub fn language() -> &'static str {
+    LANGUAGES[CURRENT.load(Ordering::Relaxed)].0
+}
+
+/// Название текущего языка (для меню)
+pub fn language_name() -> &'static str {
+    LANGUAGES[CURRENT.load(Ordering::Relaxed)].1
+}
+
+/// Выбрать язык по коду; неизвестный код — английский
+pub fn set_language(code: &str) {
+    let index = LANGUAGES.iter().position(|&(c, _)| c == code).unwrap_or_else(|| {
+        log::warn!("Неизвестный язык '{}', используется английский", code);
+        0
+    });
+    CURRENT.store(index, Ordering::Relaxed);
+}
+
+/// Переключить на следующий язык по кругу; возвращает его код
+pub fn next_language() -> &'static str {
+    let index = (CURRENT.load(Ordering::Relaxed) + 1) % LANGUAGES.len();
+    CURRENT.store(index, Ordering::Relaxed);
+    LANGUAGES[index].0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_key_exists_in_every_language() {
+        let tables: Vec<Table> = DEFAULT_TABLES.iter().map(|text| parse_table(text).unwrap()).collect();
+        let english = &tables[0];
+        for (table, (code, _)) in tables.iter().zip(LANGUAGES).skip(1) {
+            let mut missing: Vec<&String> = english.keys().filter(|k| !table.contains_key(*k)).collect();
+            let mut extra: Vec<&String> = table.keys().filter(|k| !english.contains_key(*k)).collect();
+            missing.sort();
+            extra.sort();
+            assert!(missing.is_empty(), "'{}' lacks keys: {:?}", code, missing);
+            assert!(extra.is_empty(), "'{}' has keys unknown in English: {:?}", code, extra);
+        }
+    }
+
+    #[test]
+    fn placeholders_are_filled_by_position() {
+        assert_eq!(tr_fmt("notify.scouts_back", &[&3, &-7]), "Scouts are back from (3, -7)");
+        let english = parse_table(DEFAULT_TABLES[0]).unwrap();
+        let count = |s: &str| (0..10).filter(|i| s.contains(&format!("{{{}}}", i))).count();
+        for (table, (code, _)) in DEFAULT_TABLES.iter().map(|t| parse_table(t).unwrap()).zip(LANGUAGES).skip(1) {
+            for (key, text) in &table {
+                assert_eq!(count(text), count(&english[key]), "'{}' in '{}' has other placeholders than English", key, code);
+            }
+        }
+    }
+
+    // строки, которые не переводятся (название игры)
+    const UNTRANSLATED: &[&str] = &["Cozy Kingdom"];
+
+    // строки, которые попадают в уведомления и на экран, а не в журнал или ошибки данных
+    const SINKS: &[&str] = &["message:", "Defeat(", "draw_text", "draw_button", "hint =", "title =", "format!("];
+    const NOT_SINKS: &[&str] = &["log::", "errors.push", "label:", "Err(", "panic!", "expect("];
+
+    // строковые литералы строки кода вместе с текстом перед открывающей кавычкой
+    fn literals(line: &str) -> Vec<(&str, &str)> {
+        let mut out = Vec::new();
+        let mut start = None;
+        let mut escaped = false;
+        for (i, c) in line.char_indices() {
+            match (start, c) {
+                (None, '"') => start = Some(i),
+                (Some(_), _) if escaped => escaped = false,
+                (Some(_), '\\') => escaped = true,
+                (Some(s), '"') => { out.push((&line[..s], &line[s + 1..i])); start = None; }
+                _ => {}
+            }
+        }
+        out
+    }
+
+    // есть ли в литерале слово (плейсхолдеры {..} не в счёт); ключи вида "resource.{}" — не текст
+    fn has_words(lit: &str) -> bool {
+        if !lit.contains(' ') && lit.contains('.') { return false; }
+        let mut text = String::new();
+        let mut depth = 0;
+        for c in lit.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ if depth == 0 => text.push(c),
+                _ => {}
+            }
+        }
+        text.as_bytes().windows(2).any(|w| w[0].is_ascii_alphabetic() && w[1].is_ascii_alphabetic())
+    }
+
+    #[test]
+    fn no_hardcoded_text_outside_tables() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut found = Vec::new();
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            // консоль — инструмент разработчика
+            if path.extension().is_none_or(|e| e != "rs") || path.ends_with("console.rs") { continue; }
+            let source = std::fs::read_to_string(&path).unwrap();
+            for (n, line) in source.lines().enumerate() {
+                if line.contains("#[cfg(test)]") { break; }
+                if line.trim_start().starts_with("//") { continue; }
+                if !SINKS.iter().any(|s| line.contains(s)) || NOT_SINKS.iter().any(|s| line.contains(s)) { continue; }
+                for (before, lit) in literals(line) {
+                    let translated = ["tr(", "tr_fmt(", "tr_opt("].iter().any(|f| before.ends_with(f));
+                    if has_words(lit) && !translated && !UNTRANSLATED.contains(&lit) {
+                        found.push(format!("{}:{}: {}", path.file_name().unwrap().to_string_lossy(), n + 1, lit));
+                    }
+                }
+            }
+        }
+        found.sort();
+        assert!(found.is_empty(), "text not sent through tr(): {:#?}", found);
+    }
+
+    #[test]
+    fn research_tutorials_quests_and_scenarios_are_translated() {
+        let chains = crate::scenario::load_quest_defs();
+        let scenarios = crate::scenario::load_scenarios();
+        assert!(!chains.is_empty() && !scenarios.is_empty());
+        for (code, _) in LANGUAGES.iter().skip(1) {
+            for d in crate::research::tree() {
+                assert!(d.translations.contains_key(*code), "research '{}' lacks '{}'", d.key, code);
+            }
+            for t in crate::tutorial::tracks() {
+                for (i, step) in t.step.iter().enumerate() {
+                    assert!(step.translations.contains_key(*code), "tutorial '{}' step {} lacks '{}'", t.key, i + 1, code);
+                }
+            }
+            for s in &scenarios {
+                assert!(s.translations.contains_key(*code), "scenario '{}' lacks '{}'", s.name, code);
+                for e in &s.event {
+                    assert!(e.translations.contains_key(*code), "scenario '{}' event on day {} lacks '{}'", s.name, e.day, code);
+                }
+            }
+            for d in chains.iter().chain(scenarios.iter().flat_map(|s| &s.quest)) {
+                assert!(d.translations.contains_key(*code), "quest '{}' lacks '{}'", d.key, code);
+            }
+        }
+    }
+}
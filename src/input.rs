@@ -14,6 +14,22 @@ pub struct Config {
     pub happy_variety_bonus: i32,
     pub happy_house_bonus: i32,
     pub happy_starving_penalty: i32,
+    // бонусы роскоши (соль к еде, украшения) на жителя дома
+    pub happy_salt_bonus: i32,
+    pub happy_jewelry_bonus: i32,
+    // штраф за холод (зимой без дров в доме)
+    pub happy_cold_penalty: i32,
+    // отопление: дров на дом в холодный день; здоровье (потеря от холода, восстановление в тепле)
+    pub firewood_per_house: i32,
+    pub health_cold_loss: i32,
+    pub health_recovery: i32,
+    pub happy_sick_penalty: i32,
+    // инструменты: множители времени цикла (с ними / без них при наличии кузницы) и шанс износа за цикл
+    pub tools_speed_mul: f32,
+    pub no_tools_speed_mul: f32,
+    pub tool_wear_chance: f32,
+    // удобства: бонус счастья за каждый недельный товар дома (одежда, пиво)
+    pub happy_comfort_bonus: i32,
     pub migration_join_threshold: f32,
     pub migration_leave_threshold: f32,
     // коэффициенты налоговой формулы: income = tax_rate * pop * (tax_income_base + tax_income_happy_scale * happiness_avg/100)
@@ -34,6 +50,23 @@ pub struct Config {
     pub upkeep_kiln: i32,
     pub upkeep_fishery: i32,
     pub upkeep_smelter: i32,
+    pub upkeep_coal_mine: i32,
+    pub upkeep_gold_mine: i32,
+    pub upkeep_gem_mine: i32,
+    pub upkeep_salt_works: i32,
+    pub upkeep_mint: i32,
+    pub upkeep_jeweler: i32,
+    pub upkeep_scout_tower: i32,
+    pub upkeep_dock: i32,
+    pub upkeep_charcoal_burner: i32,
+    pub upkeep_blacksmith: i32,
+    pub upkeep_sheep_farm: i32,
+    pub upkeep_weaver: i32,
+    pub upkeep_tailor: i32,
+    pub upkeep_hop_farm: i32,
+    pub upkeep_brewery: i32,
+    pub upkeep_tavern: i32,
+    pub upkeep_trading_post: i32,
     // Биомы — пороги и множители
     pub biome_swamp_thr: f32,
     pub biome_rocky_thr: f32,
@@ -46,6 +79,10 @@ pub struct Config {
     // поля пшеницы: множители времени цикла по биому
     pub biome_meadow_wheat_wmul: f32,
     pub biome_swamp_wheat_wmul: f32,
+    // Мир — число потоков генерации чанков (0 = по числу ядер)
+    pub chunk_workers: usize,
+    // Язык интерфейса — код файла в assets/lang ("en", "ru")
+    pub language: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -70,6 +107,8 @@ pub struct InputConfig {
     // Экономика
     pub tax_up: String,
     pub tax_down: String,
+    // Разведка: отправить отряд к курсору
+    pub send_expedition: String,
 }
 
 pub struct ResolvedInput {
@@ -91,6 +130,7 @@ pub struct ResolvedInput {
     pub load_game: KeyCode,
     pub tax_up: KeyCode,
     pub tax_down: KeyCode,
+    pub send_expedition: KeyCode,
 }
 
 fn code_from_str(s: &str) -> KeyCode {
@@ -99,7 +139,7 @@ fn code_from_str(s: &str) -> KeyCode {
         "W" => KeyW, "A" => KeyA, "S" => KeyS, "D" => KeyD,
         "Q" => KeyQ, "E" => KeyE, "SPACE" => Space,
         "DIGIT1" | "1" => Digit1, "DIGIT2" | "2" => Digit2, "DIGIT3" | "3" => Digit3, "DIGIT4" | "4" => Digit4,
-        "Z" => KeyZ, "X" => KeyX, "R" => KeyR, "T" => KeyT, "N" => KeyN, "F5" => F5, "F9" => F9,
+        "Z" => KeyZ, "X" => KeyX, "V" => KeyV, "R" => KeyR, "T" => KeyT, "N" => KeyN, "F5" => F5, "F9" => F9,
         "[" | "BRACKETLEFT" => BracketLeft, "]" | "BRACKETRIGHT" => BracketRight,
         _ => KeyCode::Escape,
     }
@@ -126,6 +166,7 @@ impl ResolvedInput {
             load_game: code_from_str(&cfg.load_game),
             tax_up: code_from_str(&cfg.tax_up),
             tax_down: code_from_str(&cfg.tax_down),
+            send_expedition: code_from_str(&cfg.send_expedition),
         }
     }
 }
@@ -122,7 +122,7 @@ pub fn process_jobs(
                             continue; 
                         }
                         // Цель доставки — ближайший склад; если складов нет — завершаем без Haul
-                        let target_pos = if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, pos) {
+                        let target_pos = if let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, pos) {
                             dst
                         } else {
                             jobs[jid].done = true; c.assigned_job = None; continue;
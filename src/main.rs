@@ -33,8 +33,19 @@ mod quests;
 mod menu;
 mod resource_path;
 mod tutorial;
+mod game_setup;
+mod poi;
+mod scouting;
+mod shipping;
+mod trade;
+mod contracts;
+mod advisor;
+mod scenario;
+mod i18n;
+mod font;
 use gpu_renderer::GpuRenderer;
-use menu::{MainMenu, MenuAction};
+use menu::{MainMenu, MenuAction, NewGameAction};
+use i18n::tr;
 use std::time::Instant;
 use rand::{rngs::StdRng, SeedableRng, Rng, thread_rng};
 use std::sync::atomic::{AtomicI32, Ordering};
@@ -116,6 +127,62 @@ fn main() {
     }
 }
 
+/// Запуск новой игры с настройками экрана создания игры
+fn start_new_game(
+    game_state: &mut game_state::GameState,
+    config: &mut input::Config,
+    base_config: &input::Config,
+    setup_menu: &menu::NewGameMenu,
+    window: &winit::window::Window,
+    gpu_renderer: &mut GpuRenderer,
+) {
+    // сценарий может задать карту, сложность и seed
+    let scenario = setup_menu.selected_scenario();
+    let mut setup = setup_menu.setup;
+    if let Some(sc) = scenario {
+        if let Some(map) = sc.map { setup.map = map; }
+        if let Some(difficulty) = sc.difficulty { setup.difficulty = difficulty; }
+    }
+    *config = setup.config(base_config);
+    let mut new_rng = StdRng::seed_from_u64(thread_rng().random());
+    let seed = scenario.and_then(|sc| sc.seed).or(setup_menu.seed()).unwrap_or_else(|| new_rng.random());
+    *game_state = game_state::GameState::with_setup(&mut new_rng, config, seed, setup);
+    if let Some(sc) = scenario {
+        game_state.start_scenario(sc);
+    }
+    // Используем текущий размер окна
+    let current_size = window.inner_size();
+    game_state.width_i32 = current_size.width as i32;
+    game_state.height_i32 = current_size.height as i32;
+    // Обновляем размер в gpu_renderer
+    gpu_renderer.resize(current_size);
+    atlas::load_textures(
+        &mut game_state.atlas,
+        &mut game_state.building_atlas,
+        &mut game_state.tree_atlas,
+        &mut game_state.props_atlas,
+    );
+    match music::MusicManager::new() {
+        Ok(music_manager) => {
+            game_state.music_manager = Some(music_manager);
+        }
+        Err(e) => {
+            eprintln!("Не удалось инициализировать музыку: {}", e);
+        }
+    }
+    game_state.app_state = game_state::AppState::Playing;
+}
+
+/// Переключить язык интерфейса на следующий и запомнить выбор в config.toml
+fn switch_language(base_config: &mut input::Config, config: &mut input::Config, input_cfg: &input::InputConfig) {
+    let code = i18n::next_language();
+    base_config.language = code.to_string();
+    config.language = code.to_string();
+    if let Err(e) = config::save(base_config, input_cfg) {
+        eprintln!("Не удалось сохранить настройки: {}", e);
+    }
+}
+
 fn run() -> Result<()> {
     use std::sync::Arc;
     
@@ -135,14 +202,18 @@ fn run() -> Result<()> {
     log::info!("Загрузка текстур лиц...");
     gpu_renderer.load_faces_texture()?;
     log::info!("Загрузка конфига...");
-    let (config, input) = config::load_or_create("config.toml")?;
-    let input = ResolvedInput::from(&input);
+    let (mut base_config, input_cfg) = config::load_or_create("config.toml")?;
+    i18n::set_language(&base_config.language);
+    // рабочий конфиг: базовый с поправками сложности текущей игры
+    let mut config = base_config.clone();
+    let input = ResolvedInput::from(&input_cfg);
 
     let mut camera = camera::Camera::new(Vec2::new(0.0, 0.0), 2.0);
     let mut rng_init = StdRng::seed_from_u64(thread_rng().random());
     let mut game_state = game_state::GameState::new(&mut rng_init, &config);
     let mut main_menu = MainMenu::new();
     let mut pause_menu = menu::PauseMenu::new();
+    let mut new_game_menu = menu::NewGameMenu::new();
     
     log::info!("Загрузка текстур...");
     // Загрузить все текстуры
@@ -183,34 +254,13 @@ fn run() -> Result<()> {
                         if let Some(action) = main_menu.handle_key(event.physical_key) {
                             match action {
                                 MenuAction::NewGame => {
-                                    // Создаем новую игру
-                                    let mut new_rng = StdRng::seed_from_u64(thread_rng().random());
-                                    game_state = game_state::GameState::new(&mut new_rng, &config);
-                                    // Используем текущий размер окна
-                                    let current_size = window.inner_size();
-                                    game_state.width_i32 = current_size.width as i32;
-                                    game_state.height_i32 = current_size.height as i32;
-                                    // Обновляем размер в gpu_renderer
-                                    gpu_renderer.resize(current_size);
-                                    atlas::load_textures(
-                                        &mut game_state.atlas,
-                                        &mut game_state.building_atlas,
-                                        &mut game_state.tree_atlas,
-                                        &mut game_state.props_atlas,
-                                    );
-                                    match music::MusicManager::new() {
-                                        Ok(music_manager) => {
-                                            game_state.music_manager = Some(music_manager);
-                                        }
-                                        Err(e) => {
-                                            eprintln!("Не удалось инициализировать музыку: {}", e);
-                                        }
-                                    }
-                                    game_state.app_state = game_state::AppState::Playing;
+                                    // Переходим к экрану настроек новой игры
+                                    game_state.app_state = game_state::AppState::NewGameSetup;
                                 }
                                 MenuAction::LoadGame => {
                                     // Загружаем игру
                                     if let Ok(save) = save::load_game() {
+                                        config = save.setup.config(&base_config);
                                         let mut new_rng = StdRng::seed_from_u64(save.seed);
                                         game_state = game_state::GameState::new(&mut new_rng, &config);
                                         // Используем текущий размер окна
@@ -221,80 +271,8 @@ fn run() -> Result<()> {
                                         gpu_renderer.resize(current_size);
                                         
                                         // Восстанавливаем состояние из сохранения
-                                        game_state.seed = save.seed;
-                                        game_state.world.reset_noise(save.seed);
-                                        game_state.buildings = save.to_buildings();
-                                        game_state.buildings_dirty = true;
-                                        game_state.resources = save.resources;
-                                        camera.pos = glam::Vec2::new(save.cam_x, save.cam_y);
-                                        camera.zoom = save.zoom;
-                                        
-                                        // Восстанавливаем граждан
-                                        game_state.citizens = save.citizens;
-                                        
-                                        // Восстанавливаем работы
-                                        game_state.jobs = save.jobs;
-                                        game_state.next_job_id = save.next_job_id;
-                                        
-                                        // Восстанавливаем поленья на земле
-                                        game_state.logs_on_ground = save.logs_on_ground;
-                                        
-                                        // Восстанавливаем склады
-                                        game_state.warehouses = save.warehouses;
-                                        
-                                        // Восстанавливаем население
-                                        game_state.population = save.population;
-                                        
-                                        // Восстанавливаем время игры
-                                        game_state.world_clock_ms = save.world_clock_ms;
-                                        
-                                        // Восстанавливаем экономические параметры
-                                        game_state.tax_rate = save.tax_rate;
-                                        game_state.speed_mult = save.speed_mult;
-                                        game_state.food_policy = save.food_policy;
-                                        
-                                        // Восстанавливаем занятые клетки
-                                        game_state.world.occupied.clear();
-                                        for b in &game_state.buildings {
-                                            game_state.world.occupy(b.pos);
-                                        }
-                                        
-                                        // Восстанавливаем деревья
-                                        game_state.world.trees.clear();
-                                        game_state.world.removed_trees.clear();
-                                        for t in &save.trees {
-                                            game_state.world.trees.insert((t.x, t.y), crate::world::Tree {
-                                                stage: t.stage,
-                                                age_ms: t.age_ms,
-                                            });
-                                        }
-                                        
-                                        // Восстанавливаем туман войны (разведанные тайлы)
-                                        game_state.world.explored_tiles.clear();
-                                        for &(x, y) in &save.explored_tiles {
-                                            game_state.world.explored_tiles.insert((x, y));
-                                        }
-                                        
-                                        // Восстанавливаем дороги
-                                        game_state.world.roads.clear();
-                                        for &(x, y) in &save.roads {
-                                            game_state.world.roads.insert((x, y));
-                                        }
-                                        
-                                        // Восстанавливаем системы исследований и уведомлений
-                                        if let Some(research_system) = save.research_system {
-                                            game_state.research_system = research_system;
-                                        }
-                                        if let Some(notification_system) = save.notification_system {
-                                            game_state.notification_system = notification_system;
-                                        }
-                                        if let Some(quest_system) = save.quest_system {
-                                            game_state.quest_system = quest_system;
-                                        }
-                                        if let Some(tutorial_system) = save.tutorial_system {
-                                            game_state.tutorial_system = tutorial_system;
-                                        }
-                                        
+                                        save::apply_save(&mut game_state, &mut camera, save);
+
                                         // Загружаем текстуры
                                         atlas::load_textures(
                                             &mut game_state.atlas,
@@ -318,9 +296,8 @@ fn run() -> Result<()> {
                                         eprintln!("Не удалось загрузить игру: файл save.json не найден или поврежден");
                                     }
                                 }
-                                MenuAction::Settings => {
-                                    // TODO: Реализовать настройки
-                                    eprintln!("Настройки пока не реализованы");
+                                MenuAction::Language => {
+                                    switch_language(&mut base_config, &mut config, &input_cfg);
                                 }
                                 MenuAction::Quit => elwt.exit(),
                             }
@@ -328,6 +305,20 @@ fn run() -> Result<()> {
                         return;
                     }
                     
+                    // Экран создания новой игры
+                    if game_state.app_state == game_state::AppState::NewGameSetup {
+                        match new_game_menu.handle_key(event.physical_key) {
+                            Some(NewGameAction::Start) => {
+                                start_new_game(&mut game_state, &mut config, &base_config, &new_game_menu, &window, &mut gpu_renderer);
+                            }
+                            Some(NewGameAction::Back) => {
+                                game_state.app_state = game_state::AppState::MainMenu;
+                            }
+                            None => {}
+                        }
+                        return;
+                    }
+                    
                     // Обработка меню паузы
                     if game_state.app_state == game_state::AppState::Paused {
                         use menu::PauseMenuAction;
@@ -340,6 +331,7 @@ fn run() -> Result<()> {
                                     // Сохраняем игру
                                     let save_data = save::SaveData::from_runtime(
                                         game_state.seed,
+                                        game_state.setup,
                                         &game_state.resources,
                                         &game_state.buildings,
                                         camera.pos,
@@ -349,6 +341,13 @@ fn run() -> Result<()> {
                                         &game_state.notification_system,
                                         &game_state.quest_system,
                                         &game_state.tutorial_system,
+                                        &game_state.poi_system,
+                                        &game_state.expeditions,
+                                        &game_state.shipping,
+                                        &game_state.trade,
+                                        &game_state.contracts,
+                                        &game_state.scenario,
+                                        &game_state.advisor,
                                         &game_state.citizens,
                                         &game_state.jobs,
                                         game_state.next_job_id,
@@ -356,6 +355,7 @@ fn run() -> Result<()> {
                                         &game_state.warehouses,
                                         game_state.population,
                                         game_state.world_clock_ms,
+                                        game_state.weather_system.day(),
                                         game_state.tax_rate,
                                         game_state.speed_mult,
                                         game_state.food_policy,
@@ -363,17 +363,16 @@ fn run() -> Result<()> {
                                     match save::save_game(&save_data) {
                                         Ok(_) => {
                                             eprintln!("Игра успешно сохранена");
-                                            pause_menu.set_save_message("Game saved!".to_string());
+                                            pause_menu.set_save_message(tr("menu.game_saved").to_string());
                                         }
                                         Err(e) => {
                                             eprintln!("Ошибка при сохранении игры: {}", e);
-                                            pause_menu.set_save_message(format!("Save error: {}", e));
+                                            pause_menu.set_save_message(format!("{}: {}", tr("menu.save_error"), e));
                                         }
                                     }
                                 }
-                                PauseMenuAction::Settings => {
-                                    // TODO: Реализовать настройки
-                                    eprintln!("Настройки пока не реализованы");
+                                PauseMenuAction::Language => {
+                                    switch_language(&mut base_config, &mut config, &input_cfg);
                                 }
                                 PauseMenuAction::QuitToMenu => {
                                     game_state.app_state = game_state::AppState::MainMenu;
@@ -385,7 +384,7 @@ fn run() -> Result<()> {
                     
                     // Обработка событий клавиатуры в игре (только если мы в состоянии Playing)
                     if game_state.app_state == game_state::AppState::Playing {
-                        if event_handler::handle_keyboard_input(
+                        let handled = event_handler::handle_keyboard_input(
                             event.physical_key,
                             &event.state,
                             elwt,
@@ -394,7 +393,12 @@ fn run() -> Result<()> {
                             &input,
                             &config,
                             &mut gpu_renderer,
-                        ) {
+                        );
+                        // быстрая загрузка могла сменить сложность
+                        if event.physical_key == winit::keyboard::PhysicalKey::Code(input.load_game) {
+                            config = game_state.setup.config(&base_config);
+                        }
+                        if handled {
                             return;
                         }
                     }
@@ -411,6 +415,14 @@ fn run() -> Result<()> {
                             game_state.height_i32,
                             config.ui_scale_base,
                         );
+                    } else if game_state.app_state == game_state::AppState::NewGameSetup {
+                        new_game_menu.handle_hover(
+                            game_state.cursor_xy.x,
+                            game_state.cursor_xy.y,
+                            game_state.width_i32,
+                            game_state.height_i32,
+                            config.ui_scale_base,
+                        );
                     } else if game_state.app_state == game_state::AppState::Paused {
                         pause_menu.handle_hover(
                             game_state.cursor_xy.x,
@@ -443,6 +455,7 @@ fn run() -> Result<()> {
                                             // Сохраняем игру
                                             let save_data = save::SaveData::from_runtime(
                                                 game_state.seed,
+                                                game_state.setup,
                                                 &game_state.resources,
                                                 &game_state.buildings,
                                                 camera.pos,
@@ -452,6 +465,13 @@ fn run() -> Result<()> {
                                                 &game_state.notification_system,
                                                 &game_state.quest_system,
                                                 &game_state.tutorial_system,
+                                                &game_state.poi_system,
+                                                &game_state.expeditions,
+                                                &game_state.shipping,
+                                                &game_state.trade,
+                                                &game_state.contracts,
+                                                &game_state.scenario,
+                                                &game_state.advisor,
                                                 &game_state.citizens,
                                                 &game_state.jobs,
                                                 game_state.next_job_id,
@@ -459,6 +479,7 @@ fn run() -> Result<()> {
                                                 &game_state.warehouses,
                                                 game_state.population,
                                                 game_state.world_clock_ms,
+                                                game_state.weather_system.day(),
                                                 game_state.tax_rate,
                                                 game_state.speed_mult,
                                                 game_state.food_policy,
@@ -466,17 +487,16 @@ fn run() -> Result<()> {
                                             match save::save_game(&save_data) {
                                                 Ok(_) => {
                                                     eprintln!("Игра успешно сохранена");
-                                                    pause_menu.set_save_message("Game saved!".to_string());
+                                                    pause_menu.set_save_message(tr("menu.game_saved").to_string());
                                                 }
                                                 Err(e) => {
                                                     eprintln!("Ошибка при сохранении игры: {}", e);
-                                                    pause_menu.set_save_message(format!("Save error: {}", e));
+                                                    pause_menu.set_save_message(format!("{}: {}", tr("menu.save_error"), e));
                                                 }
                                             }
                                         }
-                                        PauseMenuAction::Settings => {
-                                            // TODO: Реализовать настройки
-                                            eprintln!("Настройки пока не реализованы");
+                                        PauseMenuAction::Language => {
+                                            switch_language(&mut base_config, &mut config, &input_cfg);
                                         }
                                         PauseMenuAction::QuitToMenu => {
                                             game_state.app_state = game_state::AppState::MainMenu;
@@ -488,6 +508,29 @@ fn run() -> Result<()> {
                         return;
                     }
                     
+                    if game_state.app_state == game_state::AppState::NewGameSetup {
+                        if let winit::event::MouseButton::Left = button {
+                            if state == winit::event::ElementState::Pressed {
+                                match new_game_menu.handle_click(
+                                    game_state.cursor_xy.x,
+                                    game_state.cursor_xy.y,
+                                    game_state.width_i32,
+                                    game_state.height_i32,
+                                    config.ui_scale_base,
+                                ) {
+                                    Some(NewGameAction::Start) => {
+                                        start_new_game(&mut game_state, &mut config, &base_config, &new_game_menu, &window, &mut gpu_renderer);
+                                    }
+                                    Some(NewGameAction::Back) => {
+                                        game_state.app_state = game_state::AppState::MainMenu;
+                                    }
+                                    None => {}
+                                }
+                            }
+                        }
+                        return;
+                    }
+                    
                     if game_state.app_state == game_state::AppState::MainMenu {
                         if let winit::event::MouseButton::Left = button {
                             if state == winit::event::ElementState::Pressed {
@@ -500,33 +543,12 @@ fn run() -> Result<()> {
                                 ) {
                                     match action {
                                         MenuAction::NewGame => {
-                                            let mut new_rng = StdRng::seed_from_u64(thread_rng().random());
-                                            game_state = game_state::GameState::new(&mut new_rng, &config);
-                                            // Используем текущий размер окна
-                                            let current_size = window.inner_size();
-                                            game_state.width_i32 = current_size.width as i32;
-                                            game_state.height_i32 = current_size.height as i32;
-                                            // Обновляем размер в gpu_renderer
-                                            gpu_renderer.resize(current_size);
-                                            atlas::load_textures(
-                                                &mut game_state.atlas,
-                                                &mut game_state.building_atlas,
-                                                &mut game_state.tree_atlas,
-                                                &mut game_state.props_atlas,
-                                            );
-                                            match music::MusicManager::new() {
-                                                Ok(music_manager) => {
-                                                    game_state.music_manager = Some(music_manager);
-                                                }
-                                                Err(e) => {
-                                                    eprintln!("Не удалось инициализировать музыку: {}", e);
-                                                }
-                                            }
-                                            game_state.app_state = game_state::AppState::Playing;
+                                            game_state.app_state = game_state::AppState::NewGameSetup;
                                         }
                                         MenuAction::LoadGame => {
                                             // Загружаем игру
                                             if let Ok(save) = save::load_game() {
+                                                config = save.setup.config(&base_config);
                                                 let mut new_rng = StdRng::seed_from_u64(save.seed);
                                                 game_state = game_state::GameState::new(&mut new_rng, &config);
                                                 // Используем текущий размер окна
@@ -537,77 +559,8 @@ fn run() -> Result<()> {
                                                 gpu_renderer.resize(current_size);
                                                 
                                                 // Восстанавливаем состояние из сохранения
-                                                game_state.seed = save.seed;
-                                                game_state.world.reset_noise(save.seed);
-                                                game_state.buildings = save.to_buildings();
-                                                game_state.buildings_dirty = true;
-                                                game_state.resources = save.resources;
-                                                camera.pos = glam::Vec2::new(save.cam_x, save.cam_y);
-                                                camera.zoom = save.zoom;
-                                                
-                                                // Восстанавливаем граждан
-                                                game_state.citizens = save.citizens;
-                                                
-                                                // Восстанавливаем работы
-                                                game_state.jobs = save.jobs;
-                                                game_state.next_job_id = save.next_job_id;
-                                                
-                                                // Восстанавливаем поленья на земле
-                                                game_state.logs_on_ground = save.logs_on_ground;
-                                                
-                                                // Восстанавливаем склады
-                                                game_state.warehouses = save.warehouses;
-                                                
-                                                // Восстанавливаем население
-                                                game_state.population = save.population;
-                                                
-                                                // Восстанавливаем время игры
-                                                game_state.world_clock_ms = save.world_clock_ms;
-                                                
-                                                // Восстанавливаем экономические параметры
-                                                game_state.tax_rate = save.tax_rate;
-                                                game_state.speed_mult = save.speed_mult;
-                                                game_state.food_policy = save.food_policy;
-                                                
-                                                // Восстанавливаем занятые клетки
-                                                game_state.world.occupied.clear();
-                                                for b in &game_state.buildings {
-                                                    game_state.world.occupy(b.pos);
-                                                }
-                                                
-                                                // Восстанавливаем деревья
-                                                game_state.world.trees.clear();
-                                                game_state.world.removed_trees.clear();
-                                                for t in &save.trees {
-                                                    game_state.world.trees.insert((t.x, t.y), crate::world::Tree {
-                                                        stage: t.stage,
-                                                        age_ms: t.age_ms,
-                                                    });
-                                                }
-                                                
-                                                // Восстанавливаем туман войны (разведанные тайлы)
-                                                game_state.world.explored_tiles.clear();
-                                                for &(x, y) in &save.explored_tiles {
-                                                    game_state.world.explored_tiles.insert((x, y));
-                                                }
-                                                
-                                                // Восстанавливаем дороги
-                                                game_state.world.roads.clear();
-                                                for &(x, y) in &save.roads {
-                                                    game_state.world.roads.insert((x, y));
-                                                }
-                                                
-                                                // Восстанавливаем системы исследований и уведомлений
-                                                if let Some(research_system) = save.research_system {
-                                                    game_state.research_system = research_system;
-                                                }
-                                                if let Some(notification_system) = save.notification_system {
-                                                    game_state.notification_system = notification_system;
-                                                }
-                                                if let Some(tutorial_system) = save.tutorial_system {
-                                                    game_state.tutorial_system = tutorial_system;
-                                                }
-                                                
+                                                save::apply_save(&mut game_state, &mut camera, save);
+
                                                 // Загружаем текстуры
                                                 atlas::load_textures(
                                                     &mut game_state.atlas,
@@ -631,8 +584,8 @@ fn run() -> Result<()> {
                                                 eprintln!("Не удалось загрузить игру: файл save.json не найден или поврежден");
                                             }
                                         }
-                                        MenuAction::Settings => {
-                                            eprintln!("Настройки пока не реализованы");
+                                        MenuAction::Language => {
+                                            switch_language(&mut base_config, &mut config, &input_cfg);
                                         }
                                         MenuAction::Quit => elwt.exit(),
                                     }
@@ -642,7 +595,7 @@ fn run() -> Result<()> {
                         return;
                     }
                     
-                    if event_handler::handle_mouse_input(button, state, &mut game_state, &config, &mut gpu_renderer) {
+                    if event_handler::handle_mouse_input(button, state, &mut game_state, &mut camera, &config, &mut gpu_renderer) {
                         return;
                     }
                 }
@@ -672,6 +625,25 @@ fn run() -> Result<()> {
                         return;
                     }
                     
+                    // Рендеринг экрана создания новой игры
+                    if game_state.app_state == game_state::AppState::NewGameSetup {
+                        gpu_renderer.clear_ui();
+                        menu::draw_new_game_menu(
+                            &mut gpu_renderer,
+                            game_state.width_i32,
+                            game_state.height_i32,
+                            &new_game_menu,
+                            config.ui_scale_base,
+                            game_state.cursor_xy.x,
+                            game_state.cursor_xy.y,
+                        );
+                        if let Err(err) = gpu_renderer.render() {
+                            eprintln!("gpu_renderer.render() failed: {err}");
+                            elwt.exit();
+                        }
+                        return;
+                    }
+                    
                     if MINIMAP_CELL_PX.load(Ordering::Relaxed) == 0 {
                         let s0 = ui::ui_scale(game_state.height_i32, config.ui_scale_base);
                         MINIMAP_CELL_PX.store(3 * s0, Ordering::Relaxed);
@@ -692,6 +664,7 @@ fn run() -> Result<()> {
                         let avg_hap: f32 = if game_state.citizens.is_empty() { 50.0 } else { game_state.citizens.iter().map(|c| c.happiness as i32).sum::<i32>() as f32 / game_state.citizens.len() as f32 };
                         let pop_show = game_state.citizens.len() as i32;
                         let (wlabel, wcol) = game_state.weather_system.ui_label_and_color();
+                        let calendar = game_state.weather_system.calendar_label();
                         let hovered_building = if let Some(tp) = game_state.hovered_tile {
                             game_state.buildings.iter().find(|b| b.pos == tp).cloned()
                         } else {
@@ -774,9 +747,13 @@ fn run() -> Result<()> {
                     game_state.food_policy,
                     wlabel,
                     wcol_f32,
+                    &calendar,
                     game_state.weather_system.current(), // Текущая погода для тултипа
                     &mut game_state.world,
                     &game_state.buildings,
+                    &game_state.advisor,
+                    &game_state.poi_system.pois,
+                    &game_state.shipping.ships,
                     camera.pos.x,
                     camera.pos.y,
                     MINIMAP_CELL_PX.load(Ordering::Relaxed).max(1),
@@ -820,6 +797,59 @@ fn run() -> Result<()> {
                     );
                 }
                 
+                // Рендеринг окна торговли (если открыто)
+                if game_state.show_trade_panel {
+                    let visible = types::total_resources(&game_state.warehouses, &game_state.resources);
+                    ui_gpu::draw_trade_panel_gpu(
+                        &mut gpu_renderer,
+                        game_state.width_i32,
+                        game_state.height_i32,
+                        &game_state.trade,
+                        &visible,
+                        config.ui_scale_base,
+                        game_state.cursor_xy,
+                    );
+                }
+                
+                // Рендеринг журнала квестов (если открыт)
+                if game_state.show_quest_journal {
+                    ui_gpu::draw_quest_journal_gpu(
+                        &mut gpu_renderer,
+                        game_state.width_i32,
+                        game_state.height_i32,
+                        &game_state.quest_system,
+                        config.ui_scale_base,
+                        game_state.cursor_xy,
+                    );
+                }
+                
+                // Рендеринг журнала событий (если открыт)
+                if game_state.show_event_log {
+                    ui_gpu::draw_event_log_gpu(
+                        &mut gpu_renderer,
+                        game_state.width_i32,
+                        game_state.height_i32,
+                        &game_state.notification_system,
+                        game_state.event_log_view,
+                        config.ui_scale_base,
+                        game_state.cursor_xy,
+                    );
+                }
+                
+                // Рендеринг окна контрактов (если открыто)
+                if game_state.show_contracts_panel {
+                    let visible = types::total_resources(&game_state.warehouses, &game_state.resources);
+                    ui_gpu::draw_contracts_panel_gpu(
+                        &mut gpu_renderer,
+                        game_state.width_i32,
+                        game_state.height_i32,
+                        &game_state.contracts,
+                        &visible,
+                        config.ui_scale_base,
+                        game_state.cursor_xy,
+                    );
+                }
+                
                 // Рендеринг квестов
                 ui_gpu::draw_quests_gpu(
                     &mut gpu_renderer,
@@ -865,6 +895,19 @@ fn run() -> Result<()> {
                     );
                 }
                 
+                // Экран итога сценария (победа/поражение)
+                if let Some(scenario) = game_state.scenario.as_ref().filter(|sc| sc.result_shown()) {
+                    ui_gpu::draw_scenario_result_gpu(
+                        &mut gpu_renderer,
+                        game_state.width_i32,
+                        game_state.height_i32,
+                        scenario,
+                        game_state.weather_system.day(),
+                        config.ui_scale_base,
+                        game_state.cursor_xy,
+                    );
+                }
+                
                 let t = (game_state.world_clock_ms / game_loop::DAY_LENGTH_MS).clamp(0.0, 1.0);
                 let angle = t * std::f32::consts::TAU;
                 let daylight = 0.5 - 0.5 * angle.cos();
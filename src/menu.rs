@@ -1,11 +1,14 @@
 use crate::gpu_renderer::GpuRenderer;
+use crate::game_setup::GameSetup;
+use crate::i18n::{self, tr};
+use crate::font;
 
 /// Опции главного меню
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MenuOption {
     NewGame,
     LoadGame,
-    Settings,
+    Language,
     Quit,
 }
 
@@ -32,7 +35,7 @@ impl MainMenu {
         let options = [
             MenuOption::NewGame,
             MenuOption::LoadGame,
-            MenuOption::Settings,
+            MenuOption::Language,
             MenuOption::Quit,
         ];
         
@@ -70,8 +73,8 @@ impl MainMenu {
                 self.selected_option = Some(match start_option {
                     MenuOption::NewGame => MenuOption::Quit,
                     MenuOption::LoadGame => MenuOption::NewGame,
-                    MenuOption::Settings => MenuOption::LoadGame,
-                    MenuOption::Quit => MenuOption::Settings,
+                    MenuOption::Language => MenuOption::LoadGame,
+                    MenuOption::Quit => MenuOption::Language,
                 });
                 None
             }
@@ -82,8 +85,8 @@ impl MainMenu {
                 // Обновляем selected_option на основе текущей позиции
                 self.selected_option = Some(match start_option {
                     MenuOption::NewGame => MenuOption::LoadGame,
-                    MenuOption::LoadGame => MenuOption::Settings,
-                    MenuOption::Settings => MenuOption::Quit,
+                    MenuOption::LoadGame => MenuOption::Language,
+                    MenuOption::Language => MenuOption::Quit,
                     MenuOption::Quit => MenuOption::NewGame,
                 });
                 None
@@ -114,7 +117,7 @@ impl MainMenu {
         let options = [
             MenuOption::NewGame,
             MenuOption::LoadGame,
-            MenuOption::Settings,
+            MenuOption::Language,
             MenuOption::Quit,
         ];
         
@@ -139,7 +142,7 @@ impl MainMenu {
 pub enum PauseMenuOption {
     Resume,
     SaveGame,
-    Settings,
+    Language,
     QuitToMenu,
 }
 
@@ -186,7 +189,7 @@ impl PauseMenu {
         let options = [
             PauseMenuOption::Resume,
             PauseMenuOption::SaveGame,
-            PauseMenuOption::Settings,
+            PauseMenuOption::Language,
             PauseMenuOption::QuitToMenu,
         ];
         
@@ -219,8 +222,8 @@ impl PauseMenu {
                 self.selected_option = Some(match start_option {
                     PauseMenuOption::Resume => PauseMenuOption::QuitToMenu,
                     PauseMenuOption::SaveGame => PauseMenuOption::Resume,
-                    PauseMenuOption::Settings => PauseMenuOption::SaveGame,
-                    PauseMenuOption::QuitToMenu => PauseMenuOption::Settings,
+                    PauseMenuOption::Language => PauseMenuOption::SaveGame,
+                    PauseMenuOption::QuitToMenu => PauseMenuOption::Language,
                 });
                 None
             }
@@ -228,8 +231,8 @@ impl PauseMenu {
                 let start_option = self.selected_option.unwrap_or(PauseMenuOption::Resume);
                 self.selected_option = Some(match start_option {
                     PauseMenuOption::Resume => PauseMenuOption::SaveGame,
-                    PauseMenuOption::SaveGame => PauseMenuOption::Settings,
-                    PauseMenuOption::Settings => PauseMenuOption::QuitToMenu,
+                    PauseMenuOption::SaveGame => PauseMenuOption::Language,
+                    PauseMenuOption::Language => PauseMenuOption::QuitToMenu,
                     PauseMenuOption::QuitToMenu => PauseMenuOption::Resume,
                 });
                 None
@@ -259,7 +262,7 @@ impl PauseMenu {
         let options = [
             PauseMenuOption::Resume,
             PauseMenuOption::SaveGame,
-            PauseMenuOption::Settings,
+            PauseMenuOption::Language,
             PauseMenuOption::QuitToMenu,
         ];
         
@@ -283,7 +286,7 @@ impl PauseMenu {
 pub enum PauseMenuAction {
     Resume,
     SaveGame,
-    Settings,
+    Language,
     QuitToMenu,
 }
 
@@ -292,7 +295,7 @@ impl From<PauseMenuOption> for PauseMenuAction {
         match option {
             PauseMenuOption::Resume => PauseMenuAction::Resume,
             PauseMenuOption::SaveGame => PauseMenuAction::SaveGame,
-            PauseMenuOption::Settings => PauseMenuAction::Settings,
+            PauseMenuOption::Language => PauseMenuAction::Language,
             PauseMenuOption::QuitToMenu => PauseMenuAction::QuitToMenu,
         }
     }
@@ -303,7 +306,7 @@ impl From<PauseMenuOption> for PauseMenuAction {
 pub enum MenuAction {
     NewGame,
     LoadGame,
-    Settings,
+    Language,
     Quit,
 }
 
@@ -312,7 +315,7 @@ impl From<MenuOption> for MenuAction {
         match option {
             MenuOption::NewGame => MenuAction::NewGame,
             MenuOption::LoadGame => MenuAction::LoadGame,
-            MenuOption::Settings => MenuAction::Settings,
+            MenuOption::Language => MenuAction::Language,
             MenuOption::Quit => MenuAction::Quit,
         }
     }
@@ -336,21 +339,19 @@ pub fn draw_pause_menu(
     gpu.add_ui_rect(0.0, 0.0, width as f32, height as f32, [0.0, 0.0, 0.0, 0.7]);
     
     // Заголовок "PAUSED"
-    let title = b"PAUSED";
+    let title = tr("hud.paused");
     let title_scale = scale * 2.0;
-    // Ширина текста: каждый символ занимает 4.0 * px, где px = 2.0 * scale
-    // Для title_scale: px = 2.0 * title_scale, char_width = 4.0 * px = 8.0 * title_scale
-    let title_w = title.len() as f32 * 8.0 * title_scale;
+    let title_w = font::text_width(title, title_scale);
     let title_x = center_x - title_w / 2.0;
     let title_y = start_y - 80.0 * scale;
     gpu.draw_text(title_x, title_y, title, [1.0, 1.0, 0.8, 1.0], title_scale);
     
     // Опции меню
-    let options: &[(PauseMenuOption, &[u8])] = &[
-        (PauseMenuOption::Resume, b"Resume"),
-        (PauseMenuOption::SaveGame, b"Save Game"),
-        (PauseMenuOption::Settings, b"Settings"),
-        (PauseMenuOption::QuitToMenu, b"Quit to Menu"),
+    let options: &[(PauseMenuOption, String)] = &[
+        (PauseMenuOption::Resume, tr("menu.resume").to_string()),
+        (PauseMenuOption::SaveGame, tr("menu.save_game").to_string()),
+        (PauseMenuOption::Language, language_label()),
+        (PauseMenuOption::QuitToMenu, tr("menu.quit_to_menu").to_string()),
     ];
     
     for (i, (option, label)) in options.iter().enumerate() {
@@ -372,7 +373,7 @@ pub fn draw_pause_menu(
         gpu.add_ui_rect(btn_x, btn_y, btn_w, band, [1.0, 1.0, 1.0, 0.27]);
         gpu.add_ui_rect(btn_x, btn_y + btn_height - band, btn_w, band, [0.0, 0.0, 0.0, 0.23]);
         
-        let text_w = label.len() as f32 * 4.0 * 2.0 * scale;
+        let text_w = font::text_width(label, scale);
         let text_x = btn_x + (btn_w - text_w) / 2.0;
         let text_y = btn_y + (btn_height - 5.0 * 2.0 * scale) / 2.0;
         
@@ -382,23 +383,27 @@ pub fn draw_pause_menu(
             [220.0/255.0, 220.0/255.0, 220.0/255.0, 1.0]
         };
         
-        gpu.draw_text(text_x, text_y, *label, text_color, scale as f32);
+        gpu.draw_text(text_x, text_y, label, text_color, scale as f32);
     }
     
     // Отображаем сообщение о сохранении, если есть
     if let Some(ref message) = menu.save_message {
         let alpha = (menu.save_message_timer / 1000.0).min(1.0); // Fade out в последнюю секунду
-        let msg_text = message.as_bytes();
         let msg_scale = scale * 1.2;
-        let msg_w = msg_text.len() as f32 * 4.0 * 2.0 * msg_scale;
+        let msg_w = font::text_width(message, msg_scale);
         let msg_x = center_x - msg_w / 2.0;
         let msg_y = start_y + (options.len() as f32 * btn_spacing) + 30.0 * scale;
         
         // Текст сообщения без фона
-        gpu.draw_text(msg_x, msg_y, msg_text, [1.0, 1.0, 0.8, alpha], msg_scale);
+        gpu.draw_text(msg_x, msg_y, message, [1.0, 1.0, 0.8, alpha], msg_scale);
     }
 }
 
+/// Надпись кнопки языка: показывает текущий язык, нажатие переключает на следующий
+fn language_label() -> String {
+    format!("{}: {}", tr("menu.language"), i18n::language_name())
+}
+
 /// Рендеринг главного меню
 pub fn draw_main_menu(
     gpu: &mut GpuRenderer,
@@ -422,19 +427,19 @@ pub fn draw_main_menu(
     // gpu.add_ui_rect(0.0, 0.0, width as f32, height as f32, [0.0, 0.0, 0.0, 0.3]);
     
     // Заголовок игры
-    let title = b"Cozy Kingdom";
+    let title = "Cozy Kingdom";
     let title_scale = scale * 2.5; // Увеличенный размер заголовка
-    let title_w = title.len() as f32 * 4.0 * 2.0 * title_scale;
+    let title_w = font::text_width(title, title_scale);
     let title_x = center_x - title_w / 2.0;
     let title_y = height as f32 / 2.0 - 200.0 * scale; // Ближе к центру экрана
     gpu.draw_text(title_x, title_y, title, [1.0, 1.0, 0.8, 1.0], title_scale);
     
     // Опции меню
-    let options: &[(MenuOption, &[u8])] = &[
-        (MenuOption::NewGame, b"New Game"),
-        (MenuOption::LoadGame, b"Load Game"),
-        (MenuOption::Settings, b"Settings"),
-        (MenuOption::Quit, b"Quit"),
+    let options: &[(MenuOption, String)] = &[
+        (MenuOption::NewGame, tr("menu.new_game").to_string()),
+        (MenuOption::LoadGame, tr("menu.load_game").to_string()),
+        (MenuOption::Language, language_label()),
+        (MenuOption::Quit, tr("menu.quit").to_string()),
     ];
     
     for (i, (option, label)) in options.iter().enumerate() {
@@ -462,7 +467,7 @@ pub fn draw_main_menu(
         gpu.add_ui_rect(btn_x, btn_y + btn_height - band, btn_w, band, [0.0, 0.0, 0.0, 0.23]);
         
         // Текст кнопки
-        let text_w = label.len() as f32 * 4.0 * 2.0 * scale;
+        let text_w = font::text_width(label, scale);
         let text_x = btn_x + (btn_w - text_w) / 2.0;
         let text_y = btn_y + (btn_height - 5.0 * 2.0 * scale) / 2.0;
         
@@ -472,7 +477,266 @@ pub fn draw_main_menu(
             [220.0/255.0, 220.0/255.0, 220.0/255.0, 1.0]
         };
         
-        gpu.draw_text(text_x, text_y, *label, text_color, scale as f32);
+        gpu.draw_text(text_x, text_y, label, text_color, scale as f32);
+    }
+}
+
+/// Строки экрана создания новой игры
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NewGameRow {
+    Scenario,
+    Seed,
+    Map,
+    Abundance,
+    Start,
+    Difficulty,
+    Begin,
+    Back,
+}
+
+impl NewGameRow {
+    const ALL: [NewGameRow; 8] = [
+        NewGameRow::Scenario,
+        NewGameRow::Seed,
+        NewGameRow::Map,
+        NewGameRow::Abundance,
+        NewGameRow::Start,
+        NewGameRow::Difficulty,
+        NewGameRow::Begin,
+        NewGameRow::Back,
+    ];
+}
+
+/// Действие экрана создания игры
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NewGameAction {
+    Start,
+    Back,
+}
+
+/// Экран создания новой игры: сценарий, seed, пресет карты, ресурсы, сложность
+pub struct NewGameMenu {
+    pub selected_row: Option<NewGameRow>,
+    pub setup: GameSetup,
+    pub seed_text: String, // пусто — случайный seed
+    // сценарии из assets/scenarios; None — свободная игра
+    pub scenarios: Vec<crate::scenario::Scenario>,
+    pub scenario: Option<usize>,
+}
+
+impl NewGameMenu {
+    pub fn new() -> Self {
+        Self {
+            selected_row: None,
+            setup: GameSetup::default(),
+            seed_text: String::new(),
+            scenarios: crate::scenario::load_scenarios(),
+            scenario: None,
+        }
+    }
+    
+    /// Выбранный сценарий
+    pub fn selected_scenario(&self) -> Option<&crate::scenario::Scenario> {
+        self.scenario.and_then(|i| self.scenarios.get(i))
+    }
+    
+    /// Введённый seed (None — выбрать случайно)
+    pub fn seed(&self) -> Option<u64> {
+        self.seed_text.parse().ok()
+    }
+    
+    // Сменить значение строки: dir > 0 — следующее, dir < 0 — предыдущее
+    fn change(&mut self, row: NewGameRow, dir: i32) {
+        use crate::game_setup::{cycle, MapPreset, Abundance, StartResources, Difficulty};
+        let s = &mut self.setup;
+        match row {
+            // свободная игра и сценарии по кругу
+            NewGameRow::Scenario => {
+                let n = self.scenarios.len() as i32 + 1;
+                let cur = self.scenario.map_or(0, |i| i as i32 + 1);
+                let next = (cur + dir).rem_euclid(n);
+                self.scenario = (next > 0).then(|| next as usize - 1);
+            }
+            // вправо — новый случайный seed, влево — сброс на случайный при старте
+            NewGameRow::Seed => {
+                self.seed_text = if dir > 0 { rand::random::<u32>().to_string() } else { String::new() };
+            }
+            NewGameRow::Map => s.map = cycle(&MapPreset::ALL, s.map, dir),
+            NewGameRow::Abundance => s.abundance = cycle(&Abundance::ALL, s.abundance, dir),
+            NewGameRow::Start => s.start = cycle(&StartResources::ALL, s.start, dir),
+            NewGameRow::Difficulty => s.difficulty = cycle(&Difficulty::ALL, s.difficulty, dir),
+            NewGameRow::Begin | NewGameRow::Back => {}
+        }
+    }
+    
+    fn label(&self, row: NewGameRow) -> String {
+        match row {
+            NewGameRow::Scenario => {
+                let name = self.selected_scenario().map_or(tr("menu.free_play"), |s| s.display_name());
+                format!("< {}: {} >", tr("menu.scenario"), name)
+            }
+            NewGameRow::Seed => {
+                let seed = if self.seed_text.is_empty() { tr("menu.random") } else { self.seed_text.as_str() };
+                format!("< {}: {} >", tr("menu.seed"), seed)
+            }
+            NewGameRow::Map => format!("< {}: {} >", tr("menu.map"), self.setup.map.label()),
+            NewGameRow::Abundance => format!("< {}: {} >", tr("menu.resources"), self.setup.abundance.label()),
+            NewGameRow::Start => format!("< {}: {} >", tr("menu.start"), self.setup.start.label()),
+            NewGameRow::Difficulty => format!("< {}: {} >", tr("menu.difficulty"), self.setup.difficulty.label()),
+            NewGameRow::Begin => tr("menu.start_game").to_string(),
+            NewGameRow::Back => tr("menu.back").to_string(),
+        }
+    }
+    
+    // Строка под курсором и сторона (true — правая половина кнопки)
+    fn row_at(&self, x: i32, y: i32, width: i32, height: i32, base_scale: f32) -> Option<(NewGameRow, bool)> {
+        let scale = crate::ui::ui_scale(height, base_scale) as f32;
+        let center_x = width as f32 / 2.0;
+        let start_y = height as f32 / 2.0 - 150.0 * scale;
+        let btn_height = 40.0 * scale;
+        let btn_spacing = 50.0 * scale;
+        let btn_x = center_x - 200.0 * scale;
+        let btn_w = 400.0 * scale;
+        
+        for (i, &row) in NewGameRow::ALL.iter().enumerate() {
+            let btn_y = start_y + (i as f32 * btn_spacing);
+            if x as f32 >= btn_x && x as f32 <= btn_x + btn_w &&
+               y as f32 >= btn_y && y as f32 <= btn_y + btn_height {
+                return Some((row, x as f32 >= center_x));
+            }
+        }
+        None
+    }
+    
+    /// Обработка наведения мыши
+    pub fn handle_hover(&mut self, x: i32, y: i32, width: i32, height: i32, base_scale: f32) {
+        self.selected_row = self.row_at(x, y, width, height, base_scale).map(|(row, _)| row);
+    }
+    
+    /// Обработка клавиатуры: стрелки — выбор и смена значений, цифры — ввод seed
+    pub fn handle_key(&mut self, key: winit::keyboard::PhysicalKey) -> Option<NewGameAction> {
+        use winit::keyboard::{PhysicalKey, KeyCode};
+        
+        let PhysicalKey::Code(code) = key else { return None; };
+        let rows = &NewGameRow::ALL;
+        let current = self.selected_row.and_then(|r| rows.iter().position(|&x| x == r));
+        match code {
+            KeyCode::ArrowUp | KeyCode::KeyW => {
+                let i = current.map(|i| (i + rows.len() - 1) % rows.len()).unwrap_or(0);
+                self.selected_row = Some(rows[i]);
+                None
+            }
+            KeyCode::ArrowDown | KeyCode::KeyS => {
+                let i = current.map(|i| (i + 1) % rows.len()).unwrap_or(0);
+                self.selected_row = Some(rows[i]);
+                None
+            }
+            KeyCode::ArrowLeft | KeyCode::KeyA | KeyCode::ArrowRight | KeyCode::KeyD => {
+                let dir = if matches!(code, KeyCode::ArrowLeft | KeyCode::KeyA) { -1 } else { 1 };
+                if let Some(row) = self.selected_row { self.change(row, dir); }
+                None
+            }
+            KeyCode::Backspace => {
+                self.seed_text.pop();
+                None
+            }
+            KeyCode::Enter | KeyCode::Space => match self.selected_row {
+                Some(NewGameRow::Back) => Some(NewGameAction::Back),
+                Some(NewGameRow::Begin) | None => Some(NewGameAction::Start),
+                Some(row) => { self.change(row, 1); None }
+            },
+            KeyCode::Escape => Some(NewGameAction::Back),
+            _ => {
+                let digit = match code {
+                    KeyCode::Digit0 | KeyCode::Numpad0 => '0', KeyCode::Digit1 | KeyCode::Numpad1 => '1',
+                    KeyCode::Digit2 | KeyCode::Numpad2 => '2', KeyCode::Digit3 | KeyCode::Numpad3 => '3',
+                    KeyCode::Digit4 | KeyCode::Numpad4 => '4', KeyCode::Digit5 | KeyCode::Numpad5 => '5',
+                    KeyCode::Digit6 | KeyCode::Numpad6 => '6', KeyCode::Digit7 | KeyCode::Numpad7 => '7',
+                    KeyCode::Digit8 | KeyCode::Numpad8 => '8', KeyCode::Digit9 | KeyCode::Numpad9 => '9',
+                    _ => return None,
+                };
+                // u64 вмещает не больше 19 цифр без переполнения
+                if self.seed_text.len() < 19 { self.seed_text.push(digit); }
+                None
+            }
+        }
+    }
+    
+    /// Обработка клика мыши: левая половина строки — назад по списку, правая — вперёд
+    pub fn handle_click(&mut self, x: i32, y: i32, width: i32, height: i32, base_scale: f32) -> Option<NewGameAction> {
+        match self.row_at(x, y, width, height, base_scale)? {
+            (NewGameRow::Begin, _) => Some(NewGameAction::Start),
+            (NewGameRow::Back, _) => Some(NewGameAction::Back),
+            (row, right) => { self.change(row, if right { 1 } else { -1 }); None }
+        }
+    }
+}
+
+/// Рендеринг экрана создания новой игры
+pub fn draw_new_game_menu(
+    gpu: &mut GpuRenderer,
+    width: i32,
+    height: i32,
+    menu: &NewGameMenu,
+    base_scale: f32,
+    cursor_x: i32,
+    cursor_y: i32,
+) {
+    draw_menu_background(gpu, width, height, cursor_x, cursor_y);
+    
+    let scale = crate::ui::ui_scale(height, base_scale) as f32;
+    let center_x = width as f32 / 2.0;
+    let start_y = height as f32 / 2.0 - 150.0 * scale;
+    let btn_height = 40.0 * scale;
+    let btn_spacing = 50.0 * scale;
+    
+    // Заголовок
+    let title = tr("menu.new_game");
+    let title_scale = scale * 2.0;
+    let title_w = font::text_width(title, title_scale);
+    gpu.draw_text(center_x - title_w / 2.0, start_y - 80.0 * scale, title, [1.0, 1.0, 0.8, 1.0], title_scale);
+    
+    for (i, &row) in NewGameRow::ALL.iter().enumerate() {
+        let btn_y = start_y + (i as f32 * btn_spacing);
+        let btn_x = center_x - 200.0 * scale;
+        let btn_w = 400.0 * scale;
+        
+        let is_selected = menu.selected_row == Some(row);
+        let bg_color = if is_selected {
+            [185.0/255.0, 140.0/255.0, 95.0/255.0, 220.0/255.0]
+        } else {
+            [140.0/255.0, 105.0/255.0, 75.0/255.0, 180.0/255.0]
+        };
+        gpu.add_ui_rect(btn_x, btn_y, btn_w, btn_height, bg_color);
+        
+        let band = (2.0 * scale).max(2.0);
+        gpu.add_ui_rect(btn_x, btn_y, btn_w, band, [1.0, 1.0, 1.0, 0.27]);
+        gpu.add_ui_rect(btn_x, btn_y + btn_height - band, btn_w, band, [0.0, 0.0, 0.0, 0.23]);
+        
+        let label = menu.label(row);
+        let text_w = font::text_width(&label, scale);
+        let text_x = btn_x + (btn_w - text_w) / 2.0;
+        let text_y = btn_y + (btn_height - 5.0 * 2.0 * scale) / 2.0;
+        let text_color = if is_selected {
+            [1.0, 1.0, 0.9, 1.0]
+        } else {
+            [220.0/255.0, 220.0/255.0, 220.0/255.0, 1.0]
+        };
+        gpu.draw_text(text_x, text_y, &label, text_color, scale);
+    }
+    
+    // Подсказка по управлению
+    let hint = tr("menu.new_game_hint");
+    let hint_scale = scale * 0.8;
+    let hint_w = font::text_width(hint, hint_scale);
+    let hint_y = start_y + NewGameRow::ALL.len() as f32 * btn_spacing + 20.0 * scale;
+    gpu.draw_text(center_x - hint_w / 2.0, hint_y, hint, [0.9, 0.9, 0.8, 0.9], hint_scale);
+    
+    // Описание выбранного сценария (его карта и старт заменяют настройки меню)
+    if let Some(scenario) = menu.selected_scenario() {
+        let desc = scenario.display_description();
+        let desc_w = font::text_width(desc, hint_scale);
+        gpu.draw_text(center_x - desc_w / 2.0, hint_y + 20.0 * scale, desc, [1.0, 1.0, 0.8, 1.0], hint_scale);
     }
 }
 
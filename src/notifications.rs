@@ -1,5 +1,9 @@
+use glam::IVec2;
 use serde::{Serialize, Deserialize};
 
+// сколько записей хранит журнал событий
+const LOG_CAPACITY: usize = 300;
+
 /// Тип уведомления
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NotificationKind {
@@ -9,6 +13,69 @@ pub enum NotificationKind {
     Info { message: String },
 }
 
+/// Раздел журнала событий, по нему фильтруется окно журнала
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogCategory {
+    Research,
+    Warning,
+    Population,
+    Quest,
+    Trade,
+    World,
+    Other,
+}
+
+impl LogCategory {
+    pub const ALL: [LogCategory; 7] = [
+        LogCategory::Research,
+        LogCategory::Warning,
+        LogCategory::Population,
+        LogCategory::Quest,
+        LogCategory::Trade,
+        LogCategory::World,
+        LogCategory::Other,
+    ];
+
+    /// Подпись вкладки фильтра
+    pub fn label(self) -> &'static str {
+        match self {
+            LogCategory::Research => crate::i18n::tr("event_log.research"),
+            LogCategory::Warning => crate::i18n::tr("event_log.warnings"),
+            LogCategory::Population => crate::i18n::tr("event_log.people"),
+            LogCategory::Quest => crate::i18n::tr("event_log.quests"),
+            LogCategory::Trade => crate::i18n::tr("event_log.trade"),
+            LogCategory::World => crate::i18n::tr("event_log.world"),
+            LogCategory::Other => crate::i18n::tr("event_log.other"),
+        }
+    }
+
+    // раздел по умолчанию для уведомления без явного раздела
+    fn of(kind: &NotificationKind) -> Self {
+        match kind {
+            NotificationKind::ResearchCompleted { .. } | NotificationKind::BuildingUnlocked { .. } => LogCategory::Research,
+            NotificationKind::Warning { .. } => LogCategory::Warning,
+            NotificationKind::Info { .. } => LogCategory::Other,
+        }
+    }
+}
+
+/// Окно журнала: выбранный раздел (None — все) и сколько записей прокручено
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogView {
+    pub filter: Option<LogCategory>,
+    pub scroll: usize,
+}
+
+/// Запись журнала событий: игровое время, раздел и место на карте, если есть
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub text: String,
+    pub category: LogCategory,
+    pub day: i32,
+    pub hour: i32,
+    pub pos: Option<IVec2>,
+}
+
 /// Уведомление
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Notification {
@@ -37,13 +104,13 @@ impl Notification {
     pub fn text(&self) -> String {
         match &self.kind {
             NotificationKind::ResearchCompleted { name } => {
-                format!("Research completed: {}", name)
+                crate::i18n::tr_fmt("notify.research_completed", &[name])
             }
             NotificationKind::BuildingUnlocked { name } => {
-                format!("Unlocked: {}", name)
+                crate::i18n::tr_fmt("notify.unlocked", &[name])
             }
             NotificationKind::Warning { message } => {
-                format!("Warning: {}", message)
+                crate::i18n::tr_fmt("notify.warning", &[message])
             }
             NotificationKind::Info { message } => {
                 message.clone()
@@ -62,10 +129,18 @@ impl Notification {
     }
 }
 
-/// Система уведомлений
+/// Система уведомлений: всплывающие сообщения и журнал событий
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct NotificationSystem {
     pub notifications: Vec<Notification>,
+    // журнал событий, новые в конце
+    #[serde(default)]
+    pub log: Vec<LogEntry>,
+    // текущее игровое время для отметок в журнале (выставляет игровой цикл)
+    #[serde(default)]
+    pub day: i32,
+    #[serde(default)]
+    pub hour: i32,
 }
 
 impl NotificationSystem {
@@ -73,12 +148,36 @@ impl NotificationSystem {
     pub fn new() -> Self {
         Self {
             notifications: Vec::new(),
+            log: Vec::new(),
+            day: 0,
+            hour: 0,
         }
     }
     
-    /// Добавить уведомление
+    /// Добавить уведомление (раздел журнала — по типу уведомления)
     pub fn add(&mut self, kind: NotificationKind) {
-        self.notifications.push(Notification::new(kind));
+        self.add_event(LogCategory::of(&kind), kind, None);
+    }
+
+    /// Добавить уведомление в заданный раздел журнала, с местом на карте
+    pub fn add_event(&mut self, category: LogCategory, kind: NotificationKind, pos: Option<IVec2>) {
+        let notification = Notification::new(kind);
+        self.log.push(LogEntry { text: notification.text(), category, day: self.day, hour: self.hour, pos });
+        if self.log.len() > LOG_CAPACITY {
+            self.log.remove(0);
+        }
+        self.notifications.push(notification);
+    }
+
+    /// Выставить игровое время для новых записей журнала
+    pub fn set_time(&mut self, day: i32, hour: i32) {
+        self.day = day;
+        self.hour = hour;
+    }
+
+    /// Записи журнала раздела (все при None), новые первыми
+    pub fn entries(&self, filter: Option<LogCategory>) -> impl Iterator<Item = &LogEntry> {
+        self.log.iter().rev().filter(move |e| filter.is_none_or(|c| e.category == c))
     }
     
     /// Обновить все уведомления
@@ -86,7 +185,7 @@ impl NotificationSystem {
         self.notifications.retain_mut(|n| n.update(delta_ms));
     }
     
-    /// Очистить все уведомления
+    /// Очистить все всплывающие уведомления (журнал остаётся)
     pub fn clear(&mut self) {
         self.notifications.clear();
     }
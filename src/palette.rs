@@ -19,6 +19,23 @@ pub fn building_color(kind: BuildingKind) -> [u8; 4] {
         IronMine => [90, 90, 110, 255],
         Smelter => [190, 190, 210, 255],
         ResearchLab => [100, 100, 255, 255], // Синий цвет для лаборатории
+        CoalMine => [50, 50, 55, 255],
+        GoldMine => [210, 170, 50, 255],
+        GemMine => [120, 200, 190, 255],
+        SaltWorks => [235, 235, 225, 255],
+        Mint => [230, 200, 90, 255],
+        Jeweler => [200, 120, 200, 255],
+        ScoutTower => [150, 120, 90, 255],
+        Dock => [120, 100, 70, 255],
+        CharcoalBurner => [70, 60, 55, 255],
+        Blacksmith => [120, 120, 140, 255],
+        SheepFarm => [200, 200, 170, 255],
+        Weaver => [170, 150, 190, 255],
+        Tailor => [110, 130, 200, 255],
+        HopFarm => [120, 180, 80, 255],
+        Brewery => [180, 120, 60, 255],
+        Tavern => [200, 140, 90, 255],
+        TradingPost => [190, 160, 90, 255],
     }
 }
 
@@ -25,28 +25,30 @@ fn heuristic(a: IVec2, b: IVec2) -> i32 { (a.x - b.x).abs() + (a.y - b.y).abs()
 fn tile_cost(world: &World, p: IVec2) -> Option<i32> {
     let _t = world.fbm.get([p.x as f64, p.y as f64]) as f32; // touch NoiseFn trait
     // чтение без запроса новых чанков: если нет — считаем границу непроходимой
-    let kind = world.chunks.get(&(p.x.div_euclid(crate::world::CHUNK_W), p.y.div_euclid(crate::world::CHUNK_H)))
-        .map(|ch| {
-            let lx = p.x.rem_euclid(crate::world::CHUNK_W);
-            let ly = p.y.rem_euclid(crate::world::CHUNK_H);
-            ch.tiles[(ly * crate::world::CHUNK_W + lx) as usize]
-        })
-        .unwrap_or(TileKind::Water);
+    let kind = world.loaded_tile(p).unwrap_or(TileKind::Water);
     
     // Сначала проверяем дорогу: если дорога есть — ходим всегда
     if world.is_road(p) {
-        let on_water = matches!(kind, TileKind::Water);
+        let on_water = kind.is_water();
         return Some(if on_water { 2 } else { 1 });
     }
     
-    match kind {
-        TileKind::Water => None,
-        _ => {
-            // высокая разница стоимостей: трава=4, лес=7 (дороги обработаны выше)
-            let base = match kind { TileKind::Grass => 4, TileKind::Forest => 7, TileKind::Water => 999 };
-            Some(base)
-        }
-    }
+    // высокая разница стоимостей: трава=4, лес=7 (дороги обработаны выше)
+    let base = match kind {
+        TileKind::Water | TileKind::Mountain => return None,
+        TileKind::Grass => 4,
+        TileKind::Sand => 5,
+        TileKind::Forest => 7,
+        TileKind::Hills => 8,
+        TileKind::ShallowWater => 12,
+    };
+    // подъём/спуск по склону дороже
+    Some(base + world.slope(p) as i32 * 3)
+}
+
+// стоимость переправы — как по дороге той же длины, плюс ожидание парома
+fn ferry_cost(a: IVec2, b: IVec2) -> i32 {
+    heuristic(a, b) + 20
 }
 
 pub fn astar(world: &World, start: IVec2, goal: IVec2, max_expansions: usize) -> Option<Vec<IVec2>> {
@@ -63,9 +65,11 @@ pub fn astar(world: &World, start: IVec2, goal: IVec2, max_expansions: usize) ->
         if !closed.insert((pos.x,pos.y)) { continue; }
         expansions += 1; if expansions > max_expansions { break; }
         const NB: [(i32,i32);4] = [(1,0),(-1,0),(0,1),(0,-1)];
-        for (dx,dy) in NB {
-            let np = IVec2::new(pos.x + dx, pos.y + dy);
-            if let Some(step_cost) = tile_cost(world, np) {
+        // паромная линия: с причала можно переправиться на причал другого берега
+        let ferry = world.ferry_partner(pos).map(|to| (to, ferry_cost(pos, to)));
+        let steps = NB.iter().map(|&(dx,dy)| { let np = IVec2::new(pos.x + dx, pos.y + dy); (np, tile_cost(world, np)) });
+        for (np, cost) in steps.chain(ferry.map(|(to, c)| (to, Some(c)))) {
+            if let Some(step_cost) = cost {
                 let tentative = gscore.get(&(pos.x,pos.y)).copied().unwrap_or(i32::MAX/4) + step_cost;
                 if tentative < gscore.get(&(np.x,np.y)).copied().unwrap_or(i32::MAX/4) {
                     came_from.insert((np.x,np.y), pos);
@@ -87,4 +91,107 @@ pub fn astar(world: &World, start: IVec2, goal: IVec2, max_expansions: usize) ->
     Some(path)
 }
 
+/// Связаны ли клетки `from` и одна из `targets` непрерывной дорогой
+/// (дорога должна примыкать к обеим); заливка ограничена `limit` тайлами
+pub fn road_connected(world: &World, from: IVec2, targets: &[IVec2], limit: usize) -> bool {
+    const NB: [(i32,i32);4] = [(1,0),(-1,0),(0,1),(0,-1)];
+    let touches_target = |p: IVec2| NB.iter().any(|&(dx,dy)| targets.contains(&IVec2::new(p.x + dx, p.y + dy)));
+    let mut seen: HashSet<(i32,i32)> = HashSet::new();
+    let mut stack: Vec<IVec2> = NB.iter()
+        .map(|&(dx,dy)| IVec2::new(from.x + dx, from.y + dy))
+        .filter(|&p| world.is_road(p))
+        .collect();
+    while let Some(p) = stack.pop() {
+        if !seen.insert((p.x, p.y)) { continue; }
+        if touches_target(p) { return true; }
+        if seen.len() >= limit { break; }
+        for (dx,dy) in NB {
+            let np = IVec2::new(p.x + dx, p.y + dy);
+            if world.is_road(np) && !seen.contains(&(np.x, np.y)) { stack.push(np); }
+        }
+    }
+    false
+}
+
+/// Разметка островов заливкой суши (дороги и мосты — тоже суша) от точек `seeds`.
+/// Незагруженные чанки считаются водой; заливка острова ограничена `limit` тайлами.
+/// Упёршиеся в предел заливки считаются одним материком: иначе точки одного большого
+/// массива суши получили бы разные метки
+pub fn label_islands(world: &World, seeds: &[IVec2], limit: usize) -> HashMap<(i32,i32), u32> {
+    const NB: [(i32,i32);4] = [(1,0),(-1,0),(0,1),(0,-1)];
+    let mut labels: HashMap<(i32,i32), u32> = HashMap::new();
+    let mut next = 0u32;
+    let mut mainland: Option<u32> = None;
+    for &seed in seeds {
+        if labels.contains_key(&(seed.x, seed.y)) { continue; }
+        let label = next;
+        let mut tiles = vec![seed];
+        let mut stack = vec![seed];
+        labels.insert((seed.x, seed.y), label);
+        let mut truncated = false;
+        // дошли до недозалитого ранее материка
+        let mut joined: Option<u32> = None;
+        while let Some(p) = stack.pop() {
+            for (dx,dy) in NB {
+                let np = IVec2::new(p.x + dx, p.y + dy);
+                if let Some(&l) = labels.get(&(np.x, np.y)) {
+                    if l != label { joined = Some(l); }
+                    continue;
+                }
+                let land = world.is_road(np)
+                    || world.loaded_tile(np).is_some_and(|k| !matches!(k, TileKind::Water | TileKind::Mountain));
+                if !land { continue; }
+                if tiles.len() >= limit { truncated = true; continue; }
+                labels.insert((np.x, np.y), label);
+                tiles.push(np);
+                stack.push(np);
+            }
+        }
+        match joined.or(if truncated { mainland } else { None }) {
+            Some(l) => for p in tiles { labels.insert((p.x, p.y), l); },
+            None => {
+                if truncated { mainland = Some(label); }
+                next += 1;
+            }
+        }
+    }
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    // вне загруженных чанков сушей считаются только дороги
+    fn road_strip(world: &mut World, y: i32, x0: i32, x1: i32) {
+        for x in x0..=x1 { world.set_road(IVec2::new(x, y), true); }
+    }
+
+    #[test]
+    fn separate_land_gets_separate_labels() {
+        let mut world = World::new(1, 1);
+        road_strip(&mut world, 0, 0, 10);
+        road_strip(&mut world, 5, 0, 10);
+        let seeds = [IVec2::new(0, 0), IVec2::new(10, 0), IVec2::new(0, 5)];
+        let labels = label_islands(&world, &seeds, 1000);
+        assert_eq!(labels[&(0, 0)], labels[&(10, 0)]);
+        assert_ne!(labels[&(0, 0)], labels[&(0, 5)]);
+        assert!(!labels.contains_key(&(0, 3)));
+    }
+
+    #[test]
+    fn truncated_floods_count_as_one_mainland() {
+        let mut world = World::new(1, 1);
+        road_strip(&mut world, 0, 0, 100);
+        road_strip(&mut world, 5, 0, 100);
+        road_strip(&mut world, 10, 0, 3);
+        let seeds = [IVec2::new(0, 0), IVec2::new(100, 0), IVec2::new(0, 5), IVec2::new(0, 10)];
+        let labels = label_islands(&world, &seeds, 20);
+        // обе длинные полосы упёрлись в предел и получили одну метку
+        assert_eq!(labels[&(0, 0)], labels[&(100, 0)]);
+        assert_eq!(labels[&(0, 0)], labels[&(0, 5)]);
+        // маленький остров размечен целиком и отдельно
+        assert_ne!(labels[&(0, 10)], labels[&(0, 0)]);
+        assert_eq!(labels[&(3, 10)], labels[&(0, 10)]);
+    }
+}
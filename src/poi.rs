@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use glam::IVec2;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Serialize, Deserialize};
+
+use crate::types::TileKind;
+use crate::world::{World, CHUNK_W, CHUNK_H};
+use crate::i18n::tr;
+
+// вероятность точки интереса в чанке
+const POI_CHANCE: f64 = 0.3;
+// точка видна на миникарте, если разведанная область ближе этого расстояния
+pub const REVEAL_RADIUS: i32 = 8;
+// как часто (мс игрового времени) ищем новые точки и проверяем разведку
+const SCAN_INTERVAL_MS: i32 = 1000;
+// караван делает шаг к поселению раз в CARAVAN_STEP_MS и уходит через CARAVAN_TTL_MS
+const CARAVAN_STEP_MS: i32 = 2500;
+const CARAVAN_TTL_MS: i32 = 3 * 120_000;
+// волнения после набега бандитов: сколько дней и какая доля налогов теряется (%)
+const UNREST_DAYS: i32 = 3;
+const UNREST_TAX_LOSS_PCT: i32 = 40;
+
+/// Вид точки интереса
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoiKind {
+    Cache,      // тайник: дерево, камень, золото
+    Ruins,      // руины: ускоряют текущее исследование
+    BanditCamp, // лагерь бандитов: грабит казну, волнения урезают налоги
+    Caravan,    // караван: идёт к поселению и продаёт хлеб
+}
+
+impl PoiKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PoiKind::Cache => tr("poi.cache"),
+            PoiKind::Ruins => tr("poi.ruins"),
+            PoiKind::BanditCamp => tr("poi.bandit_camp"),
+            PoiKind::Caravan => tr("poi.caravan"),
+        }
+    }
+
+    /// Цвет метки на миникарте
+    pub fn color(self) -> [f32; 4] {
+        match self {
+            PoiKind::Cache => [1.0, 0.85, 0.2, 1.0],
+            PoiKind::Ruins => [0.65, 0.55, 0.9, 1.0],
+            PoiKind::BanditCamp => [0.9, 0.15, 0.1, 1.0],
+            PoiKind::Caravan => [0.2, 0.9, 0.9, 1.0],
+        }
+    }
+}
+
+/// Точка интереса на карте
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Poi {
+    pub kind: PoiKind,
+    pub pos: IVec2,
+    // замечена с границы разведки (видна на миникарте)
+    pub revealed: bool,
+    // величина награды/ущерба, выбранная при генерации
+    pub amount: i32,
+    // сколько ещё караван ждёт (для остальных не используется)
+    #[serde(default)]
+    pub ttl_ms: i32,
+}
+
+/// Что произошло с точкой интереса за обновление
+pub enum PoiEvent {
+    Revealed(Poi),
+    Reached(Poi),
+    CaravanLeft(Poi),
+}
+
+/// Точки интереса: генерация по чанкам, разведка, караваны и волнения
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PoiSystem {
+    pub pois: Vec<Poi>,
+    // чанки, для которых точка уже разыграна (чтобы не появлялась повторно)
+    pub scanned: HashSet<(i32, i32)>,
+    pub unrest_days: i32,
+    scan_timer_ms: i32,
+    step_timer_ms: i32,
+}
+
+impl PoiSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Сколько налогов недобрано из-за волнений (0, если их нет)
+    pub fn unrest_tax_loss(&self, income: i32) -> i32 {
+        if self.unrest_days > 0 { income * UNREST_TAX_LOSS_PCT / 100 } else { 0 }
+    }
+
+    pub fn start_unrest(&mut self) {
+        self.unrest_days = UNREST_DAYS;
+    }
+
+    /// Вызывается на рассвете
+    pub fn new_day(&mut self) {
+        self.unrest_days = (self.unrest_days - 1).max(0);
+    }
+
+    /// Раскидать точки по новым чанкам у границы, двигать караваны,
+    /// отметить замеченные и достигнутые разведкой точки
+    pub fn update(&mut self, step_ms: i32, world: &World, home: IVec2) -> Vec<PoiEvent> {
+        let mut events = Vec::new();
+
+        self.step_timer_ms += step_ms;
+        if self.step_timer_ms >= CARAVAN_STEP_MS {
+            self.step_timer_ms = 0;
+            for p in self.pois.iter_mut().filter(|p| p.kind == PoiKind::Caravan && p.revealed) {
+                p.ttl_ms -= CARAVAN_STEP_MS;
+                step_towards(world, p, home);
+            }
+            let (left, stay): (Vec<Poi>, Vec<Poi>) = self.pois.drain(..).partition(|p| p.kind == PoiKind::Caravan && p.ttl_ms <= 0);
+            self.pois = stay;
+            events.extend(left.into_iter().map(PoiEvent::CaravanLeft));
+        }
+
+        self.scan_timer_ms += step_ms;
+        if self.scan_timer_ms < SCAN_INTERVAL_MS { return events; }
+        self.scan_timer_ms = 0;
+
+        self.scan_chunks(world);
+
+        let mut i = 0;
+        while i < self.pois.len() {
+            if world.is_explored(self.pois[i].pos) {
+                events.push(PoiEvent::Reached(self.pois.swap_remove(i)));
+                continue;
+            }
+            let p = &mut self.pois[i];
+            if !p.revealed && near_explored(world, p.pos) {
+                p.revealed = true;
+                events.push(PoiEvent::Revealed(p.clone()));
+            }
+            i += 1;
+        }
+        events
+    }
+
+    // разыграть точки для разведанных чанков и их соседей
+    // (REVEAL_RADIUS меньше чанка, так что соседей достаточно)
+    fn scan_chunks(&mut self, world: &World) {
+        let explored: Vec<(i32, i32)> = world.explored_chunks().collect();
+        for (cx, cy) in explored {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let key = (cx + dx, cy + dy);
+                    if !self.scanned.insert(key) { continue; }
+                    if let Some(p) = roll_chunk(world, key) {
+                        self.pois.push(p);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// детерминированный розыгрыш точки в чанке по seed мира
+fn roll_chunk(world: &World, key: (i32, i32)) -> Option<Poi> {
+    let h = world.seed
+        ^ (key.0 as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (key.1 as i64 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    let mut rng = StdRng::seed_from_u64(h);
+    if !rng.random_bool(POI_CHANCE) { return None; }
+    let pos = IVec2::new(key.0 * CHUNK_W + rng.random_range(2..CHUNK_W - 2), key.1 * CHUNK_H + rng.random_range(2..CHUNK_H - 2));
+    // уже разведанное место не даёт награды даром (стартовая область, старые сохранения)
+    if world.is_explored(pos) { return None; }
+    let tile = world.tile_at(pos);
+    if matches!(tile, TileKind::Water | TileKind::ShallowWater | TileKind::Mountain) { return None; }
+    let roll = rng.random_range(0..100);
+    let kind = match tile {
+        // на холмах чаще руины и бандиты, караваны ходят по равнинам
+        TileKind::Hills => if roll < 50 { PoiKind::Ruins } else if roll < 80 { PoiKind::BanditCamp } else { PoiKind::Cache },
+        _ => if roll < 35 { PoiKind::Cache } else if roll < 55 { PoiKind::Ruins } else if roll < 75 { PoiKind::BanditCamp } else { PoiKind::Caravan },
+    };
+    let amount = match kind {
+        PoiKind::Cache => rng.random_range(20..=60),
+        PoiKind::Ruins => rng.random_range(1..=3),
+        PoiKind::BanditCamp => rng.random_range(10..=25),
+        PoiKind::Caravan => rng.random_range(10..=30),
+    };
+    let ttl_ms = if kind == PoiKind::Caravan { CARAVAN_TTL_MS } else { 0 };
+    Some(Poi { kind, pos, revealed: false, amount, ttl_ms })
+}
+
+fn near_explored(world: &World, pos: IVec2) -> bool {
+    let r = REVEAL_RADIUS;
+    for dy in -r..=r {
+        for dx in -r..=r {
+            if dx * dx + dy * dy <= r * r && world.is_explored(pos + IVec2::new(dx, dy)) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// шаг каравана к поселению: сначала по более длинной оси, в обход воды и гор
+fn step_towards(world: &World, p: &mut Poi, home: IVec2) {
+    let d = home - p.pos;
+    let sx = IVec2::new(d.x.signum(), 0);
+    let sy = IVec2::new(0, d.y.signum());
+    let order = if d.x.abs() >= d.y.abs() { [sx, sy] } else { [sy, sx] };
+    for s in order {
+        if s == IVec2::ZERO { continue; }
+        let next = p.pos + s;
+        if !matches!(world.tile_at(next), TileKind::Water | TileKind::Mountain) {
+            p.pos = next;
+            return;
+        }
+    }
+}
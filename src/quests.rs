@@ -1,43 +1,272 @@
+use std::collections::HashMap;
+
 use serde::{Serialize, Deserialize};
-use crate::types::{BuildingKind, Resources};
+use crate::research::ResearchSystem;
+use crate::types::{Building, BuildingKind, ResourceKind, Resources, WarehouseStore};
+use crate::world::World;
+use crate::i18n::{tr, tr_fmt, tr_opt};
 use rand::Rng;
 
+// одновременно висит не больше стольких случайных квестов (цепочки и контракты не в счёт)
+const MAX_RANDOM_QUESTS: usize = 3;
+// сколько записей хранит журнал квестов
+const LOG_CAPACITY: usize = 30;
+// связность дорог проверяем не каждый кадр, заливка ограничена
+const ROAD_CHECK_INTERVAL_MS: f32 = 1000.0;
+const ROAD_SEARCH_LIMIT: usize = 20_000;
+
 /// Тип квеста
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuestKind {
     /// Собрать определенное количество ресурса
     CollectResource {
-        resource_name: String,
+        // старые сохранения хранили имя ресурса строкой ("Wood", "Fish"...)
+        #[serde(alias = "resource_name")]
+        resource: ResourceKind,
         target_amount: i32,
+        #[serde(default)]
         current_amount: i32,
     },
     /// Построить определенное количество зданий
     BuildBuildings {
         building_kind: BuildingKind,
         target_count: i32,
+        #[serde(default)]
         current_count: i32,
     },
     /// Достичь определенного населения
     ReachPopulation {
         target_population: i32,
+        #[serde(default)]
         current_population: i32,
     },
     /// Собрать определенное количество золота
     CollectGold {
+        target_amount: i32,
+        #[serde(default)]
+        current_amount: i32,
+    },
+    /// Контракт соседнего поселения: поставка засчитывается на рассвете (см. ContractSystem)
+    Contract {
+        contract_id: u32,
+        resource: ResourceKind,
         target_amount: i32,
         current_amount: i32,
+        days_left: i32,
+        fulfilled: bool,
+    },
+    /// Прирост ресурса за день (производство минус потребление), считается на рассвете
+    ProduceDaily {
+        resource: ResourceKind,
+        target_per_day: i32,
+        #[serde(default)]
+        current_per_day: i32,
+        // запас на прошлом рассвете
+        #[serde(default)]
+        last_stock: Option<i32>,
+    },
+    /// Достичь средней счастливости жителей
+    ReachHappiness {
+        target: i32,
+        #[serde(default)]
+        current: i32,
+    },
+    /// Соединить дорогой здание этого типа со складом
+    ConnectByRoad {
+        building_kind: BuildingKind,
+        #[serde(default)]
+        connected: bool,
     },
 }
 
+/// Награда за квест
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestReward {
+    Gold(i32),
+    Resource(ResourceKind, i32),
+    /// Ускорить текущее исследование на несколько дней
+    ResearchDays(i32),
+    /// Сразу завершить исследование (ключ из assets/research.toml)
+    Research(String),
+}
+
+impl QuestReward {
+    pub fn label(&self) -> String {
+        match self {
+            QuestReward::Gold(n) => tr_fmt("quest.reward_gold", &[n]),
+            QuestReward::Resource(kind, n) => format!("{} {}", n, crate::trade::display_label(*kind)),
+            QuestReward::ResearchDays(n) => tr_fmt("quest.reward_research_days", &[n]),
+            QuestReward::Research(key) => tr_fmt("quest.reward_research", &[&crate::research::name(key)]),
+        }
+    }
+}
+
+/// Награды одной строкой для UI и уведомлений
+pub fn rewards_label(rewards: &[QuestReward]) -> String {
+    if rewards.is_empty() {
+        return tr("quest.nothing").to_string();
+    }
+    rewards.iter().map(|r| r.label()).collect::<Vec<_>>().join(", ")
+}
+
+/// Заголовок и описание квеста на одном языке
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestText {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Аргумент текста случайного квеста; названия переводятся при показе
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextArg {
+    Number(i32),
+    Resource(ResourceKind),
+    // здания во множественном числе
+    Buildings(BuildingKind),
+    Name(String),
+}
+
+impl std::fmt::Display for TextArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TextArg::Number(n) => write!(f, "{}", n),
+            TextArg::Resource(kind) => f.write_str(crate::trade::display_label(*kind)),
+            TextArg::Buildings(kind) => f.write_str(building_plural(*kind)),
+            TextArg::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Текст случайного квеста или контракта: ключи таблицы строк и аргументы к ним
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextTemplate {
+    pub title: String,
+    pub description: String,
+    pub args: Vec<TextArg>,
+}
+
+impl TextTemplate {
+    fn fill(&self, key: &str) -> String {
+        let args: Vec<&dyn std::fmt::Display> = self.args.iter().map(|a| a as &dyn std::fmt::Display).collect();
+        crate::i18n::fill(tr_opt(key).unwrap_or(key), &args)
+    }
+}
+
+/// Заголовок и описание квеста: у квестов цепочек — английский текст и переводы,
+/// у случайных квестов и контрактов — шаблон. Текст собирается на языке интерфейса,
+/// так что сохранённый квест не остаётся на языке, на котором был выдан
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct QuestLabel {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub translations: HashMap<String, QuestText>,
+    #[serde(default)]
+    pub template: Option<TextTemplate>,
+}
+
+impl QuestLabel {
+    fn template(title: &str, description: &str, args: Vec<TextArg>) -> Self {
+        let template = TextTemplate { title: title.to_string(), description: description.to_string(), args };
+        Self { template: Some(template), ..Default::default() }
+    }
+
+    /// Заголовок на текущем языке интерфейса
+    pub fn title(&self) -> String {
+        match &self.template {
+            Some(t) => t.fill(&t.title),
+            None => self.translations.get(crate::i18n::language()).map_or(&self.title, |t| &t.title).clone(),
+        }
+    }
+
+    /// Описание на текущем языке интерфейса (журнал пока показывает только заголовки)
+    #[allow(dead_code)]
+    pub fn description(&self) -> String {
+        match &self.template {
+            Some(t) => t.fill(&t.description),
+            None => self.translations.get(crate::i18n::language()).map_or(&self.description, |t| &t.description).clone(),
+        }
+    }
+}
+
 /// Квест
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Quest {
     pub id: u32,
+    // ключ квеста из цепочки (для пререквизитов); у случайных квестов и контрактов нет
+    #[serde(default)]
+    pub key: Option<String>,
     pub kind: QuestKind,
+    #[serde(flatten)]
+    pub label: QuestLabel,
+    #[serde(default)]
+    pub rewards: Vec<QuestReward>,
+    // срок в днях; по истечении квест провален
+    #[serde(default)]
+    pub days_left: Option<i32>,
+    // выполнен и ждёт, пока игрок заберёт награду
+    pub completed: bool,
+}
+
+/// Шаг цепочки квестов: выдаётся, когда выполнены все квесты из `requires`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestDef {
+    pub key: String,
     pub title: String,
     pub description: String,
-    pub reward_gold: i32,
-    pub completed: bool,
+    #[serde(default)]
+    pub requires: Vec<String>,
+    pub kind: QuestKind,
+    pub rewards: Vec<QuestReward>,
+    #[serde(default)]
+    pub days: Option<i32>,
+    // язык → заголовок и описание; нет перевода — английский текст
+    #[serde(default)]
+    pub translations: HashMap<String, QuestText>,
+}
+
+impl QuestDef {
+    /// Заголовок на текущем языке интерфейса
+    pub fn display_title(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.title.as_str(), |t| t.title.as_str())
+    }
+}
+
+/// Чем закончился квест (для журнала)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestOutcome {
+    Completed,
+    Failed,
+}
+
+/// Запись журнала квестов
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestLogEntry {
+    #[serde(flatten)]
+    pub label: QuestLabel,
+    pub outcome: QuestOutcome,
+    pub rewards: Vec<QuestReward>,
+}
+
+/// Что произошло с квестами
+pub enum QuestEvent {
+    New(Quest),
+    Completed(Quest),
+    Failed(Quest),
+}
+
+/// Состояние поселения, по которому проверяются цели квестов
+pub struct QuestContext<'a> {
+    // ресурсы вместе со складами
+    pub resources: &'a Resources,
+    pub warehouses: &'a [WarehouseStore],
+    pub buildings: &'a [Building],
+    pub population: i32,
+    pub happiness: i32,
+    pub world: &'a World,
 }
 
 /// Система управления квестами
@@ -47,6 +276,23 @@ pub struct QuestSystem {
     pub next_quest_id: u32,
     pub next_quest_timer_ms: f32,
     pub next_quest_interval_ms: f32, // Интервал между появлением новых квестов
+    // ключи выполненных квестов цепочек
+    #[serde(default)]
+    pub completed_keys: Vec<String>,
+    // ключи проваленных квестов цепочек (для условий поражения сценария)
+    #[serde(default)]
+    pub failed_keys: Vec<String>,
+    // журнал выполненных и проваленных квестов, новые в конце
+    #[serde(default)]
+    pub log: Vec<QuestLogEntry>,
+    // источники квестов: цепочки (assets/quests или квесты сценария) и случайный генератор;
+    // цепочки сохраняются с игрой, старые сохранения берут их из файлов
+    #[serde(default = "crate::scenario::load_quest_defs")]
+    pub chain: Vec<QuestDef>,
+    #[serde(default = "random_quests_default")]
+    pub random_quests: bool,
+    #[serde(skip)]
+    road_check_ms: f32,
 }
 
 impl QuestSystem {
@@ -56,200 +302,403 @@ impl QuestSystem {
             next_quest_id: 1,
             next_quest_timer_ms: 0.0,
             next_quest_interval_ms: 60000.0, // 60 секунд по умолчанию
+            completed_keys: Vec::new(),
+            failed_keys: Vec::new(),
+            log: Vec::new(),
+            chain: crate::scenario::load_quest_defs(),
+            random_quests: true,
+            road_check_ms: 0.0,
         }
     }
-    
-    /// Обновить систему квестов
-    pub fn update(&mut self, delta_ms: f32, rng: &mut impl Rng, resources: &Resources, warehouses: &[crate::types::WarehouseStore], buildings: &[crate::types::Building], population: i32) -> Vec<Quest> {
-        let mut completed_quests = Vec::new();
-        
-        // Обновляем таймер появления новых квестов
+
+    /// Обновить систему квестов: выдать новые квесты и проверить цели
+    pub fn update(&mut self, delta_ms: f32, rng: &mut impl Rng, ctx: &QuestContext) -> Vec<QuestEvent> {
+        let mut events = Vec::new();
+
+        // Шаги цепочек, у которых выполнены пререквизиты
+        for def in &self.chain {
+            let taken = self.completed_keys.contains(&def.key)
+                || self.active_quests.iter().any(|q| q.key.as_deref() == Some(def.key.as_str()));
+            if taken || !def.requires.iter().all(|r| self.completed_keys.contains(r)) {
+                continue;
+            }
+            let quest = Quest {
+                id: self.next_quest_id,
+                key: Some(def.key.clone()),
+                kind: def.kind.clone(),
+                label: QuestLabel {
+                    title: def.title.clone(),
+                    description: def.description.clone(),
+                    translations: def.translations.clone(),
+                    template: None,
+                },
+                rewards: def.rewards.clone(),
+                days_left: def.days,
+                completed: false,
+            };
+            self.next_quest_id += 1;
+            events.push(QuestEvent::New(quest.clone()));
+            self.active_quests.push(quest);
+        }
+
+        // Генерируем новый случайный квест, если пришло время и есть место
         self.next_quest_timer_ms -= delta_ms;
-        
-        // Вычисляем общее количество ресурсов (включая склады)
-        let total_res = crate::types::total_resources(warehouses, resources);
-        
-        // Генерируем новый квест, если пришло время и есть место (максимум 3 активных квеста)
-        if self.next_quest_timer_ms <= 0.0 && self.active_quests.len() < 3 {
-            if let Some(new_quest) = Self::generate_random_quest(rng, self.next_quest_id, &total_res, buildings, population) {
+        let random_quests = self.active_quests.iter()
+            .filter(|q| q.key.is_none() && !matches!(q.kind, QuestKind::Contract { .. }))
+            .count();
+        if self.random_quests && self.next_quest_timer_ms <= 0.0 && random_quests < MAX_RANDOM_QUESTS {
+            if let Some(new_quest) = Self::generate_random_quest(rng, self.next_quest_id, ctx) {
                 self.active_quests.push(new_quest);
                 self.next_quest_id += 1;
                 self.next_quest_timer_ms = self.next_quest_interval_ms;
             }
         }
-        
+
+        self.road_check_ms -= delta_ms;
+        let check_roads = self.road_check_ms <= 0.0;
+        if check_roads {
+            self.road_check_ms = ROAD_CHECK_INTERVAL_MS;
+        }
+
         // Проверяем выполнение квестов
         for quest in &mut self.active_quests {
             if quest.completed {
                 continue;
             }
-            
+
             let is_completed = match &mut quest.kind {
-                QuestKind::CollectResource { resource_name, current_amount, target_amount } => {
-                    let current = match resource_name.as_str() {
-                        "Wood" => total_res.wood,
-                        "Stone" => total_res.stone,
-                        "Clay" => total_res.clay,
-                        "Bricks" => total_res.bricks,
-                        "Wheat" => total_res.wheat,
-                        "Flour" => total_res.flour,
-                        "Bread" => total_res.bread,
-                        "Fish" => total_res.fish,
-                        "Iron Ore" => total_res.iron_ore,
-                        "Iron Ingots" => total_res.iron_ingots,
-                        _ => 0,
-                    };
-                    *current_amount = current;
-                    current >= *target_amount
+                QuestKind::CollectResource { resource, current_amount, target_amount } => {
+                    *current_amount = crate::resource_visitor::get_resource_value(ctx.resources, *resource);
+                    *current_amount >= *target_amount
                 }
                 QuestKind::BuildBuildings { building_kind, current_count, target_count } => {
-                    *current_count = buildings.iter().filter(|b| b.kind == *building_kind).count() as i32;
+                    *current_count = ctx.buildings.iter().filter(|b| b.kind == *building_kind).count() as i32;
                     *current_count >= *target_count
                 }
                 QuestKind::ReachPopulation { current_population, target_population } => {
-                    *current_population = population;
+                    *current_population = ctx.population;
                     *current_population >= *target_population
                 }
                 QuestKind::CollectGold { current_amount, target_amount } => {
-                    *current_amount = total_res.gold;
+                    *current_amount = ctx.resources.gold;
                     *current_amount >= *target_amount
                 }
+                QuestKind::Contract { resource, current_amount, fulfilled, .. } => {
+                    *current_amount = crate::resource_visitor::get_resource_value(ctx.resources, *resource);
+                    *fulfilled
+                }
+                QuestKind::ProduceDaily { current_per_day, target_per_day, last_stock, .. } => {
+                    last_stock.is_some() && *current_per_day >= *target_per_day
+                }
+                QuestKind::ReachHappiness { target, current } => {
+                    *current = ctx.happiness;
+                    *current >= *target
+                }
+                QuestKind::ConnectByRoad { building_kind, connected } => {
+                    if check_roads {
+                        *connected = road_connected_kind(ctx, *building_kind);
+                    }
+                    *connected
+                }
             };
-            
-            if is_completed && !quest.completed {
+
+            if is_completed {
                 quest.completed = true;
-                completed_quests.push(quest.clone());
+                events.push(QuestEvent::Completed(quest.clone()));
+            }
+        }
+
+        events
+    }
+
+    /// Рассвет: суточный прирост ресурсов и сроки квестов
+    pub fn new_day(&mut self, total_res: &Resources) -> Vec<QuestEvent> {
+        let mut events = Vec::new();
+        for quest in &mut self.active_quests {
+            if quest.completed {
+                continue;
+            }
+            if let QuestKind::ProduceDaily { resource, current_per_day, last_stock, .. } = &mut quest.kind {
+                let stock = crate::resource_visitor::get_resource_value(total_res, *resource);
+                if let Some(prev) = *last_stock {
+                    *current_per_day = stock - prev;
+                }
+                *last_stock = Some(stock);
+            }
+            if let Some(days) = quest.days_left.as_mut() {
+                *days -= 1;
+            }
+        }
+
+        // просроченные квесты проваливаются и уходят в журнал
+        let mut i = 0;
+        while i < self.active_quests.len() {
+            let q = &self.active_quests[i];
+            if !q.completed && q.days_left.is_some_and(|d| d <= 0) {
+                let q = self.active_quests.remove(i);
+                if let Some(key) = &q.key {
+                    self.failed_keys.push(key.clone());
+                }
+                self.push_log(&q, QuestOutcome::Failed);
+                events.push(QuestEvent::Failed(q));
+                continue;
             }
+            i += 1;
+        }
+        events
+    }
+
+    /// Забрать награду за выполненный квест; награду выдаёт вызывающий (см. grant_rewards)
+    pub fn claim(&mut self, id: u32) -> Option<Quest> {
+        let idx = self.active_quests.iter().position(|q| q.id == id && q.completed)?;
+        let quest = self.active_quests.remove(idx);
+        if let Some(key) = &quest.key {
+            self.completed_keys.push(key.clone());
+        }
+        self.push_log(&quest, QuestOutcome::Completed);
+        Some(quest)
+    }
+
+    fn push_log(&mut self, quest: &Quest, outcome: QuestOutcome) {
+        self.log.push(QuestLogEntry { label: quest.label.clone(), outcome, rewards: quest.rewards.clone() });
+        if self.log.len() > LOG_CAPACITY {
+            self.log.remove(0);
+        }
+    }
+
+    /// Принятый контракт — в список квестов (награда выдаётся как за квест)
+    pub fn add_contract(&mut self, contract: &crate::contracts::Contract, town: &str) {
+        self.active_quests.push(Quest {
+            id: self.next_quest_id,
+            key: None,
+            kind: QuestKind::Contract {
+                contract_id: contract.id,
+                resource: contract.kind,
+                target_amount: contract.amount,
+                current_amount: 0,
+                days_left: contract.days_left,
+                fulfilled: false,
+            },
+            label: QuestLabel::template("quest.contract_title", "quest.contract_desc", vec![
+                TextArg::Number(contract.amount),
+                TextArg::Resource(contract.kind),
+                TextArg::Name(town.to_string()),
+                TextArg::Number(contract.days),
+            ]),
+            rewards: vec![QuestReward::Gold(contract.reward_gold)],
+            days_left: None,
+            completed: false,
+        });
+        self.next_quest_id += 1;
+    }
+
+    fn contract_mut(&mut self, id: u32) -> Option<&mut QuestKind> {
+        self.active_quests.iter_mut()
+            .map(|q| &mut q.kind)
+            .find(|k| matches!(k, QuestKind::Contract { contract_id, .. } if *contract_id == id))
+    }
+
+    /// Поставка выполнена: квест завершится при следующем обновлении
+    pub fn fulfill_contract(&mut self, id: u32) {
+        if let Some(QuestKind::Contract { fulfilled, .. }) = self.contract_mut(id) {
+            *fulfilled = true;
+        }
+    }
+
+    pub fn set_contract_days(&mut self, id: u32, days: i32) {
+        if let Some(QuestKind::Contract { days_left, .. }) = self.contract_mut(id) {
+            *days_left = days;
         }
-        
-        // Удаляем завершенные квесты (оставляем их на некоторое время для показа награды)
-        // Пока просто удаляем сразу
-        self.active_quests.retain(|q| !q.completed);
-        
-        completed_quests
     }
-    
+
+    /// Контракт сорван — квест провален, без награды
+    pub fn fail_contract(&mut self, id: u32) {
+        let Some(idx) = self.active_quests.iter()
+            .position(|q| matches!(q.kind, QuestKind::Contract { contract_id, .. } if contract_id == id)) else { return };
+        let quest = self.active_quests.remove(idx);
+        self.push_log(&quest, QuestOutcome::Failed);
+    }
+
     /// Генерировать случайный квест
     fn generate_random_quest(
         rng: &mut impl Rng,
         quest_id: u32,
-        total_resources: &Resources,
-        buildings: &[crate::types::Building],
-        population: i32,
+        ctx: &QuestContext,
     ) -> Option<Quest> {
-        let quest_type = rng.random_range(0..4);
-        
-        match quest_type {
+        let total_resources = ctx.resources;
+        let quest_type = rng.random_range(0..7);
+        // каждый третий случайный квест — на время, за него добавка ресурсом
+        let timed = rng.random_range(0..3) == 0;
+        let bonus = [ResourceKind::Wood, ResourceKind::Stone, ResourceKind::Bricks, ResourceKind::Tools];
+        let bonus = QuestReward::Resource(bonus[rng.random_range(0..bonus.len())], rng.random_range(2..6) * 5);
+
+        let (kind, title, description, args, reward) = match quest_type {
             0 => {
                 // Квест на сбор ресурса
-                let resources_list = vec![
-                    ("Wood", total_resources.wood),
-                    ("Stone", total_resources.stone),
-                    ("Clay", total_resources.clay),
-                    ("Bricks", total_resources.bricks),
-                    ("Wheat", total_resources.wheat),
-                    ("Bread", total_resources.bread),
-                    ("Fish", total_resources.fish),
+                let resources_list = [
+                    ResourceKind::Wood,
+                    ResourceKind::Stone,
+                    ResourceKind::Clay,
+                    ResourceKind::Bricks,
+                    ResourceKind::Wheat,
+                    ResourceKind::Bread,
+                    ResourceKind::Fish,
                 ];
-                
-                let idx = rng.random_range(0..resources_list.len());
-                if let Some((name, current)) = resources_list.get(idx) {
-                    let target = current + rng.random_range(10..50);
-                    let reward = target / 2;
-                    
-                    Some(Quest {
-                        id: quest_id,
-                        kind: QuestKind::CollectResource {
-                            resource_name: name.to_string(),
-                            target_amount: target,
-                            current_amount: *current,
-                        },
-                        title: format!("Collect {} {}", target, name),
-                        description: format!("Gather {} units of {}", target, name),
-                        reward_gold: reward.max(10),
-                        completed: false,
-                    })
-                } else {
-                    None
-                }
+
+                let resource = resources_list[rng.random_range(0..resources_list.len())];
+                let current = crate::resource_visitor::get_resource_value(total_resources, resource);
+                let target = current + rng.random_range(10..50);
+                (
+                    QuestKind::CollectResource { resource, target_amount: target, current_amount: current },
+                    "quest.collect_title",
+                    "quest.collect_desc",
+                    vec![TextArg::Number(target), TextArg::Resource(resource)],
+                    (target / 2).max(10),
+                )
             }
             1 => {
                 // Квест на постройку зданий
-                let building_kinds = vec![
+                let building_kinds = [
                     BuildingKind::House,
                     BuildingKind::Lumberjack,
                     BuildingKind::Warehouse,
                     BuildingKind::WheatField,
                     BuildingKind::Fishery,
                 ];
-                
-                let idx = rng.random_range(0..building_kinds.len());
-                if let Some(&building_kind) = building_kinds.get(idx) {
-                    let current = buildings.iter().filter(|b| b.kind == building_kind).count() as i32;
-                    let target = current + rng.random_range(1..4);
-                    let reward = target * 20;
-                    
-                    let building_name = match building_kind {
-                        BuildingKind::House => "Houses",
-                        BuildingKind::Lumberjack => "Lumberjacks",
-                        BuildingKind::Warehouse => "Warehouses",
-                        BuildingKind::WheatField => "Wheat Fields",
-                        BuildingKind::Fishery => "Fisheries",
-                        _ => "Buildings",
-                    };
-                    
-                    Some(Quest {
-                        id: quest_id,
-                        kind: QuestKind::BuildBuildings {
-                            building_kind,
-                            target_count: target,
-                            current_count: current,
-                        },
-                        title: format!("Build {} {}", target, building_name),
-                        description: format!("Construct {} {}", target, building_name),
-                        reward_gold: reward.max(20),
-                        completed: false,
-                    })
-                } else {
-                    None
-                }
+
+                let building_kind = building_kinds[rng.random_range(0..building_kinds.len())];
+                let current = ctx.buildings.iter().filter(|b| b.kind == building_kind).count() as i32;
+                let target = current + rng.random_range(1..4);
+                (
+                    QuestKind::BuildBuildings { building_kind, target_count: target, current_count: current },
+                    "quest.build_title",
+                    "quest.build_desc",
+                    vec![TextArg::Number(target), TextArg::Buildings(building_kind)],
+                    (target * 20).max(20),
+                )
             }
             2 => {
                 // Квест на достижение населения
-                let target = population + rng.random_range(5..15);
-                let reward = (target - population) * 5;
-                
-                Some(Quest {
-                    id: quest_id,
-                    kind: QuestKind::ReachPopulation {
-                        target_population: target,
-                        current_population: population,
-                    },
-                    title: format!("Reach {} Population", target),
-                    description: format!("Grow your population to {} citizens", target),
-                    reward_gold: reward.max(25),
-                    completed: false,
-                })
+                let target = ctx.population + rng.random_range(5..15);
+                (
+                    QuestKind::ReachPopulation { target_population: target, current_population: ctx.population },
+                    "quest.population_title",
+                    "quest.population_desc",
+                    vec![TextArg::Number(target)],
+                    ((target - ctx.population) * 5).max(25),
+                )
             }
             3 => {
                 // Квест на сбор золота
                 let target = total_resources.gold + rng.random_range(50..200);
-                let reward = (target - total_resources.gold) / 5;
-                
-                    Some(Quest {
-                        id: quest_id,
-                        kind: QuestKind::CollectGold {
-                            target_amount: target,
-                            current_amount: total_resources.gold,
-                        },
-                    title: format!("Collect {} Gold", target),
-                    description: format!("Accumulate {} gold coins", target),
-                    reward_gold: reward.max(30),
-                    completed: false,
-                })
+                (
+                    QuestKind::CollectGold { target_amount: target, current_amount: total_resources.gold },
+                    "quest.gold_title",
+                    "quest.gold_desc",
+                    vec![TextArg::Number(target)],
+                    ((target - total_resources.gold) / 5).max(30),
+                )
+            }
+            4 => {
+                // Квест на суточное производство
+                let produce = [ResourceKind::Wood, ResourceKind::Stone, ResourceKind::Bread, ResourceKind::Fish];
+                let resource = produce[rng.random_range(0..produce.len())];
+                let target = rng.random_range(1..4) * 5;
+                (
+                    QuestKind::ProduceDaily { resource, target_per_day: target, current_per_day: 0, last_stock: None },
+                    "quest.produce_title",
+                    "quest.produce_desc",
+                    vec![TextArg::Number(target), TextArg::Resource(resource)],
+                    target * 6,
+                )
+            }
+            5 => {
+                // Квест на счастливость
+                if ctx.population == 0 || ctx.happiness >= 85 { return None; }
+                let target = (ctx.happiness + rng.random_range(5..15)).min(90);
+                (
+                    QuestKind::ReachHappiness { target, current: ctx.happiness },
+                    "quest.happiness_title",
+                    "quest.happiness_desc",
+                    vec![TextArg::Number(target)],
+                    (target - ctx.happiness) * 8,
+                )
+            }
+            6 => {
+                // Квест на подключение здания к складу дорогой
+                let kinds = [BuildingKind::Lumberjack, BuildingKind::StoneQuarry, BuildingKind::ClayPit, BuildingKind::WheatField, BuildingKind::Fishery];
+                let building_kind = kinds.into_iter()
+                    .filter(|&k| ctx.buildings.iter().any(|b| b.kind == k))
+                    .find(|&k| !road_connected_kind(ctx, k))?;
+                (
+                    QuestKind::ConnectByRoad { building_kind, connected: false },
+                    "quest.road_title",
+                    "quest.road_desc",
+                    vec![TextArg::Buildings(building_kind)],
+                    40,
+                )
+            }
+            _ => return None,
+        };
+
+        let mut rewards = vec![QuestReward::Gold(reward)];
+        if timed {
+            rewards.push(bonus);
+        }
+        Some(Quest {
+            id: quest_id,
+            key: None,
+            kind,
+            label: QuestLabel::template(title, description, args),
+            rewards,
+            days_left: timed.then_some(10),
+            completed: false,
+        })
+    }
+}
+
+/// Выдать награды за квест
+pub fn grant_rewards(rewards: &[QuestReward], resources: &mut Resources, research_system: &mut ResearchSystem) {
+    for reward in rewards {
+        match reward {
+            QuestReward::Gold(n) => resources.gold += n,
+            QuestReward::Resource(kind, n) => crate::resource_visitor::add_resource(resources, *kind, *n),
+            QuestReward::ResearchDays(days) => {
+                // без текущего исследования — золотом, как за находки в руинах
+                if !research_system.advance(*days) {
+                    resources.gold += days * 25;
+                }
+            }
+            QuestReward::Research(key) => {
+                research_system.complete(key);
             }
-            _ => None,
         }
     }
 }
 
+fn random_quests_default() -> bool {
+    true
+}
+
+// подписи зданий во множественном числе для заголовков квестов
+fn building_plural(kind: BuildingKind) -> &'static str {
+    match kind {
+        BuildingKind::House => tr("quest.houses"),
+        BuildingKind::Lumberjack => tr("quest.lumberjacks"),
+        BuildingKind::Warehouse => tr("quest.warehouses"),
+        BuildingKind::WheatField => tr("quest.wheat_fields"),
+        BuildingKind::Fishery => tr("quest.fisheries"),
+        BuildingKind::StoneQuarry => tr("quest.quarries"),
+        BuildingKind::ClayPit => tr("quest.clay_pits"),
+        _ => tr("quest.buildings"),
+    }
+}
+
+// хотя бы одно здание этого типа связано дорогой со складом
+fn road_connected_kind(ctx: &QuestContext, kind: BuildingKind) -> bool {
+    let targets: Vec<glam::IVec2> = ctx.warehouses.iter().map(|w| w.pos).collect();
+    if targets.is_empty() { return false; }
+    ctx.buildings.iter()
+        .filter(|b| b.kind == kind)
+        .any(|b| crate::path::road_connected(ctx.world, b.pos, &targets, ROAD_SEARCH_LIMIT))
+}
@@ -1,30 +1,29 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use glam::IVec2;
 use serde::{Serialize, Deserialize};
-use crate::types::{BuildingKind, Resources};
-
-/// Типы исследований
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum ResearchKind {
-    // Базовые постройки (доступны с начала)
-    BasicHousing,      // Дом
-    BasicStorage,      // Склад
-    BasicForestry,     // Лесоруб, Лесничий
-    
-    // Уровень 1 - Требует Лабораторию
-    AdvancedHousing,   // Улучшенные дома
-    StoneWorking,      // Каменоломня, Глиняный карьер
-    BasicFarming,      // Пшеничное поле
-    BasicFishing,      // Рыбацкая хижина
-    
-    // Уровень 2
-    Brickmaking,       // Печь для кирпичей
-    FoodProcessing,    // Мельница, Пекарня
-    Mining,            // Железная шахта
-    
-    // Уровень 3
-    Metallurgy,        // Плавильня
-    AdvancedFarming,   // Улучшенные фермы (будущее)
-    AdvancedMining,    // Улучшенные шахты (будущее)
-}
+use crate::types::{BuildingKind, ResourceKind, Resources, WarehouseStore};
+use crate::i18n::tr_fmt;
+
+/// Дерево по умолчанию, вшитое в бинарник: на случай, если файла нет или он с ошибками
+const DEFAULT_TREE: &str = include_str!("../assets/research.toml");
+
+/// Дерево исследований загружается один раз за запуск
+static TREE: OnceLock<Vec<ResearchDef>> = OnceLock::new();
+
+/// Столько мс работы учёного (при счастье 50) дают одно очко исследования
+pub const WORK_MS_PER_POINT: f32 = 6000.0;
+
+/// Столько очков в среднем даёт за день один учёный
+pub const POINTS_PER_DAY: i32 = 10;
+
+/// Припасы лаборатории на день и их прибавка к работе учёных, в процентах:
+/// ткань идёт на тряпичную бумагу, древесный уголь — на чернила и светильники
+pub const LAB_SUPPLIES: [(ResourceKind, i32); 2] = [
+    (ResourceKind::Cloth, 25),
+    (ResourceKind::Charcoal, 25),
+];
 
 /// Статус исследования
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -38,187 +37,243 @@ pub enum ResearchStatus {
 /// Данные активного исследования
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ActiveResearch {
-    pub kind: ResearchKind,
-    pub days_remaining: i32,
+    // ключ узла из assets/research.toml
+    pub kind: String,
+    // набранные очки
+    #[serde(default)]
+    pub progress: i32,
 }
 
 /// Состояние одного исследования
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Research {
-    pub kind: ResearchKind,
+    pub kind: String,
     pub status: ResearchStatus,
 }
 
-/// Информация об исследовании
-pub struct ResearchInfo {
-    pub name: &'static str,
-    pub description: &'static str,
-    pub days_required: i32,
-    pub cost: Resources,
-    pub prerequisites: &'static [ResearchKind],
-    pub unlocks_buildings: &'static [BuildingKind],
+/// Эффект исследования — модификатор, который учитывает симуляция
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResearchEffect {
+    /// Циклы производства здания быстрее на `percent` %
+    ProductionSpeed { building: BuildingKind, percent: i32 },
+    /// Дополнительные места в каждом доме
+    HouseCapacity(i32),
+    /// Налоги больше на столько %
+    TaxYield(i32),
+    /// Добытчики уносят на склад больше единиц за ходку
+    CarryAmount(i32),
+    /// Деревья растут быстрее на столько %
+    TreeGrowth(i32),
+    /// Горожане шагают по дорогам быстрее на столько % (скорость ходьбы;
+    /// стоимость дороги в поиске пути не меняется)
+    #[serde(alias = "RoadSpeed")]
+    RoadWalkSpeed(i32),
 }
 
-impl ResearchKind {
-    /// Получить информацию об исследовании
-    pub fn info(self) -> ResearchInfo {
+impl ResearchEffect {
+    /// Короткая подпись для подсказки в дереве
+    pub fn label(&self) -> String {
         match self {
-            ResearchKind::BasicHousing => ResearchInfo {
-                name: "Basic Housing",
-                description: "Unlocks construction of houses for citizens",
-                days_required: 0,
-                cost: Resources::default(),
-                prerequisites: &[],
-                unlocks_buildings: &[BuildingKind::House],
-            },
-            ResearchKind::BasicStorage => ResearchInfo {
-                name: "Basic Storage",
-                description: "Unlocks construction of warehouses",
-                days_required: 0,
-                cost: Resources::default(),
-                prerequisites: &[],
-                unlocks_buildings: &[BuildingKind::Warehouse],
-            },
-            ResearchKind::BasicForestry => ResearchInfo {
-                name: "Basic Forestry",
-                description: "Unlocks lumberjacks and foresters",
-                days_required: 0,
-                cost: Resources::default(),
-                prerequisites: &[],
-                unlocks_buildings: &[BuildingKind::Lumberjack, BuildingKind::Forester],
-            },
-            
-            ResearchKind::AdvancedHousing => ResearchInfo {
-                name: "Advanced Housing",
-                description: "Improved houses with greater capacity",
-                days_required: 3,
-                cost: Resources { wood: 50, gold: 100, ..Default::default() },
-                prerequisites: &[ResearchKind::BasicHousing],
-                unlocks_buildings: &[],
-            },
-            ResearchKind::StoneWorking => ResearchInfo {
-                name: "Stone Working",
-                description: "Unlocks advanced stone and clay processing",
-                days_required: 0, // Базовое исследование, доступно с начала
-                cost: Resources::default(), // Без стоимости для базового исследования
-                prerequisites: &[], // Без пререквизитов, доступно с начала
-                unlocks_buildings: &[BuildingKind::StoneQuarry, BuildingKind::ClayPit], // Каменоломня и глиняный карьер доступны с начала
-            },
-            ResearchKind::BasicFarming => ResearchInfo {
-                name: "Basic Farming",
-                description: "Unlocks wheat fields",
-                days_required: 4,
-                cost: Resources { wood: 80, gold: 120, ..Default::default() },
-                prerequisites: &[ResearchKind::BasicForestry],
-                unlocks_buildings: &[BuildingKind::WheatField],
-            },
-            ResearchKind::BasicFishing => ResearchInfo {
-                name: "Basic Fishing",
-                description: "Unlocks fishing hut",
-                days_required: 0, // Базовое исследование, доступно с начала
-                cost: Resources::default(), // Без стоимости для базового исследования
-                prerequisites: &[], // Без пререквизитов, доступно с начала
-                unlocks_buildings: &[BuildingKind::Fishery],
-            },
-            
-            ResearchKind::Brickmaking => ResearchInfo {
-                name: "Brickmaking",
-                description: "Unlocks kiln for brick production",
-                days_required: 6,
-                cost: Resources { wood: 150, gold: 200, stone: 50, clay: 50, ..Default::default() },
-                prerequisites: &[ResearchKind::StoneWorking],
-                unlocks_buildings: &[BuildingKind::Kiln],
-            },
-            ResearchKind::FoodProcessing => ResearchInfo {
-                name: "Food Processing",
-                description: "Unlocks mill and bakery",
-                days_required: 7,
-                cost: Resources { wood: 180, gold: 250, stone: 30, ..Default::default() },
-                prerequisites: &[ResearchKind::BasicFarming],
-                unlocks_buildings: &[BuildingKind::Mill, BuildingKind::Bakery],
-            },
-            ResearchKind::Mining => ResearchInfo {
-                name: "Mining",
-                description: "Unlocks iron mine",
-                days_required: 8,
-                cost: Resources { wood: 200, gold: 300, stone: 100, ..Default::default() },
-                prerequisites: &[ResearchKind::StoneWorking],
-                unlocks_buildings: &[BuildingKind::IronMine],
-            },
-            
-            ResearchKind::Metallurgy => ResearchInfo {
-                name: "Metallurgy",
-                description: "Unlocks smelter for ingot production",
-                days_required: 10,
-                cost: Resources { wood: 250, gold: 400, stone: 150, bricks: 50, ..Default::default() },
-                prerequisites: &[ResearchKind::Mining, ResearchKind::Brickmaking],
-                unlocks_buildings: &[BuildingKind::Smelter],
-            },
-            ResearchKind::AdvancedFarming => ResearchInfo {
-                name: "Advanced Farming",
-                description: "Improved farming methods",
-                days_required: 12,
-                cost: Resources { wood: 300, gold: 500, ..Default::default() },
-                prerequisites: &[ResearchKind::FoodProcessing],
-                unlocks_buildings: &[],
-            },
-            ResearchKind::AdvancedMining => ResearchInfo {
-                name: "Advanced Mining",
-                description: "Improved mining methods",
-                days_required: 12,
-                cost: Resources { wood: 300, gold: 500, iron_ingots: 20, ..Default::default() },
-                prerequisites: &[ResearchKind::Metallurgy],
-                unlocks_buildings: &[],
-            },
+            ResearchEffect::ProductionSpeed { building, percent } => tr_fmt("research_effect.speed", &[&crate::ui::building_label(*building), percent]),
+            ResearchEffect::HouseCapacity(n) => tr_fmt("research_effect.house_capacity", &[n]),
+            ResearchEffect::TaxYield(p) => tr_fmt("research_effect.tax", &[p]),
+            ResearchEffect::CarryAmount(n) => tr_fmt("research_effect.carry", &[n]),
+            ResearchEffect::TreeGrowth(p) => tr_fmt("research_effect.tree_growth", &[p]),
+            ResearchEffect::RoadWalkSpeed(p) => tr_fmt("research_effect.road", &[p]),
         }
     }
-    
-    /// Все виды исследований в порядке отображения
-    pub fn all() -> &'static [ResearchKind] {
-        &[
-            ResearchKind::BasicHousing,
-            ResearchKind::BasicStorage,
-            ResearchKind::BasicForestry,
-            ResearchKind::AdvancedHousing,
-            ResearchKind::StoneWorking,
-            ResearchKind::BasicFarming,
-            ResearchKind::BasicFishing,
-            ResearchKind::Brickmaking,
-            ResearchKind::FoodProcessing,
-            ResearchKind::Mining,
-            ResearchKind::Metallurgy,
-            ResearchKind::AdvancedFarming,
-            ResearchKind::AdvancedMining,
-        ]
-    }
-    
-    /// Позиция в дереве исследований (для UI)
-    pub fn tree_position(self) -> (i32, i32) {
-        match self {
-            // Уровень 0 (верхний ряд - стартовые, не показываются т.к. завершены)
-            ResearchKind::BasicHousing => (0, 0),
-            ResearchKind::BasicStorage => (1, 0),
-            ResearchKind::BasicForestry => (2, 0),
-            
-            // Уровень 1 - Первый ряд видимых исследований
-            ResearchKind::AdvancedHousing => (0, 1),
-            ResearchKind::StoneWorking => (1, 1),
-            ResearchKind::BasicFarming => (2, 1),
-            ResearchKind::BasicFishing => (3, 1),
-            
-            // Уровень 2 - Второй ряд
-            ResearchKind::Brickmaking => (0, 2),
-            ResearchKind::Mining => (1, 2),
-            ResearchKind::FoodProcessing => (2, 2),
-            
-            // Уровень 3 - Третий ряд
-            ResearchKind::Metallurgy => (1, 3),
-            ResearchKind::AdvancedFarming => (2, 3),
-            
-            // Уровень 4 - Четвертый ряд
-            ResearchKind::AdvancedMining => (1, 4),
+}
+
+/// Узел дерева исследований (assets/research.toml)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResearchDef {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    // очки исследования, которые нужно набрать
+    #[serde(default)]
+    pub points: i32,
+    // списывается при старте
+    #[serde(default)]
+    pub cost: Resources,
+    // узлы без пререквизитов завершены с начала игры
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    #[serde(default)]
+    pub unlocks_buildings: Vec<BuildingKind>,
+    #[serde(default)]
+    pub effects: Vec<ResearchEffect>,
+    // место в окне дерева
+    pub column: i32,
+    pub row: i32,
+    // язык → название и описание; нет перевода — английский текст
+    #[serde(default)]
+    pub translations: HashMap<String, ResearchText>,
+}
+
+/// Название и описание исследования на одном языке
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResearchText {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl ResearchDef {
+    /// Название на текущем языке интерфейса
+    pub fn display_name(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.name.as_str(), |t| t.name.as_str())
+    }
+
+    /// Описание на текущем языке интерфейса
+    pub fn display_description(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.description.as_str(), |t| t.description.as_str())
+    }
+}
+
+/// Файл дерева исследований
+#[derive(Deserialize)]
+struct ResearchFile {
+    research: Vec<ResearchDef>,
+}
+
+/// Суммарные эффекты завершённых исследований (ResearchSystem::modifiers);
+/// в симуляцию попадают через ProductionContext и параметры игрового цикла
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResearchModifiers {
+    // ускорение циклов по типам зданий, в процентах
+    pub production: Vec<(BuildingKind, i32)>,
+    pub house_capacity: i32,
+    pub tax_percent: i32,
+    pub carry_bonus: i32,
+    pub tree_growth_percent: i32,
+    pub road_walk_percent: i32,
+}
+
+impl ResearchModifiers {
+    fn add(&mut self, effect: &ResearchEffect) {
+        match *effect {
+            ResearchEffect::ProductionSpeed { building, percent } => self.production.push((building, percent)),
+            ResearchEffect::HouseCapacity(n) => self.house_capacity += n,
+            ResearchEffect::TaxYield(p) => self.tax_percent += p,
+            ResearchEffect::CarryAmount(n) => self.carry_bonus += n,
+            ResearchEffect::TreeGrowth(p) => self.tree_growth_percent += p,
+            ResearchEffect::RoadWalkSpeed(p) => self.road_walk_percent += p,
         }
     }
+
+    /// Множитель времени цикла производства здания (меньше — быстрее)
+    pub fn production_time_mul(&self, kind: BuildingKind) -> f32 {
+        let percent: i32 = self.production.iter().filter(|(k, _)| *k == kind).map(|(_, p)| p).sum();
+        speed_to_time_mul(percent)
+    }
+
+    /// Множитель времени шага горожанина по дороге
+    pub fn road_step_mul(&self) -> f32 {
+        speed_to_time_mul(self.road_walk_percent)
+    }
+
+    /// Множитель скорости роста деревьев
+    pub fn tree_growth_mul(&self) -> f32 {
+        (100 + self.tree_growth_percent).max(0) as f32 / 100.0
+    }
+
+    /// Прибавка к налогам за день
+    pub fn tax_bonus(&self, income: i32) -> i32 {
+        (income.max(0) * self.tax_percent / 100).max(0)
+    }
+}
+
+// +50% скорости — цикл занимает 100/150 прежнего времени
+fn speed_to_time_mul(percent: i32) -> f32 {
+    100.0 / (100 + percent).max(10) as f32
+}
+
+/// Все узлы дерева в порядке файла
+pub fn tree() -> &'static [ResearchDef] {
+    TREE.get_or_init(load_research_tree)
+}
+
+/// Узел дерева по ключу
+pub fn def(key: &str) -> Option<&'static ResearchDef> {
+    tree().iter().find(|d| d.key == key)
+}
+
+/// Название исследования (для неизвестного ключа — сам ключ)
+pub fn name(key: &str) -> &str {
+    def(key).map_or(key, |d| d.display_name())
+}
+
+/// Дерево из assets/research.toml; если файла нет или он с ошибками — встроенное
+fn load_research_tree() -> Vec<ResearchDef> {
+    let path = crate::resource_path::assets_path().join("research.toml");
+    match std::fs::read_to_string(&path) {
+        Ok(text) => match parse_tree(&text) {
+            Ok(defs) => return defs,
+            Err(errors) => log::warn!("Дерево исследований {:?} не загружено: {}", path, errors.join("; ")),
+        },
+        Err(e) => log::warn!("Не удалось прочитать {:?}: {}", path, e),
+    }
+    parse_tree(DEFAULT_TREE).expect("встроенное дерево исследований должно быть корректным")
+}
+
+fn parse_tree(text: &str) -> Result<Vec<ResearchDef>, Vec<String>> {
+    let file: ResearchFile = toml::from_str(text).map_err(|e| vec![e.to_string()])?;
+    validate_tree(&file.research)?;
+    Ok(file.research)
+}
+
+/// Проверка дерева: ключи, пререквизиты без циклов, позиции, очки и эффекты
+pub fn validate_tree(defs: &[ResearchDef]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut keys = HashSet::new();
+    let mut cells = HashSet::new();
+    for d in defs {
+        if d.key.is_empty() { errors.push(format!("research '{}' has an empty key", d.name)); }
+        if !keys.insert(d.key.as_str()) { errors.push(format!("duplicate research key '{}'", d.key)); }
+        if d.column < 0 || d.row < 0 { errors.push(format!("research '{}' has a negative position", d.key)); }
+        if !cells.insert((d.column, d.row)) { errors.push(format!("research '{}' overlaps another node", d.key)); }
+        if d.points < 0 { errors.push(format!("research '{}' has negative points", d.key)); }
+        let bad_effect = d.effects.iter().any(|e| matches!(*e,
+            ResearchEffect::ProductionSpeed { percent: n, .. } | ResearchEffect::HouseCapacity(n) | ResearchEffect::CarryAmount(n) if n <= 0));
+        if bad_effect { errors.push(format!("research '{}' has a non-positive effect", d.key)); }
+    }
+    for d in defs {
+        for p in &d.prerequisites {
+            if !keys.contains(p.as_str()) { errors.push(format!("research '{}' requires unknown research '{}'", d.key, p)); }
+        }
+    }
+    // циклы: снимаем узлы, все пререквизиты которых уже сняты; что осталось — в цикле
+    let mut resolved: HashSet<&str> = HashSet::new();
+    loop {
+        let before = resolved.len();
+        for d in defs {
+            if d.prerequisites.iter().all(|p| resolved.contains(p.as_str()) || !keys.contains(p.as_str())) {
+                resolved.insert(d.key.as_str());
+            }
+        }
+        if resolved.len() == before { break; }
+    }
+    for d in defs.iter().filter(|d| !resolved.contains(d.key.as_str())) {
+        errors.push(format!("research '{}' is part of a prerequisite cycle", d.key));
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Ресурсы стоимости исследования: (вид, количество)
+pub fn cost_items(cost: &Resources) -> Vec<(ResourceKind, i32)> {
+    crate::trade::GOODS.iter().map(|g| g.0)
+        .chain(std::iter::once(ResourceKind::Gold))
+        .map(|kind| (kind, crate::resource_visitor::get_resource_value(cost, kind)))
+        .filter(|&(_, n)| n > 0)
+        .collect()
+}
+
+/// Хватает ли запасов на стоимость исследования
+pub fn can_afford(key: &str, resources: &Resources, warehouses: &[WarehouseStore]) -> bool {
+    let Some(info) = def(key) else { return false; };
+    cost_items(&info.cost).into_iter().all(|(kind, n)| crate::trade::stock_of(resources, warehouses, kind) >= n)
 }
 
 /// Система управления исследованиями
@@ -228,122 +283,268 @@ pub struct ResearchSystem {
     pub active_research: Option<ActiveResearch>,
     #[serde(default)]
     pub has_research_lab: bool,
+    // очередь исследований после активного
+    #[serde(default)]
+    pub queue: Vec<String>,
+    // накопленная работа учёных, ещё не ставшая очком
+    #[serde(default)]
+    pub work_ms: f32,
+    #[serde(default)]
+    pub points_today: i32,
+    // очков за вчерашний день — для окна исследований
+    #[serde(default)]
+    pub points_per_day: i32,
+    // лаборатории, получившие припасы сегодня: позиция и прибавка в %
+    #[serde(default)]
+    pub lab_supplies: Vec<(IVec2, i32)>,
+    // эффекты завершённых исследований, пересчитываются в update_statuses
+    #[serde(skip)]
+    pub modifiers: ResearchModifiers,
 }
 
 impl ResearchSystem {
     /// Создать новую систему исследований
     pub fn new() -> Self {
         let mut researches = Vec::new();
-        
+
         // Инициализируем все исследования
-        for &kind in ResearchKind::all() {
-            let info = kind.info();
-            let status = if info.prerequisites.is_empty() {
+        for d in tree() {
+            let status = if d.prerequisites.is_empty() {
                 ResearchStatus::Completed // Базовые исследования завершены с начала
             } else {
                 ResearchStatus::Locked
             };
-            
-            researches.push(Research { kind, status });
+
+            researches.push(Research { kind: d.key.clone(), status });
         }
-        
+
         let mut system = Self {
             researches,
             active_research: None,
             has_research_lab: false,
+            queue: Vec::new(),
+            work_ms: 0.0,
+            points_today: 0,
+            points_per_day: 0,
+            lab_supplies: Vec::new(),
+            modifiers: ResearchModifiers::default(),
         };
-        
+
         // Обновляем статусы, чтобы разблокировать доступные исследования
         system.update_statuses();
-        
+
         system
     }
-    
-    /// Обновить статусы исследований на основе завершённых
+
+    /// Привести старое сохранение к текущему дереву: добавить новые узлы, убрать исчезнувшие
+    pub fn add_missing(&mut self) {
+        for d in tree() {
+            if !self.researches.iter().any(|r| r.kind == d.key) {
+                let status = if d.prerequisites.is_empty() { ResearchStatus::Completed } else { ResearchStatus::Locked };
+                self.researches.push(Research { kind: d.key.clone(), status });
+            }
+        }
+        self.researches.retain(|r| def(&r.kind).is_some());
+        if self.active_research.as_ref().is_some_and(|a| def(&a.kind).is_none()) {
+            self.active_research = None;
+        }
+        self.update_statuses();
+        self.prune_queue();
+    }
+
+    /// Обновить статусы исследований на основе завершённых и пересчитать эффекты
     pub fn update_statuses(&mut self) {
         for i in 0..self.researches.len() {
-            if self.researches[i].status == ResearchStatus::InProgress 
+            if self.researches[i].status == ResearchStatus::InProgress
                 || self.researches[i].status == ResearchStatus::Completed {
                 continue;
             }
-            
-            let kind = self.researches[i].kind;
-            let info = kind.info();
-            
+
+            let Some(info) = def(&self.researches[i].kind) else { continue; };
+
             // Проверяем, выполнены ли все пререквизиты
-            let all_prerequisites_met = info.prerequisites.iter().all(|&prereq| {
-                self.researches.iter().any(|r| r.kind == prereq && r.status == ResearchStatus::Completed)
+            let all_prerequisites_met = info.prerequisites.iter().all(|prereq| {
+                self.researches.iter().any(|r| &r.kind == prereq && r.status == ResearchStatus::Completed)
             });
-            
+
             if all_prerequisites_met {
                 self.researches[i].status = ResearchStatus::Available;
             } else {
                 self.researches[i].status = ResearchStatus::Locked;
             }
         }
+
+        let mut modifiers = ResearchModifiers::default();
+        for r in self.researches.iter().filter(|r| r.status == ResearchStatus::Completed) {
+            for effect in def(&r.kind).map_or(&[][..], |d| &d.effects) {
+                modifiers.add(effect);
+            }
+        }
+        self.modifiers = modifiers;
     }
-    
-    /// Начать исследование
-    pub fn start_research(&mut self, kind: ResearchKind) -> bool {
-        // Проверяем, что нет активного исследования
-        if self.active_research.is_some() {
+
+    /// Можно ли поставить исследование в очередь: пререквизиты завершены, изучаются или стоят в очереди раньше
+    pub fn can_queue(&self, kind: &str) -> bool {
+        let Some(info) = def(kind) else { return false; };
+        if self.get_status(kind) != ResearchStatus::Available && self.get_status(kind) != ResearchStatus::Locked {
             return false;
         }
-        
-        // Находим исследование
-        if let Some(research) = self.researches.iter_mut().find(|r| r.kind == kind) {
-            if research.status == ResearchStatus::Available {
-                let info = kind.info();
-                research.status = ResearchStatus::InProgress;
-                self.active_research = Some(ActiveResearch {
-                    kind,
-                    days_remaining: info.days_required,
-                });
-                return true;
-            }
+        if self.queue.iter().any(|k| k == kind) {
+            return false;
         }
-        
-        false
+        info.prerequisites.iter().all(|p| self.is_planned(p))
+    }
+
+    // завершено, изучается или уже в очереди
+    fn is_planned(&self, kind: &str) -> bool {
+        matches!(self.get_status(kind), ResearchStatus::Completed | ResearchStatus::InProgress)
+            || self.queue.iter().any(|k| k == kind)
+    }
+
+    /// Поставить исследование в очередь; если лаборатория свободна — сразу начать
+    pub fn enqueue(&mut self, kind: &str, resources: &mut Resources, warehouses: &mut [WarehouseStore]) -> bool {
+        if !self.can_queue(kind) {
+            return false;
+        }
+        self.queue.push(kind.to_string());
+        self.start_next(resources, warehouses);
+        true
+    }
+
+    /// Убрать исследование из очереди вместе с зависящими от него
+    pub fn dequeue(&mut self, kind: &str) -> bool {
+        let before = self.queue.len();
+        self.queue.retain(|k| k != kind);
+        self.prune_queue();
+        self.queue.len() != before
     }
-    
-    /// Обновить прогресс исследования (вызывается каждый игровой день)
-    pub fn update_daily(&mut self) -> Option<ResearchKind> {
-        if let Some(ref mut active) = self.active_research {
-            active.days_remaining -= 1;
-            
-            if active.days_remaining <= 0 {
-                let completed_kind = active.kind;
-                
-                // Завершаем исследование
-                if let Some(research) = self.researches.iter_mut().find(|r| r.kind == completed_kind) {
-                    research.status = ResearchStatus::Completed;
-                }
-                
-                self.active_research = None;
-                self.update_statuses();
-                
-                return Some(completed_kind);
+
+    // убрать из очереди завершённое и то, чьи пререквизиты больше не запланированы
+    fn prune_queue(&mut self) {
+        let mut kept: Vec<String> = Vec::new();
+        for kind in std::mem::take(&mut self.queue) {
+            let Some(info) = def(&kind) else { continue; };
+            if matches!(self.get_status(&kind), ResearchStatus::Completed | ResearchStatus::InProgress) {
+                continue;
+            }
+            let planned = info.prerequisites.iter().all(|p| {
+                matches!(self.get_status(p), ResearchStatus::Completed | ResearchStatus::InProgress) || kept.contains(p)
+            });
+            if planned && !kept.contains(&kind) {
+                kept.push(kind);
             }
         }
-        
-        None
+        self.queue = kept;
     }
-    
+
+    /// Начать первое доступное исследование из очереди, списав его стоимость.
+    /// Если запасов не хватает — ждём, пока их накопят.
+    pub fn start_next(&mut self, resources: &mut Resources, warehouses: &mut [WarehouseStore]) -> Option<String> {
+        if self.active_research.is_some() {
+            return None;
+        }
+        let kind = self.queue.iter().find(|k| self.get_status(k) == ResearchStatus::Available)?.clone();
+        if !can_afford(&kind, resources, warehouses) {
+            return None;
+        }
+        let info = def(&kind)?;
+        for (res, n) in cost_items(&info.cost) {
+            crate::trade::take(resources, warehouses, res, n);
+        }
+        self.queue.retain(|k| *k != kind);
+        if let Some(research) = self.researches.iter_mut().find(|r| r.kind == kind) {
+            research.status = ResearchStatus::InProgress;
+        }
+        self.active_research = Some(ActiveResearch { kind: kind.clone(), progress: 0 });
+        Some(kind)
+    }
+
+    /// Очки, которые ещё нужно набрать активному исследованию
+    pub fn points_remaining(&self) -> i32 {
+        self.active_research.as_ref()
+            .map_or(0, |a| (def(&a.kind).map_or(0, |d| d.points) - a.progress).max(0))
+    }
+
+    /// Добавить работу учёных; Some — ключ исследования, которое завершилось
+    pub fn add_work(&mut self, ms: f32) -> Option<String> {
+        let active = self.active_research.as_mut()?;
+        self.work_ms += ms;
+        let points = (self.work_ms / WORK_MS_PER_POINT) as i32;
+        if points == 0 {
+            return None;
+        }
+        self.work_ms -= points as f32 * WORK_MS_PER_POINT;
+        self.points_today += points;
+        active.progress += points;
+        if self.points_remaining() > 0 {
+            return None;
+        }
+        let kind = self.active_research.take()?.kind;
+        if let Some(research) = self.researches.iter_mut().find(|r| r.kind == kind) {
+            research.status = ResearchStatus::Completed;
+        }
+        self.update_statuses();
+        Some(kind)
+    }
+
+    /// Новый день: итог очков за вчера и сброс припасов лабораторий
+    pub fn new_day(&mut self) {
+        self.points_per_day = self.points_today;
+        self.points_today = 0;
+        self.lab_supplies.clear();
+    }
+
+    /// Прибавка лаборатории от припасов, в %. Когда учёные впервые за день садятся
+    /// за работу, лаборатория забирает со склада по единице каждого припаса, какие есть.
+    pub fn lab_bonus(&mut self, lab: IVec2, resources: &mut Resources, warehouses: &mut [WarehouseStore]) -> i32 {
+        if let Some(&(_, bonus)) = self.lab_supplies.iter().find(|(p, _)| *p == lab) {
+            return bonus;
+        }
+        let bonus = LAB_SUPPLIES.iter()
+            .filter(|&&(kind, _)| crate::trade::take(resources, warehouses, kind, 1) > 0)
+            .map(|&(_, percent)| percent)
+            .sum();
+        self.lab_supplies.push((lab, bonus));
+        bonus
+    }
+
+    /// Ускорить активное исследование на несколько дней работы одного учёного (находки в руинах).
+    /// Завершает его всё равно работа в лаборатории, поэтому остаётся минимум очко.
+    pub fn advance(&mut self, days: i32) -> bool {
+        let remaining = self.points_remaining();
+        let Some(active) = self.active_research.as_mut() else { return false; };
+        active.progress += (days * POINTS_PER_DAY).min(remaining - 1).max(0);
+        true
+    }
+
+    /// Сразу завершить исследование (награда за квест); false — если оно уже завершено
+    pub fn complete(&mut self, kind: &str) -> bool {
+        let Some(research) = self.researches.iter_mut().find(|r| r.kind == kind) else { return false; };
+        if research.status == ResearchStatus::Completed {
+            return false;
+        }
+        research.status = ResearchStatus::Completed;
+        if self.active_research.as_ref().is_some_and(|a| a.kind == kind) {
+            self.active_research = None;
+        }
+        self.update_statuses();
+        self.prune_queue();
+        true
+    }
+
     /// Проверить, разблокировано ли здание
     pub fn is_building_unlocked(&self, building: BuildingKind) -> bool {
         for research in &self.researches {
-            if research.status == ResearchStatus::Completed {
-                let info = research.kind.info();
-                if info.unlocks_buildings.contains(&building) {
-                    return true;
-                }
+            if research.status == ResearchStatus::Completed
+                && def(&research.kind).is_some_and(|d| d.unlocks_buildings.contains(&building)) {
+                return true;
             }
         }
         false
     }
-    
+
     /// Получить статус исследования
-    pub fn get_status(&self, kind: ResearchKind) -> ResearchStatus {
+    pub fn get_status(&self, kind: &str) -> ResearchStatus {
         self.researches.iter()
             .find(|r| r.kind == kind)
             .map(|r| r.status)
@@ -351,3 +552,60 @@ impl ResearchSystem {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // исследование, доступное с начала игры, и зависящее от него
+    fn chain() -> (&'static ResearchDef, &'static ResearchDef) {
+        let system = ResearchSystem::new();
+        tree().iter()
+            .filter(|d| system.get_status(&d.key) == ResearchStatus::Available)
+            .find_map(|a| tree().iter().find(|b| b.prerequisites == [a.key.clone()]).map(|b| (a, b)))
+            .expect("research tree has no two-step chain")
+    }
+
+    fn funds(defs: &[&ResearchDef]) -> Resources {
+        let mut res = Resources::default();
+        for d in defs {
+            for (kind, n) in cost_items(&d.cost) { crate::resource_visitor::add_resource(&mut res, kind, n); }
+        }
+        res
+    }
+
+    #[test]
+    fn queue_accepts_children_of_planned_research_only() {
+        let (a, b) = chain();
+        let mut system = ResearchSystem::new();
+        let mut res = Resources::default();
+        assert!(!system.can_queue(&b.key));
+        // без запасов первое исследование ждёт в очереди, но уже позволяет планировать следующее
+        assert!(system.enqueue(&a.key, &mut res, &mut []));
+        assert!(system.active_research.is_none());
+        assert!(system.enqueue(&b.key, &mut res, &mut []));
+        assert!(!system.enqueue(&b.key, &mut res, &mut []));
+        // снятие пререквизита убирает из очереди и зависящее от него
+        assert!(system.dequeue(&a.key));
+        assert!(system.queue.is_empty());
+    }
+
+    #[test]
+    fn queue_runs_in_order_and_pays_on_start() {
+        let (a, b) = chain();
+        let mut system = ResearchSystem::new();
+        let mut res = funds(&[a, b]);
+        system.enqueue(&a.key, &mut res, &mut []);
+        system.enqueue(&b.key, &mut res, &mut []);
+        assert_eq!(system.active_research.as_ref().map(|r| r.kind.as_str()), Some(a.key.as_str()));
+        assert_eq!(system.queue, vec![b.key.clone()]);
+
+        let done = system.add_work(a.points as f32 * WORK_MS_PER_POINT);
+        assert_eq!(done.as_deref(), Some(a.key.as_str()));
+        assert_eq!(system.start_next(&mut res, &mut []).as_deref(), Some(b.key.as_str()));
+        assert!(system.queue.is_empty());
+        // обе стоимости списаны
+        for (kind, _) in cost_items(&a.cost).into_iter().chain(cost_items(&b.cost)) {
+            assert_eq!(crate::resource_visitor::get_resource_value(&res, kind), 0);
+        }
+    }
+}
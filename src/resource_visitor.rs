@@ -29,6 +29,18 @@ pub trait ResourceVisitor {
     fn visit_fish(&mut self, amount: i32) -> Self::Output;
     fn visit_iron_ore(&mut self, amount: i32) -> Self::Output;
     fn visit_iron_ingot(&mut self, amount: i32) -> Self::Output;
+    fn visit_gold_ore(&mut self, amount: i32) -> Self::Output;
+    fn visit_coal(&mut self, amount: i32) -> Self::Output;
+    fn visit_salt(&mut self, amount: i32) -> Self::Output;
+    fn visit_gems(&mut self, amount: i32) -> Self::Output;
+    fn visit_jewelry(&mut self, amount: i32) -> Self::Output;
+    fn visit_charcoal(&mut self, amount: i32) -> Self::Output;
+    fn visit_tools(&mut self, amount: i32) -> Self::Output;
+    fn visit_wool(&mut self, amount: i32) -> Self::Output;
+    fn visit_cloth(&mut self, amount: i32) -> Self::Output;
+    fn visit_clothes(&mut self, amount: i32) -> Self::Output;
+    fn visit_hops(&mut self, amount: i32) -> Self::Output;
+    fn visit_ale(&mut self, amount: i32) -> Self::Output;
 }
 
 /// Trait для посетителя ресурсов (mutable операции)
@@ -46,6 +58,18 @@ pub trait ResourceVisitorMut {
     fn visit_fish_mut(&mut self, amount: &mut i32) -> Self::Output;
     fn visit_iron_ore_mut(&mut self, amount: &mut i32) -> Self::Output;
     fn visit_iron_ingot_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_gold_ore_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_coal_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_salt_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_gems_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_jewelry_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_charcoal_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_tools_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_wool_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_cloth_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_clothes_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_hops_mut(&mut self, amount: &mut i32) -> Self::Output;
+    fn visit_ale_mut(&mut self, amount: &mut i32) -> Self::Output;
 }
 
 // Реализация для Resources
@@ -66,6 +90,18 @@ impl ResourceVisitable for Resources {
             ResourceKind::Fish => visitor.visit_fish(self.fish),
             ResourceKind::IronOre => visitor.visit_iron_ore(self.iron_ore),
             ResourceKind::IronIngot => visitor.visit_iron_ingot(self.iron_ingots),
+            ResourceKind::GoldOre => visitor.visit_gold_ore(self.gold_ore),
+            ResourceKind::Coal => visitor.visit_coal(self.coal),
+            ResourceKind::Salt => visitor.visit_salt(self.salt),
+            ResourceKind::Gems => visitor.visit_gems(self.gems),
+            ResourceKind::Jewelry => visitor.visit_jewelry(self.jewelry),
+            ResourceKind::Charcoal => visitor.visit_charcoal(self.charcoal),
+            ResourceKind::Tools => visitor.visit_tools(self.tools),
+            ResourceKind::Wool => visitor.visit_wool(self.wool),
+            ResourceKind::Cloth => visitor.visit_cloth(self.cloth),
+            ResourceKind::Clothes => visitor.visit_clothes(self.clothes),
+            ResourceKind::Hops => visitor.visit_hops(self.hops),
+            ResourceKind::Ale => visitor.visit_ale(self.ale),
         }
     }
     
@@ -85,6 +121,18 @@ impl ResourceVisitable for Resources {
             ResourceKind::Fish => visitor.visit_fish_mut(&mut self.fish),
             ResourceKind::IronOre => visitor.visit_iron_ore_mut(&mut self.iron_ore),
             ResourceKind::IronIngot => visitor.visit_iron_ingot_mut(&mut self.iron_ingots),
+            ResourceKind::GoldOre => visitor.visit_gold_ore_mut(&mut self.gold_ore),
+            ResourceKind::Coal => visitor.visit_coal_mut(&mut self.coal),
+            ResourceKind::Salt => visitor.visit_salt_mut(&mut self.salt),
+            ResourceKind::Gems => visitor.visit_gems_mut(&mut self.gems),
+            ResourceKind::Jewelry => visitor.visit_jewelry_mut(&mut self.jewelry),
+            ResourceKind::Charcoal => visitor.visit_charcoal_mut(&mut self.charcoal),
+            ResourceKind::Tools => visitor.visit_tools_mut(&mut self.tools),
+            ResourceKind::Wool => visitor.visit_wool_mut(&mut self.wool),
+            ResourceKind::Cloth => visitor.visit_cloth_mut(&mut self.cloth),
+            ResourceKind::Clothes => visitor.visit_clothes_mut(&mut self.clothes),
+            ResourceKind::Hops => visitor.visit_hops_mut(&mut self.hops),
+            ResourceKind::Ale => visitor.visit_ale_mut(&mut self.ale),
         }
     }
 }
@@ -107,6 +155,18 @@ impl ResourceVisitable for WarehouseStore {
             ResourceKind::Fish => visitor.visit_fish(self.fish),
             ResourceKind::IronOre => visitor.visit_iron_ore(self.iron_ore),
             ResourceKind::IronIngot => visitor.visit_iron_ingot(self.iron_ingots),
+            ResourceKind::GoldOre => visitor.visit_gold_ore(self.gold_ore),
+            ResourceKind::Coal => visitor.visit_coal(self.coal),
+            ResourceKind::Salt => visitor.visit_salt(self.salt),
+            ResourceKind::Gems => visitor.visit_gems(self.gems),
+            ResourceKind::Jewelry => visitor.visit_jewelry(self.jewelry),
+            ResourceKind::Charcoal => visitor.visit_charcoal(self.charcoal),
+            ResourceKind::Tools => visitor.visit_tools(self.tools),
+            ResourceKind::Wool => visitor.visit_wool(self.wool),
+            ResourceKind::Cloth => visitor.visit_cloth(self.cloth),
+            ResourceKind::Clothes => visitor.visit_clothes(self.clothes),
+            ResourceKind::Hops => visitor.visit_hops(self.hops),
+            ResourceKind::Ale => visitor.visit_ale(self.ale),
         }
     }
     
@@ -126,6 +186,18 @@ impl ResourceVisitable for WarehouseStore {
             ResourceKind::Fish => visitor.visit_fish_mut(&mut self.fish),
             ResourceKind::IronOre => visitor.visit_iron_ore_mut(&mut self.iron_ore),
             ResourceKind::IronIngot => visitor.visit_iron_ingot_mut(&mut self.iron_ingots),
+            ResourceKind::GoldOre => visitor.visit_gold_ore_mut(&mut self.gold_ore),
+            ResourceKind::Coal => visitor.visit_coal_mut(&mut self.coal),
+            ResourceKind::Salt => visitor.visit_salt_mut(&mut self.salt),
+            ResourceKind::Gems => visitor.visit_gems_mut(&mut self.gems),
+            ResourceKind::Jewelry => visitor.visit_jewelry_mut(&mut self.jewelry),
+            ResourceKind::Charcoal => visitor.visit_charcoal_mut(&mut self.charcoal),
+            ResourceKind::Tools => visitor.visit_tools_mut(&mut self.tools),
+            ResourceKind::Wool => visitor.visit_wool_mut(&mut self.wool),
+            ResourceKind::Cloth => visitor.visit_cloth_mut(&mut self.cloth),
+            ResourceKind::Clothes => visitor.visit_clothes_mut(&mut self.clothes),
+            ResourceKind::Hops => visitor.visit_hops_mut(&mut self.hops),
+            ResourceKind::Ale => visitor.visit_ale_mut(&mut self.ale),
         }
     }
 }
@@ -187,6 +259,47 @@ impl ResourceVisitor for SumVisitor {
     fn visit_iron_ingot(&mut self, amount: i32) -> Self::Output {
         self.total += amount;
     }
+    
+    fn visit_gold_ore(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    
+    fn visit_coal(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    
+    fn visit_salt(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    
+    fn visit_gems(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    
+    fn visit_jewelry(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_charcoal(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_tools(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_wool(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_cloth(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_clothes(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_hops(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
+    fn visit_ale(&mut self, amount: i32) -> Self::Output {
+        self.total += amount;
+    }
 }
 
 /// Посетитель для проверки достаточности ресурса
@@ -250,6 +363,47 @@ impl ResourceVisitor for CheckEnoughVisitor {
     fn visit_iron_ingot(&mut self, amount: i32) -> Self::Output {
         self.result = amount >= self.required;
     }
+    
+    fn visit_gold_ore(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    
+    fn visit_coal(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    
+    fn visit_salt(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    
+    fn visit_gems(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    
+    fn visit_jewelry(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_charcoal(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_tools(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_wool(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_cloth(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_clothes(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_hops(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
+    fn visit_ale(&mut self, amount: i32) -> Self::Output {
+        self.result = amount >= self.required;
+    }
 }
 
 /// Посетитель для списания ресурсов
@@ -346,6 +500,168 @@ impl ResourceVisitorMut for SpendVisitor {
         self.spent += take;
         self.amount -= take;
     }
+    
+    fn visit_gold_ore_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    
+    fn visit_coal_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    
+    fn visit_salt_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    
+    fn visit_gems_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    
+    fn visit_jewelry_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_charcoal_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_tools_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_wool_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_cloth_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_clothes_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_hops_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+    fn visit_ale_mut(&mut self, amount: &mut i32) -> Self::Output {
+        let take = self.amount.min(*amount);
+        *amount -= take;
+        self.spent += take;
+        self.amount -= take;
+    }
+}
+
+/// Посетитель для зачисления ресурсов (награды)
+pub struct AddVisitor {
+    pub amount: i32,
+}
+
+impl AddVisitor {
+    pub fn new(amount: i32) -> Self {
+        Self { amount }
+    }
+}
+
+impl ResourceVisitorMut for AddVisitor {
+    type Output = ();
+    
+    fn visit_wood_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_gold_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_stone_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_clay_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_bricks_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_wheat_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_flour_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_bread_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_fish_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_iron_ore_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_iron_ingot_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_gold_ore_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_coal_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_salt_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_gems_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_jewelry_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_charcoal_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_tools_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_wool_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_cloth_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_clothes_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_hops_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
+    fn visit_ale_mut(&mut self, amount: &mut i32) -> Self::Output {
+        *amount += self.amount;
+    }
 }
 
 /// Посетитель для получения значения ресурса
@@ -405,6 +721,47 @@ impl ResourceVisitor for GetValueVisitor {
     fn visit_iron_ingot(&mut self, amount: i32) -> Self::Output {
         self.value = Some(amount);
     }
+    
+    fn visit_gold_ore(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    
+    fn visit_coal(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    
+    fn visit_salt(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    
+    fn visit_gems(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    
+    fn visit_jewelry(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_charcoal(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_tools(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_wool(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_cloth(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_clothes(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_hops(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
+    fn visit_ale(&mut self, amount: i32) -> Self::Output {
+        self.value = Some(amount);
+    }
 }
 
 /// Вспомогательная функция для получения значения ресурса из Resources
@@ -416,7 +773,6 @@ pub fn get_resource_value(resources: &Resources, resource: ResourceKind) -> i32
 }
 
 /// Вспомогательная функция для получения значения ресурса из WarehouseStore
-#[allow(dead_code)] // Может быть полезно для будущего использования
 pub fn get_warehouse_resource_value(warehouse: &WarehouseStore, resource: ResourceKind) -> i32 {
     let mut visitor = GetValueVisitor::new();
     warehouse.accept(&mut visitor, resource);
@@ -432,3 +788,9 @@ pub fn sum_warehouses_resource(warehouses: &[WarehouseStore], resource: Resource
     visitor.total
 }
 
+
+/// Зачислить ресурс в глобальный запас
+pub fn add_resource(resources: &mut Resources, resource: ResourceKind, amount: i32) {
+    let mut visitor = AddVisitor::new(amount);
+    resources.accept_mut(&mut visitor, resource);
+}
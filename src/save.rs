@@ -5,7 +5,9 @@ use crate::types::{Building, BuildingKind, Resources, Citizen, Job, WarehouseSto
 use crate::research::ResearchSystem;
 use crate::notifications::NotificationSystem;
 use crate::quests::QuestSystem;
+use crate::poi::PoiSystem;
 use crate::tutorial::TutorialSystem;
+use crate::game_setup::GameSetup;
 
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
@@ -24,6 +26,8 @@ pub struct SaveData {
     pub quest_system: Option<QuestSystem>,
     #[serde(default)]
     pub tutorial_system: Option<TutorialSystem>,
+    #[serde(default)]
+    pub poi_system: Option<PoiSystem>,
     // Расширенные данные
     #[serde(default)]
     pub citizens: Vec<Citizen>,
@@ -39,18 +43,48 @@ pub struct SaveData {
     pub population: i32,
     #[serde(default)]
     pub world_clock_ms: f32,
+    // номер игрового дня (календарь сезонов)
+    #[serde(default)]
+    pub day: i32,
     #[serde(default)]
     pub tax_rate: f32,
     #[serde(default)]
     pub speed_mult: f32,
     #[serde(default)]
     pub food_policy: FoodPolicy,
-    // Туман войны (разведанные тайлы)
+    // Туман войны: разведанная область битами по чанкам
     #[serde(default)]
+    pub explored: crate::world::ChunkBits,
+    // старый формат (списком тайлов) — только для загрузки старых сохранений
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub explored_tiles: Vec<(i32, i32)>,
+    // Отряды разведчиков в пути
+    #[serde(default)]
+    pub expeditions: Vec<crate::scouting::Expedition>,
+    // Паромные корабли (линии пересобираются по причалам)
+    #[serde(default)]
+    pub shipping: Option<crate::shipping::ShippingSystem>,
+    // Рынок: цены, история и приказы игрока
+    #[serde(default)]
+    pub trade: Option<crate::trade::TradeSystem>,
+    // Соседние поселения: репутация и контракты
+    #[serde(default)]
+    pub contracts: Option<crate::contracts::ContractSystem>,
+    // Сценарий: цели, события и итог (None — свободная игра)
+    #[serde(default)]
+    pub scenario: Option<crate::scenario::ScenarioState>,
+    // Советник: найденные проблемы (значки над зданиями)
+    #[serde(default)]
+    pub advisor: Option<crate::advisor::Advisor>,
     // Дороги
     #[serde(default)]
     pub roads: Vec<(i32, i32)>,
+    // Настройки новой игры (пресет карты, сложность)
+    #[serde(default)]
+    pub setup: GameSetup,
+    // Остатки разработанных месторождений: ((x, y), [глина, камень, железо, уголь, золото, самоцветы, соль])
+    #[serde(default)]
+    pub deposits: crate::world::ChunkDeposits,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy)]
@@ -63,6 +97,8 @@ pub struct SaveBuilding {
     pub workers_target: i32,
     #[serde(default)]
     pub capacity: i32,
+    #[serde(default)]
+    pub comfort: u8,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy)]
@@ -73,9 +109,16 @@ pub struct SaveTree {
     pub age_ms: i32,
 }
 
+impl SaveTree {
+    pub fn to_tree(self) -> ((i32, i32), crate::world::Tree) {
+        ((self.x, self.y), crate::world::Tree { stage: self.stage, age_ms: self.age_ms })
+    }
+}
+
 impl SaveData {
     pub fn from_runtime(
         seed: u64,
+        setup: GameSetup,
         res: &Resources,
         buildings: &Vec<Building>,
         cam_px: Vec2,
@@ -85,6 +128,13 @@ impl SaveData {
         notification_system: &NotificationSystem,
         quest_system: &QuestSystem,
         tutorial_system: &TutorialSystem,
+        poi_system: &PoiSystem,
+        expeditions: &[crate::scouting::Expedition],
+        shipping: &crate::shipping::ShippingSystem,
+        trade: &crate::trade::TradeSystem,
+        contracts: &crate::contracts::ContractSystem,
+        scenario: &Option<crate::scenario::ScenarioState>,
+        advisor: &crate::advisor::Advisor,
         citizens: &Vec<Citizen>,
         jobs: &Vec<Job>,
         next_job_id: u64,
@@ -92,6 +142,7 @@ impl SaveData {
         warehouses: &Vec<WarehouseStore>,
         population: i32,
         world_clock_ms: f32,
+        day: i32,
         tax_rate: f32,
         speed_mult: f32,
         food_policy: FoodPolicy,
@@ -105,16 +156,19 @@ impl SaveData {
                 timer_ms: b.timer_ms,
                 workers_target: b.workers_target,
                 capacity: b.capacity,
+                comfort: b.comfort,
             })
             .collect();
         let mut trees = Vec::new();
-        for (&(x, y), tr) in world.trees.iter() {
+        for ((x, y), tr) in world.all_trees() {
             trees.push(SaveTree { x, y, stage: tr.stage, age_ms: tr.age_ms });
         }
-        // Сохраняем разведанные тайлы (туман войны)
-        let explored_tiles: Vec<(i32, i32)> = world.explored_tiles.iter().copied().collect();
+        // Сохраняем разведанную область (туман войны)
+        let explored = world.explored_bits();
         // Сохраняем дороги
-        let roads: Vec<(i32, i32)> = world.roads.iter().copied().collect();
+        let roads: Vec<(i32, i32)> = world.all_roads();
+        // Сохраняем остатки месторождений
+        let deposits = world.all_deposits();
         SaveData { 
             seed, 
             resources: *res, 
@@ -127,6 +181,7 @@ impl SaveData {
             notification_system: Some(notification_system.clone()),
             quest_system: Some(quest_system.clone()),
             tutorial_system: Some(tutorial_system.clone()),
+            poi_system: Some(poi_system.clone()),
             citizens: citizens.clone(),
             jobs: jobs.clone(),
             next_job_id,
@@ -134,11 +189,21 @@ impl SaveData {
             warehouses: warehouses.clone(),
             population,
             world_clock_ms,
+            day,
             tax_rate,
             speed_mult,
             food_policy,
-            explored_tiles,
+            explored,
+            explored_tiles: Vec::new(),
+            expeditions: expeditions.to_vec(),
+            shipping: Some(shipping.clone()),
+            trade: Some(trade.clone()),
+            contracts: Some(contracts.clone()),
+            scenario: scenario.clone(),
+            advisor: Some(advisor.clone()),
             roads,
+            setup,
+            deposits,
         }
     }
 
@@ -151,6 +216,7 @@ impl SaveData {
                 timer_ms: sb.timer_ms,
                 workers_target: sb.workers_target,
                 capacity: sb.capacity,
+                comfort: sb.comfort,
                 is_highlighted: false,
             })
             .collect()
@@ -174,3 +240,66 @@ pub fn load_game() -> anyhow::Result<SaveData> {
 }
 
 
+
+/// Восстановить состояние игры из сохранения (общее для загрузки из меню и по F9)
+pub fn apply_save(game_state: &mut crate::game_state::GameState, camera: &mut crate::camera::Camera, save: SaveData) {
+    game_state.seed = save.seed;
+    game_state.setup = save.setup;
+    game_state.world.map_gen = save.setup.map_params();
+    game_state.world.reset_noise(save.seed);
+    game_state.buildings = save.to_buildings();
+    game_state.buildings_dirty = true;
+    game_state.resources = save.resources;
+    camera.pos = Vec2::new(save.cam_x, save.cam_y);
+    camera.zoom = save.zoom;
+
+    // Граждане, работы, поленья на земле, склады
+    game_state.citizens = save.citizens;
+    game_state.jobs = save.jobs;
+    game_state.next_job_id = save.next_job_id;
+    game_state.logs_on_ground = save.logs_on_ground;
+    game_state.warehouses = save.warehouses;
+    game_state.population = save.population;
+
+    // Время игры и экономические параметры
+    game_state.world_clock_ms = save.world_clock_ms;
+    game_state.weather_system.set_day(save.day);
+    game_state.tax_rate = save.tax_rate;
+    game_state.speed_mult = save.speed_mult;
+    game_state.food_policy = save.food_policy;
+
+    // Занятые клетки, деревья, туман войны
+    game_state.world.clear_occupied();
+    for b in &game_state.buildings {
+        game_state.world.occupy(b.pos);
+    }
+    game_state.world.restore_trees(save.trees.iter().map(|t| t.to_tree()));
+    game_state.world.restore_explored(save.explored.iter().cloned());
+    // старые сохранения хранили список тайлов
+    for &(x, y) in &save.explored_tiles {
+        game_state.world.explore_area(glam::IVec2::new(x, y), 0);
+    }
+    game_state.expeditions = save.expeditions;
+    game_state.shipping = save.shipping.unwrap_or_default();
+    game_state.trade = save.trade.unwrap_or_default();
+    game_state.contracts = save.contracts.unwrap_or_default();
+    game_state.scenario = save.scenario;
+    game_state.advisor = save.advisor.unwrap_or_default();
+
+    // Дороги, месторождения, точки интереса
+    game_state.world.restore_roads(save.roads.iter().copied());
+    game_state.world.restore_deposits(save.deposits.iter().cloned());
+    game_state.poi_system = save.poi_system.unwrap_or_default();
+
+    // Системы исследований, уведомлений, заданий и обучения (в старых сохранениях — новые)
+    game_state.research_system = save.research_system.unwrap_or_else(ResearchSystem::new);
+    // исследования, добавленные после сохранения
+    game_state.research_system.add_missing();
+    game_state.notification_system = save.notification_system.unwrap_or_default();
+    if let Some(quest_system) = save.quest_system {
+        game_state.quest_system = quest_system;
+    }
+    if let Some(tutorial_system) = save.tutorial_system {
+        game_state.tutorial_system = tutorial_system;
+    }
+}
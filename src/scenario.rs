@@ -0,0 +1,365 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::{Serialize, Deserialize};
+
+use crate::game_setup::{Difficulty, MapPreset};
+use crate::quests::{QuestDef, QuestKind, QuestReward, QuestSystem};
+use crate::types::{BuildingKind, Resources};
+use crate::i18n::{tr, tr_fmt};
+
+// здания сценария ставятся в стартовой области (см. GameState::with_setup)
+const START_AREA_RADIUS: i32 = 10;
+
+/// Файл с цепочками квестов (assets/quests/*.toml)
+#[derive(Deserialize)]
+struct QuestFile {
+    #[serde(default)]
+    quest: Vec<QuestDef>,
+}
+
+/// Стартовое здание сценария (координаты относительно центра карты)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioBuilding {
+    pub kind: BuildingKind,
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Скриптовое событие сценария: сообщение и подарок на заданный день
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioEvent {
+    pub day: i32,
+    pub message: String,
+    #[serde(default)]
+    pub rewards: Vec<QuestReward>,
+    // язык → сообщение; нет перевода — английский текст
+    #[serde(default)]
+    pub translations: HashMap<String, EventText>,
+}
+
+/// Сообщение события на одном языке
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventText {
+    pub message: String,
+}
+
+impl ScenarioEvent {
+    /// Сообщение на текущем языке интерфейса
+    pub fn display_message(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.message.as_str(), |t| t.message.as_str())
+    }
+}
+
+/// Условия поражения
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScenarioDefeat {
+    // не успели выполнить цели за столько дней
+    pub days: Option<i32>,
+    // казна ушла ниже порога
+    pub gold_below: Option<i32>,
+    // провален один из этих квестов
+    pub failed_quests: Vec<String>,
+}
+
+/// Сценарий (assets/scenarios/*.toml): карта, старт, цели и события
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub map: Option<MapPreset>,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    // стартовые запасы вместо выбранных в меню
+    #[serde(default)]
+    pub resources: Option<Resources>,
+    #[serde(default)]
+    pub buildings: Vec<ScenarioBuilding>,
+    // случайные квесты в дополнение к квестам сценария
+    #[serde(default)]
+    pub random_quests: bool,
+    #[serde(default)]
+    pub quest: Vec<QuestDef>,
+    // победа — когда выполнены все эти квесты
+    pub victory: Vec<String>,
+    #[serde(default)]
+    pub defeat: ScenarioDefeat,
+    #[serde(default)]
+    pub event: Vec<ScenarioEvent>,
+    // язык → название и описание; нет перевода — английский текст
+    #[serde(default)]
+    pub translations: HashMap<String, ScenarioText>,
+}
+
+/// Название и описание сценария на одном языке
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioText {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl Scenario {
+    /// Название на текущем языке интерфейса
+    pub fn display_name(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.name.as_str(), |t| t.name.as_str())
+    }
+
+    /// Описание на текущем языке интерфейса
+    pub fn display_description(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.description.as_str(), |t| t.description.as_str())
+    }
+}
+
+/// Чем закончился сценарий
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScenarioOutcome {
+    Victory,
+    Defeat(String),
+}
+
+/// Идущий сценарий: цели, события и итог; сохраняется вместе с игрой
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioState {
+    pub name: String,
+    pub victory: Vec<String>,
+    pub defeat: ScenarioDefeat,
+    pub events: Vec<ScenarioEvent>,
+    // день старта по календарю погоды
+    pub start_day: i32,
+    pub outcome: Option<ScenarioOutcome>,
+    // игрок закрыл экран итога и играет дальше
+    #[serde(default)]
+    pub dismissed: bool,
+    // переводы названия сценария
+    #[serde(default)]
+    pub translations: HashMap<String, ScenarioText>,
+}
+
+impl ScenarioState {
+    pub fn new(scenario: &Scenario, start_day: i32) -> Self {
+        Self {
+            name: scenario.name.clone(),
+            victory: scenario.victory.clone(),
+            defeat: scenario.defeat.clone(),
+            events: scenario.event.clone(),
+            start_day,
+            outcome: None,
+            dismissed: false,
+            translations: scenario.translations.clone(),
+        }
+    }
+
+    /// Название на текущем языке интерфейса
+    pub fn display_name(&self) -> &str {
+        self.translations.get(crate::i18n::language()).map_or(self.name.as_str(), |t| t.name.as_str())
+    }
+
+    /// Дней с начала сценария
+    pub fn days_passed(&self, day: i32) -> i32 {
+        day - self.start_day
+    }
+
+    /// Показывать ли экран итога
+    pub fn result_shown(&self) -> bool {
+        self.outcome.is_some() && !self.dismissed
+    }
+
+    /// События, чей день настал (удаляются из списка)
+    pub fn due_events(&mut self, day: i32) -> Vec<ScenarioEvent> {
+        let passed = self.days_passed(day);
+        let (due, rest): (Vec<_>, Vec<_>) = self.events.drain(..).partition(|e| e.day <= passed);
+        self.events = rest;
+        due
+    }
+
+    /// Проверить победу и поражение; Some — если итог только что определился
+    pub fn check(&mut self, day: i32, quests: &QuestSystem, gold: i32) -> Option<ScenarioOutcome> {
+        if self.outcome.is_some() { return None; }
+        let done = |key: &String| quests.completed_keys.contains(key)
+            || quests.active_quests.iter().any(|q| q.completed && q.key.as_ref() == Some(key));
+        let outcome = if !self.victory.is_empty() && self.victory.iter().all(done) {
+            ScenarioOutcome::Victory
+        } else if let Some(key) = self.defeat.failed_quests.iter().find(|k| quests.failed_keys.contains(k)) {
+            let title = quests.chain.iter().find(|d| &d.key == key).map_or(key.as_str(), |d| d.display_title());
+            ScenarioOutcome::Defeat(tr_fmt("notify.quest_failed", &[&title]))
+        } else if self.defeat.days.is_some_and(|d| self.days_passed(day) > d) {
+            ScenarioOutcome::Defeat(tr("scenario.time_up").to_string())
+        } else if self.defeat.gold_below.is_some_and(|g| gold < g) {
+            ScenarioOutcome::Defeat(tr("scenario.bankrupt").to_string())
+        } else {
+            return None;
+        };
+        self.outcome = Some(outcome.clone());
+        Some(outcome)
+    }
+}
+
+/// Цепочки квестов из assets/quests; файлы с ошибками пропускаются
+pub fn load_quest_defs() -> Vec<QuestDef> {
+    let mut defs: Vec<QuestDef> = Vec::new();
+    for (path, text) in read_dir_toml(&crate::resource_path::assets_path().join("quests")) {
+        let file: QuestFile = match toml::from_str(&text) {
+            Ok(f) => f,
+            Err(e) => { log::warn!("Квесты {:?} не загружены: {}", path, e); continue; }
+        };
+        // ключи должны быть уникальны и среди уже загруженных файлов
+        let mut all = defs.clone();
+        all.extend(file.quest.iter().cloned());
+        match validate_quests(&all) {
+            Ok(()) => defs = all,
+            Err(errors) => log::warn!("Квесты {:?} не загружены: {}", path, errors.join("; ")),
+        }
+    }
+    defs
+}
+
+/// Сценарии из assets/scenarios (по имени файла); файлы с ошибками пропускаются
+pub fn load_scenarios() -> Vec<Scenario> {
+    let mut scenarios = Vec::new();
+    for (path, text) in read_dir_toml(&crate::resource_path::assets_path().join("scenarios")) {
+        let scenario: Scenario = match toml::from_str(&text) {
+            Ok(s) => s,
+            Err(e) => { log::warn!("Сценарий {:?} не загружен: {}", path, e); continue; }
+        };
+        match validate_scenario(&scenario) {
+            Ok(()) => scenarios.push(scenario),
+            Err(errors) => log::warn!("Сценарий {:?} не загружен: {}", path, errors.join("; ")),
+        }
+    }
+    scenarios
+}
+
+/// Все *.toml каталога по алфавиту (нет каталога — пусто)
+pub fn read_dir_toml(dir: &Path) -> Vec<(std::path::PathBuf, String)> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new(); };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    paths.into_iter()
+        .filter_map(|p| match std::fs::read_to_string(&p) {
+            Ok(text) => Some((p, text)),
+            Err(e) => { log::warn!("Не удалось прочитать {:?}: {}", p, e); None }
+        })
+        .collect()
+}
+
+/// Проверка определений квестов: ключи, ссылки на пререквизиты, цели и награды
+pub fn validate_quests(defs: &[QuestDef]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut keys = HashSet::new();
+    for d in defs {
+        if d.key.is_empty() { errors.push(format!("quest '{}' has an empty key", d.title)); }
+        if !keys.insert(d.key.as_str()) { errors.push(format!("duplicate quest key '{}'", d.key)); }
+    }
+    for d in defs {
+        for r in &d.requires {
+            if r == &d.key { errors.push(format!("quest '{}' requires itself", d.key)); }
+            else if !keys.contains(r.as_str()) { errors.push(format!("quest '{}' requires unknown quest '{}'", d.key, r)); }
+        }
+        let target = match &d.kind {
+            QuestKind::CollectResource { target_amount, .. } | QuestKind::CollectGold { target_amount, .. } => *target_amount,
+            QuestKind::BuildBuildings { target_count, .. } => *target_count,
+            QuestKind::ReachPopulation { target_population, .. } => *target_population,
+            QuestKind::ProduceDaily { target_per_day, .. } => *target_per_day,
+            QuestKind::ReachHappiness { target, .. } => *target,
+            QuestKind::ConnectByRoad { .. } => 1,
+            QuestKind::Contract { .. } => { errors.push(format!("quest '{}': contracts come from settlements only", d.key)); 1 }
+        };
+        if target <= 0 { errors.push(format!("quest '{}' has a non-positive target", d.key)); }
+        if d.days.is_some_and(|days| days <= 0) { errors.push(format!("quest '{}' has a non-positive deadline", d.key)); }
+        let bad_reward = d.rewards.iter().any(|r| matches!(*r, QuestReward::Gold(n) | QuestReward::Resource(_, n) | QuestReward::ResearchDays(n) if n <= 0));
+        if bad_reward { errors.push(format!("quest '{}' has a non-positive reward", d.key)); }
+        for r in &d.rewards {
+            if let QuestReward::Research(key) = r
+                && crate::research::def(key).is_none() {
+                errors.push(format!("quest '{}' rewards unknown research '{}'", d.key, key));
+            }
+        }
+    }
+    // циклы: снимаем квесты, все требования которых уже сняты; что осталось — в цикле
+    // (ссылки на себя и неизвестные квесты уже отмечены выше)
+    let mut resolved: HashSet<&str> = HashSet::new();
+    loop {
+        let before = resolved.len();
+        for d in defs {
+            if d.requires.iter().all(|r| resolved.contains(r.as_str()) || r == &d.key || !keys.contains(r.as_str())) {
+                resolved.insert(d.key.as_str());
+            }
+        }
+        if resolved.len() == before { break; }
+    }
+    for d in defs.iter().filter(|d| !resolved.contains(d.key.as_str())) {
+        errors.push(format!("quest '{}' is part of a requires cycle", d.key));
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Проверка сценария: квесты, цели победы и поражения, стартовые здания и события
+pub fn validate_scenario(s: &Scenario) -> Result<(), Vec<String>> {
+    let mut errors = validate_quests(&s.quest).err().unwrap_or_default();
+    if s.name.is_empty() { errors.push("scenario has no name".to_string()); }
+    if s.victory.is_empty() { errors.push("scenario has no victory quests".to_string()); }
+    let known = |k: &String| s.quest.iter().any(|d| &d.key == k);
+    for k in s.victory.iter().chain(&s.defeat.failed_quests) {
+        if !known(k) { errors.push(format!("goal refers to unknown quest '{}'", k)); }
+    }
+    if s.defeat.days.is_some_and(|d| d <= 0) { errors.push("defeat.days must be positive".to_string()); }
+    for b in &s.buildings {
+        if b.x.abs().max(b.y.abs()) > START_AREA_RADIUS {
+            errors.push(format!("{:?} at ({}, {}) is outside the start area", b.kind, b.x, b.y));
+        }
+    }
+    for e in &s.event {
+        if e.day <= 0 { errors.push(format!("event '{}' has a non-positive day", e.message)); }
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quests(text: &str) -> Vec<QuestDef> {
+        toml::from_str::<QuestFile>(text).unwrap().quest
+    }
+
+    #[test]
+    fn requires_cycle_is_rejected() {
+        let defs = quests(r#"
+            [[quest]]
+            key = "a"
+            title = "A"
+            description = ""
+            kind = { CollectGold = { target_amount = 10 } }
+            rewards = []
+
+            [[quest]]
+            key = "b"
+            requires = ["a", "c"]
+            title = "B"
+            description = ""
+            kind = { CollectGold = { target_amount = 10 } }
+            rewards = []
+
+            [[quest]]
+            key = "c"
+            requires = ["b"]
+            title = "C"
+            description = ""
+            kind = { CollectGold = { target_amount = 10 } }
+            rewards = []
+        "#);
+        let mut errors = validate_quests(&defs).unwrap_err();
+        errors.sort();
+        assert_eq!(errors, ["quest 'b' is part of a requires cycle", "quest 'c' is part of a requires cycle"]);
+        assert!(validate_quests(&defs[..1]).is_ok());
+    }
+}
@@ -0,0 +1,79 @@
+use glam::{IVec2, Vec2};
+use serde::{Serialize, Deserialize};
+
+use crate::types::{Building, BuildingKind, Resources};
+use crate::world::{World, SCOUT_VISION_RADIUS};
+
+// скорость отряда (мс игрового времени на тайл) и дальность похода от башни
+const SCOUT_STEP_MS: f32 = 400.0;
+pub const EXPEDITION_RANGE: i32 = 60;
+
+/// Стоимость снаряжения экспедиции
+pub fn expedition_cost() -> Resources {
+    Resources { gold: 30, ..Default::default() }
+}
+
+/// Отряд разведчиков: идёт от башни к цели, разведывая путь, и возвращается
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Expedition {
+    pub tower: IVec2,
+    pub target: IVec2,
+    pub pos: Vec2,
+    pub returning: bool,
+}
+
+impl Expedition {
+    pub fn tile(&self) -> IVec2 {
+        self.pos.round().as_ivec2()
+    }
+}
+
+/// Свободная башня, ближайшая к цели (у каждой башни — не больше одного отряда)
+pub fn free_tower_for(buildings: &[Building], expeditions: &[Expedition], target: IVec2) -> Option<IVec2> {
+    buildings.iter()
+        .filter(|b| b.kind == BuildingKind::ScoutTower)
+        .filter(|b| !expeditions.iter().any(|e| e.tower == b.pos))
+        .map(|b| b.pos)
+        .min_by_key(|p| (*p - target).length_squared())
+}
+
+/// Новый отряд к цели; слишком далёкая цель урезается до дальности похода
+pub fn launch(tower: IVec2, target: IVec2) -> Expedition {
+    let d = (target - tower).as_vec2();
+    let target = if d.length() > EXPEDITION_RANGE as f32 {
+        tower + (d.normalize() * EXPEDITION_RANGE as f32).round().as_ivec2()
+    } else {
+        target
+    };
+    Expedition { tower, target, pos: tower.as_vec2(), returning: false }
+}
+
+/// Двигать отряды; по пути туда разведываем местность.
+/// Возвращает цели отрядов, вернувшихся домой (или потерявших башню)
+pub fn update_expeditions(expeditions: &mut Vec<Expedition>, buildings: &[Building], world: &mut World, step_ms: f32) -> Vec<IVec2> {
+    let mut finished = Vec::new();
+    let step = step_ms / SCOUT_STEP_MS;
+    expeditions.retain_mut(|e| {
+        if !buildings.iter().any(|b| b.kind == BuildingKind::ScoutTower && b.pos == e.tower) {
+            finished.push(e.target);
+            return false;
+        }
+        let goal = if e.returning { e.tower } else { e.target }.as_vec2();
+        let d = goal - e.pos;
+        if d.length() <= step {
+            e.pos = goal;
+            if e.returning {
+                finished.push(e.target);
+                return false;
+            }
+            e.returning = true;
+        } else {
+            e.pos += d.normalize() * step;
+        }
+        if !e.returning {
+            world.explore_area(e.tile(), SCOUT_VISION_RADIUS);
+        }
+        true
+    });
+    finished
+}
@@ -0,0 +1,202 @@
+use glam::{IVec2, Vec2};
+use serde::{Serialize, Deserialize};
+
+use crate::resource_visitor::{get_warehouse_resource_value, ResourceVisitable, SpendVisitor};
+use crate::types::{Building, BuildingKind, ResourceKind, WarehouseStore};
+use crate::world::World;
+
+// самая длинная паромная линия (по прямой, в тайлах)
+pub const FERRY_RANGE: i32 = 48;
+// время переправы на тайл линии (мс игрового времени) — для кораблей и пассажиров
+pub const FERRY_MS_PER_TILE: f32 = 200.0;
+// сколько везёт корабль и ради какого перекоса запасов стоит грузиться
+const SHIP_CAPACITY: i32 = 20;
+const SHIP_MIN_SURPLUS: i32 = 10;
+// как часто пересобираем линии и разметку островов
+const RELINK_INTERVAL_MS: i32 = 3000;
+// предел заливки одного острова (дальше большой материк не размечаем, такие считаются одним)
+const ISLAND_FLOOD_LIMIT: usize = 100_000;
+
+// что корабли возят между складами (золото — казна, его не возят)
+const CARGO: [ResourceKind; 22] = [
+    ResourceKind::Wood, ResourceKind::Stone, ResourceKind::Clay, ResourceKind::Bricks, ResourceKind::Wheat,
+    ResourceKind::Flour, ResourceKind::Bread, ResourceKind::Fish, ResourceKind::IronOre, ResourceKind::IronIngot,
+    ResourceKind::GoldOre, ResourceKind::Coal, ResourceKind::Salt, ResourceKind::Gems, ResourceKind::Jewelry, ResourceKind::Charcoal, ResourceKind::Tools,
+    ResourceKind::Wool, ResourceKind::Cloth, ResourceKind::Clothes, ResourceKind::Hops, ResourceKind::Ale,
+];
+
+/// Корабль на паромной линии: плывёт от причала к причалу
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Ship {
+    pub from: IVec2,
+    pub to: IVec2,
+    // доля пройденного пути (0..1)
+    pub progress: f32,
+    pub cargo: Option<(ResourceKind, i32)>,
+}
+
+impl Ship {
+    pub fn pos(&self) -> Vec2 {
+        self.from.as_vec2().lerp(self.to.as_vec2(), self.progress)
+    }
+}
+
+/// Паромные линии между причалами и корабли, выравнивающие запасы островов
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShippingSystem {
+    pub ships: Vec<Ship>,
+    relink_timer_ms: i32,
+    // для какой ревизии суши и каких зданий размечены острова
+    #[serde(skip)]
+    islands_for: Option<(u64, Vec<IVec2>)>,
+}
+
+impl ShippingSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Пересобрать линии и острова, двигать корабли
+    pub fn update(&mut self, step_ms: f32, buildings: &[Building], warehouses: &mut [WarehouseStore], world: &mut World) {
+        self.relink_timer_ms -= step_ms as i32;
+        if self.relink_timer_ms <= 0 {
+            self.relink_timer_ms = RELINK_INTERVAL_MS;
+            self.relink(buildings, world);
+        }
+
+        for ship in &mut self.ships {
+            let len = (ship.to - ship.from).as_vec2().length().max(1.0);
+            ship.progress += step_ms / (len * FERRY_MS_PER_TILE);
+            if ship.progress < 1.0 { continue; }
+            if let Some((kind, amount)) = ship.cargo.take() {
+                unload(warehouses, world, ship.to, kind, amount);
+            }
+            std::mem::swap(&mut ship.from, &mut ship.to);
+            ship.progress = 0.0;
+            ship.cargo = load(warehouses, world, ship.from, ship.to);
+        }
+    }
+
+    // линии: у причала не больше одной линии; пары берём от самых коротких,
+    // так что итог не зависит от порядка зданий.
+    // Острова размечаем только при наличии причалов и только если изменилась суша или здания
+    fn relink(&mut self, buildings: &[Building], world: &mut World) {
+        let docks: Vec<IVec2> = buildings.iter().filter(|b| b.kind == BuildingKind::Dock).map(|b| b.pos).collect();
+        let links = pair_docks(&docks);
+
+        let seeds: Vec<IVec2> = if docks.is_empty() { Vec::new() } else { buildings.iter().map(|b| b.pos).collect() };
+        let key = (world.land_revision(), seeds);
+        if self.islands_for.as_ref() != Some(&key) {
+            let islands = if key.1.is_empty() {
+                Default::default()
+            } else {
+                crate::path::label_islands(world, &key.1, ISLAND_FLOOD_LIMIT)
+            };
+            world.set_islands(islands);
+            self.islands_for = Some(key);
+        }
+
+        // по кораблю на линию; корабли снесённых линий исчезают вместе с грузом
+        self.ships.retain(|s| links.iter().any(|&(a, b)| (a, b) == (s.from, s.to) || (b, a) == (s.from, s.to)));
+        for &(a, b) in &links {
+            if !self.ships.iter().any(|s| (a, b) == (s.from, s.to) || (b, a) == (s.from, s.to)) {
+                self.ships.push(Ship { from: a, to: b, progress: 0.0, cargo: None });
+            }
+        }
+        world.ferries = links;
+    }
+}
+
+// пары причалов в пределах FERRY_RANGE, от ближайших; равные расстояния — по координатам
+fn pair_docks(docks: &[IVec2]) -> Vec<(IVec2, IVec2)> {
+    let mut pairs: Vec<(i32, IVec2, IVec2)> = Vec::new();
+    for (i, &a) in docks.iter().enumerate() {
+        for &b in &docks[i + 1..] {
+            if (b - a).as_vec2().length() > FERRY_RANGE as f32 { continue; }
+            let (a, b) = if (a.x, a.y) <= (b.x, b.y) { (a, b) } else { (b, a) };
+            pairs.push(((b - a).length_squared(), a, b));
+        }
+    }
+    pairs.sort_by_key(|&(d, a, b)| (d, a.x, a.y, b.x, b.y));
+    let mut used: Vec<IVec2> = Vec::new();
+    let mut links = Vec::new();
+    for (_, a, b) in pairs {
+        if used.contains(&a) || used.contains(&b) { continue; }
+        used.push(a);
+        used.push(b);
+        links.push((a, b));
+    }
+    links
+}
+
+// склады острова, на котором стоит причал
+fn island_warehouses(warehouses: &[WarehouseStore], world: &World, dock: IVec2) -> Vec<usize> {
+    let island = world.island_at(dock);
+    (0..warehouses.len()).filter(|&i| world.island_at(warehouses[i].pos) == island).collect()
+}
+
+// погрузить то, чего на этом острове заметно больше, чем на том
+fn load(warehouses: &mut [WarehouseStore], world: &World, from: IVec2, to: IVec2) -> Option<(ResourceKind, i32)> {
+    let here = island_warehouses(warehouses, world, from);
+    let there = island_warehouses(warehouses, world, to);
+    // тот же остров (или разметки нет) или некуда выгружать — возить нечего
+    if here.is_empty() || there.is_empty() || here == there { return None; }
+    let stock = |idx: &[usize], kind| idx.iter().map(|&i| get_warehouse_resource_value(&warehouses[i], kind)).sum::<i32>();
+    let (kind, surplus) = CARGO.iter()
+        .map(|&k| (k, stock(&here, k) - stock(&there, k)))
+        .max_by_key(|&(_, d)| d)?;
+    if surplus < SHIP_MIN_SURPLUS { return None; }
+    let mut visitor = SpendVisitor::new((surplus / 2).min(SHIP_CAPACITY));
+    for &i in &here {
+        if visitor.amount <= 0 { break; }
+        warehouses[i].accept_mut(&mut visitor, kind);
+    }
+    (visitor.spent > 0).then_some((kind, visitor.spent))
+}
+
+// выгрузить на ближайший к причалу склад его острова (или на любой ближайший)
+pub fn unload(warehouses: &mut [WarehouseStore], world: &World, dock: IVec2, kind: ResourceKind, amount: i32) {
+    let Some(dst) = crate::types::find_nearest_warehouse(warehouses, world, dock) else { return };
+    let Some(w) = warehouses.iter_mut().find(|w| w.pos == dst) else { return };
+    match kind {
+        ResourceKind::Wood => w.wood += amount,
+        ResourceKind::Stone => w.stone += amount,
+        ResourceKind::Clay => w.clay += amount,
+        ResourceKind::Bricks => w.bricks += amount,
+        ResourceKind::Wheat => w.wheat += amount,
+        ResourceKind::Flour => w.flour += amount,
+        ResourceKind::Bread => w.bread += amount,
+        ResourceKind::Fish => w.fish += amount,
+        ResourceKind::Gold => w.gold += amount,
+        ResourceKind::IronOre => w.iron_ore += amount,
+        ResourceKind::IronIngot => w.iron_ingots += amount,
+        ResourceKind::GoldOre => w.gold_ore += amount,
+        ResourceKind::Coal => w.coal += amount,
+        ResourceKind::Salt => w.salt += amount,
+        ResourceKind::Gems => w.gems += amount,
+        ResourceKind::Jewelry => w.jewelry += amount,
+        ResourceKind::Charcoal => w.charcoal += amount,
+        ResourceKind::Tools => w.tools += amount,
+        ResourceKind::Wool => w.wool += amount,
+        ResourceKind::Cloth => w.cloth += amount,
+        ResourceKind::Clothes => w.clothes += amount,
+        ResourceKind::Hops => w.hops += amount,
+        ResourceKind::Ale => w.ale += amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docks_pair_by_distance_regardless_of_order() {
+        let docks = [IVec2::new(0, 0), IVec2::new(30, 0), IVec2::new(34, 0), IVec2::new(200, 0)];
+        let mut reversed = docks;
+        reversed.reverse();
+        let links = pair_docks(&docks);
+        assert_eq!(links, vec![(IVec2::new(30, 0), IVec2::new(34, 0))]);
+        assert_eq!(pair_docks(&reversed), links);
+    }
+}
@@ -0,0 +1,223 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::resource_visitor::{get_resource_value, sum_warehouses_resource, ResourceVisitable, SpendVisitor};
+use crate::types::{Building, BuildingKind, ResourceKind, Resources, WarehouseStore};
+use crate::world::World;
+
+// купец заходит раз в столько дней (при наличии торгового поста)
+pub const MERCHANT_INTERVAL_DAYS: i32 = 4;
+// сколько единиц одного товара купец берёт или привозит за визит
+const MERCHANT_CAPACITY: i32 = 100;
+// наценка купца: продаём дешевле рыночной цены, покупаем дороже
+const SELL_MARGIN: f32 = 0.85;
+const BUY_MARGIN: f32 = 1.15;
+// запас, при котором рынок считает товар «обычным» (больше — цена ниже)
+const STOCK_REF: f32 = 200.0;
+// объём недавних продаж, при котором цена проседает вдвое сильнее
+const SALES_REF: f32 = 100.0;
+// сколько дней истории цен храним для графика
+pub const PRICE_HISTORY_DAYS: usize = 20;
+// шаг изменения порогов в приказах
+pub const ORDER_STEP: i32 = 10;
+
+/// Товары рынка: вид, подпись в UI и базовая цена (золото за единицу)
+pub const GOODS: [(ResourceKind, &str, i32); 22] = [
+    (ResourceKind::Wood, "Wood", 2),
+    (ResourceKind::Stone, "Stone", 3),
+    (ResourceKind::Clay, "Clay", 2),
+    (ResourceKind::Bricks, "Bricks", 6),
+    (ResourceKind::Wheat, "Wheat", 2),
+    (ResourceKind::Flour, "Flour", 4),
+    (ResourceKind::Bread, "Bread", 6),
+    (ResourceKind::Fish, "Fish", 4),
+    (ResourceKind::IronOre, "Iron Ore", 6),
+    (ResourceKind::IronIngot, "Iron Ingots", 14),
+    (ResourceKind::GoldOre, "Gold Ore", 12),
+    (ResourceKind::Coal, "Coal", 5),
+    (ResourceKind::Salt, "Salt", 8),
+    (ResourceKind::Gems, "Gems", 25),
+    (ResourceKind::Jewelry, "Jewelry", 60),
+    (ResourceKind::Charcoal, "Charcoal", 5),
+    (ResourceKind::Tools, "Tools", 30),
+    (ResourceKind::Wool, "Wool", 4),
+    (ResourceKind::Cloth, "Cloth", 9),
+    (ResourceKind::Clothes, "Clothes", 20),
+    (ResourceKind::Hops, "Hops", 3),
+    (ResourceKind::Ale, "Ale", 10),
+];
+
+/// Рынок одного товара: текущая цена, история и постоянные приказы игрока
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MarketEntry {
+    pub kind: ResourceKind,
+    pub price: f32,
+    // чистые продажи игрока за последние дни (покупки вычитаются), затухают со временем
+    pub sold_recently: f32,
+    pub history: Vec<i32>,
+    // продавать всё, что выше порога; докупать до порога
+    pub sell_above: Option<i32>,
+    pub buy_below: Option<i32>,
+}
+
+impl MarketEntry {
+    /// Цена, по которой купец берёт товар; `bonus` — надбавка от репутации у соседей
+    pub fn sell_price(&self, bonus: f32) -> i32 {
+        ((self.price * (SELL_MARGIN + bonus)).round() as i32).max(1)
+    }
+
+    /// Цена, по которой купец продаёт товар; с хорошей репутацией дешевле
+    pub fn buy_price(&self, bonus: f32) -> i32 {
+        ((self.price * (BUY_MARGIN - bonus)).round() as i32).max(1)
+    }
+}
+
+/// Итог визита купца (для уведомления)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TradeReport {
+    pub sold: i32,
+    pub bought: i32,
+    pub gold_in: i32,
+    pub gold_out: i32,
+}
+
+/// Торговля с купцом: цены от спроса и предложения, приказы на покупку и продажу
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeSystem {
+    pub market: Vec<MarketEntry>,
+    pub days_to_merchant: i32,
+    // надбавка к ценам от репутации у соседних поселений (см. ContractSystem::price_bonus)
+    pub reputation_bonus: f32,
+}
+
+impl Default for TradeSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TradeSystem {
+    pub fn new() -> Self {
+        let market = GOODS.iter().map(|&(kind, _, base)| MarketEntry {
+            kind,
+            price: base as f32,
+            sold_recently: 0.0,
+            history: vec![base],
+            sell_above: None,
+            buy_below: None,
+        }).collect();
+        Self { market, days_to_merchant: MERCHANT_INTERVAL_DAYS, reputation_bonus: 0.0 }
+    }
+
+    /// Рассвет: пересчитать цены; если есть торговый пост — ждать купца и торговать по приказам
+    pub fn new_day(&mut self, buildings: &[Building], resources: &mut Resources, warehouses: &mut [WarehouseStore], world: &World, rng: &mut impl Rng) -> Option<TradeReport> {
+        // старые сохранения без какого-то товара — добавляем его по базовой цене
+        for &(kind, _, base) in GOODS.iter() {
+            if !self.market.iter().any(|e| e.kind == kind) {
+                self.market.push(MarketEntry { kind, price: base as f32, sold_recently: 0.0, history: vec![base], sell_above: None, buy_below: None });
+            }
+        }
+        self.update_prices(resources, warehouses, rng);
+
+        let post = buildings.iter().find(|b| b.kind == BuildingKind::TradingPost)?.pos;
+        self.days_to_merchant -= 1;
+        if self.days_to_merchant > 0 { return None; }
+        self.days_to_merchant = MERCHANT_INTERVAL_DAYS;
+        Some(self.fill_orders(post, resources, warehouses, world))
+    }
+
+    // цена тянется к цели: много запаса и недавних продаж — дешевле, дефицит и закупки — дороже
+    fn update_prices(&mut self, resources: &Resources, warehouses: &[WarehouseStore], rng: &mut impl Rng) {
+        for e in &mut self.market {
+            let base = base_price(e.kind) as f32;
+            let stock = stock_of(resources, warehouses, e.kind).max(0) as f32;
+            let supply = 1.3 - 0.6 * stock / (stock + STOCK_REF);
+            let sales = 1.0 - 0.5 * e.sold_recently / (e.sold_recently.abs() + SALES_REF);
+            let target = (base * supply * sales).clamp(base * 0.3, base * 3.0);
+            let noise = rng.random_range(-0.05..=0.05);
+            e.price = (e.price + (target - e.price) * 0.3) * (1.0 + noise);
+            e.price = e.price.clamp(base * 0.3, base * 3.0);
+            e.sold_recently *= 0.7;
+            e.history.push(e.price.round() as i32);
+            if e.history.len() > PRICE_HISTORY_DAYS {
+                e.history.remove(0);
+            }
+        }
+    }
+
+    // купец у поста: продаём излишки выше порогов, докупаем до порогов, пока хватает золота
+    fn fill_orders(&mut self, post: glam::IVec2, resources: &mut Resources, warehouses: &mut [WarehouseStore], world: &World) -> TradeReport {
+        let mut report = TradeReport::default();
+        let bonus = self.reputation_bonus;
+        for e in &mut self.market {
+            let stock = stock_of(resources, warehouses, e.kind);
+            if let Some(limit) = e.sell_above
+                && stock > limit {
+                let sold = take(resources, warehouses, e.kind, (stock - limit).min(MERCHANT_CAPACITY));
+                let gold = sold * e.sell_price(bonus);
+                resources.gold += gold;
+                e.sold_recently += sold as f32;
+                report.sold += sold;
+                report.gold_in += gold;
+            }
+            if let Some(limit) = e.buy_below
+                && stock < limit {
+                let gold_have = stock_of(resources, warehouses, ResourceKind::Gold);
+                let amount = (limit - stock).min(MERCHANT_CAPACITY).min(gold_have / e.buy_price(bonus));
+                // без склада купцу некуда выгружать
+                if amount <= 0 || warehouses.is_empty() { continue; }
+                let paid = take(resources, warehouses, ResourceKind::Gold, amount * e.buy_price(bonus));
+                crate::shipping::unload(warehouses, world, post, e.kind, amount);
+                e.sold_recently -= amount as f32;
+                report.bought += amount;
+                report.gold_out += paid;
+            }
+        }
+        report
+    }
+}
+
+/// Подпись товара в UI
+pub fn label(kind: ResourceKind) -> &'static str {
+    GOODS.iter().find(|g| g.0 == kind).map_or("?", |g| g.1)
+}
+
+/// Название товара на языке интерфейса; label остаётся ключом иконок
+pub fn display_label(kind: ResourceKind) -> &'static str {
+    let id = label(kind);
+    crate::i18n::tr_opt(&format!("resource.{}", id.to_lowercase().replace(' ', "_"))).unwrap_or(id)
+}
+
+/// Базовая цена товара (без спроса и предложения)
+pub fn base_price(kind: ResourceKind) -> i32 {
+    GOODS.iter().find(|g| g.0 == kind).map_or(1, |g| g.2)
+}
+
+/// «+» у порога приказа: включить (сразу с запасом в сотню) или поднять на шаг
+pub fn raise_order(order: &mut Option<i32>) {
+    *order = Some(order.map_or(ORDER_STEP * 10, |v| v + ORDER_STEP));
+}
+
+/// «-» у порога приказа: опустить на шаг; ниже нуля — приказ снимается
+pub fn lower_order(order: &mut Option<i32>) {
+    *order = order.and_then(|v| (v > 0).then(|| (v - ORDER_STEP).max(0)));
+}
+
+// всего товара у поселения (глобальный запас и склады)
+pub fn stock_of(resources: &Resources, warehouses: &[WarehouseStore], kind: ResourceKind) -> i32 {
+    get_resource_value(resources, kind) + sum_warehouses_resource(warehouses, kind)
+}
+
+/// Списать товар: сначала со складов, затем из глобального запаса; возвращает списанное
+pub fn take(resources: &mut Resources, warehouses: &mut [WarehouseStore], kind: ResourceKind, amount: i32) -> i32 {
+    let mut visitor = SpendVisitor::new(amount);
+    for w in warehouses.iter_mut() {
+        if visitor.amount <= 0 { break; }
+        w.accept_mut(&mut visitor, kind);
+    }
+    if visitor.amount > 0 {
+        resources.accept_mut(&mut visitor, kind);
+    }
+    visitor.spent
+}
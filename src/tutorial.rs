@@ -1,174 +1,108 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
 use serde::{Serialize, Deserialize};
-use crate::types::{BuildingKind, Building};
+use crate::research::{ResearchStatus, ResearchSystem};
+use crate::types::{BuildingKind, Building, ResourceKind, Resources, WarehouseStore};
+use crate::world::World;
+
+/// Треки по умолчанию, вшитые в бинарник: если в assets/tutorials нет ни одного корректного файла
+const DEFAULT_TRACKS: [&str; 3] = [
+    include_str!("../assets/tutorials/01_basics.toml"),
+    include_str!("../assets/tutorials/02_research.toml"),
+    include_str!("../assets/tutorials/03_production.toml"),
+];
+
+/// Треки загружаются один раз за запуск
+static TRACKS: OnceLock<Vec<TutorialTrack>> = OnceLock::new();
 
-/// Шаг туториала
+// связность дорог считается не чаще раза в секунду
+const ROAD_CHECK_INTERVAL_MS: f32 = 1000.0;
+const ROAD_SEARCH_LIMIT: usize = 20_000;
+
+/// Условие завершения шага (или начала трека)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum TutorialStep {
-    /// Приветствие - первый шаг
-    Welcome,
-    /// Объяснение камеры и навигации
-    CameraMovement,
-    /// Выбор категории Housing
-    SelectHousingCategory,
-    /// Постройка первого дома
-    BuildHouse,
-    /// Объяснение жителей
-    ExplainCitizens,
-    /// Выбор категории Forestry
-    SelectForestryCategory,
-    /// Постройка лесопилки
-    BuildLumberjack,
-    /// Выбор категории Storage
-    SelectStorageCategory,
-    /// Постройка склада
-    BuildWarehouse,
-    /// Объяснение ресурсов
-    ExplainResources,
-    /// Объяснение управления скоростью
-    ExplainSpeed,
-    /// Объяснение дорог
-    ExplainRoads,
-    /// Объяснение депозитов
-    ExplainDeposits,
-    /// Завершение туториала
-    Complete,
+pub enum TutorialCondition {
+    /// Игрок нажал пробел
+    Continue,
+    /// Открыта категория в нижней панели
+    Category(crate::ui::UICategory),
+    /// Построено столько зданий вида
+    Build {
+        building: BuildingKind,
+        #[serde(default = "one")]
+        count: i32,
+    },
+    /// Здание вида связано дорогой со складом
+    RoadConnects(BuildingKind),
+    /// Выбрано любое исследование
+    ResearchStarted,
+    /// Исследование завершено
+    ResearchCompleted(String),
+    /// Жителей не меньше N
+    Population(i32),
+    /// Запас ресурса (с учётом складов) не меньше amount
+    Resource { resource: ResourceKind, amount: i32 },
+}
+
+fn one() -> i32 { 1 }
+
+/// Перевод текста шага
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StepText {
+    pub title: String,
+    pub message: String,
+}
+
+/// Шаг туториала из файла трека
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TutorialStep {
+    pub title: String,
+    pub message: String,
+    #[serde(default)]
+    pub highlight: Option<TutorialHighlight>,
+    pub complete: TutorialCondition,
+    // панель в углу, даже если шаг ждёт пробела (игроку нужно действовать на карте)
+    #[serde(default)]
+    pub corner: bool,
+    // язык → заголовок и текст; нет перевода — английский текст
+    #[serde(default)]
+    pub translations: HashMap<String, StepText>,
 }
 
 impl TutorialStep {
-    /// Получить следующий шаг
-    pub fn next(&self) -> Option<TutorialStep> {
-        match self {
-            TutorialStep::Welcome => Some(TutorialStep::CameraMovement),
-            TutorialStep::CameraMovement => Some(TutorialStep::SelectHousingCategory),
-            TutorialStep::SelectHousingCategory => Some(TutorialStep::BuildHouse),
-            TutorialStep::BuildHouse => Some(TutorialStep::ExplainCitizens),
-            TutorialStep::ExplainCitizens => Some(TutorialStep::SelectForestryCategory),
-            TutorialStep::SelectForestryCategory => Some(TutorialStep::BuildLumberjack),
-            TutorialStep::BuildLumberjack => Some(TutorialStep::SelectStorageCategory),
-            TutorialStep::SelectStorageCategory => Some(TutorialStep::BuildWarehouse),
-            TutorialStep::BuildWarehouse => Some(TutorialStep::ExplainResources),
-            TutorialStep::ExplainResources => Some(TutorialStep::ExplainRoads),
-            TutorialStep::ExplainRoads => Some(TutorialStep::ExplainDeposits),
-            TutorialStep::ExplainDeposits => Some(TutorialStep::ExplainSpeed),
-            TutorialStep::ExplainSpeed => Some(TutorialStep::Complete),
-            TutorialStep::Complete => None,
-        }
-    }
-    
-    /// Получить заголовок шага
-    pub fn title(&self) -> &'static str {
-        match self {
-            TutorialStep::Welcome => "Welcome to Cozy Kingdom!",
-            TutorialStep::CameraMovement => "Camera Controls",
-            TutorialStep::SelectHousingCategory => "Building Houses",
-            TutorialStep::BuildHouse => "Place Your First House",
-            TutorialStep::ExplainCitizens => "Your Citizens",
-            TutorialStep::SelectForestryCategory => "Gathering Resources",
-            TutorialStep::BuildLumberjack => "Build a Lumberjack",
-            TutorialStep::SelectStorageCategory => "Storage",
-            TutorialStep::BuildWarehouse => "Build a Warehouse",
-            TutorialStep::ExplainResources => "Resources Overview",
-            TutorialStep::ExplainRoads => "Building Roads",
-            TutorialStep::ExplainDeposits => "Resource Deposits",
-            TutorialStep::ExplainSpeed => "Game Speed",
-            TutorialStep::Complete => "Tutorial Complete!",
-        }
-    }
-    
-    /// Получить текст подсказки
-    pub fn message(&self) -> &'static str {
-        match self {
-            TutorialStep::Welcome => 
-                "Welcome, Your Majesty! This is your new kingdom.\nLet's learn the basics of building a prosperous settlement.",
-            TutorialStep::CameraMovement => 
-                "Use WASD to move the camera.\nScroll the mouse wheel to zoom in and out.\nPress SPACE to continue.",
-            TutorialStep::SelectHousingCategory => 
-                "Click on the 'Housing' category in the bottom panel\nto see available housing buildings.",
-            TutorialStep::BuildHouse => 
-                "Select 'House' and click on the map to place it.\nHouses provide shelter for your citizens.",
-            TutorialStep::ExplainCitizens => 
-                "Citizens will move into houses automatically.\nThey need food and work to be happy.\nPress SPACE to continue.",
-            TutorialStep::SelectForestryCategory => 
-                "Click on the 'Forestry' category\nto see wood production buildings.",
-            TutorialStep::BuildLumberjack => 
-                "Build a Lumberjack near trees.\nLumberjacks cut down trees to produce wood.",
-            TutorialStep::SelectStorageCategory => 
-                "Click on the 'Storage' category\nto see storage buildings.",
-            TutorialStep::BuildWarehouse => 
-                "Build a Warehouse to store your resources.\nWarehouses increase your storage capacity.",
-            TutorialStep::ExplainResources => 
-                "Resources are shown in the top panel:\nWood, Stone, Food, and Gold.\nPress SPACE to continue.",
-            TutorialStep::ExplainSpeed => 
-                "Press 1, 2, 3 to change game speed.\nPress SPACE to pause the game.\nPress SPACE to continue.",
-            TutorialStep::ExplainRoads => 
-                "Press R to enter road building mode.\nClick and drag to build roads.\nRoads help citizens move faster.\nPress SPACE to continue.",
-            TutorialStep::ExplainDeposits => 
-                "Click the 'Deposits' button to see\nresource deposits on the map.\nBuild mines near deposits for better production.\nPress SPACE to continue.",
-            TutorialStep::Complete => 
-                "Congratulations! You've completed the tutorial.\nNow build your kingdom and make it prosper!",
-        }
-    }
-    
-    /// Получить элемент UI для подсветки (если есть)
-    pub fn highlight_element(&self) -> Option<TutorialHighlight> {
-        match self {
-            TutorialStep::SelectHousingCategory => Some(TutorialHighlight::Category(crate::ui::UICategory::Housing)),
-            TutorialStep::BuildHouse => Some(TutorialHighlight::Building(BuildingKind::House)),
-            TutorialStep::SelectForestryCategory => Some(TutorialHighlight::Category(crate::ui::UICategory::Forestry)),
-            TutorialStep::BuildLumberjack => Some(TutorialHighlight::Building(BuildingKind::Lumberjack)),
-            TutorialStep::SelectStorageCategory => Some(TutorialHighlight::Category(crate::ui::UICategory::Storage)),
-            TutorialStep::BuildWarehouse => Some(TutorialHighlight::Building(BuildingKind::Warehouse)),
-            _ => None,
-        }
-    }
-    
-    /// Проверить условие завершения шага
-    pub fn is_complete(&self, context: &TutorialContext) -> bool {
-        match self {
-            TutorialStep::Welcome => context.space_pressed,
-            TutorialStep::CameraMovement => context.space_pressed,
-            TutorialStep::SelectHousingCategory => context.current_category == crate::ui::UICategory::Housing,
-            TutorialStep::BuildHouse => context.house_count > 0,
-            TutorialStep::ExplainCitizens => context.space_pressed,
-            TutorialStep::SelectForestryCategory => context.current_category == crate::ui::UICategory::Forestry,
-            TutorialStep::BuildLumberjack => context.lumberjack_count > 0,
-            TutorialStep::SelectStorageCategory => context.current_category == crate::ui::UICategory::Storage,
-            TutorialStep::BuildWarehouse => context.warehouse_count > 0,
-            TutorialStep::ExplainResources => context.space_pressed,
-            TutorialStep::ExplainRoads => context.space_pressed,
-            TutorialStep::ExplainDeposits => context.space_pressed,
-            TutorialStep::ExplainSpeed => context.space_pressed,
-            TutorialStep::Complete => context.space_pressed,
+    /// Заголовок и текст на языке интерфейса (или английские)
+    pub fn text(&self) -> (&str, &str) {
+        match self.translations.get(crate::i18n::language()) {
+            Some(t) => (t.title.as_str(), t.message.as_str()),
+            None => (self.title.as_str(), self.message.as_str()),
         }
     }
-    
+
     /// Требует ли шаг нажатия пробела для продолжения
     pub fn requires_space(&self) -> bool {
-        matches!(self, 
-            TutorialStep::Welcome | 
-            TutorialStep::CameraMovement | 
-            TutorialStep::ExplainCitizens |
-            TutorialStep::ExplainResources |
-            TutorialStep::ExplainRoads |
-            TutorialStep::ExplainDeposits |
-            TutorialStep::ExplainSpeed |
-            TutorialStep::Complete
-        )
+        self.complete == TutorialCondition::Continue
     }
-    
+
     /// Требует ли шаг интеракции (клика) - в этом случае панель должна быть в углу
     pub fn requires_interaction(&self) -> bool {
-        // Шаги с подсветкой элементов UI
-        if self.highlight_element().is_some() {
-            return true;
-        }
-        // Шаг про дороги тоже требует интеракции (строительство дорог)
-        matches!(self, TutorialStep::ExplainRoads)
+        !self.requires_space() || self.corner
     }
 }
 
+/// Трек туториала (assets/tutorials/*.toml): идут по порядку файлов
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TutorialTrack {
+    pub key: String,
+    pub name: String,
+    // трек начинается, когда выполнено условие (None — сразу после предыдущего)
+    #[serde(default)]
+    pub start: Option<TutorialCondition>,
+    pub step: Vec<TutorialStep>,
+}
+
 /// Элемент для подсветки в туториале
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TutorialHighlight {
     /// Подсветить категорию
     Category(crate::ui::UICategory),
@@ -179,40 +113,120 @@ pub enum TutorialHighlight {
 }
 
 /// Контекст для проверки условий туториала
-#[derive(Default)]
-pub struct TutorialContext {
-    pub space_pressed: bool,
+pub struct TutorialContext<'a> {
     pub current_category: crate::ui::UICategory,
-    pub house_count: i32,
-    pub lumberjack_count: i32,
-    pub warehouse_count: i32,
+    pub buildings: &'a [Building],
+    pub warehouses: &'a [WarehouseStore],
+    // запасы с учётом складов
+    pub resources: &'a Resources,
+    pub research: &'a ResearchSystem,
+    pub world: &'a World,
+    pub population: i32,
 }
 
-impl TutorialContext {
-    /// Создать контекст из текущего состояния игры
-    pub fn from_game_state(
-        category: crate::ui::UICategory,
-        buildings: &[Building],
-    ) -> Self {
-        let house_count = buildings.iter().filter(|b| b.kind == BuildingKind::House).count() as i32;
-        let lumberjack_count = buildings.iter().filter(|b| b.kind == BuildingKind::Lumberjack).count() as i32;
-        let warehouse_count = buildings.iter().filter(|b| b.kind == BuildingKind::Warehouse).count() as i32;
-        
-        Self {
-            space_pressed: false,
-            current_category: category,
-            house_count,
-            lumberjack_count,
-            warehouse_count,
+impl TutorialCondition {
+    /// Выполнено ли условие; дороги проверяются только при check_roads
+    pub fn is_met(&self, ctx: &TutorialContext, space_pressed: bool, check_roads: bool) -> bool {
+        match self {
+            TutorialCondition::Continue => space_pressed,
+            TutorialCondition::Category(category) => ctx.current_category == *category,
+            TutorialCondition::Build { building, count } => {
+                ctx.buildings.iter().filter(|b| b.kind == *building).count() as i32 >= *count
+            }
+            TutorialCondition::RoadConnects(kind) => {
+                let targets: Vec<glam::IVec2> = ctx.warehouses.iter().map(|w| w.pos).collect();
+                check_roads && !targets.is_empty() && ctx.buildings.iter()
+                    .filter(|b| b.kind == *kind)
+                    .any(|b| crate::path::road_connected(ctx.world, b.pos, &targets, ROAD_SEARCH_LIMIT))
+            }
+            TutorialCondition::ResearchStarted => {
+                // выбранное или уже завершённое исследование (не из стартовых)
+                ctx.research.active_research.is_some() || ctx.research.researches.iter().any(|r| {
+                    r.status == ResearchStatus::Completed
+                        && crate::research::def(&r.kind).is_some_and(|d| !d.prerequisites.is_empty())
+                })
+            }
+            TutorialCondition::ResearchCompleted(key) => ctx.research.get_status(key) == ResearchStatus::Completed,
+            TutorialCondition::Population(n) => ctx.population >= *n,
+            TutorialCondition::Resource { resource, amount } => {
+                crate::resource_visitor::get_resource_value(ctx.resources, *resource) >= *amount
+            }
         }
     }
 }
 
+/// Все треки в порядке файлов
+pub fn tracks() -> &'static [TutorialTrack] {
+    TRACKS.get_or_init(load_tracks)
+}
+
+/// Трек по ключу
+pub fn track(key: &str) -> Option<&'static TutorialTrack> {
+    tracks().iter().find(|t| t.key == key)
+}
+
+/// Треки из assets/tutorials; файлы с ошибками пропускаются, если не осталось ни одного — встроенные
+fn load_tracks() -> Vec<TutorialTrack> {
+    let dir = crate::resource_path::assets_path().join("tutorials");
+    let mut loaded: Vec<TutorialTrack> = Vec::new();
+    for (path, text) in crate::scenario::read_dir_toml(&dir) {
+        let track: TutorialTrack = match toml::from_str(&text) {
+            Ok(t) => t,
+            Err(e) => { log::warn!("Туториал {:?} не загружен: {}", path, e); continue; }
+        };
+        let mut all = loaded.clone();
+        all.push(track);
+        match validate_tracks(&all) {
+            Ok(()) => loaded = all,
+            Err(errors) => log::warn!("Туториал {:?} не загружен: {}", path, errors.join("; ")),
+        }
+    }
+    if !loaded.is_empty() {
+        return loaded;
+    }
+    DEFAULT_TRACKS.iter()
+        .map(|text| toml::from_str(text).expect("встроенный туториал должен быть корректным"))
+        .collect()
+}
+
+/// Проверка треков: ключи, шаги, исследования и числа в условиях
+pub fn validate_tracks(tracks: &[TutorialTrack]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut keys = HashSet::new();
+    for t in tracks {
+        if t.key.is_empty() { errors.push(format!("tutorial '{}' has an empty key", t.name)); }
+        if !keys.insert(t.key.as_str()) { errors.push(format!("duplicate tutorial key '{}'", t.key)); }
+        if t.step.is_empty() { errors.push(format!("tutorial '{}' has no steps", t.key)); }
+        let conditions = t.start.iter().chain(t.step.iter().map(|s| &s.complete));
+        for c in conditions {
+            match c {
+                TutorialCondition::ResearchCompleted(key) if crate::research::def(key).is_none() => {
+                    errors.push(format!("tutorial '{}' waits for unknown research '{}'", t.key, key));
+                }
+                TutorialCondition::Build { count: n, .. }
+                | TutorialCondition::Population(n)
+                | TutorialCondition::Resource { amount: n, .. } if *n <= 0 => {
+                    errors.push(format!("tutorial '{}' has a non-positive target", t.key));
+                }
+                _ => {}
+            }
+        }
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
 /// Система туториала
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TutorialSystem {
-    /// Текущий шаг туториала
-    pub current_step: Option<TutorialStep>,
+    /// Текущий трек (None — ждём начала следующего)
+    #[serde(default)]
+    pub track: Option<String>,
+    /// Номер шага в треке
+    #[serde(default)]
+    pub step: usize,
+    /// Пройденные треки
+    #[serde(default)]
+    pub finished_tracks: Vec<String>,
     /// Туториал активен
     pub active: bool,
     /// Туториал завершён
@@ -226,45 +240,64 @@ pub struct TutorialSystem {
     pub panel_position: f32,
     /// Целевая позиция панели
     pub target_panel_position: f32,
+    #[serde(skip)]
+    road_check_ms: f32,
 }
 
 impl TutorialSystem {
     /// Создать новую систему туториала
     pub fn new() -> Self {
         Self {
-            current_step: Some(TutorialStep::Welcome),
+            track: tracks().first().map(|t| t.key.clone()),
+            step: 0,
+            finished_tracks: Vec::new(),
             active: true,
             completed: false,
             message_time_ms: 0.0,
             space_pressed_this_frame: false,
             panel_position: 0.0,
             target_panel_position: 0.0,
+            road_check_ms: 0.0,
         }
     }
-    
+
     /// Создать систему туториала в выключенном состоянии (для загруженных игр)
     pub fn new_inactive() -> Self {
         Self {
-            current_step: None,
+            track: None,
             active: false,
             completed: true,
-            message_time_ms: 0.0,
-            space_pressed_this_frame: false,
-            panel_position: 0.0,
-            target_panel_position: 0.0,
+            ..Self::new()
         }
     }
-    
+
+    /// Текущий шаг
+    pub fn current_step(&self) -> Option<&'static TutorialStep> {
+        self.track.as_deref().and_then(track).and_then(|t| t.step.get(self.step))
+    }
+
     /// Обновить туториал
     pub fn update(&mut self, delta_ms: f32, context: &TutorialContext) {
         if !self.active || self.completed {
             return;
         }
-        
+
+        self.road_check_ms -= delta_ms;
+        let check_roads = self.road_check_ms <= 0.0;
+        if check_roads {
+            self.road_check_ms = ROAD_CHECK_INTERVAL_MS;
+        }
+        let space_pressed = std::mem::take(&mut self.space_pressed_this_frame);
+
+        if self.track.is_none() {
+            self.start_next_track(context, check_roads);
+            return;
+        }
+
         self.message_time_ms += delta_ms;
-        
+
         // Определяем целевую позицию панели
-        if let Some(ref step) = self.current_step {
+        if let Some(step) = self.current_step() {
             if step.requires_interaction() {
                 // Если шаг требует интеракции - панель в правом верхнем углу
                 self.target_panel_position = 1.0;
@@ -273,7 +306,7 @@ impl TutorialSystem {
                 self.target_panel_position = 0.0;
             }
         }
-        
+
         // Плавная анимация перемещения панели
         let move_speed = 2.0; // Скорость перемещения (1.0 = мгновенно)
         let move_delta = (delta_ms / 1000.0) * move_speed;
@@ -282,72 +315,84 @@ impl TutorialSystem {
         } else if self.panel_position > self.target_panel_position {
             self.panel_position = (self.panel_position - move_delta).max(self.target_panel_position);
         }
-        
-        // Создаём контекст с учётом нажатия пробела
-        let ctx = TutorialContext {
-            space_pressed: self.space_pressed_this_frame,
-            current_category: context.current_category,
-            house_count: context.house_count,
-            lumberjack_count: context.lumberjack_count,
-            warehouse_count: context.warehouse_count,
-        };
-        
+
         // Проверяем условие завершения текущего шага
-        if let Some(ref step) = self.current_step {
-            if step.is_complete(&ctx) {
-                self.advance();
-            }
+        match self.current_step() {
+            Some(step) if step.complete.is_met(context, space_pressed, check_roads) => self.advance(),
+            Some(_) => {}
+            // трек исчез из файлов — считаем пройденным
+            None => self.finish_track(),
         }
-        
-        // Сбрасываем флаг нажатия пробела
-        self.space_pressed_this_frame = false;
     }
-    
+
     /// Перейти к следующему шагу
     pub fn advance(&mut self) {
-        if let Some(ref step) = self.current_step {
-            if let Some(next) = step.next() {
-                self.current_step = Some(next);
-                self.message_time_ms = 0.0;
-            } else {
-                // Туториал завершён
-                self.current_step = None;
-                self.active = false;
-                self.completed = true;
-            }
+        let Some(track) = self.track.as_deref().and_then(track) else { return; };
+        if self.step + 1 < track.step.len() {
+            self.step += 1;
+            self.message_time_ms = 0.0;
+        } else {
+            self.finish_track();
+        }
+    }
+
+    // трек пройден: ждём следующий; если треков не осталось — туториал завершён
+    fn finish_track(&mut self) {
+        if let Some(key) = self.track.take() {
+            self.finished_tracks.push(key);
+        }
+        self.step = 0;
+        if !tracks().iter().any(|t| !self.finished_tracks.contains(&t.key)) {
+            self.active = false;
+            self.completed = true;
         }
     }
-    
+
+    // следующий непройденный трек начинается, когда выполнено его стартовое условие
+    fn start_next_track(&mut self, context: &TutorialContext, check_roads: bool) {
+        let Some(next) = tracks().iter().find(|t| !self.finished_tracks.contains(&t.key)) else {
+            self.active = false;
+            self.completed = true;
+            return;
+        };
+        if next.start.as_ref().is_none_or(|c| c.is_met(context, false, check_roads)) {
+            self.track = Some(next.key.clone());
+            self.step = 0;
+            self.message_time_ms = 0.0;
+            self.panel_position = 0.0;
+        }
+    }
+
     /// Пропустить туториал
     pub fn skip(&mut self) {
-        self.current_step = None;
+        self.track = None;
         self.active = false;
         self.completed = true;
     }
-    
+
     /// Обработать нажатие пробела
     pub fn handle_space(&mut self) {
         self.space_pressed_this_frame = true;
     }
-    
+
     /// Получить текущий элемент для подсветки
     pub fn current_highlight(&self) -> Option<TutorialHighlight> {
-        self.current_step.as_ref().and_then(|s| s.highlight_element())
+        self.current_step().and_then(|s| s.highlight.clone())
     }
-    
+
     /// Получить текущий заголовок
-    pub fn current_title(&self) -> Option<&'static str> {
-        self.current_step.as_ref().map(|s| s.title())
+    pub fn current_title(&self) -> Option<&str> {
+        self.current_step().map(|s| s.text().0)
     }
-    
+
     /// Получить текущее сообщение
-    pub fn current_message(&self) -> Option<&'static str> {
-        self.current_step.as_ref().map(|s| s.message())
+    pub fn current_message(&self) -> Option<&str> {
+        self.current_step().map(|s| s.text().1)
     }
-    
+
     /// Требует ли текущий шаг нажатия пробела
     pub fn requires_space(&self) -> bool {
-        self.current_step.as_ref().map(|s| s.requires_space()).unwrap_or(false)
+        self.current_step().is_some_and(|s| s.requires_space())
     }
 }
 
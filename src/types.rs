@@ -6,6 +6,17 @@ pub enum TileKind {
     Grass,
     Forest,
     Water,
+    ShallowWater, // мелководье — проходимо вброд, но медленно
+    Sand,         // пляж у воды
+    Hills,        // холмы — нужны для шахт
+    Mountain,     // горы — непроходимы, строить нельзя
+}
+
+impl TileKind {
+    /// Вода любой глубины
+    pub fn is_water(self) -> bool { matches!(self, TileKind::Water | TileKind::ShallowWater) }
+    /// Суша, на которой можно ставить здания
+    pub fn is_buildable(self) -> bool { matches!(self, TileKind::Grass | TileKind::Forest | TileKind::Sand | TileKind::Hills) }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,6 +51,36 @@ pub enum BuildingKind {
     IronMine,
     Smelter,
     ResearchLab,  // Лаборатория исследований
+    // Редкие ресурсы
+    CoalMine,
+    GoldMine,
+    GemMine,
+    SaltWorks,    // солеварня на побережье
+    Mint,         // золотая руда → монеты
+    Jeweler,      // самоцветы + золотая руда → украшения
+    ScoutTower,   // дозорная башня: широкий обзор, отправка разведчиков
+    Dock,         // причал: паромная линия к причалу на другом берегу
+    CharcoalBurner, // угольщик: дрова → древесный уголь
+    Blacksmith,   // кузница: слитки → инструменты
+    SheepFarm,    // овчарня: шерсть
+    Weaver,       // ткацкая: шерсть → ткань
+    Tailor,       // портной: ткань → одежда
+    HopFarm,      // хмельник: хмель (не растёт зимой)
+    Brewery,      // пивоварня: хмель → пиво
+    Tavern,       // таверна: дома рядом пьют пиво
+    TradingPost,  // торговый пост: сюда заходит купец
+}
+
+impl BuildingKind {
+    /// Радиус обзора здания (в тайлах) для тумана войны
+    pub fn vision_radius(self) -> i32 {
+        match self {
+            BuildingKind::ScoutTower => 16,
+            BuildingKind::House => 9,
+            BuildingKind::Warehouse | BuildingKind::ResearchLab => 7,
+            _ => 5,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -50,6 +91,8 @@ pub struct Building {
     pub workers_target: i32,
     // Для домов: вместимость жильцов (у остальных 0)
     pub capacity: i32,
+    // Для домов: уровень удобств за неделю (одежда, пиво), 0..2
+    pub comfort: u8,
     // Подсветка при наведении/выборе
     pub is_highlighted: bool,
 }
@@ -68,6 +111,18 @@ pub struct Resources {
     pub fish: i32,
     pub iron_ore: i32,
     pub iron_ingots: i32,
+    pub gold_ore: i32,
+    pub coal: i32,
+    pub salt: i32,
+    pub gems: i32,
+    pub jewelry: i32,
+    pub charcoal: i32,
+    pub tools: i32,
+    pub wool: i32,
+    pub cloth: i32,
+    pub clothes: i32,
+    pub hops: i32,
+    pub ale: i32,
 }
 
 // Единый источник стоимости зданий для логики и UI
@@ -87,6 +142,23 @@ pub fn building_cost(kind: BuildingKind) -> Resources {
         BuildingKind::IronMine => Resources { wood: 15, gold: 20, ..Default::default() },
         BuildingKind::Smelter => Resources { wood: 20, gold: 25, ..Default::default() },
         BuildingKind::ResearchLab => Resources { wood: 50, gold: 100, stone: 30, ..Default::default() },
+        BuildingKind::CoalMine => Resources { wood: 20, gold: 25, stone: 10, ..Default::default() },
+        BuildingKind::GoldMine => Resources { wood: 30, gold: 60, stone: 20, ..Default::default() },
+        BuildingKind::GemMine => Resources { wood: 30, gold: 80, stone: 30, ..Default::default() },
+        BuildingKind::SaltWorks => Resources { wood: 20, gold: 30, ..Default::default() },
+        BuildingKind::Mint => Resources { wood: 20, gold: 50, bricks: 20, iron_ingots: 5, ..Default::default() },
+        BuildingKind::Jeweler => Resources { wood: 20, gold: 80, bricks: 20, ..Default::default() },
+        BuildingKind::ScoutTower => Resources { wood: 30, gold: 40, stone: 10, ..Default::default() },
+        BuildingKind::Dock => Resources { wood: 40, gold: 50, stone: 10, ..Default::default() },
+        BuildingKind::CharcoalBurner => Resources { wood: 25, gold: 20, stone: 5, ..Default::default() },
+        BuildingKind::Blacksmith => Resources { wood: 30, gold: 40, bricks: 10, ..Default::default() },
+        BuildingKind::SheepFarm => Resources { wood: 25, gold: 15, ..Default::default() },
+        BuildingKind::Weaver => Resources { wood: 25, gold: 25, stone: 5, ..Default::default() },
+        BuildingKind::Tailor => Resources { wood: 20, gold: 30, bricks: 5, ..Default::default() },
+        BuildingKind::HopFarm => Resources { wood: 20, gold: 10, ..Default::default() },
+        BuildingKind::Brewery => Resources { wood: 30, gold: 25, bricks: 10, ..Default::default() },
+        BuildingKind::Tavern => Resources { wood: 40, gold: 40, bricks: 10, ..Default::default() },
+        BuildingKind::TradingPost => Resources { wood: 50, gold: 60, stone: 20, ..Default::default() },
     }
 }
 
@@ -120,8 +192,19 @@ pub struct Citizen {
     pub happiness: u8,
     // Маска потреблённой еды в недавние дни (бит0=bread, бит1=fish), для бонуса разнообразия
     pub last_food_mask: u8,
+    // замёрз сегодня (зимой без дров в доме)
+    #[serde(default)]
+    pub cold_today: bool,
+    // Здоровье 0..100: падает от холода, ниже game::SICK_HEALTH житель болеет
+    #[serde(default = "full_health")]
+    pub health: u8,
+    // износ инструмента рабочего: каждый цикл добавляет долю, целая единица — минус инструмент со склада
+    #[serde(default)]
+    pub tool_wear: f32,
 }
 
+fn full_health() -> u8 { 100 }
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum JobKind { ChopWood { pos: IVec2 }, HaulWood { from: IVec2, to: IVec2 } }
 
@@ -148,11 +231,35 @@ pub struct WarehouseStore {
     pub gold: i32,
     pub iron_ore: i32,
     pub iron_ingots: i32,
+    #[serde(default)]
+    pub gold_ore: i32,
+    #[serde(default)]
+    pub coal: i32,
+    #[serde(default)]
+    pub salt: i32,
+    #[serde(default)]
+    pub gems: i32,
+    #[serde(default)]
+    pub jewelry: i32,
+    #[serde(default)]
+    pub charcoal: i32,
+    #[serde(default)]
+    pub tools: i32,
+    #[serde(default)]
+    pub wool: i32,
+    #[serde(default)]
+    pub cloth: i32,
+    #[serde(default)]
+    pub clothes: i32,
+    #[serde(default)]
+    pub hops: i32,
+    #[serde(default)]
+    pub ale: i32,
 }
 
 impl Default for WarehouseStore {
     fn default() -> Self {
-        Self { pos: IVec2::new(0,0), wood: 0, stone: 0, clay: 0, bricks: 0, wheat: 0, flour: 0, bread: 0, fish: 0, gold: 0, iron_ore: 0, iron_ingots: 0 }
+        Self { pos: IVec2::new(0,0), wood: 0, stone: 0, clay: 0, bricks: 0, wheat: 0, flour: 0, bread: 0, fish: 0, gold: 0, iron_ore: 0, iron_ingots: 0, gold_ore: 0, coal: 0, salt: 0, gems: 0, jewelry: 0, charcoal: 0, tools: 0, wool: 0, cloth: 0, clothes: 0, hops: 0, ale: 0 }
     }
 }
 
@@ -177,6 +284,18 @@ pub enum ResourceKind {
     Gold,
     IronOre,
     IronIngot,
+    GoldOre,
+    Coal,
+    Salt,
+    Gems,
+    Jewelry,
+    Charcoal,
+    Tools,
+    Wool,
+    Cloth,
+    Clothes,
+    Hops,
+    Ale,
 }
 
 // удалено: DepositKind (не используется)
@@ -228,14 +347,30 @@ pub fn total_resources(warehouses: &[WarehouseStore], resources: &Resources) ->
         fish: resources.fish + crate::resource_visitor::sum_warehouses_resource(warehouses, Fish),
         iron_ore: resources.iron_ore + crate::resource_visitor::sum_warehouses_resource(warehouses, IronOre),
         iron_ingots: resources.iron_ingots + crate::resource_visitor::sum_warehouses_resource(warehouses, IronIngot),
+        gold_ore: resources.gold_ore + crate::resource_visitor::sum_warehouses_resource(warehouses, GoldOre),
+        coal: resources.coal + crate::resource_visitor::sum_warehouses_resource(warehouses, Coal),
+        salt: resources.salt + crate::resource_visitor::sum_warehouses_resource(warehouses, Salt),
+        gems: resources.gems + crate::resource_visitor::sum_warehouses_resource(warehouses, Gems),
+        jewelry: resources.jewelry + crate::resource_visitor::sum_warehouses_resource(warehouses, Jewelry),
+        charcoal: resources.charcoal + crate::resource_visitor::sum_warehouses_resource(warehouses, Charcoal),
+        tools: resources.tools + crate::resource_visitor::sum_warehouses_resource(warehouses, Tools),
+        wool: resources.wool + crate::resource_visitor::sum_warehouses_resource(warehouses, Wool),
+        cloth: resources.cloth + crate::resource_visitor::sum_warehouses_resource(warehouses, Cloth),
+        clothes: resources.clothes + crate::resource_visitor::sum_warehouses_resource(warehouses, Clothes),
+        hops: resources.hops + crate::resource_visitor::sum_warehouses_resource(warehouses, Hops),
+        ale: resources.ale + crate::resource_visitor::sum_warehouses_resource(warehouses, Ale),
     }
 }
 
-// Найти ближайший склад к указанной позиции
-pub fn find_nearest_warehouse(warehouses: &[WarehouseStore], pos: IVec2) -> Option<IVec2> {
+// Найти ближайший склад к указанной позиции: сперва на том же острове, затем любой
+pub fn find_nearest_warehouse(warehouses: &[WarehouseStore], world: &crate::world::World, pos: IVec2) -> Option<IVec2> {
+    let dist = |w: &&WarehouseStore| (w.pos.x - pos.x).abs() + (w.pos.y - pos.y).abs();
+    let island = world.island_at(pos);
     warehouses
         .iter()
-        .min_by_key(|w| (w.pos.x - pos.x).abs() + (w.pos.y - pos.y).abs())
+        .filter(|w| island.is_some() && world.island_at(w.pos) == island)
+        .min_by_key(dist)
+        .or_else(|| warehouses.iter().min_by_key(dist))
         .map(|w| w.pos)
 }
 
@@ -1,6 +1,6 @@
-// Удалены неиспользуемые импорты (BuildingKind, FoodPolicy больше не нужны в layout функциях)
+use crate::types::BuildingKind;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum UICategory { 
     Housing, 
     Storage, 
@@ -8,12 +8,110 @@ pub enum UICategory {
     Forestry, 
     Mining, 
     Food, 
+    Rare,       // редкие ресурсы и ценные товары
     Logistics, 
     Research 
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UITab { Build, Economy }
 
+/// Категории нижней панели в порядке кнопок
+pub const CATEGORIES: [UICategory; 8] = [
+    UICategory::Housing, UICategory::Storage, UICategory::Forestry, UICategory::Mining,
+    UICategory::Food, UICategory::Rare, UICategory::Logistics, UICategory::Research,
+];
+
+// ключ категории в таблицах строк (category.*, category_desc.*)
+fn category_key(cat: UICategory) -> &'static str {
+    match cat {
+        UICategory::Housing => "housing",
+        UICategory::Storage => "storage",
+        UICategory::Forestry => "forestry",
+        UICategory::Mining => "mining",
+        UICategory::Food => "food",
+        UICategory::Rare => "rare",
+        UICategory::Logistics => "logistics",
+        UICategory::Research => "research",
+    }
+}
+
+/// Подпись кнопки категории на языке интерфейса
+pub fn category_label(cat: UICategory) -> &'static str {
+    crate::i18n::tr_opt(&format!("category.{}", category_key(cat))).unwrap_or("?")
+}
+
+/// Описание категории для тултипа
+pub fn category_description(cat: UICategory) -> &'static str {
+    crate::i18n::tr_opt(&format!("category_desc.{}", category_key(cat))).unwrap_or("")
+}
+
+/// Здания категории в порядке кнопок
+pub fn category_buildings(cat: UICategory) -> &'static [BuildingKind] {
+    match cat {
+        UICategory::Housing => &[BuildingKind::House, BuildingKind::SheepFarm, BuildingKind::Weaver, BuildingKind::Tailor, BuildingKind::Tavern],
+        UICategory::Storage => &[BuildingKind::Warehouse],
+        UICategory::Forestry => &[BuildingKind::Lumberjack, BuildingKind::Forester, BuildingKind::CharcoalBurner],
+        UICategory::Mining => &[BuildingKind::StoneQuarry, BuildingKind::ClayPit, BuildingKind::IronMine, BuildingKind::Kiln, BuildingKind::Smelter, BuildingKind::Blacksmith],
+        UICategory::Food => &[BuildingKind::WheatField, BuildingKind::Mill, BuildingKind::Bakery, BuildingKind::Fishery, BuildingKind::HopFarm, BuildingKind::Brewery],
+        UICategory::Rare => &[BuildingKind::CoalMine, BuildingKind::GoldMine, BuildingKind::GemMine, BuildingKind::SaltWorks, BuildingKind::Mint, BuildingKind::Jeweler],
+        UICategory::Logistics => &[BuildingKind::ScoutTower, BuildingKind::Dock, BuildingKind::TradingPost],
+        UICategory::Research => &[BuildingKind::ResearchLab],
+    }
+}
+
+// ключ здания в таблицах строк (building.*, building_desc.*)
+fn building_key(kind: BuildingKind) -> &'static str {
+    match kind {
+        BuildingKind::House => "house",
+        BuildingKind::Warehouse => "warehouse",
+        BuildingKind::Lumberjack => "lumberjack",
+        BuildingKind::Forester => "forester",
+        BuildingKind::StoneQuarry => "stone_quarry",
+        BuildingKind::ClayPit => "clay_pit",
+        BuildingKind::Kiln => "kiln",
+        BuildingKind::WheatField => "wheat_field",
+        BuildingKind::Mill => "mill",
+        BuildingKind::Bakery => "bakery",
+        BuildingKind::Fishery => "fishery",
+        BuildingKind::IronMine => "iron_mine",
+        BuildingKind::Smelter => "smelter",
+        BuildingKind::ResearchLab => "research_lab",
+        BuildingKind::CoalMine => "coal_mine",
+        BuildingKind::GoldMine => "gold_mine",
+        BuildingKind::GemMine => "gem_mine",
+        BuildingKind::SaltWorks => "salt_works",
+        BuildingKind::Mint => "mint",
+        BuildingKind::Jeweler => "jeweler",
+        BuildingKind::ScoutTower => "scout_tower",
+        BuildingKind::Dock => "dock",
+        BuildingKind::CharcoalBurner => "charcoal_burner",
+        BuildingKind::Blacksmith => "blacksmith",
+        BuildingKind::SheepFarm => "sheep_farm",
+        BuildingKind::Weaver => "weaver",
+        BuildingKind::Tailor => "tailor",
+        BuildingKind::HopFarm => "hop_farm",
+        BuildingKind::Brewery => "brewery",
+        BuildingKind::Tavern => "tavern",
+        BuildingKind::TradingPost => "trading_post",
+    }
+}
+
+/// Название здания на языке интерфейса
+pub fn building_label(kind: BuildingKind) -> &'static str {
+    crate::i18n::tr_opt(&format!("building.{}", building_key(kind))).unwrap_or("?")
+}
+
+/// Описание здания для тултипа
+pub fn building_description(kind: BuildingKind) -> &'static str {
+    crate::i18n::tr_opt(&format!("building_desc.{}", building_key(kind))).unwrap_or("")
+}
+
+/// Ширина кнопки категории (одна формула для отрисовки, кликов и подсветки туториала)
+pub fn category_button_w(cat: UICategory, s: i32) -> i32 { (text_w(category_label(cat), s) + 12).max(60) }
+
+/// Ширина кнопки здания
+pub fn building_button_w(kind: BuildingKind, s: i32) -> i32 { (text_w(building_label(kind), s) + 12).max(70) }
+
 pub fn ui_gap(s: i32) -> i32 { 6 * s }
 pub fn ui_pad(s: i32) -> i32 { 8 * s }
 pub fn ui_item_h(s: i32) -> i32 { 18 * s }
@@ -47,7 +145,7 @@ pub fn layout_building_panel(fw: i32, fh: i32, s: i32) -> BuildingPanelLayout {
     // Поднимем панель выше, чтобы не конфликтовала с миникартой и нижней панелью
     let y = fh - bottom_h - panel_h - 24 * s;
     // Кнопки +/- (высота как у общих кнопок)
-    let minus_w = button_w_for(b"-", s); let minus_h = row_h; let plus_w = button_w_for(b"+", s); let plus_h = row_h;
+    let minus_w = button_w_for("-", s); let minus_h = row_h; let plus_w = button_w_for("+", s); let plus_h = row_h;
     let minus_x = x + w - (plus_w + minus_w + 16 * s);
     // выравниваем по строке Workers — (row2)
     let workers_row_y = y + pad_top + row_h + vgap;
@@ -55,22 +153,144 @@ pub fn layout_building_panel(fw: i32, fh: i32, s: i32) -> BuildingPanelLayout {
     let plus_x = x + w - (plus_w + 10 * s);
     let plus_y = workers_row_y;
     // кнопка сноса — в той же строке, что и блок производства (row3)
-    let dem_w = button_w_for(b"DEMOLISH", s); let dem_h = row_h;
+    let dem_w = button_w_for("DEMOLISH", s); let dem_h = row_h;
     let dem_x = x + w - dem_w - 10 * s;
     let dem_y = y + pad_top + (row_h + vgap) * 2; // row3 y
     BuildingPanelLayout { minus_x, minus_y, minus_w, minus_h, plus_x, plus_y, plus_w, plus_h, dem_x, dem_y, dem_w, dem_h }
 }
 
+/// Раскладка окна торговли: строки товаров с ценой, графиком и приказами (-/+ у порогов)
+#[derive(Clone, Copy, Debug)]
+pub struct TradePanelLayout { pub x: i32, pub y: i32, pub w: i32, pub h: i32, pub close_x: i32, pub close_y: i32, pub close_size: i32, pub rows_y: i32, pub row_h: i32, pub max_rows: i32, pub name_x: i32, pub stock_x: i32, pub price_x: i32, pub chart_x: i32, pub chart_w: i32, pub sell_x: i32, pub buy_x: i32, pub btn_w: i32, pub value_w: i32 }
+
+pub fn layout_trade_panel(fw: i32, fh: i32, s: i32) -> TradePanelLayout {
+    let w = ((fw as f32 * 0.8) as i32).clamp(700.min(fw), 1100 * s);
+    let h = (fh as f32 * 0.85) as i32;
+    let x = (fw - w) / 2; let y = (fh - h) / 2;
+    let pad = 16 * s;
+    let close_size = 20 * s;
+    let close_x = x + w - pad - close_size; let close_y = y + pad;
+    // заголовок, строка купца и шапка таблицы
+    let rows_y = y + pad + 28 * s + ui_item_h(s) * 2 + ui_gap(s) * 2;
+    let row_h = ui_item_h(s) + 4 * s;
+    let max_rows = ((y + h - pad - rows_y) / row_h).max(0);
+    // колонки: товар | запас | цена (продажа/покупка) | график | порог продажи | порог покупки
+    let btn_w = button_w_for("-", s); let value_w = text_w(crate::i18n::tr("trade.off"), s).max(text_w("9999", s)) + 8 * s;
+    let order_w = btn_w * 2 + value_w + 4 * s;
+    let name_x = x + pad;
+    let stock_x = name_x + text_w(crate::trade::display_label(crate::types::ResourceKind::IronIngot), s) + 12 * s;
+    let price_x = stock_x + text_w("99999", s) + 12 * s;
+    let chart_x = price_x + text_w("999/999", s) + 12 * s;
+    let buy_x = x + w - pad - order_w;
+    let sell_x = buy_x - order_w - 12 * s;
+    let chart_w = (sell_x - 12 * s - chart_x).max(20 * s);
+    TradePanelLayout { x, y, w, h, close_x, close_y, close_size, rows_y, row_h, max_rows, name_x, stock_x, price_x, chart_x, chart_w, sell_x, buy_x, btn_w, value_w }
+}
+
+pub struct QuestJournalLayout { pub x: i32, pub y: i32, pub w: i32, pub h: i32, pub close_x: i32, pub close_y: i32, pub close_size: i32, pub rows_y: i32, pub row_h: i32, pub max_rows: i32, pub log_y: i32, pub log_rows: i32, pub title_x: i32, pub progress_x: i32, pub reward_x: i32, pub claim_x: i32, pub claim_w: i32 }
+
+pub fn layout_quest_journal(fw: i32, fh: i32, s: i32) -> QuestJournalLayout {
+    let w = ((fw as f32 * 0.75) as i32).clamp(700.min(fw), 1040 * s);
+    let h = (fh as f32 * 0.85) as i32;
+    let x = (fw - w) / 2; let y = (fh - h) / 2;
+    let pad = 16 * s;
+    let close_size = 20 * s;
+    let close_x = x + w - pad - close_size; let close_y = y + pad;
+    let row_h = ui_item_h(s) + 4 * s;
+    // верхние 3/5 окна — активные квесты, остальное — журнал
+    let rows_y = y + pad + 28 * s + ui_item_h(s) + ui_gap(s);
+    let list_h = (y + h - pad - rows_y) * 3 / 5;
+    let max_rows = (list_h / row_h).max(0);
+    let log_y = rows_y + max_rows * row_h + ui_gap(s) * 2 + ui_item_h(s);
+    let log_rows = ((y + h - pad - log_y) / row_h).max(0);
+    // колонки: квест | прогресс | награда | кнопка
+    let claim_w = button_w_for(crate::i18n::tr("journal.claim"), s);
+    let title_x = x + pad;
+    let progress_x = title_x + text_w(&crate::i18n::tr_fmt("quest.road_title", &[&crate::i18n::tr("quest.lumberjacks")]), s) + 12 * s;
+    let reward_x = progress_x + text_w("9999/9999  99d", s) + 12 * s;
+    let claim_x = x + w - pad - claim_w;
+    QuestJournalLayout { x, y, w, h, close_x, close_y, close_size, rows_y, row_h, max_rows, log_y, log_rows, title_x, progress_x, reward_x, claim_x, claim_w }
+}
+
+pub struct EventLogLayout { pub x: i32, pub y: i32, pub w: i32, pub h: i32, pub close_x: i32, pub close_y: i32, pub close_size: i32, pub tabs_y: i32, pub tabs: Vec<(i32, i32)>, pub rows_y: i32, pub row_h: i32, pub max_rows: i32, pub time_x: i32, pub text_x: i32, pub go_x: i32, pub go_w: i32 }
+
+pub fn layout_event_log(fw: i32, fh: i32, s: i32) -> EventLogLayout {
+    let w = ((fw as f32 * 0.7) as i32).clamp(640.min(fw), 960 * s);
+    let h = (fh as f32 * 0.85) as i32;
+    let x = (fw - w) / 2; let y = (fh - h) / 2;
+    let pad = 16 * s;
+    let close_size = 20 * s;
+    let close_x = x + w - pad - close_size; let close_y = y + pad;
+    // вкладки фильтра: ALL и разделы журнала
+    let tabs_y = y + pad + 28 * s;
+    let mut tabs = Vec::new();
+    let mut tx = x + pad;
+    for label in std::iter::once(crate::i18n::tr("event_log.all")).chain(crate::notifications::LogCategory::ALL.iter().map(|c| c.label())) {
+        let tw = button_w_for(label, s);
+        tabs.push((tx, tw));
+        tx += tw + ui_gap(s);
+    }
+    let row_h = ui_item_h(s) + 4 * s;
+    let rows_y = tabs_y + ui_item_h(s) + ui_gap(s) * 2;
+    let max_rows = ((y + h - pad - rows_y) / row_h).max(0);
+    // колонки: время | текст | кнопка перехода к месту
+    let go_w = button_w_for(crate::i18n::tr("event_log.go"), s);
+    let time_x = x + pad;
+    let text_x = time_x + text_w(&crate::i18n::tr_fmt("event_log.time", &[&999, &23]), s) + 12 * s;
+    let go_x = x + w - pad - go_w;
+    EventLogLayout { x, y, w, h, close_x, close_y, close_size, tabs_y, tabs, rows_y, row_h, max_rows, time_x, text_x, go_x, go_w }
+}
+
+pub struct ScenarioResultLayout { pub x: i32, pub y: i32, pub w: i32, pub h: i32, pub continue_x: i32, pub menu_x: i32, pub btn_y: i32, pub btn_w: i32 }
+
+pub fn layout_scenario_result(fw: i32, fh: i32, s: i32) -> ScenarioResultLayout {
+    let w = (420 * s).min(fw); let h = (200 * s).min(fh);
+    let x = (fw - w) / 2; let y = (fh - h) / 2;
+    let pad = 16 * s;
+    // две кнопки внизу по центру
+    let btn_w = button_w_for(crate::i18n::tr("scenario.main_menu"), s).max(button_w_for(crate::i18n::tr("scenario.continue"), s));
+    let gap = ui_gap(s) * 2;
+    let continue_x = x + (w - btn_w * 2 - gap) / 2;
+    let menu_x = continue_x + btn_w + gap;
+    let btn_y = y + h - pad - ui_item_h(s);
+    ScenarioResultLayout { x, y, w, h, continue_x, menu_x, btn_y, btn_w }
+}
+
+pub struct ContractsPanelLayout { pub x: i32, pub y: i32, pub w: i32, pub h: i32, pub close_x: i32, pub close_y: i32, pub close_size: i32, pub towns_y: i32, pub rows_y: i32, pub row_h: i32, pub max_rows: i32, pub town_x: i32, pub rep_x: i32, pub goods_x: i32, pub amount_x: i32, pub days_x: i32, pub reward_x: i32, pub accept_x: i32, pub decline_x: i32, pub accept_w: i32, pub decline_w: i32 }
+
+pub fn layout_contracts_panel(fw: i32, fh: i32, s: i32, towns: i32) -> ContractsPanelLayout {
+    let w = ((fw as f32 * 0.7) as i32).clamp(640.min(fw), 960 * s);
+    let h = (fh as f32 * 0.8) as i32;
+    let x = (fw - w) / 2; let y = (fh - h) / 2;
+    let pad = 16 * s;
+    let close_size = 20 * s;
+    let close_x = x + w - pad - close_size; let close_y = y + pad;
+    let row_h = ui_item_h(s) + 4 * s;
+    // заголовок, шапка и строки поселений, затем шапка и строки контрактов
+    let towns_y = y + pad + 28 * s + ui_item_h(s) + ui_gap(s);
+    let rows_y = towns_y + towns * row_h + ui_gap(s) * 2 + ui_item_h(s) * 2;
+    let max_rows = ((y + h - pad - rows_y) / row_h).max(0);
+    // колонки: поселение | репутация | товар | количество | срок | награда | кнопки
+    let accept_w = button_w_for(crate::i18n::tr("contracts.accept"), s); let decline_w = button_w_for(crate::i18n::tr("contracts.decline"), s);
+    let town_x = x + pad;
+    let rep_x = town_x + text_w("Millhaven", s) + 16 * s;
+    let goods_x = rep_x;
+    let amount_x = goods_x + text_w(crate::trade::display_label(crate::types::ResourceKind::IronIngot), s) + 16 * s;
+    let days_x = amount_x + text_w("999/999", s) + 16 * s;
+    let reward_x = days_x + text_w(&crate::i18n::tr_fmt("contracts.days_n", &[&99]), s) + 16 * s;
+    let decline_x = x + w - pad - decline_w;
+    let accept_x = decline_x - accept_w - 6 * s;
+    ContractsPanelLayout { x, y, w, h, close_x, close_y, close_size, towns_y, rows_y, row_h, max_rows, town_x, rep_x, goods_x, amount_x, days_x, reward_x, accept_x, decline_x, accept_w, decline_w }
+}
 
 pub fn point_in_rect(px: i32, py: i32, x: i32, y: i32, w: i32, h: i32) -> bool { px >= x && py >= y && px < x + w && py < y + h }
 
-pub fn button_w_for(label: &[u8], s: i32) -> i32 {
-    let px = 2 * s; // ширина «пикселя» глифа
-    let text_w = (label.len() as i32) * 4 * px; // 3x5 глиф с шагом 4
-    text_w + 12 * s // паддинги
+pub fn button_w_for(label: &str, s: i32) -> i32 {
+    text_w(label, s) + 12 * s // паддинги
 }
 
-pub fn text_w(label: &[u8], s: i32) -> i32 { (label.len() as i32) * 4 * (2 * s) }
+/// Ширина текста в пикселях экрана: пиксель глифа = 2 * s
+pub fn text_w(label: &str, s: i32) -> i32 { crate::font::measure(label) * (2 * s) }
 
 
 // ============================================================
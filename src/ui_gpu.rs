@@ -4,6 +4,8 @@
 use crate::gpu_renderer::GpuRenderer;
 use crate::types::{Resources, BuildingKind, FoodPolicy};
 use crate::ui::{self, UICategory, UITab};
+use crate::i18n::{tr, tr_fmt};
+use crate::font;
 use glam;
 
 // Маппинг ресурсов на индексы спрайтов в props.png (сетка 5x4)
@@ -29,10 +31,45 @@ fn get_props_index_for_resource(resource_name: &str) -> u32 {
         "Fish" => 16,       // (1, 3) - четвертая строка, вторая колонка
         "Iron Ore" => 17,   // (2, 3) - четвертая строка, третья колонка
         "Iron Ingots" => 18, // (3, 3) - четвертая строка, четвертая колонка
+        // редкие ресурсы: своих иконок нет, перекрашиваем похожие (см. get_props_tint_for_resource)
+        "Gold Ore" | "Coal" => 17,
+        "Salt" | "Gems" => 10,
+        "Jewelry" => 1,
+        "Charcoal" => 17,
+        "Tools" => 18,
+        "Wool" => 13,
+        "Cloth" => 14,
+        "Clothes" => 12,
+        "Hops" => 13,
+        "Ale" => 15,
         _ => 0,             // По умолчанию первый спрайт
     }
 }
 
+// Редкие ресурсы (в верхней панели только при ненулевом запасе)
+pub fn is_rare_resource(resource_name: &str) -> bool {
+    matches!(resource_name, "Gold Ore" | "Coal" | "Salt" | "Gems" | "Jewelry" | "Charcoal" | "Tools" | "Wool" | "Cloth" | "Clothes" | "Hops" | "Ale")
+}
+
+// Тинт иконки ресурса (для редких ресурсов, рисуемых чужими иконками)
+fn get_props_tint_for_resource(resource_name: &str) -> [f32; 4] {
+    match resource_name {
+        "Gold Ore" => [1.0, 0.85, 0.3, 1.0],
+        "Coal" => [0.35, 0.35, 0.4, 1.0],
+        "Salt" => [1.3, 1.3, 1.3, 1.0],
+        "Gems" => [0.4, 1.0, 0.9, 1.0],
+        "Jewelry" => [1.0, 0.5, 1.0, 1.0],
+        "Charcoal" => [0.25, 0.22, 0.22, 1.0],
+        "Tools" => [0.75, 0.8, 0.95, 1.0],
+        "Wool" => [1.4, 1.4, 1.4, 1.0],
+        "Cloth" => [0.9, 0.8, 1.2, 1.0],
+        "Clothes" => [0.5, 0.6, 1.0, 1.0],
+        "Hops" => [0.5, 1.0, 0.4, 1.0],
+        "Ale" => [1.0, 0.7, 0.3, 1.0],
+        _ => [1.0, 1.0, 1.0, 1.0],
+    }
+}
+
 /// Вычисляет ширину, которую займет список ресурсов
 fn calculate_resources_list_width(
     cost: &crate::types::Resources,
@@ -55,6 +92,18 @@ fn calculate_resources_list_width(
         ("Fish", cost.fish),
         ("Iron Ore", cost.iron_ore),
         ("Iron Ingots", cost.iron_ingots),
+        ("Gold Ore", cost.gold_ore),
+        ("Coal", cost.coal),
+        ("Salt", cost.salt),
+        ("Gems", cost.gems),
+        ("Jewelry", cost.jewelry),
+        ("Charcoal", cost.charcoal),
+        ("Tools", cost.tools),
+        ("Wool", cost.wool),
+        ("Cloth", cost.cloth),
+        ("Clothes", cost.clothes),
+        ("Hops", cost.hops),
+        ("Ale", cost.ale),
     ];
     
     for (_name, amount) in resources_to_show.iter() {
@@ -94,12 +143,24 @@ fn draw_resources_list(
         ("Fish", cost.fish),
         ("Iron Ore", cost.iron_ore),
         ("Iron Ingots", cost.iron_ingots),
+        ("Gold Ore", cost.gold_ore),
+        ("Coal", cost.coal),
+        ("Salt", cost.salt),
+        ("Gems", cost.gems),
+        ("Jewelry", cost.jewelry),
+        ("Charcoal", cost.charcoal),
+        ("Tools", cost.tools),
+        ("Wool", cost.wool),
+        ("Cloth", cost.cloth),
+        ("Clothes", cost.clothes),
+        ("Hops", cost.hops),
+        ("Ale", cost.ale),
     ];
     
     for (name, amount) in resources_to_show.iter() {
         if *amount > 0 {
             // Иконка ресурса
-            gpu.draw_ui_props_icon(current_x, y, icon_size, get_props_index_for_resource(name));
+            gpu.draw_ui_props_icon_tinted(current_x, y, icon_size, get_props_index_for_resource(name), get_props_tint_for_resource(name));
             current_x += icon_size + 2.0 * scale;
             
             // Определяем цвет числа: красный если ресурсов не хватает, белый если хватает
@@ -116,6 +177,18 @@ fn draw_resources_list(
                     "Fish" => available.fish,
                     "Iron Ore" => available.iron_ore,
                     "Iron Ingots" => available.iron_ingots,
+                    "Gold Ore" => available.gold_ore,
+                    "Coal" => available.coal,
+                    "Salt" => available.salt,
+                    "Gems" => available.gems,
+                    "Jewelry" => available.jewelry,
+                    "Charcoal" => available.charcoal,
+                    "Tools" => available.tools,
+                    "Wool" => available.wool,
+                    "Cloth" => available.cloth,
+                    "Clothes" => available.clothes,
+                    "Hops" => available.hops,
+                    "Ale" => available.ale,
                     _ => *amount,
                 };
                 if available_amount < *amount {
@@ -158,12 +231,16 @@ pub fn draw_ui_gpu(
     tax_rate: f32,
     ui_tab: UITab,
     food_policy: FoodPolicy,
-    weather_label: &[u8],
+    weather_label: &str,
     weather_icon_col: [f32; 4],
+    calendar_label: &str, // сезон и день, например "WINTER DAY 2"
     current_weather: crate::types::WeatherKind, // Текущая погода для тултипа
     // Данные для миникарты
     world: &mut crate::world::World,
     buildings: &[crate::types::Building],
+    advisor: &crate::advisor::Advisor,
+    pois: &[crate::poi::Poi],
+    ships: &[crate::shipping::Ship],
     cam_x: f32,
     cam_y: f32,
     cell_size: i32,
@@ -197,6 +274,10 @@ pub fn draw_ui_gpu(
     
     let s = ui::ui_scale(fh, base_scale_k);
     let scale = s as f32;
+
+    // значки советника над проблемными зданиями — под панелями интерфейса
+    let cam_px = glam::Vec2::new(cam_x, cam_y);
+    draw_building_status_icons(gpu, advisor, |p| crate::camera::tile_to_screen_px(p, fw, fh, cam_px, atlas_half_w, atlas_half_h, zoom), atlas_half_h as f32 * zoom, scale);
     
     // === ВЕРХНЯЯ ПАНЕЛЬ ===
     let panel_height = ui::top_panel_height(s) as f32;
@@ -266,7 +347,7 @@ pub fn draw_ui_gpu(
     right_x -= fps_num_w;
     gpu.draw_number(right_x, row1_y, fps_rounded, [220.0/255.0, 220.0/255.0, 220.0/255.0, 1.0], scale);
     right_x -= 80.0;
-    gpu.draw_text(right_x, row1_y, b"FPS", [180.0/255.0, 180.0/255.0, 180.0/255.0, 1.0], scale);
+    gpu.draw_text(right_x, row1_y, tr("hud.fps"), [180.0/255.0, 180.0/255.0, 180.0/255.0, 1.0], scale);
     right_x -= gap;
     
     // Speed (слева от FPS)
@@ -275,23 +356,29 @@ pub fn draw_ui_gpu(
     right_x -= speed_num_w;
     gpu.draw_number(right_x, row1_y, speed_val, [220.0/255.0, 220.0/255.0, 220.0/255.0, 1.0], scale);
     right_x -= 30.0;
-    gpu.draw_text(right_x, row1_y, b"x", [180.0/255.0, 180.0/255.0, 180.0/255.0, 1.0], scale);
+    gpu.draw_text(right_x, row1_y, "x", [180.0/255.0, 180.0/255.0, 180.0/255.0, 1.0], scale);
     right_x -= gap;
     
     
     // Weather (слева от Speed)
-    let weather_text_w = weather_label.len() as f32 * 4.0 * 2.0 * scale;
+    let weather_text_w = font::text_width(weather_label, scale);
     right_x -= weather_text_w;
     gpu.draw_text(right_x, row1_y + 2.0, weather_label, [230.0/255.0, 230.0/255.0, 230.0/255.0, 1.0], scale);
     right_x -= icon_size + 4.0;
     // Погода пока оставляем как цветной квадратик (можно будет заменить позже)
     gpu.draw_ui_resource_icon(right_x, row1_y, icon_size, weather_icon_col);
+    right_x -= gap;
+
+    // Календарь (слева от погоды)
+    let calendar_text_w = font::text_width(calendar_label, scale);
+    right_x -= calendar_text_w;
+    gpu.draw_text(right_x, row1_y + 2.0, calendar_label, [230.0/255.0, 220.0/255.0, 190.0/255.0, 1.0], scale);
     
     // PAUSED (если активна, вторая строка)
     if paused {
         let paused_x = fw as f32 - pad - 200.0;
         let paused_y = row1_y + icon_size + 20.0;
-        gpu.draw_text(paused_x, paused_y, b"PAUSED", [255.0/255.0, 120.0/255.0, 120.0/255.0, 1.0], scale);
+        gpu.draw_text(paused_x, paused_y, tr("hud.paused"), [1.0, 120.0/255.0, 120.0/255.0, 1.0], scale);
     }
     
     // Вторая строка: ресурсы
@@ -309,10 +396,24 @@ pub fn draw_ui_gpu(
         ("Fish", resources.fish),
         ("Iron Ore", resources.iron_ore),
         ("Iron Ingots", resources.iron_ingots),
+        ("Gold Ore", resources.gold_ore),
+        ("Coal", resources.coal),
+        ("Salt", resources.salt),
+        ("Gems", resources.gems),
+        ("Jewelry", resources.jewelry),
+        ("Charcoal", resources.charcoal),
+        ("Tools", resources.tools),
+        ("Wool", resources.wool),
+        ("Cloth", resources.cloth),
+        ("Clothes", resources.clothes),
+        ("Hops", resources.hops),
+        ("Ale", resources.ale),
     ];
     
     for (name, amount) in resources_list {
-        gpu.draw_ui_props_icon(x, row2_y, icon_size, get_props_index_for_resource(name));
+        // редкие ресурсы показываем, только когда они появились
+        if amount <= 0 && is_rare_resource(name) { continue; }
+        gpu.draw_ui_props_icon_tinted(x, row2_y, icon_size, get_props_index_for_resource(name), get_props_tint_for_resource(name));
         x += icon_size + 4.0;
         gpu.draw_number(x, row2_y + text_y_offset, amount.max(0) as u32, [1.0, 1.0, 1.0, 1.0], scale);
         x += (amount.max(0) as u32).to_string().len() as f32 * 4.0 * 2.0 * scale + gap;
@@ -336,25 +437,45 @@ pub fn draw_ui_gpu(
     let tab_y = bottom_y + pad;
     
     // Вкладки Build / Economy
-    let build_w = (ui::button_w_for(b"Build", s) as f32).max(60.0);
-    let economy_w = (ui::button_w_for(b"Economy", s) as f32).max(80.0);
+    let build_w = (ui::button_w_for(tr("tab.build"), s) as f32).max(60.0);
+    let economy_w = (ui::button_w_for(tr("tab.economy"), s) as f32).max(80.0);
     
-    gpu.draw_button(current_x, tab_y, build_w, btn_h, b"Build", ui_tab == UITab::Build, btn_scale);
+    gpu.draw_button(current_x, tab_y, build_w, btn_h, tr("tab.build"), ui_tab == UITab::Build, btn_scale);
     current_x += build_w + 6.0 * scale;
     
-    gpu.draw_button(current_x, tab_y, economy_w, btn_h, b"Economy", ui_tab == UITab::Economy, btn_scale);
+    gpu.draw_button(current_x, tab_y, economy_w, btn_h, tr("tab.economy"), ui_tab == UITab::Economy, btn_scale);
     current_x += economy_w + 6.0 * scale;
     
     // Кнопка для депозитов ресурсов
-    let deposits_w = (ui::button_w_for(b"Deposits", s) as f32).max(80.0);
-    gpu.draw_button(current_x, tab_y, deposits_w, btn_h, b"Deposits", show_deposits, btn_scale);
+    let deposits_w = (ui::button_w_for(tr("tab.deposits"), s) as f32).max(80.0);
+    gpu.draw_button(current_x, tab_y, deposits_w, btn_h, tr("tab.deposits"), show_deposits, btn_scale);
     current_x += deposits_w + 6.0 * scale;
     
+    // Кнопка журнала квестов
+    let quests_w = (ui::button_w_for(tr("tab.quests"), s) as f32).max(80.0);
+    gpu.draw_button(current_x, tab_y, quests_w, btn_h, tr("tab.quests"), false, btn_scale);
+    current_x += quests_w + 6.0 * scale;
+    
+    // Кнопка журнала событий
+    let log_w = (ui::button_w_for(tr("tab.log"), s) as f32).max(80.0);
+    gpu.draw_button(current_x, tab_y, log_w, btn_h, tr("tab.log"), false, btn_scale);
+    current_x += log_w + 6.0 * scale;
+    
     // Кнопка для открытия окна исследований (только если есть лаборатория)
     let has_lab = buildings.iter().any(|b| b.kind == crate::types::BuildingKind::ResearchLab);
     if has_lab {
-        let research_w = (ui::button_w_for(b"Research (T)", s) as f32).max(100.0);
-        gpu.draw_button(current_x, tab_y, research_w, btn_h, b"Research (T)", false, btn_scale);
+        let research_w = (ui::button_w_for(tr("tab.research"), s) as f32).max(100.0);
+        gpu.draw_button(current_x, tab_y, research_w, btn_h, tr("tab.research"), false, btn_scale);
+        current_x += research_w + 6.0 * scale;
+    }
+    
+    // Кнопка окна торговли (только если есть торговый пост)
+    if buildings.iter().any(|b| b.kind == crate::types::BuildingKind::TradingPost) {
+        let trade_w = (ui::button_w_for(tr("tab.trade"), s) as f32).max(80.0);
+        gpu.draw_button(current_x, tab_y, trade_w, btn_h, tr("tab.trade"), false, btn_scale);
+        current_x += trade_w + 6.0 * scale;
+        let contracts_w = (ui::button_w_for(tr("tab.contracts"), s) as f32).max(100.0);
+        gpu.draw_button(current_x, tab_y, contracts_w, btn_h, tr("tab.contracts"), false, btn_scale);
     }
     
     if ui_tab == UITab::Build {
@@ -362,22 +483,12 @@ pub fn draw_ui_gpu(
         current_x = pad;
         let cat_y = tab_y + btn_h + 6.0;
         
-        let categories: &[(UICategory, &[u8])] = &[
-            (UICategory::Housing, b"Housing"),
-            (UICategory::Storage, b"Storage"),
-            (UICategory::Forestry, b"Forestry"),
-            (UICategory::Mining, b"Mining"),
-            (UICategory::Food, b"Food"),
-            (UICategory::Logistics, b"Logistics"),
-            (UICategory::Research, b"Research"),
-        ];
-        
-        for (cat, label) in categories.iter() {
-            let btn_w = (label.len() as f32 * 4.0 * 2.0 * scale + 12.0).max(60.0);
+        for cat in ui::CATEGORIES {
+            let btn_w = ui::category_button_w(cat, s) as f32;
             if current_x + btn_w > fw as f32 - pad {
                 break;
             }
-            gpu.draw_button(current_x, cat_y, btn_w, btn_h, *label, *cat == category, btn_scale);
+            gpu.draw_button(current_x, cat_y, btn_w, btn_h, ui::category_label(cat), cat == category, btn_scale);
             current_x += btn_w + 6.0 * scale;
         }
         
@@ -385,47 +496,23 @@ pub fn draw_ui_gpu(
         current_x = pad;
         let build_y = cat_y + btn_h + 6.0;
         
-        let buildings_for_cat: &[(BuildingKind, &[u8])] = match category {
-            UICategory::Housing => &[(BuildingKind::House, b"House")],
-            UICategory::Storage => &[(BuildingKind::Warehouse, b"Warehouse")],
-            UICategory::Forestry => &[
-                (BuildingKind::Lumberjack, b"Lumberjack"),
-                (BuildingKind::Forester, b"Forester")
-            ],
-            UICategory::Mining => &[
-                (BuildingKind::StoneQuarry, b"Quarry"),
-                (BuildingKind::ClayPit, b"Clay Pit"),
-                (BuildingKind::IronMine, b"Iron Mine"),
-                (BuildingKind::Kiln, b"Kiln"),
-                (BuildingKind::Smelter, b"Smelter")
-            ],
-            UICategory::Food => &[
-                (BuildingKind::WheatField, b"Wheat Field"),
-                (BuildingKind::Mill, b"Mill"),
-                (BuildingKind::Bakery, b"Bakery"),
-                (BuildingKind::Fishery, b"Fishery")
-            ],
-            UICategory::Logistics => &[],
-            UICategory::Research => &[(BuildingKind::ResearchLab, b"Research Lab")],
-        };
-        
-        for (bk, label) in buildings_for_cat.iter() {
-            let btn_w = (label.len() as f32 * 4.0 * 2.0 * scale + 12.0).max(70.0);
+        for &bk in ui::category_buildings(category) {
+            let btn_w = ui::building_button_w(bk, s) as f32;
             if current_x + btn_w > fw as f32 - pad {
                 break;
             }
             
             // Проверяем разблокировку здания (ResearchLab всегда разблокирована)
-            let is_unlocked = *bk == BuildingKind::ResearchLab 
-                || research_system.is_building_unlocked(*bk);
+            let is_unlocked = bk == BuildingKind::ResearchLab 
+                || research_system.is_building_unlocked(bk);
             
             gpu.draw_button_disabled(
                 current_x, 
                 build_y, 
                 btn_w, 
                 btn_h, 
-                label, 
-                selected == Some(*bk), 
+                ui::building_label(bk), 
+                selected == Some(bk), 
                 !is_unlocked, // disabled если не разблокировано
                 btn_scale
             );
@@ -439,8 +526,9 @@ pub fn draw_ui_gpu(
         let control_y = tab_y + btn_h + 6.0;
         
         // TAX контролы - динамический расчет
-        let tax_label_w = (3.0 * 4.0 * 2.0 * scale + 12.0).max(40.0); // "TAX"
-        gpu.draw_text(current_x, control_y + 5.0, b"TAX", [200.0/255.0, 200.0/255.0, 200.0/255.0, 1.0], btn_scale);
+        let tax_label = tr("economy.tax");
+        let tax_label_w = (ui::text_w(tax_label, s) as f32 + 12.0).max(40.0);
+        gpu.draw_text(current_x, control_y + 5.0, tax_label, [200.0/255.0, 200.0/255.0, 200.0/255.0, 1.0], btn_scale);
         current_x += tax_label_w + 6.0 * scale;
         
         let taxp = tax_rate as u32;
@@ -452,28 +540,29 @@ pub fn draw_ui_gpu(
         let minus_btn_w = (1.0 * 4.0 * 2.0 * scale + 12.0).max(30.0); // "-"
         let plus_btn_w = (1.0 * 4.0 * 2.0 * scale + 12.0).max(30.0); // "+"
         
-        gpu.draw_button(current_x, control_y, minus_btn_w, btn_h, b"-", false, btn_scale);
+        gpu.draw_button(current_x, control_y, minus_btn_w, btn_h, "-", false, btn_scale);
         current_x += minus_btn_w + 6.0 * scale;
-        gpu.draw_button(current_x, control_y, plus_btn_w, btn_h, b"+", false, btn_scale);
+        gpu.draw_button(current_x, control_y, plus_btn_w, btn_h, "+", false, btn_scale);
         
         // === ТРЕТЬЯ СТРОКА: Политика еды ===
         let _ = current_x; // значение присваивается, но сразу переопределяется ниже
         current_x = pad;
         let policy_y = control_y + btn_h + 6.0;
         
-        let policy_label_w = (11.0 * 4.0 * 2.0 * scale + 12.0).max(100.0); // "FOOD POLICY"
-        gpu.draw_text(current_x, policy_y + 5.0, b"FOOD POLICY", [200.0/255.0, 200.0/255.0, 200.0/255.0, 1.0], btn_scale);
+        let policy_label = tr("economy.food_policy");
+        let policy_label_w = (ui::text_w(policy_label, s) as f32 + 12.0).max(100.0);
+        gpu.draw_text(current_x, policy_y + 5.0, policy_label, [200.0/255.0, 200.0/255.0, 200.0/255.0, 1.0], btn_scale);
         current_x += policy_label_w + 6.0 * scale;
         
         // Food policy buttons - динамический расчет
-        let food_policies: &[(FoodPolicy, &[u8])] = &[
-            (FoodPolicy::Balanced, b"Balanced"),
-            (FoodPolicy::BreadFirst, b"Bread"),
-            (FoodPolicy::FishFirst, b"Fish"),
+        let food_policies = [
+            (FoodPolicy::Balanced, tr("economy.balanced")),
+            (FoodPolicy::BreadFirst, tr("economy.bread")),
+            (FoodPolicy::FishFirst, tr("economy.fish")),
         ];
         
         for (policy, label) in food_policies.iter() {
-            let btn_w = (label.len() as f32 * 4.0 * 2.0 * scale + 12.0).max(50.0);
+            let btn_w = (ui::text_w(label, s) as f32 + 12.0).max(50.0);
             if current_x + btn_w > fw as f32 - pad {
                 break;
             }
@@ -525,7 +614,7 @@ pub fn draw_ui_gpu(
     
     // Подготавливаем миникарту (используем atlas_half_w и atlas_half_h из параметров функции)
     gpu.prepare_minimap_with_atlas(
-        world, buildings,
+        world, buildings, pois, ships,
         cam_x, cam_y,
         minimap_x as i32, minimap_y as i32, 
         widget_w as i32, widget_h as i32,
@@ -642,9 +731,9 @@ pub fn draw_ui_gpu(
         if let Some(tp) = tile_pos {
             let biome = world.biome(tp);
             let biome_name = match biome {
-                crate::types::BiomeKind::Meadow => "Meadow",
-                crate::types::BiomeKind::Swamp => "Swamp", 
-                crate::types::BiomeKind::Rocky => "Rocky",
+                crate::types::BiomeKind::Meadow => tr("biome.meadow"),
+                crate::types::BiomeKind::Swamp => tr("biome.swamp"),
+                crate::types::BiomeKind::Rocky => tr("biome.rocky"),
             };
             
             draw_biome_debug_tooltip(
@@ -661,6 +750,36 @@ pub fn draw_ui_gpu(
         }
     }
     
+    // Тултип разведки: остатки месторождений под курсором и вокруг (радиус добычи)
+    if show_deposits && !biome_debug_mode {
+        let tile_pos = screen_to_tile_px(
+            cursor_x as i32,
+            cursor_y as i32,
+            fw,
+            fh,
+            glam::Vec2::new(cam_x, cam_y),
+            atlas_half_w,
+            atlas_half_h,
+            zoom
+        );
+
+        if let Some(tp) = tile_pos {
+            let mut lines = Vec::new();
+            for kind in crate::world::DepositKind::ALL {
+                let here = world.deposit_left(tp, kind);
+                let around = world.deposit_left_around(tp, crate::building_production::DEPOSIT_RADIUS, kind);
+                if here > 0 || around > 0 {
+                    lines.push(tr_fmt("deposit.left", &[&kind.label(), &here, &around]));
+                } else if world.is_depleted(tp, kind) {
+                    lines.push(tr_fmt("deposit.exhausted", &[&kind.label()]));
+                }
+            }
+            if !lines.is_empty() {
+                draw_prospecting_tooltip(gpu, cursor_x, cursor_y, &lines, scale, fw as f32);
+            }
+        }
+    }
+    
     // Рендерим консоль, если она открыта
     if console_open {
         draw_console_gpu(gpu, fw, fh, s, console_input, console_log);
@@ -692,28 +811,51 @@ pub fn draw_building_tooltip(
     let cost = building_cost(building_kind);
     
     // Получаем информацию о здании
-    let (name, prod, cons) = match building_kind {
-        BuildingKind::House => ("House", "Housing", None),
-        BuildingKind::Warehouse => ("Warehouse", "Storage", None),
-        BuildingKind::Lumberjack => ("Lumberjack", "+ Wood", None),
-        BuildingKind::Forester => ("Forester", "Forestry", None),
-        BuildingKind::StoneQuarry => ("Stone Quarry", "+ Stone", None),
-        BuildingKind::ClayPit => ("Clay Pit", "+ Clay", None),
-        BuildingKind::Kiln => ("Kiln", "+ Bricks", Some("- Clay, - Wood")),
-        BuildingKind::IronMine => ("Iron Mine", "+ Iron Ore", None),
-        BuildingKind::WheatField => ("Wheat Field", "+ Wheat", None),
-        BuildingKind::Mill => ("Mill", "+ Flour", Some("- Wheat")),
-        BuildingKind::Bakery => ("Bakery", "+ Bread", Some("- Flour, - Wood")),
-        BuildingKind::Smelter => ("Smelter", "+ Iron Ingot", Some("- Iron Ore, - Wood")),
-        BuildingKind::Fishery => ("Fishery", "+ Fish", None),
-        BuildingKind::ResearchLab => ("Research Lab", "Research", None),
+    let name = ui::building_label(building_kind);
+    use crate::types::ResourceKind as R;
+    let gain = |kind: R| format!("+ {}", crate::trade::display_label(kind));
+    let spend = |kinds: &[R]| kinds.iter().map(|&k| format!("- {}", crate::trade::display_label(k))).collect::<Vec<_>>().join(", ");
+    // топливо плавильных: уголь, древесный уголь или дрова
+    let fuel = || [R::Coal, R::Charcoal, R::Wood].map(crate::trade::display_label).join("/");
+    let (prod, cons): (String, Option<String>) = match building_kind {
+        BuildingKind::House => (tr("tooltip.housing").to_string(), None),
+        BuildingKind::Warehouse => (tr("tooltip.storage").to_string(), None),
+        BuildingKind::Lumberjack => (gain(R::Wood), None),
+        BuildingKind::Forester => (tr("tooltip.forestry").to_string(), None),
+        BuildingKind::StoneQuarry => (gain(R::Stone), None),
+        BuildingKind::ClayPit => (gain(R::Clay), None),
+        BuildingKind::Kiln => (gain(R::Bricks), Some(spend(&[R::Clay, R::Wood]))),
+        BuildingKind::IronMine => (gain(R::IronOre), None),
+        BuildingKind::WheatField => (gain(R::Wheat), None),
+        BuildingKind::Mill => (gain(R::Flour), Some(spend(&[R::Wheat]))),
+        BuildingKind::Bakery => (gain(R::Bread), Some(spend(&[R::Flour, R::Wood]))),
+        BuildingKind::Smelter => (gain(R::IronIngot), Some(format!("{}, - {}", spend(&[R::IronOre]), fuel()))),
+        BuildingKind::Fishery => (gain(R::Fish), None),
+        BuildingKind::ResearchLab => (tr("tooltip.research").to_string(), None),
+        BuildingKind::CoalMine => (gain(R::Coal), None),
+        BuildingKind::GoldMine => (gain(R::GoldOre), None),
+        BuildingKind::GemMine => (gain(R::Gems), None),
+        BuildingKind::SaltWorks => (gain(R::Salt), None),
+        BuildingKind::Mint => (format!("+ {}", tr("resource.gold")), Some(format!("{}, - {}", spend(&[R::GoldOre]), fuel()))),
+        BuildingKind::Jeweler => (gain(R::Jewelry), Some(spend(&[R::Gems, R::GoldOre]))),
+        BuildingKind::ScoutTower => (tr("tooltip.vision").to_string(), None),
+        BuildingKind::Dock => (tr("tooltip.ferry").to_string(), None),
+        BuildingKind::CharcoalBurner => (gain(R::Charcoal), Some(spend(&[R::Wood]))),
+        BuildingKind::Blacksmith => (gain(R::Tools), Some(format!("{}, - {}", spend(&[R::IronIngot]), fuel()))),
+        BuildingKind::SheepFarm => (gain(R::Wool), None),
+        BuildingKind::Weaver => (gain(R::Cloth), Some(spend(&[R::Wool]))),
+        BuildingKind::Tailor => (gain(R::Clothes), Some(spend(&[R::Cloth]))),
+        BuildingKind::HopFarm => (gain(R::Hops), None),
+        BuildingKind::Brewery => (gain(R::Ale), Some(spend(&[R::Hops, R::Wood]))),
+        BuildingKind::Tavern => (tr("tooltip.comfort").to_string(), Some(tr("tooltip.tavern_desc").to_string())),
+        BuildingKind::TradingPost => (tr("tooltip.trade").to_string(), Some(tr("tooltip.trading_post_desc").to_string())),
     };
     
     // Вычисляем размер тултипа
-    let name_w = name.len() as f32 * 4.0 * 2.0 * scale;
-    let prod_w = prod.len() as f32 * 4.0 * 2.0 * scale;
-    let cons_w = cons.map(|c| c.len() as f32 * 4.0 * 2.0 * scale).unwrap_or(0.0);
-    let workers_w = format!("Workers: {}/{}", workers_current, workers_target).len() as f32 * 4.0 * 2.0 * scale;
+    let name_w = font::text_width(name, scale);
+    let prod_w = font::text_width(&prod, scale);
+    let cons_w = cons.as_ref().map(|c| font::text_width(c, scale)).unwrap_or(0.0);
+    let workers_w = font::text_width(&format!("{}: {}/{}", tr("tooltip.workers"), workers_current, workers_target), scale);
     
     // Ширина для строки с материалами (без учета доступных ресурсов, так как это только для вычисления размера)
     let cost_w = calculate_resources_list_width(&cost, scale);
@@ -740,16 +882,16 @@ pub fn draw_building_tooltip(
     let mut text_y = tooltip_y + pad;
     
     // Название здания
-    gpu.draw_text(tooltip_x + pad, text_y, name.as_bytes(), [1.0, 1.0, 1.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, name, [1.0, 1.0, 1.0, 1.0], scale);
     text_y += line_height;
     
     // Производство
-    gpu.draw_text(tooltip_x + pad, text_y, prod.as_bytes(), [0.7, 1.0, 0.7, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, &prod, [0.7, 1.0, 0.7, 1.0], scale);
     text_y += line_height;
     
     // Потребление
     if let Some(cons_text) = cons {
-        gpu.draw_text(tooltip_x + pad, text_y, cons_text.as_bytes(), [1.0, 0.7, 0.7, 1.0], scale);
+        gpu.draw_text(tooltip_x + pad, text_y, &cons_text, [1.0, 0.7, 0.7, 1.0], scale);
         text_y += line_height;
     }
     
@@ -760,8 +902,8 @@ pub fn draw_building_tooltip(
     text_y += icon_size + pad * 0.5;
     
     // Работники
-    let workers_text = format!("Workers: {}/{}", workers_current, workers_target);
-    gpu.draw_text(tooltip_x + pad, text_y, workers_text.as_bytes(), [1.0, 1.0, 0.7, 1.0], scale);
+    let workers_text = format!("{}: {}/{}", tr("tooltip.workers"), workers_current, workers_target);
+    gpu.draw_text(tooltip_x + pad, text_y, &workers_text, [1.0, 1.0, 0.7, 1.0], scale);
 }
 
 /// Рендеринг тултипа для кнопки интерфейса
@@ -800,6 +942,23 @@ pub fn draw_button_tooltip(
         "House" => Some(BuildingKind::House),
         "Warehouse" => Some(BuildingKind::Warehouse),
         "Research Lab" => Some(BuildingKind::ResearchLab),
+        "Coal Mine" => Some(BuildingKind::CoalMine),
+        "Gold Mine" => Some(BuildingKind::GoldMine),
+        "Gem Mine" => Some(BuildingKind::GemMine),
+        "Salt Works" => Some(BuildingKind::SaltWorks),
+        "Mint" => Some(BuildingKind::Mint),
+        "Jeweler" => Some(BuildingKind::Jeweler),
+        "Scout Tower" => Some(BuildingKind::ScoutTower),
+        "Dock" => Some(BuildingKind::Dock),
+        "Charcoal Burner" => Some(BuildingKind::CharcoalBurner),
+        "Blacksmith" => Some(BuildingKind::Blacksmith),
+        "Sheep Farm" => Some(BuildingKind::SheepFarm),
+        "Weaver" => Some(BuildingKind::Weaver),
+        "Tailor" => Some(BuildingKind::Tailor),
+        "Hop Farm" => Some(BuildingKind::HopFarm),
+        "Brewery" => Some(BuildingKind::Brewery),
+        "Tavern" => Some(BuildingKind::Tavern),
+        "Trading Post" => Some(BuildingKind::TradingPost),
         _ => None,
     };
     
@@ -807,57 +966,41 @@ pub fn draw_button_tooltip(
     let cost_opt = building_kind_opt.map(|bk| building_cost(bk));
     
     // Получаем информацию о кнопке
-    let (name, description) = match button_text {
-        // Здания (названия должны совпадать с get_hovered_button)
-        "Lumberjack" => ("Lumberjack", "Produces wood from trees. Requires workers."),
-        "Forester" => ("Forester", "Plants new trees. Requires workers."),
-        "Quarry" => ("Stone Quarry", "Mines stone from deposits. Requires workers."),
-        "Clay Pit" => ("Clay Pit", "Mines clay from deposits. Requires workers."),
-        "Iron Mine" => ("Iron Mine", "Mines iron ore from deposits. Requires workers."),
-        "Wheat Field" => ("Wheat Field", "Grows wheat for food. Requires workers."),
-        "Mill" => ("Mill", "Processes wheat into flour. Requires workers."),
-        "Bakery" => ("Bakery", "Bakes bread from flour. Requires workers."),
-        "Kiln" => ("Kiln", "Bakes clay into bricks. Requires workers."),
-        "Smelter" => ("Smelter", "Smelts iron ore into iron ingots. Requires workers."),
-        "Fishery" => ("Fishery", "Catches fish from water. Requires workers."),
-        "House" => ("House", "Provides housing for citizens."),
-        "Warehouse" => ("Warehouse", "Stores resources and goods."),
-        "Research Lab" => ("Research Lab", "Enables research and unlocks new technologies."),
-        
-        // Управление
-        "Pause" => ("Pause", "Pause/unpause the game."),
-        "Resume" => ("Resume", "Resume the game."),
-        "Speed 1x" => ("Speed 1x", "Set game speed to normal."),
-        "Speed 2x" => ("Speed 2x", "Set game speed to 2x."),
-        "Speed 4x" => ("Speed 4x", "Set game speed to 4x."),
-        
-        // Вкладки
-        "Build Tab" => ("Build Tab", "Switch to building construction mode."),
-        "Economy Tab" => ("Economy Tab", "Switch to economy management mode."),
-        
-        // Категории
-        "Housing" => ("Housing", "Buildings for citizen housing."),
-        "Storage" => ("Storage", "Buildings for resource storage."),
-        "Forestry" => ("Forestry", "Buildings for wood production."),
-        "Mining" => ("Mining", "Buildings for resource extraction."),
-        "Food" => ("Food", "Buildings for food production."),
-        "Logistics" => ("Logistics", "Buildings for transportation."),
-        
-        // Экономика
-        "Decrease Tax" => ("Decrease Tax", "Lower the tax rate."),
-        "Increase Tax" => ("Increase Tax", "Raise the tax rate."),
-        "Balanced Food Policy" => ("Balanced Food Policy", "Equal distribution of bread and fish."),
-        "Bread First Policy" => ("Bread First Policy", "Prioritize bread distribution."),
-        "Fish First Policy" => ("Fish First Policy", "Prioritize fish distribution."),
-        
-        "Deposits" => ("Deposits", "Toggle resource deposits display on/off."),
-        
-        _ => (button_text, "Click to interact."),
+    let category = ui::CATEGORIES.into_iter().find(|c| format!("{:?}", c) == button_text);
+    let (name, description) = if let Some(bk) = building_kind_opt {
+        (ui::building_label(bk), ui::building_description(bk))
+    } else if let Some(cat) = category {
+        (ui::category_label(cat), ui::category_description(cat))
+    } else {
+        match button_text {
+            // Управление
+            "Pause" => (tr("tooltip.pause"), tr("tooltip.pause_desc")),
+            "Resume" => (tr("tooltip.resume"), tr("tooltip.resume_desc")),
+            "Speed 1x" => (tr("tooltip.speed_1x"), tr("tooltip.speed_1x_desc")),
+            "Speed 2x" => (tr("tooltip.speed_2x"), tr("tooltip.speed_2x_desc")),
+            "Speed 4x" => (tr("tooltip.speed_4x"), tr("tooltip.speed_4x_desc")),
+        
+            // Вкладки
+            "Build Tab" => (tr("tooltip.build_tab"), tr("tooltip.build_tab_desc")),
+            "Economy Tab" => (tr("tooltip.economy_tab"), tr("tooltip.economy_tab_desc")),
+        
+            // Экономика
+            "Decrease Tax" => (tr("tooltip.decrease_tax"), tr("tooltip.decrease_tax_desc")),
+            "Increase Tax" => (tr("tooltip.increase_tax"), tr("tooltip.increase_tax_desc")),
+            "Balanced Food Policy" => (tr("tooltip.balanced_policy"), tr("tooltip.balanced_policy_desc")),
+            "Bread First Policy" => (tr("tooltip.bread_policy"), tr("tooltip.bread_policy_desc")),
+            "Fish First Policy" => (tr("tooltip.fish_policy"), tr("tooltip.fish_policy_desc")),
+        
+            "Deposits" => (tr("tooltip.deposits"), tr("tooltip.deposits_desc")),
+        
+            _ => (button_text, tr("tooltip.default_desc")),
+        }
     };
     
-    // Вычисляем размер тултипа
-    let name_w = name.len() as f32 * 4.0 * 2.0 * scale;
-    let desc_w = description.len() as f32 * 4.0 * 2.0 * scale;
+    // Вычисляем размер тултипа; длинное описание переносится по трети экрана
+    let name_w = font::text_width(name, scale);
+    let desc_lines = font::wrap(description, (screen_width / 3.0).max(name_w), scale);
+    let desc_w = desc_lines.iter().map(|l| font::text_width(l, scale)).fold(0.0_f32, f32::max);
     
     // Если есть стоимость, учитываем её в ширине
     let cost_w = if let Some(ref cost) = cost_opt {
@@ -869,7 +1012,7 @@ pub fn draw_button_tooltip(
     let tooltip_w = [name_w, desc_w, cost_w].iter().fold(0.0_f32, |a, &b| a.max(b)) + pad * 2.0;
     
     // Вычисляем высоту тултипа
-    let mut tooltip_h = line_height * 2.0 + pad * 2.0; // название + описание
+    let mut tooltip_h = line_height * (1 + desc_lines.len()) as f32 + pad * 2.0; // название + описание
     if cost_opt.is_some() {
         tooltip_h += icon_size + pad; // строка с материалами
     }
@@ -886,12 +1029,14 @@ pub fn draw_button_tooltip(
     let mut text_y = tooltip_y + pad;
     
     // Название кнопки
-    gpu.draw_text(tooltip_x + pad, text_y, name.as_bytes(), [1.0, 1.0, 1.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, name, [1.0, 1.0, 1.0, 1.0], scale);
     text_y += line_height;
     
     // Описание
-    gpu.draw_text(tooltip_x + pad, text_y, description.as_bytes(), [0.8, 0.8, 0.8, 1.0], scale);
-    text_y += line_height;
+    for line in &desc_lines {
+        gpu.draw_text(tooltip_x + pad, text_y, line, [0.8, 0.8, 0.8, 1.0], scale);
+        text_y += line_height;
+    }
     
     // Стоимость материалов (с иконками) - только для кнопок строительства
     // Показываем красным цветом, если ресурсов не хватает
@@ -921,10 +1066,10 @@ pub fn draw_weather_tooltip(
     
     // Название погоды
     let (weather_name, weather_desc) = match weather {
-        WeatherKind::Clear => ("Clear", "Normal weather conditions."),
-        WeatherKind::Rain => ("Rain", "Rainy weather affects production."),
-        WeatherKind::Fog => ("Fog", "Foggy weather reduces visibility."),
-        WeatherKind::Snow => ("Snow", "Snowy weather slows down production."),
+        WeatherKind::Clear => (tr("weather_tooltip.clear"), tr("weather_tooltip.clear_desc")),
+        WeatherKind::Rain => (tr("weather_tooltip.rain"), tr("weather_tooltip.rain_desc")),
+        WeatherKind::Fog => (tr("weather_tooltip.fog"), tr("weather_tooltip.fog_desc")),
+        WeatherKind::Snow => (tr("weather_tooltip.snow"), tr("weather_tooltip.snow_desc")),
     };
     
     // Собираем список зданий с их модификаторами
@@ -940,35 +1085,23 @@ pub fn draw_weather_tooltip(
         BuildingKind::Kiln,
         BuildingKind::Smelter,
         BuildingKind::Fishery,
+        BuildingKind::CoalMine,
+        BuildingKind::GoldMine,
+        BuildingKind::GemMine,
+        BuildingKind::SaltWorks,
     ];
     
     let mut effects: Vec<(&str, f32)> = Vec::new();
     for &bk in buildings_list.iter() {
         let multiplier = production_weather_wmul(weather, bk);
         if multiplier != 1.0 {
-            let building_name = match bk {
-                BuildingKind::Lumberjack => "Lumberjack",
-                BuildingKind::Forester => "Forester",
-                BuildingKind::StoneQuarry => "Quarry",
-                BuildingKind::ClayPit => "Clay Pit",
-                BuildingKind::IronMine => "Iron Mine",
-                BuildingKind::WheatField => "Wheat Field",
-                BuildingKind::Mill => "Mill",
-                BuildingKind::Bakery => "Bakery",
-                BuildingKind::Kiln => "Kiln",
-                BuildingKind::Smelter => "Smelter",
-                BuildingKind::Fishery => "Fishery",
-                _ => "",
-            };
-            if !building_name.is_empty() {
-                effects.push((building_name, multiplier));
-            }
+            effects.push((ui::building_label(bk), multiplier));
         }
     }
     
     // Вычисляем размер тултипа
-    let name_w = weather_name.len() as f32 * 4.0 * 2.0 * scale;
-    let desc_w = weather_desc.len() as f32 * 4.0 * 2.0 * scale;
+    let name_w = font::text_width(weather_name, scale);
+    let desc_w = font::text_width(weather_desc, scale);
     
     // Максимальная ширина для эффектов
     let mut max_effect_w: f32 = 0.0;
@@ -978,7 +1111,7 @@ pub fn draw_weather_tooltip(
         } else {
             format!("-{}%", ((mult - 1.0) * 100.0).round() as i32)
         };
-        let effect_w = (name.len() + mult_text.len() + 2) as f32 * 4.0 * 2.0 * scale;
+        let effect_w = font::text_width(&format!("{}: {}", name, mult_text), scale);
         max_effect_w = max_effect_w.max(effect_w);
     }
     
@@ -997,11 +1130,11 @@ pub fn draw_weather_tooltip(
     let mut text_y = tooltip_y + pad;
     
     // Название погоды
-    gpu.draw_text(tooltip_x + pad, text_y, weather_name.as_bytes(), [1.0, 1.0, 1.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, weather_name, [1.0, 1.0, 1.0, 1.0], scale);
     text_y += line_height;
     
     // Описание
-    gpu.draw_text(tooltip_x + pad, text_y, weather_desc.as_bytes(), [0.8, 0.8, 0.8, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, weather_desc, [0.8, 0.8, 0.8, 1.0], scale);
     text_y += line_height;
     
     // Эффекты на производство
@@ -1022,12 +1155,12 @@ pub fn draw_weather_tooltip(
             };
             
             let effect_line = format!("{}: {}", name, mult_text);
-            gpu.draw_text(tooltip_x + pad, text_y, effect_line.as_bytes(), effect_color, scale);
+            gpu.draw_text(tooltip_x + pad, text_y, &effect_line, effect_color, scale);
             text_y += line_height;
         }
     } else {
         // Если нет эффектов (Clear)
-        gpu.draw_text(tooltip_x + pad, text_y, b"No production effects", [0.7, 0.7, 0.7, 1.0], scale);
+        gpu.draw_text(tooltip_x + pad, text_y, tr("weather_tooltip.no_effects"), [0.7, 0.7, 0.7, 1.0], scale);
     }
 }
 
@@ -1058,32 +1191,44 @@ pub fn draw_resource_tooltip(
     
     // Получаем информацию о ресурсе
     let (name, description, current_value) = match resource_name {
-        "Population" => ("Population", "Total number of citizens in your city.", population),
-        "Gold" => ("Gold", "Currency used for building construction and maintenance.", resources.gold),
-        "Happiness" => ("Happiness", "Overall citizen satisfaction. Affects productivity.", avg_happiness.round() as i32),
-        "Tax" => ("Tax Rate", "Percentage of income collected as taxes.", (tax_rate * 100.0).round() as i32),
-        "Idle" => ("Idle Citizens", "Citizens without assigned work.", citizens_idle),
-        "Working" => ("Working Citizens", "Citizens currently employed in buildings.", citizens_working),
-        "Sleeping" => ("Sleeping Citizens", "Citizens resting at home.", citizens_sleeping),
-        "Hauling" => ("Hauling Citizens", "Citizens transporting goods.", citizens_hauling),
-        "Fetching" => ("Fetching Citizens", "Citizens gathering resources.", citizens_fetching),
-        "Wood" => ("Wood", "Basic construction material. Produced by lumberjacks.", total_wood),
-        "Stone" => ("Stone", "Building material. Mined from stone quarries.", resources.stone),
-        "Clay" => ("Clay", "Raw material for bricks. Mined from clay pits.", resources.clay),
-        "Bricks" => ("Bricks", "Processed clay. Made in kilns.", resources.bricks),
-        "Wheat" => ("Wheat", "Grain crop. Grown in wheat fields.", resources.wheat),
-        "Flour" => ("Flour", "Processed wheat. Made in mills.", resources.flour),
-        "Bread" => ("Bread", "Food for citizens. Baked in bakeries.", resources.bread),
-        "Fish" => ("Fish", "Food for citizens. Caught by fisheries.", resources.fish),
-        "Iron Ore" => ("Iron Ore", "Raw metal. Mined from iron mines.", resources.iron_ore),
-        "Iron Ingots" => ("Iron Ingots", "Processed metal. Made in smelters.", resources.iron_ingots),
-        _ => (resource_name, "Resource information.", 0),
+        "Population" => (tr("resource.population"), tr("resource_desc.population"), population),
+        "Gold" => (tr("resource.gold"), tr("resource_desc.gold"), resources.gold),
+        "Happiness" => (tr("resource.happiness"), tr("resource_desc.happiness"), avg_happiness.round() as i32),
+        "Tax" => (tr("resource.tax"), tr("resource_desc.tax"), (tax_rate * 100.0).round() as i32),
+        "Idle" => (tr("resource.idle"), tr("resource_desc.idle"), citizens_idle),
+        "Working" => (tr("resource.working"), tr("resource_desc.working"), citizens_working),
+        "Sleeping" => (tr("resource.sleeping"), tr("resource_desc.sleeping"), citizens_sleeping),
+        "Hauling" => (tr("resource.hauling"), tr("resource_desc.hauling"), citizens_hauling),
+        "Fetching" => (tr("resource.fetching"), tr("resource_desc.fetching"), citizens_fetching),
+        "Wood" => (tr("resource.wood"), tr("resource_desc.wood"), total_wood),
+        "Stone" => (tr("resource.stone"), tr("resource_desc.stone"), resources.stone),
+        "Clay" => (tr("resource.clay"), tr("resource_desc.clay"), resources.clay),
+        "Bricks" => (tr("resource.bricks"), tr("resource_desc.bricks"), resources.bricks),
+        "Wheat" => (tr("resource.wheat"), tr("resource_desc.wheat"), resources.wheat),
+        "Flour" => (tr("resource.flour"), tr("resource_desc.flour"), resources.flour),
+        "Bread" => (tr("resource.bread"), tr("resource_desc.bread"), resources.bread),
+        "Fish" => (tr("resource.fish"), tr("resource_desc.fish"), resources.fish),
+        "Iron Ore" => (tr("resource.iron_ore"), tr("resource_desc.iron_ore"), resources.iron_ore),
+        "Iron Ingots" => (tr("resource.iron_ingots"), tr("resource_desc.iron_ingots"), resources.iron_ingots),
+        "Gold Ore" => (tr("resource.gold_ore"), tr("resource_desc.gold_ore"), resources.gold_ore),
+        "Coal" => (tr("resource.coal"), tr("resource_desc.coal"), resources.coal),
+        "Salt" => (tr("resource.salt"), tr("resource_desc.salt"), resources.salt),
+        "Gems" => (tr("resource.gems"), tr("resource_desc.gems"), resources.gems),
+        "Jewelry" => (tr("resource.jewelry"), tr("resource_desc.jewelry"), resources.jewelry),
+        "Charcoal" => (tr("resource.charcoal"), tr("resource_desc.charcoal"), resources.charcoal),
+        "Tools" => (tr("resource.tools"), tr("resource_desc.tools"), resources.tools),
+        "Wool" => (tr("resource.wool"), tr("resource_desc.wool"), resources.wool),
+        "Cloth" => (tr("resource.cloth"), tr("resource_desc.cloth"), resources.cloth),
+        "Clothes" => (tr("resource.clothes"), tr("resource_desc.clothes"), resources.clothes),
+        "Hops" => (tr("resource.hops"), tr("resource_desc.hops"), resources.hops),
+        "Ale" => (tr("resource.ale"), tr("resource_desc.ale"), resources.ale),
+        _ => (resource_name, tr("resource_desc.default"), 0),
     };
     
     // Вычисляем размер тултипа
-    let name_w = name.len() as f32 * 4.0 * 2.0 * scale;
-    let desc_w = description.len() as f32 * 4.0 * 2.0 * scale;
-    let value_w = format!("Current: {}", current_value).len() as f32 * 4.0 * 2.0 * scale;
+    let name_w = font::text_width(name, scale);
+    let desc_w = font::text_width(description, scale);
+    let value_w = font::text_width(&format!("{}: {}", tr("tooltip.current"), current_value), scale);
     
     let tooltip_w = [name_w, desc_w, value_w].iter().fold(0.0_f32, |a, &b| a.max(b)) + pad * 2.0;
     let tooltip_h = line_height * 3.0 + pad * 2.0;
@@ -1100,16 +1245,16 @@ pub fn draw_resource_tooltip(
     let mut text_y = tooltip_y + pad;
     
     // Название ресурса
-    gpu.draw_text(tooltip_x + pad, text_y, name.as_bytes(), [1.0, 1.0, 1.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, name, [1.0, 1.0, 1.0, 1.0], scale);
     text_y += line_height;
     
     // Описание
-    gpu.draw_text(tooltip_x + pad, text_y, description.as_bytes(), [0.8, 0.8, 0.8, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, description, [0.8, 0.8, 0.8, 1.0], scale);
     text_y += line_height;
     
     // Текущее значение
-    let value_text = format!("Current: {}", current_value);
-    gpu.draw_text(tooltip_x + pad, text_y, value_text.as_bytes(), [1.0, 1.0, 0.7, 1.0], scale);
+    let value_text = format!("{}: {}", tr("tooltip.current"), current_value);
+    gpu.draw_text(tooltip_x + pad, text_y, &value_text, [1.0, 1.0, 0.7, 1.0], scale);
 }
 
 /// Рендеринг консоли разработчика
@@ -1131,18 +1276,20 @@ pub fn draw_console_gpu(
     // Фон консоли
     gpu.add_ui_rect(0.0, y0 as f32, fw as f32, height as f32, [0.0, 0.0, 0.0, 0.7]);
     
-    // Последние строки лога
-    let start = log.len().saturating_sub(lines_visible);
+    // Последние строки лога (длинные переносятся по ширине консоли)
+    let max_w = (fw - pad * 2) as f32;
+    let wrapped: Vec<String> = log[log.len().saturating_sub(lines_visible)..].iter().flat_map(|line| font::wrap(line, max_w, s as f32)).collect();
+    let start = wrapped.len().saturating_sub(lines_visible);
     let mut y = y0 + pad;
-    for line in &log[start..] {
-        gpu.draw_text(pad as f32, y as f32, line.as_bytes(), [0.86, 0.86, 0.86, 1.0], s as f32);
+    for line in &wrapped[start..] {
+        gpu.draw_text(pad as f32, y as f32, line, [0.86, 0.86, 0.86, 1.0], s as f32);
         y += line_h;
     }
     
     // Строка ввода с префиксом
-    gpu.draw_text(pad as f32, y as f32, b"> ", [0.86, 0.86, 0.7, 1.0], s as f32);
-    let prefix_w = ui::text_w(b"> ", s);
-    gpu.draw_text((pad + prefix_w) as f32, y as f32, input.as_bytes(), [0.9, 0.9, 0.9, 1.0], s as f32);
+    gpu.draw_text(pad as f32, y as f32, "> ", [0.86, 0.86, 0.7, 1.0], s as f32);
+    let prefix_w = ui::text_w("> ", s);
+    gpu.draw_text((pad + prefix_w) as f32, y as f32, input, [0.9, 0.9, 0.9, 1.0], s as f32);
 }
 
 /// Преобразование экранных координат в координаты тайла
@@ -1189,14 +1336,14 @@ pub fn draw_biome_debug_tooltip(
     let line_height = 16.0 * scale;
     
     // Текст тултипа
-    let title = "Biome Debug";
-    let biome_text = format!("Biome: {}", biome_name);
-    let pos_text = format!("Position: ({}, {})", tile_x, tile_y);
+    let title = tr("biome.debug_title");
+    let biome_text = tr_fmt("biome.biome", &[&biome_name]);
+    let pos_text = tr_fmt("biome.position", &[&tile_x, &tile_y]);
     
     // Вычисляем размеры тултипа
-    let title_w = ui::text_w(title.as_bytes(), scale as i32) as f32;
-    let biome_w = ui::text_w(biome_text.as_bytes(), scale as i32) as f32;
-    let pos_w = ui::text_w(pos_text.as_bytes(), scale as i32) as f32;
+    let title_w = ui::text_w(title, scale as i32) as f32;
+    let biome_w = ui::text_w(&biome_text, scale as i32) as f32;
+    let pos_w = ui::text_w(&pos_text, scale as i32) as f32;
     let tooltip_w = (title_w.max(biome_w).max(pos_w) + pad * 2.0).max(120.0);
     let tooltip_h = line_height * 3.0 + pad * 2.0;
     
@@ -1220,15 +1367,55 @@ pub fn draw_biome_debug_tooltip(
     let mut text_y = tooltip_y + pad;
     
     // Заголовок
-    gpu.draw_text(tooltip_x + pad, text_y, title.as_bytes(), [1.0, 1.0, 0.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, title, [1.0, 1.0, 0.0, 1.0], scale);
     text_y += line_height;
     
     // Биом
-    gpu.draw_text(tooltip_x + pad, text_y, biome_text.as_bytes(), [0.8, 1.0, 0.8, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, &biome_text, [0.8, 1.0, 0.8, 1.0], scale);
     text_y += line_height;
     
     // Позиция
-    gpu.draw_text(tooltip_x + pad, text_y, pos_text.as_bytes(), [0.8, 0.8, 1.0, 1.0], scale);
+    gpu.draw_text(tooltip_x + pad, text_y, &pos_text, [0.8, 0.8, 1.0, 1.0], scale);
+}
+
+/// Рендеринг тултипа разведки месторождений
+pub fn draw_prospecting_tooltip(
+    gpu: &mut GpuRenderer,
+    x: f32,
+    y: f32,
+    lines: &[String],
+    scale: f32,
+    screen_width: f32,
+) {
+    gpu.ensure_tooltip_layer();
+    let pad = 8.0 * scale;
+    let line_height = 16.0 * scale;
+    let title = tr("deposit.prospecting");
+
+    let text_w = lines.iter()
+        .map(|l| ui::text_w(l, scale as i32) as f32)
+        .fold(ui::text_w(title, scale as i32) as f32, f32::max);
+    let tooltip_w = (text_w + pad * 2.0).max(120.0);
+    let tooltip_h = line_height * (lines.len() + 1) as f32 + pad * 2.0;
+
+    let mut tooltip_x = x + 10.0;
+    let mut tooltip_y = y - tooltip_h - 10.0;
+    if tooltip_x + tooltip_w > screen_width {
+        tooltip_x = screen_width - tooltip_w - 10.0;
+    }
+    if tooltip_y < 0.0 {
+        tooltip_y = y + 10.0;
+    }
+
+    gpu.add_ui_rect(tooltip_x, tooltip_y, tooltip_w, tooltip_h, [0.0, 0.0, 0.0, 0.8]);
+    gpu.add_ui_rect(tooltip_x + 1.0, tooltip_y + 1.0, tooltip_w - 2.0, tooltip_h - 2.0, [0.2, 0.2, 0.2, 0.9]);
+
+    let mut text_y = tooltip_y + pad;
+    gpu.draw_text(tooltip_x + pad, text_y, title, [1.0, 1.0, 0.0, 1.0], scale);
+    for line in lines {
+        text_y += line_height;
+        gpu.draw_text(tooltip_x + pad, text_y, line, [0.9, 0.9, 0.9, 1.0], scale);
+    }
 }
 
 /// Рисование окна дерева исследований
@@ -1237,13 +1424,13 @@ pub fn draw_research_tree_gpu(
     fw: i32,
     fh: i32,
     research_system: &crate::research::ResearchSystem,
-    _resources: &Resources,
+    resources: &Resources,
     base_scale_k: f32,
     cursor_x: i32,
     cursor_y: i32,
     scroll_offset: f32,
-) -> Option<crate::research::ResearchKind> {
-    use crate::research::{ResearchKind, ResearchStatus};
+) -> Option<String> {
+    use crate::research::ResearchStatus;
     
     // Фиксируем границу: все элементы до этого момента - обычный UI,
     // все элементы после - окно дерева исследований
@@ -1323,12 +1510,12 @@ pub fn draw_research_tree_gpu(
     
     // Заголовок
     let title = if research_system.has_research_lab {
-        "RESEARCH TREE"
+        tr("research.title")
     } else {
-        "BUILD A LABORATORY FOR RESEARCH"
+        tr("research.no_lab")
     };
     
-    gpu.draw_text(window_x + pad, window_y + pad, title.as_bytes(), [1.0, 1.0, 0.8, 1.0], scale * 1.5);
+    gpu.draw_text(window_x + pad, window_y + pad, title, [1.0, 1.0, 0.8, 1.0], scale * 1.5);
     
     // Кнопка закрытия в стиле обычных кнопок
     let close_btn_size = (20 * s) as f32;
@@ -1338,7 +1525,7 @@ pub fn draw_research_tree_gpu(
     let is_close_hovered = cursor_x >= close_btn_x as i32 && cursor_x < (close_btn_x + close_btn_size) as i32
         && cursor_y >= close_btn_y as i32 && cursor_y < (close_btn_y + close_btn_size) as i32;
     
-    gpu.draw_button(close_btn_x, close_btn_y, close_btn_size, close_btn_size, b"X", is_close_hovered, scale);
+    gpu.draw_button(close_btn_x, close_btn_y, close_btn_size, close_btn_size, "X", is_close_hovered, scale);
     
     if !research_system.has_research_lab {
         return None;
@@ -1346,8 +1533,8 @@ pub fn draw_research_tree_gpu(
     
     // Информация об активном исследовании с улучшенным дизайном
     let info_y = window_y + pad + title_height + pad + 12.0;
-    if let Some(ref active) = research_system.active_research {
-        let info = active.kind.info();
+    if let Some(ref active) = research_system.active_research
+        && let Some(info) = crate::research::def(&active.kind) {
         
         let text_w = window_w - pad * 2.0;
         let info_h = (32 * s) as f32;
@@ -1356,14 +1543,22 @@ pub fn draw_research_tree_gpu(
         gpu.add_ui_rect(window_x + pad, info_y, text_w, info_h, [0.0, 0.0, 0.0, 0.6]);
         
         // Название исследования
-        let progress_text = format!("ACTIVE: {}", info.name.to_uppercase());
+        let progress_text = format!("{} {}", tr("research.active"), info.display_name().to_uppercase());
         gpu.draw_text(window_x + pad + 8.0, info_y + 4.0, 
-                      progress_text.as_bytes(), [1.0, 1.0, 0.8, 1.0], scale);
+                      &progress_text, [1.0, 1.0, 0.8, 1.0], scale);
+        
+        // Справа: скорость лабораторий и следующее в очереди
+        let rate_text = match research_system.queue.first() {
+            Some(next) => tr_fmt("research.rate_next", &[&research_system.points_per_day, &crate::research::name(next)]),
+            None => tr_fmt("research.rate", &[&research_system.points_per_day]),
+        };
+        let rate_x = window_x + pad + text_w - 8.0 - font::text_width(&rate_text, scale * 0.8);
+        gpu.draw_text(rate_x, info_y + 6.0, &rate_text, [0.8, 0.8, 0.8, 1.0], scale * 0.8);
         
         // Прогресс бар в стиле интерфейса
-        let total_days = info.days_required;
-        let progress = if total_days > 0 {
-            1.0 - (active.days_remaining as f32 / total_days as f32)
+        let total_points = info.points;
+        let progress = if total_points > 0 {
+            (active.progress as f32 / total_points as f32).min(1.0)
         } else {
             1.0
         };
@@ -1381,10 +1576,9 @@ pub fn draw_research_tree_gpu(
         }
         
         // Текст прогресса
-        let days_text = format!("{}/{} days ({}%)", 
-            total_days - active.days_remaining, total_days, (progress * 100.0) as i32);
-        let text_x = window_x + pad + 8.0 + (bar_w - days_text.len() as f32 * 4.0 * scale * 0.8) / 2.0;
-        gpu.draw_text(text_x, bar_y + 1.0, days_text.as_bytes(), [1.0, 1.0, 1.0, 1.0], scale * 0.8);
+        let days_text = tr_fmt("research.progress", &[&active.progress, &total_points, &((progress * 100.0) as i32)]);
+        let text_x = window_x + pad + 8.0 + (bar_w - font::text_width(&days_text, scale * 0.8)) / 2.0;
+        gpu.draw_text(text_x, bar_y + 1.0, &days_text, [1.0, 1.0, 1.0, 1.0], scale * 0.8);
     } else {
         // Подсказка когда нет активного исследования
         let hint_h = (20 * s) as f32;
@@ -1392,10 +1586,17 @@ pub fn draw_research_tree_gpu(
         
         gpu.add_ui_rect(window_x + pad, info_y, text_w, hint_h, [0.0, 0.0, 0.0, 0.4]);
         
-        let hint = "Select an available research to begin...";
-        let hint_x = window_x + pad + (text_w - hint.len() as f32 * 4.0 * scale * 0.8) / 2.0;
+        // очередь ждёт ресурсов на стоимость первого доступного исследования
+        let waiting = research_system.queue.iter()
+            .find(|k| research_system.get_status(k) == ResearchStatus::Available)
+            .filter(|k| !crate::research::can_afford(k, resources, &[]));
+        let hint = match waiting {
+            Some(k) => tr_fmt("research.waiting", &[&crate::research::name(k)]),
+            None => tr("research.select_hint").to_string(),
+        };
+        let hint_x = window_x + pad + (text_w - font::text_width(&hint, scale * 0.8)) / 2.0;
         gpu.draw_text(hint_x, info_y + 6.0, 
-                      hint.as_bytes(), [0.7, 0.7, 0.7, 1.0], scale * 0.8);
+                      &hint, [0.7, 0.7, 0.7, 1.0], scale * 0.8);
     }
     
     // Дерево исследований
@@ -1405,8 +1606,8 @@ pub fn draw_research_tree_gpu(
     let gap_x = (35 * s) as f32;   // Горизонтальный отступ между узлами
     let gap_y = (45 * s) as f32;   // Вертикальный отступ между узлами
     
-    // Вычисляем общую ширину дерева
-    let tree_cols = 4; // Максимум 4 колонки (от 0 до 3)
+    // Вычисляем общую ширину дерева (колонки задаются в assets/research.toml)
+    let tree_cols = crate::research::tree().iter().map(|d| d.column + 1).max().unwrap_or(1);
     let total_tree_width = (tree_cols as f32 * node_w) + ((tree_cols - 1) as f32 * gap_x);
     
     // Центрируем дерево по горизонтали
@@ -1420,13 +1621,7 @@ pub fn draw_research_tree_gpu(
     let tree_area_right = window_x + window_w - pad;
     
     // Вычисляем максимальную высоту дерева
-    let mut max_row = 0;
-    for &research_kind in ResearchKind::all() {
-        let (_, row) = research_kind.tree_position();
-        if row > max_row {
-            max_row = row;
-        }
-    }
+    let max_row = crate::research::tree().iter().map(|d| d.row).max().unwrap_or(0);
     // Высота дерева: позиция верхней границы последнего узла + высота узла
     // Позиция последнего узла: max_row * (node_h + gap_y)
     // Нижняя граница последнего узла: max_row * (node_h + gap_y) + node_h
@@ -1448,20 +1643,20 @@ pub fn draw_research_tree_gpu(
     );
     
     // Сначала рисуем все линии связей (чтобы они были под узлами)
-    for &research_kind in ResearchKind::all() {
-        let (col, row) = research_kind.tree_position();
-        let status = research_system.get_status(research_kind);
-        let info = research_kind.info();
+    for info in crate::research::tree() {
+        let (col, row) = (info.column, info.row);
+        let status = research_system.get_status(&info.key);
         
-        if status == ResearchStatus::Completed && info.days_required == 0 {
+        if status == ResearchStatus::Completed && info.points == 0 {
             continue;
         }
         
         let node_x = tree_area_left + (col as f32) * (node_w + gap_x);
         let node_y = tree_start_y + (row as f32) * (node_h + gap_y) - scroll_offset;
         
-        for &prereq in info.prerequisites {
-            let (prereq_col, prereq_row) = prereq.tree_position();
+        for prereq in &info.prerequisites {
+            let Some(prereq_def) = crate::research::def(prereq) else { continue; };
+            let (prereq_col, prereq_row) = (prereq_def.column, prereq_def.row);
             let prereq_x = tree_area_left + (prereq_col as f32) * (node_w + gap_x) + node_w / 2.0;
             let prereq_y = tree_start_y + (prereq_row as f32) * (node_h + gap_y) + node_h - scroll_offset;
             let current_x = node_x + node_w / 2.0;
@@ -1491,12 +1686,11 @@ pub fn draw_research_tree_gpu(
     }
     
     // Теперь рисуем узлы
-    for &research_kind in ResearchKind::all() {
-        let (col, row) = research_kind.tree_position();
-        let status = research_system.get_status(research_kind);
-        let info = research_kind.info();
+    for info in crate::research::tree() {
+        let (col, row) = (info.column, info.row);
+        let status = research_system.get_status(&info.key);
         
-        if status == ResearchStatus::Completed && info.days_required == 0 {
+        if status == ResearchStatus::Completed && info.points == 0 {
             continue;
         }
         
@@ -1512,35 +1706,40 @@ pub fn draw_research_tree_gpu(
             && cursor_y >= node_y as i32 && cursor_y < (node_y + node_h) as i32;
         
         if is_hovered {
-            hovered_research = Some((research_kind, status, node_x, node_y + node_h));
+            hovered_research = Some((info, status, node_x, node_y + node_h));
         }
         
+        // Место в очереди исследований
+        let queue_pos = research_system.queue.iter().position(|k| *k == info.key);
+        let queued_text = queue_pos.map(|i| tr_fmt("research.queued", &[&(i + 1)]));
+        
         // Цвета в зависимости от статуса
         let (status_bg, text_color, status_text) = match status {
             ResearchStatus::Locked => (
                 [0.25, 0.25, 0.25, 0.85], // Серый
                 [1.0, 1.0, 1.0, 1.0],
-                "LOCKED",
+                tr("research.locked"),
             ),
             ResearchStatus::Available => (
                 [0.90, 0.78, 0.22, 0.90], // Жёлтый
                 [1.0, 1.0, 1.0, 1.0],
-                "READY",
+                tr("research.ready"),
             ),
             ResearchStatus::InProgress => (
                 [0.30, 0.70, 0.32, 0.90], // Зелёный активный
                 [1.0, 1.0, 1.0, 1.0],
-                "ACTIVE",
+                tr("research.active"),
             ),
             ResearchStatus::Completed => (
                 [0.22, 0.60, 0.26, 0.95], // Зелёный завершённый
                 [1.0, 1.0, 1.0, 1.0],
-                "DONE",
+                tr("research.done"),
             ),
         };
         
         // Используем стиль кнопок интерфейса
-        let is_active = status == ResearchStatus::Available || status == ResearchStatus::InProgress;
+        let is_active = status == ResearchStatus::Available || status == ResearchStatus::InProgress
+            || queue_pos.is_some() || research_system.can_queue(&info.key);
         let btn_h = node_h;
         
         // Обводка/тень для читаемости на деревянном фоне
@@ -1553,7 +1752,7 @@ pub fn draw_research_tree_gpu(
         );
         
         // Рисуем узел как кнопку
-        gpu.draw_button(node_x, node_y, node_w, btn_h, b"", is_active && is_hovered, scale);
+        gpu.draw_button(node_x, node_y, node_w, btn_h, "", is_active && is_hovered, scale);
         // Заливаем статусным цветом, чтобы оттенок был очевиден
         gpu.add_ui_rect(node_x, node_y, node_w, btn_h, status_bg);
         
@@ -1571,13 +1770,14 @@ pub fn draw_research_tree_gpu(
         
         // Статус в верхней части
         let status_y = inner_y;
-        gpu.draw_text(inner_x, status_y, status_text.as_bytes(), text_color, scale * 0.7);
+        let status_text = queued_text.as_deref().unwrap_or(status_text);
+        gpu.draw_text(inner_x, status_y, status_text, text_color, scale * 0.7);
         
         // Название
-        let name_lines = split_text(info.name, ((inner_w) / (4.0 * scale * 0.85)) as usize);
+        let name_lines = split_text(info.display_name(), ((inner_w) / (4.0 * scale * 0.85)) as usize);
         let mut text_y = inner_y + (12 * s) as f32;
         for line in name_lines.iter().take(2) {
-            gpu.draw_text(inner_x, text_y, line.as_bytes(), text_color, scale * 0.85);
+            gpu.draw_text(inner_x, text_y, line, text_color, scale * 0.85);
             text_y += (11 * s) as f32;
         }
         
@@ -1585,7 +1785,7 @@ pub fn draw_research_tree_gpu(
         let separator_y = inner_y + inner_h * 0.6;
         gpu.add_ui_rect(inner_x, separator_y, inner_w, 1.0, [1.0, 1.0, 1.0, 0.2]);
         
-        // Информация о стоимости и времени
+        // Информация о стоимости и очках
         if status != ResearchStatus::Completed {
             // Секция ресурсов
             let cost_y = separator_y + (4 * s) as f32;
@@ -1593,17 +1793,17 @@ pub fn draw_research_tree_gpu(
             // Используем функцию draw_resources_list для единообразия
             draw_resources_list(gpu, inner_x, cost_y, &info.cost, scale * 0.7, None);
             
-            // Время исследования
-            if info.days_required > 0 {
+            // Очки исследования
+            if info.points > 0 {
                 let time_y = cost_y + (14 * s) as f32;
-                let time_text = format!("{} days", info.days_required);
+                let time_text = tr_fmt("research.pts", &[&info.points]);
                 let time_x = inner_x;
-                gpu.draw_text(time_x, time_y, time_text.as_bytes(), text_color, scale * 0.7);
+                gpu.draw_text(time_x, time_y, &time_text, text_color, scale * 0.7);
             }
         } else {
             // Для завершенных исследований показываем только статус
             let done_y = separator_y + (4 * s) as f32;
-            gpu.draw_text(inner_x, done_y, b"COMPLETED", text_color, scale * 0.7);
+            gpu.draw_text(inner_x, done_y, tr("research.completed"), text_color, scale * 0.7);
         }
         
     }
@@ -1616,36 +1816,35 @@ pub fn draw_research_tree_gpu(
     gpu.start_tooltips();
     
     // Компактный тултип для наведенного исследования
-    if let Some((kind, status, _x, _y)) = hovered_research {
-        let info = kind.info();
+    if let Some((info, status, _x, _y)) = hovered_research {
         
         let tooltip_pad = (6 * s) as f32;
         let section_gap = (4 * s) as f32;
         
         // Вычисляем ширину тултипа на основе содержимого
         let status_text = match status {
-            ResearchStatus::Available => "READY",
-            ResearchStatus::InProgress => "ACTIVE",
-            ResearchStatus::Completed => "DONE",
-            ResearchStatus::Locked => "LOCKED",
+            ResearchStatus::Available => tr("research.ready"),
+            ResearchStatus::InProgress => tr("research.active"),
+            ResearchStatus::Completed => tr("research.done"),
+            ResearchStatus::Locked => tr("research.locked"),
         };
         
         // Ширина названия + статуса (статус справа, поэтому нужна полная ширина)
-        let name_w = info.name.len() as f32 * 4.0 * 2.0 * scale;
-        let status_w = status_text.len() as f32 * 4.0 * 2.0 * scale * 0.7;
+        let name_w = font::text_width(info.display_name(), scale);
+        let status_w = font::text_width(status_text, scale * 0.7);
         // Статус рисуется справа, поэтому нужна полная ширина: название + отступ + статус + отступ
         let header_w = name_w + tooltip_pad + status_w + tooltip_pad;
         
         // Ширина описания
-        let desc_w = info.description.len() as f32 * 4.0 * 2.0 * scale * 0.8;
+        let desc_w = font::text_width(info.display_description(), scale * 0.8);
         
         // Ширина разблокирует
         let mut unlocks_w = 0.0;
         if !info.unlocks_buildings.is_empty() {
-            unlocks_w = "UNLOCKS:".len() as f32 * 4.0 * 2.0 * scale;
-            for building in info.unlocks_buildings {
+            unlocks_w = font::text_width(tr("research.unlocks"), scale);
+            for building in &info.unlocks_buildings {
                 let building_name = format!("{:?}", building);
-                let building_w = building_name.len() as f32 * 4.0 * 2.0 * scale * 0.8 + 8.0; // +8 для отступа
+                let building_w = font::text_width(&building_name, scale * 0.8) + 8.0; // +8 для отступа
                 unlocks_w = unlocks_w.max(building_w);
             }
         }
@@ -1653,14 +1852,23 @@ pub fn draw_research_tree_gpu(
         // Ширина требований
         let mut requires_w = 0.0;
         if !info.prerequisites.is_empty() {
-            requires_w = "REQUIRES:".len() as f32 * 4.0 * 2.0 * scale;
-            for prereq in info.prerequisites {
-                let prereq_info = prereq.info();
-                let prereq_w = prereq_info.name.len() as f32 * 4.0 * 2.0 * scale * 0.8 + 8.0; // +8 для отступа
+            requires_w = font::text_width(tr("research.requires"), scale);
+            for prereq in &info.prerequisites {
+                let prereq_w = font::text_width(crate::research::name(prereq), scale * 0.8) + 8.0; // +8 для отступа
                 requires_w = requires_w.max(prereq_w);
             }
         }
         
+        // Ширина эффектов
+        let mut effects_w = 0.0;
+        if !info.effects.is_empty() {
+            effects_w = font::text_width(tr("research.effects"), scale);
+            for effect in &info.effects {
+                let effect_w = font::text_width(&effect.label(), scale * 0.8) + 8.0;
+                effects_w = effects_w.max(effect_w);
+            }
+        }
+        
         // Ширина стоимости
         let cost_w = if status != ResearchStatus::Completed {
             calculate_resources_list_width(&info.cost, scale)
@@ -1668,15 +1876,15 @@ pub fn draw_research_tree_gpu(
             0.0
         };
         
-        // Ширина времени
-        let time_w = if status != ResearchStatus::Completed && info.days_required > 0 {
-            format!("{} days", info.days_required).len() as f32 * 4.0 * 2.0 * scale
+        // Ширина очков
+        let time_w = if status != ResearchStatus::Completed && info.points > 0 {
+            font::text_width(&tr_fmt("research.points", &[&info.points]), scale)
         } else {
             0.0
         };
         
         // Максимальная ширина из всех элементов
-        let tooltip_w = [header_w, desc_w, unlocks_w, requires_w, cost_w, time_w]
+        let tooltip_w = [header_w, desc_w, unlocks_w, effects_w, requires_w, cost_w, time_w]
             .iter()
             .fold(0.0_f32, |a, &b| a.max(b))
             + tooltip_pad * 2.0;
@@ -1691,8 +1899,9 @@ pub fn draw_research_tree_gpu(
         let header_h = (12 * s) as f32;
         tooltip_h += header_h + section_gap;
         
-        // Описание (одна строка, обрезается если нужно)
-        let desc_h = (12 * s) as f32;
+        // Описание переносится по ширине тултипа
+        let desc_lines = font::wrap(info.display_description(), tooltip_w - tooltip_pad * 2.0, scale * 0.8);
+        let desc_h = desc_lines.len() as f32 * (12 * s) as f32;
         tooltip_h += desc_h + section_gap;
         
         // Разблокирует (если есть)
@@ -1700,6 +1909,11 @@ pub fn draw_research_tree_gpu(
             tooltip_h += (12 * s) as f32 + (info.unlocks_buildings.len() as f32 * (12 * s) as f32) + section_gap;
         }
         
+        // Эффекты (если есть)
+        if !info.effects.is_empty() {
+            tooltip_h += (12 * s) as f32 + (info.effects.len() as f32 * (12 * s) as f32) + section_gap;
+        }
+        
         // Требования (если есть)
         if !info.prerequisites.is_empty() {
             tooltip_h += (12 * s) as f32 + (info.prerequisites.len() as f32 * (12 * s) as f32) + section_gap;
@@ -1708,8 +1922,8 @@ pub fn draw_research_tree_gpu(
         // Стоимость и время
         if status != ResearchStatus::Completed {
             tooltip_h += (14 * s) as f32; // ресурсы
-            if info.days_required > 0 {
-                tooltip_h += (12 * s) as f32; // время
+            if info.points > 0 {
+                tooltip_h += (12 * s) as f32; // очки
             }
         }
         
@@ -1725,38 +1939,47 @@ pub fn draw_research_tree_gpu(
         // === ЗАГОЛОВОК ===
         // Название исследования
         gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
-                      info.name.as_bytes(), [1.0, 1.0, 0.8, 1.0], scale);
+                      info.display_name(), [1.0, 1.0, 0.8, 1.0], scale);
         
         // Статус справа
-        // Ширина текста: len * 4.0 * px, где px = 2.0 * scale
-        // Для масштаба 0.7: len * 4.0 * 2.0 * scale * 0.7
-        let status_text_w = status_text.len() as f32 * 4.0 * 2.0 * scale * 0.7;
+        let status_text_w = font::text_width(status_text, scale * 0.7);
         let status_x = tooltip_x + tooltip_w - tooltip_pad - status_text_w;
         gpu.draw_text(status_x, current_y, 
-                      status_text.as_bytes(), [0.8, 0.8, 0.8, 1.0], scale * 0.7);
+                      status_text, [0.8, 0.8, 0.8, 1.0], scale * 0.7);
         current_y += (12 * s) as f32 + section_gap;
         
         // === ОПИСАНИЕ ===
-        let max_desc_len = ((tooltip_w - tooltip_pad * 2.0) / (4.0 * scale * 0.8)) as usize;
-        let desc = if info.description.len() > max_desc_len {
-            format!("{}...", &info.description[..max_desc_len - 3])
-        } else {
-            info.description.to_string()
-        };
-        gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
-                      desc.as_bytes(), [0.8, 0.8, 0.8, 1.0], scale * 0.8);
-        current_y += desc_h + section_gap;
+        for line in &desc_lines {
+            gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
+                          line, [0.8, 0.8, 0.8, 1.0], scale * 0.8);
+            current_y += (12 * s) as f32;
+        }
+        current_y += section_gap;
         
         // === РАЗБЛОКИРУЕТ ===
         if !info.unlocks_buildings.is_empty() {
             gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
-                          b"UNLOCKS:", [0.7, 1.0, 0.7, 1.0], scale);
+                          tr("research.unlocks"), [0.7, 1.0, 0.7, 1.0], scale);
             current_y += (12 * s) as f32;
             
-            for building in info.unlocks_buildings {
+            for building in &info.unlocks_buildings {
                 let building_name = format!("{:?}", building);
                 gpu.draw_text(tooltip_x + tooltip_pad + 8.0, current_y, 
-                              building_name.as_bytes(), [0.9, 0.9, 0.9, 1.0], scale * 0.8);
+                              &building_name, [0.9, 0.9, 0.9, 1.0], scale * 0.8);
+                current_y += (12 * s) as f32;
+            }
+            current_y += section_gap;
+        }
+        
+        // === ЭФФЕКТЫ ===
+        if !info.effects.is_empty() {
+            gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
+                          tr("research.effects"), [0.7, 0.85, 1.0, 1.0], scale);
+            current_y += (12 * s) as f32;
+            
+            for effect in &info.effects {
+                gpu.draw_text(tooltip_x + tooltip_pad + 8.0, current_y, 
+                              &effect.label(), [0.9, 0.9, 0.9, 1.0], scale * 0.8);
                 current_y += (12 * s) as f32;
             }
             current_y += section_gap;
@@ -1765,12 +1988,11 @@ pub fn draw_research_tree_gpu(
         // === ТРЕБОВАНИЯ ===
         if !info.prerequisites.is_empty() {
             gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
-                          b"REQUIRES:", [1.0, 0.7, 0.7, 1.0], scale);
+                          tr("research.requires"), [1.0, 0.7, 0.7, 1.0], scale);
             current_y += (12 * s) as f32;
             
-            for prereq in info.prerequisites {
-                let prereq_info = prereq.info();
-                let prereq_status = research_system.get_status(*prereq);
+            for prereq in &info.prerequisites {
+                let prereq_status = research_system.get_status(prereq);
                 let prereq_done = prereq_status == ResearchStatus::Completed;
                 
                 let text_color = if prereq_done { 
@@ -1779,23 +2001,23 @@ pub fn draw_research_tree_gpu(
                     [1.0, 0.7, 0.7, 1.0] 
                 };
                 gpu.draw_text(tooltip_x + tooltip_pad + 8.0, current_y, 
-                              prereq_info.name.as_bytes(), text_color, scale * 0.8);
+                              crate::research::name(prereq), text_color, scale * 0.8);
                 current_y += (12 * s) as f32;
             }
             current_y += section_gap;
         }
         
-        // === СТОИМОСТЬ И ВРЕМЯ ===
+        // === СТОИМОСТЬ И ОЧКИ ===
         if status != ResearchStatus::Completed {
             // Используем функцию draw_resources_list для единообразия
             draw_resources_list(gpu, tooltip_x + tooltip_pad, current_y, &info.cost, scale, None);
             current_y += (14 * s) as f32;
             
-            // Время исследования
-            if info.days_required > 0 {
-                let time_text = format!("{} days", info.days_required);
+            // Очки исследования
+            if info.points > 0 {
+                let time_text = tr_fmt("research.points", &[&info.points]);
                 gpu.draw_text(tooltip_x + tooltip_pad, current_y, 
-                              time_text.as_bytes(), [1.0, 1.0, 0.7, 1.0], scale);
+                              &time_text, [1.0, 1.0, 0.7, 1.0], scale);
             }
         }
     }
@@ -1838,6 +2060,423 @@ pub fn draw_research_tree_gpu(
     None // Функция больше не возвращает клики, только рендерит
 }
 
+/// Строки окна торговли: редкие товары — только когда они есть или на них стоит приказ
+pub fn trade_panel_rows(trade: &crate::trade::TradeSystem, visible: &Resources) -> Vec<usize> {
+    (0..trade.market.len())
+        .filter(|&i| {
+            let e = &trade.market[i];
+            !is_rare_resource(crate::trade::label(e.kind))
+                || crate::resource_visitor::get_resource_value(visible, e.kind) > 0
+                || e.sell_above.is_some()
+                || e.buy_below.is_some()
+        })
+        .collect()
+}
+
+/// Окно торговли: цены купца, график цен за последние дни и постоянные приказы
+pub fn draw_trade_panel_gpu(
+    gpu: &mut GpuRenderer,
+    fw: i32,
+    fh: i32,
+    trade: &crate::trade::TradeSystem,
+    visible: &Resources,
+    base_scale_k: f32,
+    cursor: glam::IVec2,
+) {
+    // окно рисуется поверх обычного UI (тот же слой, что у дерева исследований)
+    gpu.start_research_tree();
+
+    let s = ui::ui_scale(fh, base_scale_k);
+    let scale = s as f32;
+    let l = ui::layout_trade_panel(fw, fh, s);
+    let (x, y, w, h) = (l.x as f32, l.y as f32, l.w as f32, l.h as f32);
+    let pad = (16 * s) as f32;
+
+    gpu.add_ui_rect(0.0, 0.0, fw as f32, fh as f32, [0.0, 0.0, 0.0, 0.7]);
+    gpu.add_ui_rect(x, y, w, h, [0.25, 0.18, 0.12, 1.0]);
+    let border = (6 * s) as f32;
+    gpu.add_ui_rect(x + border, y + border, w - border * 2.0, h - border * 2.0, [0.45, 0.32, 0.22, 0.95]);
+
+    gpu.draw_text(x + pad, y + pad, tr("trade.title"), [1.0, 1.0, 0.8, 1.0], scale * 1.5);
+    let hovered = |bx: i32, by: i32, bw: i32, bh: i32| ui::point_in_rect(cursor.x, cursor.y, bx, by, bw, bh);
+    gpu.draw_button(l.close_x as f32, l.close_y as f32, l.close_size as f32, l.close_size as f32, "X",
+        hovered(l.close_x, l.close_y, l.close_size, l.close_size), scale);
+
+    // строка купца
+    let info_y = y + pad + (28 * s) as f32;
+    let info = if trade.days_to_merchant <= 1 {
+        tr("trade.merchant_dawn").to_string()
+    } else {
+        tr_fmt("trade.merchant_in", &[&trade.days_to_merchant])
+    };
+    gpu.draw_text(x + pad, info_y, &info, [0.9, 0.9, 0.9, 1.0], scale);
+
+    // шапка таблицы
+    let head_y = (l.rows_y - ui::ui_item_h(s)) as f32;
+    let head_col = [1.0, 0.9, 0.6, 1.0];
+    gpu.draw_text(l.name_x as f32, head_y, tr("trade.good"), head_col, scale);
+    gpu.draw_text(l.stock_x as f32, head_y, tr("trade.stock"), head_col, scale);
+    gpu.draw_text(l.price_x as f32, head_y, tr("trade.sell_buy"), head_col, scale);
+    gpu.draw_text(l.chart_x as f32, head_y, tr("trade.price_history"), head_col, scale);
+    gpu.draw_text(l.sell_x as f32, head_y, tr("trade.sell_above"), head_col, scale);
+    gpu.draw_text(l.buy_x as f32, head_y, tr("trade.buy_below"), head_col, scale);
+
+    let btn_h = ui::ui_item_h(s);
+    let text_dy = (btn_h as f32 - 5.0 * 2.0 * scale) / 2.0;
+    for (row, &i) in trade_panel_rows(trade, visible).iter().take(l.max_rows.max(0) as usize).enumerate() {
+        let e = &trade.market[i];
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let ryf = ry as f32;
+        if row % 2 == 0 {
+            gpu.add_ui_rect(x + pad - 4.0, ryf - 2.0, w - pad * 2.0 + 8.0, l.row_h as f32, [0.0, 0.0, 0.0, 0.15]);
+        }
+        let name = crate::trade::label(e.kind);
+        let icon = btn_h as f32 * 0.8;
+        gpu.draw_ui_props_icon_tinted(l.name_x as f32, ryf + 1.0, icon, get_props_index_for_resource(name), get_props_tint_for_resource(name));
+        gpu.draw_text(l.name_x as f32 + icon + 4.0, ryf + text_dy, crate::trade::display_label(e.kind), [1.0, 1.0, 1.0, 1.0], scale);
+        let stock = crate::resource_visitor::get_resource_value(visible, e.kind).max(0);
+        gpu.draw_number(l.stock_x as f32, ryf + text_dy, stock as u32, [1.0, 1.0, 1.0, 1.0], scale);
+        let prices = format!("{}/{}", e.sell_price(trade.reputation_bonus), e.buy_price(trade.reputation_bonus));
+        gpu.draw_text(l.price_x as f32, ryf + text_dy, &prices, [1.0, 0.85, 0.3, 1.0], scale);
+
+        // график: столбик на день, высота относительно максимума за период
+        let max_p = e.history.iter().copied().max().unwrap_or(1).max(1) as f32;
+        let bar_w = l.chart_w as f32 / crate::trade::PRICE_HISTORY_DAYS as f32;
+        let chart_h = btn_h as f32 - 2.0;
+        let rising = e.history.last() >= e.history.first();
+        let bar_col = if rising { [0.5, 0.9, 0.5, 0.9] } else { [0.95, 0.5, 0.4, 0.9] };
+        gpu.add_ui_rect(l.chart_x as f32, ryf, l.chart_w as f32, chart_h + 1.0, [0.0, 0.0, 0.0, 0.3]);
+        for (d, &p) in e.history.iter().enumerate() {
+            let bh = (chart_h * p as f32 / max_p).max(1.0);
+            gpu.add_ui_rect(l.chart_x as f32 + d as f32 * bar_w + 1.0, ryf + chart_h - bh + 1.0, (bar_w - 2.0).max(1.0), bh, bar_col);
+        }
+
+        // приказы: [-] порог [+]
+        for (col_x, order) in [(l.sell_x, e.sell_above), (l.buy_x, e.buy_below)] {
+            let minus_x = col_x;
+            let value_x = minus_x + l.btn_w + 2 * s;
+            let plus_x = value_x + l.value_w + 2 * s;
+            gpu.draw_button(minus_x as f32, ryf, l.btn_w as f32, btn_h as f32, "-", hovered(minus_x, ry, l.btn_w, btn_h), scale);
+            let value = order.map_or(tr("trade.off").to_string(), |v| v.to_string());
+            let value_col = if order.is_some() { [1.0, 1.0, 1.0, 1.0] } else { [0.6, 0.6, 0.6, 1.0] };
+            let tx = value_x as f32 + (l.value_w - ui::text_w(&value, s)) as f32 / 2.0;
+            gpu.draw_text(tx, ryf + text_dy, &value, value_col, scale);
+            gpu.draw_button(plus_x as f32, ryf, l.btn_w as f32, btn_h as f32, "+", hovered(plus_x, ry, l.btn_w, btn_h), scale);
+        }
+    }
+}
+
+/// Журнал квестов: активные квесты с наградами и кнопкой получения, ниже — история
+pub fn draw_quest_journal_gpu(
+    gpu: &mut GpuRenderer,
+    fw: i32,
+    fh: i32,
+    quests: &crate::quests::QuestSystem,
+    base_scale_k: f32,
+    cursor: glam::IVec2,
+) {
+    gpu.start_research_tree();
+
+    let s = ui::ui_scale(fh, base_scale_k);
+    let scale = s as f32;
+    let l = ui::layout_quest_journal(fw, fh, s);
+    let (x, y, w, h) = (l.x as f32, l.y as f32, l.w as f32, l.h as f32);
+    let pad = (16 * s) as f32;
+
+    gpu.add_ui_rect(0.0, 0.0, fw as f32, fh as f32, [0.0, 0.0, 0.0, 0.7]);
+    gpu.add_ui_rect(x, y, w, h, [0.25, 0.18, 0.12, 1.0]);
+    let border = (6 * s) as f32;
+    gpu.add_ui_rect(x + border, y + border, w - border * 2.0, h - border * 2.0, [0.45, 0.32, 0.22, 0.95]);
+
+    gpu.draw_text(x + pad, y + pad, tr("journal.title"), [1.0, 1.0, 0.8, 1.0], scale * 1.5);
+    let hovered = |bx: i32, by: i32, bw: i32, bh: i32| ui::point_in_rect(cursor.x, cursor.y, bx, by, bw, bh);
+    gpu.draw_button(l.close_x as f32, l.close_y as f32, l.close_size as f32, l.close_size as f32, "X",
+        hovered(l.close_x, l.close_y, l.close_size, l.close_size), scale);
+
+    let btn_h = ui::ui_item_h(s);
+    let text_dy = (btn_h as f32 - 5.0 * 2.0 * scale) / 2.0;
+    let head_col = [1.0, 0.9, 0.6, 1.0];
+
+    // активные квесты: название и прогресс, награда, кнопка получения
+    let head_y = (l.rows_y - ui::ui_item_h(s)) as f32;
+    gpu.draw_text(l.title_x as f32, head_y, tr("journal.active"), head_col, scale);
+    gpu.draw_text(l.progress_x as f32, head_y, tr("journal.progress"), head_col, scale);
+    gpu.draw_text(l.reward_x as f32, head_y, tr("journal.reward"), head_col, scale);
+    if quests.active_quests.is_empty() {
+        gpu.draw_text(l.title_x as f32, l.rows_y as f32 + text_dy, tr("journal.no_quests"), [0.7, 0.7, 0.7, 1.0], scale);
+    }
+    for (row, q) in quests.active_quests.iter().take(l.max_rows.max(0) as usize).enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let ryf = ry as f32;
+        if row % 2 == 0 {
+            gpu.add_ui_rect(x + pad - 4.0, ryf - 2.0, w - pad * 2.0 + 8.0, l.row_h as f32, [0.0, 0.0, 0.0, 0.15]);
+        }
+        let title_col = if q.key.is_some() { [1.0, 0.95, 0.6, 1.0] } else { [1.0, 1.0, 1.0, 1.0] };
+        gpu.draw_text(l.title_x as f32, ryf + text_dy, &q.label.title(), title_col, scale);
+        let progress = match q.days_left {
+            Some(days) if !q.completed => format!("{}  {}d", quest_progress_text(&q.kind), days),
+            _ => quest_progress_text(&q.kind),
+        };
+        gpu.draw_text(l.progress_x as f32, ryf + text_dy, &progress, [0.9, 0.9, 0.9, 1.0], scale);
+        let reward = crate::quests::rewards_label(&q.rewards);
+        gpu.draw_text(l.reward_x as f32, ryf + text_dy, &reward, [1.0, 0.85, 0.3, 1.0], scale);
+        if q.completed {
+            gpu.draw_button(l.claim_x as f32, ryf, l.claim_w as f32, btn_h as f32, tr("journal.claim"), hovered(l.claim_x, ry, l.claim_w, btn_h), scale);
+        }
+    }
+
+    // журнал: последние записи сверху
+    let log_head_y = (l.log_y - ui::ui_item_h(s)) as f32;
+    gpu.draw_text(l.title_x as f32, log_head_y, tr("journal.log"), head_col, scale);
+    for (row, entry) in quests.log.iter().rev().take(l.log_rows.max(0) as usize).enumerate() {
+        let ryf = (l.log_y + row as i32 * l.row_h) as f32;
+        let (status, col) = match entry.outcome {
+            crate::quests::QuestOutcome::Completed => (tr("journal.completed"), [0.6, 0.95, 0.6, 1.0]),
+            crate::quests::QuestOutcome::Failed => (tr("journal.failed"), [0.95, 0.5, 0.4, 1.0]),
+        };
+        gpu.draw_text(l.title_x as f32, ryf + text_dy, &entry.label.title(), [0.85, 0.85, 0.85, 1.0], scale);
+        gpu.draw_text(l.progress_x as f32, ryf + text_dy, status, col, scale);
+        if entry.outcome == crate::quests::QuestOutcome::Completed {
+            let reward = crate::quests::rewards_label(&entry.rewards);
+            gpu.draw_text(l.reward_x as f32, ryf + text_dy, &reward, [0.85, 0.75, 0.4, 1.0], scale);
+        }
+    }
+}
+
+/// Значки проблем над зданиями (по последней проверке советника); несколько проблем — в ряд
+fn draw_building_status_icons(
+    gpu: &mut GpuRenderer,
+    advisor: &crate::advisor::Advisor,
+    to_screen: impl Fn(glam::IVec2) -> glam::Vec2,
+    tile_half_h: f32,
+    scale: f32,
+) {
+    let size = 14.0 * scale;
+    let border = scale.max(1.0);
+    let mut seen: Vec<glam::IVec2> = Vec::new();
+    for pos in advisor.problems.iter().filter_map(|p| p.pos) {
+        if seen.contains(&pos) { continue; }
+        seen.push(pos);
+        let issues: Vec<_> = advisor.issues_at(pos).collect();
+        let center = to_screen(pos);
+        // над крышей здания
+        let y = center.y - tile_half_h * 4.0 - size;
+        let mut x = center.x - issues.len() as f32 * (size + border * 2.0) / 2.0;
+        for issue in issues {
+            gpu.add_ui_rect(x - border, y - border, size + border * 2.0, size + border * 2.0, [0.1, 0.05, 0.02, 0.9]);
+            gpu.add_ui_rect(x, y, size, size, issue.color());
+            gpu.draw_text(x + (size - 6.0 * scale) / 2.0, y + 2.0 * scale, "!", [0.1, 0.05, 0.02, 1.0], scale);
+            x += size + border * 2.0;
+        }
+    }
+}
+
+/// Журнал событий: вкладки разделов, записи с игровым временем и переходом к месту на карте
+pub fn draw_event_log_gpu(
+    gpu: &mut GpuRenderer,
+    fw: i32,
+    fh: i32,
+    notifications: &crate::notifications::NotificationSystem,
+    view: crate::notifications::LogView,
+    base_scale_k: f32,
+    cursor: glam::IVec2,
+) {
+    use crate::notifications::LogCategory;
+    gpu.start_research_tree();
+
+    let s = ui::ui_scale(fh, base_scale_k);
+    let scale = s as f32;
+    let l = ui::layout_event_log(fw, fh, s);
+    let (x, y, w, h) = (l.x as f32, l.y as f32, l.w as f32, l.h as f32);
+    let pad = (16 * s) as f32;
+
+    gpu.add_ui_rect(0.0, 0.0, fw as f32, fh as f32, [0.0, 0.0, 0.0, 0.7]);
+    gpu.add_ui_rect(x, y, w, h, [0.25, 0.18, 0.12, 1.0]);
+    let border = (6 * s) as f32;
+    gpu.add_ui_rect(x + border, y + border, w - border * 2.0, h - border * 2.0, [0.45, 0.32, 0.22, 0.95]);
+
+    gpu.draw_text(x + pad, y + pad, tr("event_log.title"), [1.0, 1.0, 0.8, 1.0], scale * 1.5);
+    let hovered = |bx: i32, by: i32, bw: i32, bh: i32| ui::point_in_rect(cursor.x, cursor.y, bx, by, bw, bh);
+    gpu.draw_button(l.close_x as f32, l.close_y as f32, l.close_size as f32, l.close_size as f32, "X",
+        hovered(l.close_x, l.close_y, l.close_size, l.close_size), scale);
+
+    let btn_h = ui::ui_item_h(s);
+    let text_dy = (btn_h as f32 - 5.0 * 2.0 * scale) / 2.0;
+
+    // вкладки: выбранная подсвечена
+    let tabs = std::iter::once(None).chain(LogCategory::ALL.iter().copied().map(Some));
+    for (&(tx, tw), category) in l.tabs.iter().zip(tabs) {
+        let label = category.map_or(tr("event_log.all"), |c| c.label());
+        gpu.draw_button(tx as f32, l.tabs_y as f32, tw as f32, btn_h as f32, label,
+            category == view.filter || hovered(tx, l.tabs_y, tw, btn_h), scale);
+    }
+
+    let entries: Vec<_> = notifications.entries(view.filter).skip(view.scroll).take(l.max_rows.max(0) as usize).collect();
+    if entries.is_empty() {
+        gpu.draw_text(l.time_x as f32, l.rows_y as f32 + text_dy, tr("event_log.no_events"), [0.7, 0.7, 0.7, 1.0], scale);
+    }
+    for (row, e) in entries.into_iter().enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let ryf = ry as f32;
+        if row % 2 == 0 {
+            gpu.add_ui_rect(x + pad - 4.0, ryf - 2.0, w - pad * 2.0 + 8.0, l.row_h as f32, [0.0, 0.0, 0.0, 0.15]);
+        }
+        let time = tr_fmt("event_log.time", &[&e.day, &format!("{:02}", e.hour)]);
+        gpu.draw_text(l.time_x as f32, ryf + text_dy, &time, [0.8, 0.8, 0.8, 1.0], scale);
+        let col = match e.category {
+            LogCategory::Warning => [1.0, 0.6, 0.45, 1.0],
+            LogCategory::Research => [0.7, 0.85, 1.0, 1.0],
+            LogCategory::Quest => [1.0, 0.95, 0.6, 1.0],
+            LogCategory::Trade => [1.0, 0.85, 0.3, 1.0],
+            _ => [1.0, 1.0, 1.0, 1.0],
+        };
+        // длинные сообщения обрезаем до кнопки перехода
+        let max_chars = ((l.go_x - l.text_x - 8 * s) / ui::text_w("W", s)).max(4) as usize;
+        let text = if e.text.len() > max_chars {
+            format!("{}...", e.text.chars().take(max_chars - 3).collect::<String>())
+        } else {
+            e.text.clone()
+        };
+        gpu.draw_text(l.text_x as f32, ryf + text_dy, &text, col, scale);
+        if e.pos.is_some() {
+            gpu.draw_button(l.go_x as f32, ryf, l.go_w as f32, btn_h as f32, tr("event_log.go"), hovered(l.go_x, ry, l.go_w, btn_h), scale);
+        }
+    }
+}
+
+/// Экран итога сценария: победа или поражение, продолжить игру или выйти в меню
+pub fn draw_scenario_result_gpu(
+    gpu: &mut GpuRenderer,
+    fw: i32,
+    fh: i32,
+    scenario: &crate::scenario::ScenarioState,
+    day: i32,
+    base_scale_k: f32,
+    cursor: glam::IVec2,
+) {
+    let Some(outcome) = &scenario.outcome else { return; };
+    gpu.start_research_tree();
+
+    let s = ui::ui_scale(fh, base_scale_k);
+    let scale = s as f32;
+    let l = ui::layout_scenario_result(fw, fh, s);
+    let (x, y, w, h) = (l.x as f32, l.y as f32, l.w as f32, l.h as f32);
+    let pad = (16 * s) as f32;
+
+    gpu.add_ui_rect(0.0, 0.0, fw as f32, fh as f32, [0.0, 0.0, 0.0, 0.7]);
+    gpu.add_ui_rect(x, y, w, h, [0.25, 0.18, 0.12, 1.0]);
+    let border = (6 * s) as f32;
+    gpu.add_ui_rect(x + border, y + border, w - border * 2.0, h - border * 2.0, [0.45, 0.32, 0.22, 0.95]);
+
+    let (title, reason, col): (&str, String, [f32; 4]) = match outcome {
+        crate::scenario::ScenarioOutcome::Victory => (tr("scenario.victory"), tr("scenario.all_goals").to_string(), [0.6, 1.0, 0.6, 1.0]),
+        crate::scenario::ScenarioOutcome::Defeat(r) => (tr("scenario.defeat"), r.clone(), [1.0, 0.5, 0.4, 1.0]),
+    };
+    let title_w = ui::text_w(title, s) as f32 * 2.0;
+    gpu.draw_text(x + (w - title_w) / 2.0, y + pad, title, col, scale * 2.0);
+    let line_h = (ui::ui_item_h(s) + 4 * s) as f32;
+    let text_y = y + pad + 40.0 * scale;
+    gpu.draw_text(x + pad, text_y, scenario.display_name(), [1.0, 1.0, 0.8, 1.0], scale);
+    gpu.draw_text(x + pad, text_y + line_h, &reason, [0.9, 0.9, 0.9, 1.0], scale);
+    let days = tr_fmt("scenario.days_played", &[&scenario.days_passed(day)]);
+    gpu.draw_text(x + pad, text_y + line_h * 2.0, &days, [0.9, 0.9, 0.9, 1.0], scale);
+
+    let btn_h = ui::ui_item_h(s);
+    let hovered = |bx: i32| ui::point_in_rect(cursor.x, cursor.y, bx, l.btn_y, l.btn_w, btn_h);
+    gpu.draw_button(l.continue_x as f32, l.btn_y as f32, l.btn_w as f32, btn_h as f32, tr("scenario.continue"), hovered(l.continue_x), scale);
+    gpu.draw_button(l.menu_x as f32, l.btn_y as f32, l.btn_w as f32, btn_h as f32, tr("scenario.main_menu"), hovered(l.menu_x), scale);
+}
+
+/// Окно контрактов: соседние поселения с репутацией, предложения и принятые поставки
+pub fn draw_contracts_panel_gpu(
+    gpu: &mut GpuRenderer,
+    fw: i32,
+    fh: i32,
+    contracts: &crate::contracts::ContractSystem,
+    visible: &Resources,
+    base_scale_k: f32,
+    cursor: glam::IVec2,
+) {
+    gpu.start_research_tree();
+
+    let s = ui::ui_scale(fh, base_scale_k);
+    let scale = s as f32;
+    let l = ui::layout_contracts_panel(fw, fh, s, contracts.settlements.len() as i32);
+    let (x, y, w, h) = (l.x as f32, l.y as f32, l.w as f32, l.h as f32);
+    let pad = (16 * s) as f32;
+
+    gpu.add_ui_rect(0.0, 0.0, fw as f32, fh as f32, [0.0, 0.0, 0.0, 0.7]);
+    gpu.add_ui_rect(x, y, w, h, [0.25, 0.18, 0.12, 1.0]);
+    let border = (6 * s) as f32;
+    gpu.add_ui_rect(x + border, y + border, w - border * 2.0, h - border * 2.0, [0.45, 0.32, 0.22, 0.95]);
+
+    gpu.draw_text(x + pad, y + pad, tr("contracts.title"), [1.0, 1.0, 0.8, 1.0], scale * 1.5);
+    let hovered = |bx: i32, by: i32, bw: i32, bh: i32| ui::point_in_rect(cursor.x, cursor.y, bx, by, bw, bh);
+    gpu.draw_button(l.close_x as f32, l.close_y as f32, l.close_size as f32, l.close_size as f32, "X",
+        hovered(l.close_x, l.close_y, l.close_size, l.close_size), scale);
+
+    let btn_h = ui::ui_item_h(s);
+    let text_dy = (btn_h as f32 - 5.0 * 2.0 * scale) / 2.0;
+    let head_col = [1.0, 0.9, 0.6, 1.0];
+
+    // поселения: репутация и отношение
+    let head_y = (l.towns_y - ui::ui_item_h(s)) as f32;
+    gpu.draw_text(l.town_x as f32, head_y, tr("contracts.settlement"), head_col, scale);
+    gpu.draw_text(l.rep_x as f32, head_y, tr("contracts.reputation"), head_col, scale);
+    for (row, town) in contracts.settlements.iter().enumerate() {
+        let ryf = (l.towns_y + row as i32 * l.row_h) as f32;
+        gpu.draw_text(l.town_x as f32, ryf + text_dy, &town.name, [1.0, 1.0, 1.0, 1.0], scale);
+        let rep_col = if town.reputation < 0 { [0.95, 0.5, 0.4, 1.0] } else { [0.6, 0.95, 0.6, 1.0] };
+        let rep = format!("{:+} {}", town.reputation, town.standing());
+        gpu.draw_text(l.rep_x as f32, ryf + text_dy, &rep, rep_col, scale);
+        let wants: Vec<&str> = town.wants.iter().map(|&k| crate::trade::display_label(k)).collect();
+        let wants = format!("{}: {}", tr("contracts.wants"), wants.join(", "));
+        gpu.draw_text(l.reward_x as f32, ryf + text_dy, &wants, [0.85, 0.85, 0.85, 1.0], scale);
+    }
+
+    // предложения и принятые контракты
+    let head_y = (l.rows_y - ui::ui_item_h(s)) as f32;
+    gpu.draw_text(l.town_x as f32, head_y, tr("contracts.from"), head_col, scale);
+    gpu.draw_text(l.goods_x as f32, head_y, tr("contracts.goods"), head_col, scale);
+    gpu.draw_text(l.amount_x as f32, head_y, tr("contracts.amount"), head_col, scale);
+    gpu.draw_text(l.days_x as f32, head_y, tr("contracts.days"), head_col, scale);
+    gpu.draw_text(l.reward_x as f32, head_y, tr("contracts.reward"), head_col, scale);
+    if contracts.contracts.is_empty() {
+        gpu.draw_text(l.town_x as f32, l.rows_y as f32 + text_dy, tr("contracts.no_offers"), [0.7, 0.7, 0.7, 1.0], scale);
+    }
+    for (row, c) in contracts.contracts.iter().take(l.max_rows.max(0) as usize).enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let ryf = ry as f32;
+        if row % 2 == 0 {
+            gpu.add_ui_rect(x + pad - 4.0, ryf - 2.0, w - pad * 2.0 + 8.0, l.row_h as f32, [0.0, 0.0, 0.0, 0.15]);
+        }
+        let town = contracts.settlements.get(c.settlement).map_or("?", |t| t.name.as_str());
+        gpu.draw_text(l.town_x as f32, ryf + text_dy, town, [1.0, 1.0, 1.0, 1.0], scale);
+        let name = crate::trade::label(c.kind);
+        let icon = btn_h as f32 * 0.8;
+        gpu.draw_ui_props_icon_tinted(l.goods_x as f32, ryf + 1.0, icon, get_props_index_for_resource(name), get_props_tint_for_resource(name));
+        gpu.draw_text(l.goods_x as f32 + icon + 4.0, ryf + text_dy, crate::trade::display_label(c.kind), [1.0, 1.0, 1.0, 1.0], scale);
+        // у принятого контракта показываем, сколько уже есть на складах
+        let amount = if c.accepted {
+            format!("{}/{}", crate::resource_visitor::get_resource_value(visible, c.kind).max(0).min(c.amount), c.amount)
+        } else {
+            c.amount.to_string()
+        };
+        gpu.draw_text(l.amount_x as f32, ryf + text_dy, &amount, [1.0, 1.0, 1.0, 1.0], scale);
+        let days = if c.accepted { c.days_left } else { c.days };
+        let days_col = if c.accepted && c.days_left <= 2 { [1.0, 0.5, 0.4, 1.0] } else { [1.0, 1.0, 1.0, 1.0] };
+        gpu.draw_text(l.days_x as f32, ryf + text_dy, &tr_fmt("contracts.days_n", &[&days]), days_col, scale);
+        let reward = tr_fmt("contracts.reward_n", &[&c.reward_gold, &c.reward_rep]);
+        gpu.draw_text(l.reward_x as f32, ryf + text_dy, &reward, [1.0, 0.85, 0.3, 1.0], scale);
+        if c.accepted {
+            gpu.draw_text(l.accept_x as f32, ryf + text_dy, tr("contracts.active"), [0.6, 0.95, 0.6, 1.0], scale);
+        } else {
+            gpu.draw_button(l.accept_x as f32, ryf, l.accept_w as f32, btn_h as f32, tr("contracts.accept"), hovered(l.accept_x, ry, l.accept_w, btn_h), scale);
+            gpu.draw_button(l.decline_x as f32, ryf, l.decline_w as f32, btn_h as f32, tr("contracts.decline"), hovered(l.decline_x, ry, l.decline_w, btn_h), scale);
+        }
+    }
+}
+
 /// Разделить текст на строки по максимальной ширине
 fn split_text(text: &str, max_width: usize) -> Vec<String> {
     let words: Vec<&str> = text.split_whitespace().collect();
@@ -1912,10 +2551,12 @@ pub fn draw_notifications_gpu(
             }
         };
         
-        // Вычисляем ширину уведомления
-        let text_w = text.len() as f32 * 4.0 * 2.0 * scale;
+        // Вычисляем ширину уведомления: длинный текст переносится по трети экрана
+        let lines = font::wrap(&text, fw as f32 / 3.0, scale);
+        let line_h = (12 * s) as f32;
+        let text_w = lines.iter().map(|l| font::text_width(l, scale)).fold(0.0_f32, f32::max);
         let notification_w = text_w + icon_size + notification_pad * 3.0;
-        let notification_h = icon_size + notification_pad * 2.0;
+        let notification_h = icon_size.max(line_h * lines.len() as f32) + notification_pad * 2.0;
         let x = right_x - notification_w;
         
         // Цветной фон уведомления в зависимости от типа (почти непрозрачный)
@@ -1945,13 +2586,49 @@ pub fn draw_notifications_gpu(
         
         // Текст белый без обводки
         let text_x = icon_x + icon_size + notification_pad;
-        let text_y = icon_y;
-        gpu.draw_text(text_x, text_y, text.as_bytes(), text_color, scale);
+        for (i, line) in lines.iter().enumerate() {
+            gpu.draw_text(text_x, icon_y + i as f32 * line_h, line, text_color, scale);
+        }
         
         y += notification_h + gap;
     }
 }
 
+/// Прогресс квеста одной строкой
+pub fn quest_progress_text(kind: &crate::quests::QuestKind) -> String {
+    use crate::quests::QuestKind;
+    match kind {
+        QuestKind::CollectResource { current_amount, target_amount, .. } => {
+            format!("{}/{}", current_amount, target_amount)
+        }
+        QuestKind::BuildBuildings { current_count, target_count, .. } => {
+            format!("{}/{}", current_count, target_count)
+        }
+        QuestKind::ReachPopulation { current_population, target_population } => {
+            format!("{}/{}", current_population, target_population)
+        }
+        QuestKind::CollectGold { current_amount, target_amount } => {
+            format!("{}/{}", current_amount, target_amount)
+        }
+        QuestKind::Contract { current_amount, target_amount, days_left, .. } => {
+            tr_fmt("journal.progress_days", &[current_amount, target_amount, days_left])
+        }
+        QuestKind::ProduceDaily { current_per_day, target_per_day, last_stock, .. } => {
+            if last_stock.is_some() {
+                tr_fmt("journal.per_day", &[current_per_day, target_per_day])
+            } else {
+                tr_fmt("journal.per_day_pending", &[target_per_day])
+            }
+        }
+        QuestKind::ReachHappiness { current, target } => {
+            format!("{}%/{}%", current, target)
+        }
+        QuestKind::ConnectByRoad { connected, .. } => {
+            if *connected { tr("journal.connected").to_string() } else { tr("journal.not_connected").to_string() }
+        }
+    }
+}
+
 /// Рисование панели квестов
 pub fn draw_quests_gpu(
     gpu: &mut GpuRenderer,
@@ -1979,40 +2656,35 @@ pub fn draw_quests_gpu(
     let graphite_color = [0.1, 0.1, 0.1, 1.0];
     let white_color = [1.0, 1.0, 1.0, 1.0];
     
-    gpu.draw_text_outlined(x, y, b"QUESTS:", graphite_color, white_color, scale);
+    gpu.draw_text_outlined(x, y, tr("journal.quests"), graphite_color, white_color, scale);
     y += (14 * s) as f32 + gap;
     
-    for quest in quests.iter().take(3) {
+    // три случайных квеста и до трёх контрактов соседей
+    for quest in quests.iter().take(6) {
         let mut text_y = y;
         let tab_offset = (16 * s) as f32; // Отступ для вложенной информации (2 таба)
         
         // Заголовок квеста с символом *
-        let quest_title = format!("* {}", quest.title);
-        gpu.draw_text_outlined(x, text_y, quest_title.as_bytes(), graphite_color, white_color, scale * 0.9);
+        let quest_title = format!("* {}", quest.label.title());
+        gpu.draw_text_outlined(x, text_y, &quest_title, graphite_color, white_color, scale * 0.9);
         text_y += (12 * s) as f32;
         
-        // Прогресс (с отступом)
-        let progress_text = match &quest.kind {
-            crate::quests::QuestKind::CollectResource { current_amount, target_amount, .. } => {
-                format!("{}/{}", current_amount, target_amount)
-            }
-            crate::quests::QuestKind::BuildBuildings { current_count, target_count, .. } => {
-                format!("{}/{}", current_count, target_count)
-            }
-            crate::quests::QuestKind::ReachPopulation { current_population, target_population } => {
-                format!("{}/{}", current_population, target_population)
-            }
-            crate::quests::QuestKind::CollectGold { current_amount, target_amount } => {
-                format!("{}/{}", current_amount, target_amount)
+        // Прогресс (с отступом); выполненный квест ждёт, пока заберут награду
+        let progress_text = if quest.completed {
+            tr("journal.claim_hint").to_string()
+        } else {
+            match quest.days_left {
+                Some(days) => tr_fmt("journal.days_left", &[&quest_progress_text(&quest.kind), &days]),
+                None => quest_progress_text(&quest.kind),
             }
         };
         
-        gpu.draw_text_outlined(x + tab_offset, text_y, progress_text.as_bytes(), graphite_color, white_color, scale * 0.8);
+        gpu.draw_text_outlined(x + tab_offset, text_y, &progress_text, graphite_color, white_color, scale * 0.8);
         text_y += (10 * s) as f32;
         
         // Награда (с отступом)
-        let reward_text = format!("Reward: {} gold", quest.reward_gold);
-        gpu.draw_text_outlined(x + tab_offset, text_y, reward_text.as_bytes(), graphite_color, white_color, scale * 0.7);
+        let reward_text = tr_fmt("journal.reward_line", &[&crate::quests::rewards_label(&quest.rewards)]);
+        gpu.draw_text_outlined(x + tab_offset, text_y, &reward_text, graphite_color, white_color, scale * 0.7);
         text_y += (10 * s) as f32; // Высота строки награды
         
         // Обновляем y для следующего квеста
@@ -2125,9 +2797,9 @@ pub fn draw_tutorial_gpu(
     
     // Заголовок (золотой цвет) - центрирован
     let title_color = [1.0, 0.9, 0.6, alpha];
-    let title_w = title.len() as f32 * 4.0 * 2.0 * scaled_scale * 1.2;
+    let title_w = font::text_width(title, scaled_scale * 1.2);
     let title_x = panel_x + (scaled_panel_w - title_w) / 2.0;
-    gpu.draw_text(title_x, text_y, title.as_bytes(), title_color, scaled_scale * 1.2);
+    gpu.draw_text(title_x, text_y, title, title_color, scaled_scale * 1.2);
     text_y += scaled_title_height + (8 * s) as f32 * scale_factor;
     
     // Разделитель
@@ -2137,9 +2809,9 @@ pub fn draw_tutorial_gpu(
     // Сообщение (белый текст) - центрировано
     let text_color = [1.0, 1.0, 1.0, alpha];
     for line in lines {
-        let line_w = line.len() as f32 * 4.0 * 2.0 * scaled_scale;
+        let line_w = font::text_width(line, scaled_scale);
         let line_x = panel_x + (scaled_panel_w - line_w) / 2.0;
-        gpu.draw_text(line_x, text_y, line.as_bytes(), text_color, scaled_scale);
+        gpu.draw_text(line_x, text_y, line, text_color, scaled_scale);
         text_y += scaled_line_height;
     }
     
@@ -2147,20 +2819,20 @@ pub fn draw_tutorial_gpu(
     if tutorial.requires_space() {
         text_y += (8 * s) as f32 * scale_factor;
         let hint_color = [0.7, 0.9, 1.0, alpha * (0.5 + 0.5 * (tutorial.message_time_ms * 0.003).sin())];
-        let hint = "[ Press SPACE to continue ]";
-        let hint_w = hint.len() as f32 * 4.0 * 2.0 * scaled_scale * 0.8;
+        let hint = tr("tutorial.continue");
+        let hint_w = font::text_width(hint, scaled_scale * 0.8);
         let hint_x = panel_x + (scaled_panel_w - hint_w) / 2.0;
-        gpu.draw_text(hint_x, text_y, hint.as_bytes(), hint_color, scaled_scale * 0.8);
+        gpu.draw_text(hint_x, text_y, hint, hint_color, scaled_scale * 0.8);
         text_y += scaled_line_height;
     }
     
     // Подсказка для пропуска туториала
     text_y += (4 * s) as f32 * scale_factor;
     let skip_color = [0.6, 0.6, 0.6, alpha * 0.7];
-    let skip_hint = "[ Press TAB to skip tutorial ]";
-    let skip_w = skip_hint.len() as f32 * 4.0 * 2.0 * scaled_scale * 0.7;
+    let skip_hint = tr("tutorial.skip");
+    let skip_w = font::text_width(skip_hint, scaled_scale * 0.7);
     let skip_x = panel_x + (scaled_panel_w - skip_w) / 2.0;
-    gpu.draw_text(skip_x, text_y, skip_hint.as_bytes(), skip_color, scaled_scale * 0.7);
+    gpu.draw_text(skip_x, text_y, skip_hint, skip_color, scaled_scale * 0.7);
 }
 
 /// Получить элемент для подсветки туториалом
@@ -2172,8 +2844,6 @@ pub fn get_tutorial_highlight_rect(
     ui_category: crate::ui::UICategory,
 ) -> Option<(f32, f32, f32, f32)> {
     use crate::tutorial::TutorialHighlight;
-    use crate::ui::UICategory;
-    use crate::types::BuildingKind;
     
     let highlight = tutorial.current_highlight()?;
     
@@ -2193,20 +2863,10 @@ pub fn get_tutorial_highlight_rect(
             let tab_y = bottom_y + pad;
             let cat_y = tab_y + btn_h + 6.0;
             
-            let categories: &[(UICategory, &[u8])] = &[
-                (UICategory::Housing, b"Housing"),
-                (UICategory::Storage, b"Storage"),
-                (UICategory::Forestry, b"Forestry"),
-                (UICategory::Mining, b"Mining"),
-                (UICategory::Food, b"Food"),
-                (UICategory::Logistics, b"Logistics"),
-                (UICategory::Research, b"Research"),
-            ];
-            
             let mut current_x = pad;
-            for (cat, label) in categories.iter() {
-                let btn_w = (label.len() as f32 * 4.0 * 2.0 * scale + 12.0).max(60.0);
-                if *cat == target_category {
+            for cat in ui::CATEGORIES {
+                let btn_w = ui::category_button_w(cat, s) as f32;
+                if cat == target_category {
                     return Some((current_x - 2.0, cat_y - 2.0, btn_w + 4.0, btn_h + 4.0));
                 }
                 current_x += btn_w + gap;
@@ -2220,34 +2880,10 @@ pub fn get_tutorial_highlight_rect(
             let cat_y = tab_y + btn_h + 6.0;
             let build_y = cat_y + btn_h + 6.0;
             
-            let buildings_for_cat: &[(BuildingKind, &[u8])] = match ui_category {
-                UICategory::Housing => &[(BuildingKind::House, b"House")],
-                UICategory::Storage => &[(BuildingKind::Warehouse, b"Warehouse")],
-                UICategory::Forestry => &[
-                    (BuildingKind::Lumberjack, b"Lumberjack"),
-                    (BuildingKind::Forester, b"Forester")
-                ],
-                UICategory::Mining => &[
-                    (BuildingKind::StoneQuarry, b"Quarry"),
-                    (BuildingKind::ClayPit, b"Clay Pit"),
-                    (BuildingKind::IronMine, b"Iron Mine"),
-                    (BuildingKind::Kiln, b"Kiln"),
-                    (BuildingKind::Smelter, b"Smelter")
-                ],
-                UICategory::Food => &[
-                    (BuildingKind::WheatField, b"Wheat Field"),
-                    (BuildingKind::Mill, b"Mill"),
-                    (BuildingKind::Bakery, b"Bakery"),
-                    (BuildingKind::Fishery, b"Fishery")
-                ],
-                UICategory::Logistics => &[],
-                UICategory::Research => &[(BuildingKind::ResearchLab, b"Research Lab")],
-            };
-            
             let mut current_x = pad;
-            for (bk, label) in buildings_for_cat.iter() {
-                let btn_w = (label.len() as f32 * 4.0 * 2.0 * scale + 12.0).max(70.0);
-                if *bk == building_kind {
+            for &bk in ui::category_buildings(ui_category) {
+                let btn_w = ui::building_button_w(bk, s) as f32;
+                if bk == building_kind {
                     return Some((current_x - 2.0, build_y - 2.0, btn_w + 4.0, btn_h + 4.0));
                 }
                 current_x += btn_w + gap;
@@ -4,10 +4,18 @@ use crate::atlas::TileAtlas;
 use crate::input::Config;
 use crate::types::{Building, BuildingKind, Citizen, Resources, WarehouseStore, CitizenState, building_cost};
 use crate::ui;
+use crate::i18n::tr;
 use crate::types::FoodPolicy;
-use crate::world::World;
+use crate::world::{DepositKind, World};
 use crate::research::ResearchSystem;
 
+/// Новое здание со стандартным числом рабочих (у домов — базовая вместимость)
+pub fn new_building(kind: BuildingKind, pos: IVec2) -> Building {
+    let default_workers = match kind { BuildingKind::House | BuildingKind::Warehouse | BuildingKind::ScoutTower | BuildingKind::Dock | BuildingKind::Tavern | BuildingKind::TradingPost => 0, _ => 1 };
+    let capacity = match kind { BuildingKind::House => crate::game::HOUSE_BASE_CAPACITY, _ => 0 };
+    Building { kind, pos, timer_ms: 0, workers_target: default_workers, capacity, comfort: 0, is_highlighted: false }
+}
+
 /// Проверка возможности размещения здания указанного типа в клетке `tp`.
 pub fn building_allowed_at(world: &mut World, kind: BuildingKind, tp: IVec2) -> bool {
     // Проверяем, разблокирован ли тайл для строительства
@@ -15,19 +23,35 @@ pub fn building_allowed_at(world: &mut World, kind: BuildingKind, tp: IVec2) ->
         return false;
     }
     let tile_kind = world.get_tile(tp.x, tp.y);
-    let mut allowed = !world.is_occupied(tp) && tile_kind != crate::types::TileKind::Water;
+    let mut allowed = !world.is_occupied(tp) && tile_kind.is_buildable();
+    // на крутых склонах ставим только добытчиков
+    if allowed && world.slope(tp) >= crate::world::STEEP_SLOPE {
+        allowed = matches!(kind, BuildingKind::Lumberjack | BuildingKind::Forester | BuildingKind::StoneQuarry | BuildingKind::IronMine
+            | BuildingKind::CoalMine | BuildingKind::GoldMine | BuildingKind::GemMine);
+    }
     if allowed {
         match kind {
-            BuildingKind::Fishery => {
+            BuildingKind::Fishery | BuildingKind::Dock | BuildingKind::TradingPost => {
                 // Требуем: клетка суши и не занята, и хотя бы один из 8 соседей — вода
                 const NB8: [(i32,i32);8] = [(1,0),(-1,0),(0,1),(0,-1),(1,1),(1,-1),(-1,1),(-1,-1)];
-                let near_water = NB8.iter().any(|(dx,dy)| world.get_tile(tp.x + dx, tp.y + dy) == crate::types::TileKind::Water);
+                let near_water = NB8.iter().any(|(dx,dy)| world.get_tile(tp.x + dx, tp.y + dy).is_water());
                 allowed = !world.is_occupied(tp) && near_water;
             }
             BuildingKind::WheatField => { allowed = tile_kind == crate::types::TileKind::Grass; }
             BuildingKind::StoneQuarry => { allowed = world.has_stone_deposit(tp + IVec2::new(1, 1)); }
             BuildingKind::ClayPit => { allowed = world.has_clay_deposit(tp + IVec2::new(1, 1)); }
-            BuildingKind::IronMine => { allowed = world.has_iron_deposit(tp + IVec2::new(1, 1)); }
+            BuildingKind::IronMine => {
+                // шахты роют только в холмах
+                allowed = tile_kind == crate::types::TileKind::Hills && world.has_iron_deposit(tp + IVec2::new(1, 1));
+            }
+            BuildingKind::CoalMine => { allowed = world.deposit_left(tp + IVec2::new(1, 1), DepositKind::Coal) > 0; }
+            BuildingKind::GoldMine => {
+                allowed = tile_kind == crate::types::TileKind::Hills && world.deposit_left(tp + IVec2::new(1, 1), DepositKind::Gold) > 0;
+            }
+            BuildingKind::GemMine => {
+                allowed = tile_kind == crate::types::TileKind::Hills && world.deposit_left(tp + IVec2::new(1, 1), DepositKind::Gems) > 0;
+            }
+            BuildingKind::SaltWorks => { allowed = world.deposit_left(tp + IVec2::new(1, 1), DepositKind::Salt) > 0; }
             _ => {}
         }
     }
@@ -62,6 +86,10 @@ pub fn handle_left_click(
     show_deposits: &mut bool,
     research_system: &mut ResearchSystem,
     show_research_tree: &mut bool,
+    show_trade_panel: &mut bool,
+    show_contracts_panel: &mut bool,
+    show_quest_journal: &mut bool,
+    show_event_log: &mut bool,
 ) -> bool {
     let ui_s = ui::ui_scale(height_i32, config.ui_scale_base);
     let _bar_h = ui::top_panel_height(ui_s);
@@ -73,7 +101,7 @@ pub fn handle_left_click(
         let s = ui_s; let pad = ui::ui_pad(s); let base_cell = 2 * s; let base_w_tiles = 96; let base_h_tiles = 64;
         let widget_w = base_w_tiles * base_cell; let widget_h = base_h_tiles * base_cell;
         let x = width_i32 - pad - widget_w; let y = height_i32 - bottom_bar_h - pad - widget_h;
-        let btn_h = ui::ui_item_h(s); let btn_w = ui::button_w_for(b"+", s); let gap = ui::ui_gap(s);
+        let btn_h = ui::ui_item_h(s); let btn_w = ui::button_w_for("+", s); let gap = ui::ui_gap(s);
         let plus_x = x - (btn_w + gap); let plus_y = y;
         let minus_x = plus_x; let minus_y = plus_y + btn_h + gap;
         if ui::point_in_rect(cursor_xy.x, cursor_xy.y, minus_x, minus_y, btn_w, btn_h) {
@@ -93,12 +121,12 @@ pub fn handle_left_click(
 
     // Вкладки
     let s = ui_s; let padb = 8 * s; let btn_h = 18 * s; let by0 = height_i32 - bottom_bar_h;
-    let build_w = ui::button_w_for(b"Build", s); let econ_w = ui::button_w_for(b"Economy", s);
+    let build_w = ui::button_w_for(tr("tab.build"), s); let econ_w = ui::button_w_for(tr("tab.economy"), s);
     if ui::point_in_rect(cursor_xy.x, cursor_xy.y, padb, by0 + padb, build_w, btn_h) { *ui_tab = ui::UITab::Build; return true; }
     if ui::point_in_rect(cursor_xy.x, cursor_xy.y, padb + build_w + 6 * s, by0 + padb, econ_w, btn_h) { *ui_tab = ui::UITab::Economy; return true; }
 
     // Кнопка депозитов
-    let deposits_w = ui::button_w_for(b"Deposits", s).max(80);
+    let deposits_w = ui::button_w_for(tr("tab.deposits"), s).max(80);
     let deposits_x = padb + build_w + 6 * s + econ_w + 6 * s;
     let deposits_y = by0 + padb;
     if ui::point_in_rect(cursor_xy.x, cursor_xy.y, deposits_x, deposits_y, deposits_w, btn_h) { 
@@ -106,15 +134,69 @@ pub fn handle_left_click(
         return true; 
     }
 
+    // Кнопка журнала квестов
+    let mut next_x = deposits_x + deposits_w + 6 * s;
+    let quests_w = ui::button_w_for(tr("tab.quests"), s).max(80);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, next_x, by0 + padb, quests_w, btn_h) {
+        *show_quest_journal = !*show_quest_journal;
+        *show_research_tree = false;
+        *show_trade_panel = false;
+        *show_contracts_panel = false;
+        *show_event_log = false;
+        return true;
+    }
+    next_x += quests_w + 6 * s;
+
+    // Кнопка журнала событий
+    let log_w = ui::button_w_for(tr("tab.log"), s).max(80);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, next_x, by0 + padb, log_w, btn_h) {
+        *show_event_log = !*show_event_log;
+        *show_research_tree = false;
+        *show_trade_panel = false;
+        *show_contracts_panel = false;
+        *show_quest_journal = false;
+        return true;
+    }
+    next_x += log_w + 6 * s;
+
     // Кнопка Research (только если есть лаборатория)
     if research_system.has_research_lab {
-        let research_w = ui::button_w_for(b"Research (T)", s).max(100);
-        let research_x = deposits_x + deposits_w + 6 * s;
+        let research_w = ui::button_w_for(tr("tab.research"), s).max(100);
+        let research_x = next_x;
         let research_y = by0 + padb;
         if ui::point_in_rect(cursor_xy.x, cursor_xy.y, research_x, research_y, research_w, btn_h) { 
             *show_research_tree = !*show_research_tree; 
+            *show_trade_panel = false;
+            *show_contracts_panel = false;
+            *show_quest_journal = false;
+            *show_event_log = false;
             return true; 
         }
+        next_x += research_w + 6 * s;
+    }
+
+    // Кнопка Trade (только если есть торговый пост)
+    if buildings.iter().any(|b| b.kind == BuildingKind::TradingPost) {
+        let trade_w = ui::button_w_for(tr("tab.trade"), s).max(80);
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, next_x, by0 + padb, trade_w, btn_h) {
+            *show_trade_panel = !*show_trade_panel;
+            *show_research_tree = false;
+            *show_contracts_panel = false;
+            *show_quest_journal = false;
+            *show_event_log = false;
+            return true;
+        }
+        next_x += trade_w + 6 * s;
+        // Кнопка Contracts рядом с Trade: связь с соседями тоже через торговый пост
+        let contracts_w = ui::button_w_for(tr("tab.contracts"), s).max(100);
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, next_x, by0 + padb, contracts_w, btn_h) {
+            *show_contracts_panel = !*show_contracts_panel;
+            *show_research_tree = false;
+            *show_trade_panel = false;
+            *show_quest_journal = false;
+            *show_event_log = false;
+            return true;
+        }
     }
 
     // Если вкладка Economy — клики по её контролам (динамический расчет)
@@ -124,7 +206,7 @@ pub fn handle_left_click(
         
         // Динамический расчет координат для налогов (как в ui_gpu.rs)
         let mut current_x = padb;
-        let tax_label_w = ((3 * 4 * 2 * ui_s) + 12).max(40); // "TAX"
+        let tax_label_w = (ui::text_w(tr("economy.tax"), ui_s) + 12).max(40);
         current_x += tax_label_w + 6 * ui_s;
         
         let taxp = (*tax_rate * 100.0).round().clamp(0.0, 100.0) as u32;
@@ -147,18 +229,18 @@ pub fn handle_left_click(
         
         // Динамический расчет координат для политики еды
         current_x = padb;
-        let policy_label_w = ((11 * 4 * 2 * ui_s) + 12).max(100); // "FOOD POLICY"
+        let policy_label_w = (ui::text_w(tr("economy.food_policy"), ui_s) + 12).max(100);
         current_x += policy_label_w + 6 * ui_s;
         
         // Кнопки политики еды
-        let food_policies: &[(FoodPolicy, &[u8])] = &[
-            (FoodPolicy::Balanced, b"Balanced"),
-            (FoodPolicy::BreadFirst, b"Bread"),
-            (FoodPolicy::FishFirst, b"Fish"),
+        let food_policies: &[(FoodPolicy, &str)] = &[
+            (FoodPolicy::Balanced, tr("economy.balanced")),
+            (FoodPolicy::BreadFirst, tr("economy.bread")),
+            (FoodPolicy::FishFirst, tr("economy.fish")),
         ];
         
         for (policy, label) in food_policies.iter() {
-            let btn_w = ((label.len() as i32 * 4 * 2 * ui_s) + 12).max(50);
+            let btn_w = (ui::text_w(label, ui_s) + 12).max(50);
             if current_x + btn_w > width_i32 - padb {
                 break;
             }
@@ -171,53 +253,19 @@ pub fn handle_left_click(
     }
 
     // клик по категориям (Build): 2-я строка, с переносом
-    let cats = [
-        (ui::UICategory::Housing, b"Housing".as_ref()),
-        (ui::UICategory::Storage, b"Storage".as_ref()),
-        (ui::UICategory::Forestry, b"Forestry".as_ref()),
-        (ui::UICategory::Mining, b"Mining".as_ref()),
-        (ui::UICategory::Food, b"Food".as_ref()),
-        (ui::UICategory::Logistics, b"Logistics".as_ref()),
-        (ui::UICategory::Research, b"Research".as_ref()),
-    ];
     let row_y = [by0 + padb + btn_h + 6 * s, by0 + padb + (btn_h + 6 * s) * 2];
     let mut row: usize = 0; let mut cx = padb;
-    for (cat, label) in cats.iter() {
-        let bw = ((label.len() as i32) * 4 * 2 * s + 12).max(60); // та же формула, что в ui_gpu.rs
+    for cat in ui::CATEGORIES {
+        let bw = ui::category_button_w(cat, s); // та же формула, что в ui_gpu.rs
         if cx + bw > width_i32 - padb { row = (row + 1).min(row_y.len()-1); cx = padb; }
         let y = row_y[row];
-        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, cx, y, bw, btn_h) { *ui_category = *cat; return true; }
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, cx, y, bw, btn_h) { *ui_category = cat; return true; }
         cx += bw + 6 * s;
     }
     // клик по зданиям выбранной категории — 3-я строка
     let mut bx = padb; let by2 = by0 + padb + (btn_h + 6 * s) * 2;
-    let buildings_for_cat: &[BuildingKind] = match *ui_category {
-        ui::UICategory::Housing => &[BuildingKind::House],
-        ui::UICategory::Storage => &[BuildingKind::Warehouse],
-        ui::UICategory::Forestry => &[BuildingKind::Lumberjack, BuildingKind::Forester],
-        ui::UICategory::Mining => &[BuildingKind::StoneQuarry, BuildingKind::ClayPit, BuildingKind::IronMine, BuildingKind::Kiln],
-        ui::UICategory::Food => &[BuildingKind::WheatField, BuildingKind::Mill, BuildingKind::Bakery, BuildingKind::Fishery],
-        ui::UICategory::Logistics => &[],
-        ui::UICategory::Research => &[BuildingKind::ResearchLab],
-    };
-    for &bk in buildings_for_cat.iter() {
-        let label = match bk {
-            BuildingKind::Lumberjack => b"Lumberjack".as_ref(),
-            BuildingKind::House => b"House".as_ref(),
-            BuildingKind::Warehouse => b"Warehouse".as_ref(),
-            BuildingKind::Forester => b"Forester".as_ref(),
-            BuildingKind::StoneQuarry => b"Quarry".as_ref(),
-            BuildingKind::ClayPit => b"Clay Pit".as_ref(),
-            BuildingKind::Kiln => b"Kiln".as_ref(),
-            BuildingKind::WheatField => b"Wheat Field".as_ref(),
-            BuildingKind::Mill => b"Mill".as_ref(),
-            BuildingKind::Bakery => b"Bakery".as_ref(),
-            BuildingKind::Fishery => b"Fishery".as_ref(),
-            BuildingKind::IronMine => b"Iron Mine".as_ref(),
-            BuildingKind::Smelter => b"Smelter".as_ref(),
-            BuildingKind::ResearchLab => b"Research Lab".as_ref(),
-        };
-        let bw = ((label.len() as i32) * 4 * 2 * ui_s + 12).max(70); // та же формула, что в ui_gpu.rs
+    for &bk in ui::category_buildings(*ui_category) {
+        let bw = ui::building_button_w(bk, ui_s); // та же формула, что в ui_gpu.rs
         if bx + bw > width_i32 - padb { break; }
         if ui::point_in_rect(cursor_xy.x, cursor_xy.y, bx, by2, bw, btn_h) {
             // Проверяем разблокировку перед выбором здания (ResearchLab всегда разблокирована)
@@ -249,7 +297,7 @@ pub fn handle_left_click(
             if let Some(idx) = buildings.iter().position(|bb| bb.pos == p) {
                 let b = buildings.remove(idx);
                 // освободить клетку
-                world.occupied.remove(&(p.x, p.y));
+                world.vacate(p);
                 // вернуть 50% стоимости и 50% накопленных ресурсов (если применимо)
                 let cost = crate::types::building_cost(b.kind);
                 // Возврат половины стоимости
@@ -273,6 +321,18 @@ pub fn handle_left_click(
                             resources.gold += (w.gold as f32 * 0.5).round() as i32;
                             resources.iron_ore += (w.iron_ore as f32 * 0.5).round() as i32;
                             resources.iron_ingots += (w.iron_ingots as f32 * 0.5).round() as i32;
+                            resources.gold_ore += (w.gold_ore as f32 * 0.5).round() as i32;
+                            resources.coal += (w.coal as f32 * 0.5).round() as i32;
+                            resources.salt += (w.salt as f32 * 0.5).round() as i32;
+                            resources.gems += (w.gems as f32 * 0.5).round() as i32;
+                            resources.jewelry += (w.jewelry as f32 * 0.5).round() as i32;
+                            resources.charcoal += (w.charcoal as f32 * 0.5).round() as i32;
+                            resources.tools += (w.tools as f32 * 0.5).round() as i32;
+                            resources.wool += (w.wool as f32 * 0.5).round() as i32;
+                            resources.cloth += (w.cloth as f32 * 0.5).round() as i32;
+                            resources.clothes += (w.clothes as f32 * 0.5).round() as i32;
+                            resources.hops += (w.hops as f32 * 0.5).round() as i32;
+                            resources.ale += (w.ale as f32 * 0.5).round() as i32;
                         }
                     }
                     _ => { /* производственные не хранят, возвращаем только стоимость */ }
@@ -322,9 +382,7 @@ pub fn handle_left_click(
                 if crate::types::can_afford_building(warehouses, resources, &cost) {
                     let _ = crate::types::spend_building_cost(warehouses, resources, &cost);
                     world.occupy(tp);
-                    let default_workers = match building_kind { BuildingKind::House | BuildingKind::Warehouse => 0, _ => 1 };
-                    let capacity = match building_kind { BuildingKind::House => 2, _ => 0 };
-                    buildings.push(Building { kind: building_kind, pos: tp, timer_ms: 0, workers_target: default_workers, capacity, is_highlighted: false });
+                    buildings.push(new_building(building_kind, tp));
                     // если построен склад — зарегистрировать его в списке складов, чтобы заработали доставки
                     if building_kind == BuildingKind::Warehouse {
                         warehouses.push(WarehouseStore { pos: tp, ..Default::default() });
@@ -339,14 +397,12 @@ pub fn handle_left_click(
                             pos: tp, target: tp, moving: false, progress: 0.0, carrying_log: false, assigned_job: None,
                             idle_timer_ms: 0, home: tp, workplace: None, state: CitizenState::Idle, work_timer_ms: 0,
                             carrying: None, pending_input: None, path: Vec::new(), path_index: 0, fed_today: true, manual_workplace: false,
-                            happiness: 50, last_food_mask: 0,
+                            happiness: 50, last_food_mask: 0, cold_today: false, health: 100, tool_wear: 0.0,
                         });
                         *population += 1;
-                        // Разблокируем область вокруг нового дома
-                        let base_radius = 8;
-                        let radius = base_radius + (*population / 5).min(20);
-                        world.explore_area(tp, radius);
                     }
+                    // Разведываем обзор нового здания сразу, не дожидаясь пересчёта видимости
+                    world.explore_area(tp, building_kind.vision_radius());
                     // Отменяем выбор здания после постройки
                     *selected_building = None;
                     // Не открываем панель автоматически после постройки
@@ -378,7 +434,7 @@ pub fn get_hovered_button(
     let btn_h = 18 * ui_s;
     
     // Кнопки управления (пауза, скорость)
-    let control_btn_w = ui::button_w_for(b"Pause", ui_s);
+    let control_btn_w = ui::button_w_for("Pause", ui_s);
     let control_x = width_i32 - padb - control_btn_w * 4 - 6 * ui_s * 3;
     let control_y = by0 + padb;
     
@@ -386,7 +442,7 @@ pub fn get_hovered_button(
         return Some(if paused { "Resume" } else { "Pause" });
     }
     
-    let speed_btn_w = ui::button_w_for(b"1x", ui_s);
+    let speed_btn_w = ui::button_w_for("1x", ui_s);
     let speed_x = control_x + control_btn_w + 6 * ui_s;
     if ui::point_in_rect(cursor_xy.x, cursor_xy.y, speed_x, control_y, speed_btn_w, btn_h) {
         return Some("Speed 1x");
@@ -403,8 +459,8 @@ pub fn get_hovered_button(
     }
     
     // Вкладки (с теми же минимальными размерами, что и в ui_gpu.rs)
-    let build_w = ui::button_w_for(b"Build", ui_s).max(60);
-    let econ_w = ui::button_w_for(b"Economy", ui_s).max(80);
+    let build_w = ui::button_w_for(tr("tab.build"), ui_s).max(60);
+    let econ_w = ui::button_w_for(tr("tab.economy"), ui_s).max(80);
     let build_x = padb;
     let build_y = by0 + padb;
     let econ_x = padb + build_w + 6 * ui_s; // используем масштабированный отступ, как в handle_left_click
@@ -418,7 +474,7 @@ pub fn get_hovered_button(
     }
     
     // Кнопка депозитов
-    let deposits_w = ui::button_w_for(b"Deposits", ui_s).max(80);
+    let deposits_w = ui::button_w_for(tr("tab.deposits"), ui_s).max(80);
     let deposits_x = econ_x + econ_w + 6 * ui_s;
     let deposits_y = by0 + padb;
     if ui::point_in_rect(cursor_xy.x, cursor_xy.y, deposits_x, deposits_y, deposits_w, btn_h) {
@@ -432,7 +488,7 @@ pub fn get_hovered_button(
         
         // Динамический расчет координат для налогов (как в ui_gpu.rs)
         let mut current_x = padb;
-        let tax_label_w = ((3 * 4 * 2 * ui_s) + 12).max(40); // "TAX"
+        let tax_label_w = (ui::text_w(tr("economy.tax"), ui_s) + 12).max(40);
         current_x += tax_label_w + 6 * ui_s;
         
         let taxp = (tax_rate * 100.0).round().clamp(0.0, 100.0) as u32;
@@ -453,18 +509,18 @@ pub fn get_hovered_button(
         
         // Динамический расчет координат для политики еды
         current_x = padb;
-        let policy_label_w = ((11 * 4 * 2 * ui_s) + 12).max(100); // "FOOD POLICY"
+        let policy_label_w = (ui::text_w(tr("economy.food_policy"), ui_s) + 12).max(100);
         current_x += policy_label_w + 6 * ui_s;
         
         // Кнопки политики еды
-        let food_policies: &[(FoodPolicy, &[u8], &str)] = &[
-            (FoodPolicy::Balanced, b"Balanced", "Balanced Food Policy"),
-            (FoodPolicy::BreadFirst, b"Bread", "Bread First Policy"),
-            (FoodPolicy::FishFirst, b"Fish", "Fish First Policy"),
+        let food_policies: &[(FoodPolicy, &str, &str)] = &[
+            (FoodPolicy::Balanced, tr("economy.balanced"), "Balanced Food Policy"),
+            (FoodPolicy::BreadFirst, tr("economy.bread"), "Bread First Policy"),
+            (FoodPolicy::FishFirst, tr("economy.fish"), "Fish First Policy"),
         ];
         
         for (_policy, label, tooltip) in food_policies.iter() {
-            let btn_w = ((label.len() as i32 * 4 * 2 * ui_s) + 12).max(50);
+            let btn_w = (ui::text_w(label, ui_s) + 12).max(50);
             if current_x + btn_w > width_i32 - padb {
                 break;
             }
@@ -482,12 +538,14 @@ pub fn get_hovered_button(
         (ui::UICategory::Forestry, "Forestry"),
         (ui::UICategory::Mining, "Mining"),
         (ui::UICategory::Food, "Food"),
+        (ui::UICategory::Rare, "Rare"),
         (ui::UICategory::Logistics, "Logistics"),
+        (ui::UICategory::Research, "Research"),
     ];
     let row_y = [by0 + padb + btn_h + 6 * ui_s, by0 + padb + (btn_h + 6 * ui_s) * 2];
     let mut row: usize = 0; let mut cx = padb;
-    for (_cat, label) in cats.iter() {
-        let bw = ((label.len() as i32) * 4 * 2 * ui_s + 12).max(60); // та же формула, что в ui_gpu.rs
+    for &(cat, label) in cats.iter() {
+        let bw = ui::category_button_w(cat, ui_s); // та же формула, что в ui_gpu.rs
         if cx + bw > width_i32 - padb { row = (row + 1).min(row_y.len()-1); cx = padb; }
         let y = row_y[row];
         if ui::point_in_rect(cursor_xy.x, cursor_xy.y, cx, y, bw, btn_h) {
@@ -499,16 +557,7 @@ pub fn get_hovered_button(
     // Здания выбранной категории
     let mut bx = padb;
     let by2 = by0 + padb + btn_h + 6 * ui_s + btn_h + 6 * ui_s; // две строки с масштабированными отступами
-    let buildings_for_cat: &[BuildingKind] = match ui_category {
-        ui::UICategory::Housing => &[BuildingKind::House],
-        ui::UICategory::Storage => &[BuildingKind::Warehouse],
-        ui::UICategory::Forestry => &[BuildingKind::Lumberjack, BuildingKind::Forester],
-        ui::UICategory::Mining => &[BuildingKind::StoneQuarry, BuildingKind::ClayPit, BuildingKind::IronMine, BuildingKind::Kiln],
-        ui::UICategory::Food => &[BuildingKind::WheatField, BuildingKind::Mill, BuildingKind::Bakery, BuildingKind::Fishery],
-        ui::UICategory::Logistics => &[],
-        ui::UICategory::Research => &[BuildingKind::ResearchLab],
-    };
-    for &bk in buildings_for_cat.iter() {
+    for &bk in ui::category_buildings(ui_category) {
         let label = match bk {
             BuildingKind::Lumberjack => "Lumberjack",
             BuildingKind::House => "House",
@@ -524,8 +573,25 @@ pub fn get_hovered_button(
             BuildingKind::IronMine => "Iron Mine",
             BuildingKind::Smelter => "Smelter",
             BuildingKind::ResearchLab => "Research Lab",
+            BuildingKind::CoalMine => "Coal Mine",
+            BuildingKind::GoldMine => "Gold Mine",
+            BuildingKind::GemMine => "Gem Mine",
+            BuildingKind::SaltWorks => "Salt Works",
+            BuildingKind::Mint => "Mint",
+            BuildingKind::Jeweler => "Jeweler",
+            BuildingKind::ScoutTower => "Scout Tower",
+            BuildingKind::Dock => "Dock",
+            BuildingKind::CharcoalBurner => "Charcoal Burner",
+            BuildingKind::Blacksmith => "Blacksmith",
+            BuildingKind::SheepFarm => "Sheep Farm",
+            BuildingKind::Weaver => "Weaver",
+            BuildingKind::Tailor => "Tailor",
+            BuildingKind::HopFarm => "Hop Farm",
+            BuildingKind::Brewery => "Brewery",
+            BuildingKind::Tavern => "Tavern",
+            BuildingKind::TradingPost => "Trading Post",
         };
-        let bw = ((label.len() as i32) * 4 * 2 * ui_s + 12).max(70); // та же формула, что в ui_gpu.rs
+        let bw = ui::building_button_w(bk, ui_s); // та же формула, что в ui_gpu.rs
         if bx + bw > width_i32 - padb { break; }
         if ui::point_in_rect(cursor_xy.x, cursor_xy.y, bx, by2, bw, btn_h) {
             return Some(label);
@@ -552,7 +618,7 @@ pub fn get_hovered_resource(
     citizens_sleeping: i32,
     citizens_hauling: i32,
     citizens_fetching: i32,
-    weather_label: &[u8], // Добавляем параметр для проверки наведения на погоду
+    weather_label: &str, // Добавляем параметр для проверки наведения на погоду
 ) -> Option<&'static str> {
     let ui_s = ui::ui_scale(height_i32, config.ui_scale_base);
     let panel_height = ui::top_panel_height(ui_s);
@@ -627,9 +693,22 @@ pub fn get_hovered_resource(
             ("Fish", resources.fish),
             ("Iron Ore", resources.iron_ore),
             ("Iron Ingots", resources.iron_ingots),
+            ("Gold Ore", resources.gold_ore),
+            ("Coal", resources.coal),
+            ("Salt", resources.salt),
+            ("Gems", resources.gems),
+            ("Jewelry", resources.jewelry),
+            ("Charcoal", resources.charcoal),
+            ("Tools", resources.tools),
+            ("Wool", resources.wool),
+            ("Cloth", resources.cloth),
+            ("Clothes", resources.clothes),
+            ("Hops", resources.hops),
+            ("Ale", resources.ale),
         ];
         
         for (name, amount) in resources_list {
+            if amount <= 0 && crate::ui_gpu::is_rare_resource(name) { continue; }
             if ui::point_in_rect(cursor_xy.x, cursor_xy.y, x as i32, row2_y as i32, icon_size as i32, icon_size as i32) {
                 return Some(name);
             }
@@ -649,7 +728,7 @@ pub fn get_hovered_resource(
         right_x -= speed_text_w;
         
         // Weather (слева от Speed)
-        let weather_text_w = weather_label.len() as f32 * 4.0 * 2.0 * (ui_s as f32);
+        let weather_text_w = ui::text_w(weather_label, ui_s) as f32;
         let weather_area_w = icon_size + 4.0 + weather_text_w;
         let weather_area_x = right_x - weather_area_w;
         
@@ -674,8 +753,7 @@ pub fn handle_research_tree_click(
     resources: &mut crate::types::Resources,
     scroll_offset: f32,
 ) -> bool {
-    use crate::research::{ResearchKind, ResearchStatus};
-    use crate::types;
+    use crate::research::ResearchStatus;
     
     let s = ui::ui_scale(fh, base_scale_k);
     
@@ -709,48 +787,187 @@ pub fn handle_research_tree_click(
     let gap_y = (45 * s) as f32;   // Вертикальный отступ
     
     // Вычисляем центрирование дерева (как в ui_gpu.rs)
-    let tree_cols = 4;
+    let tree_cols = crate::research::tree().iter().map(|d| d.column + 1).max().unwrap_or(1);
     let total_tree_width = (tree_cols as f32 * node_w) + ((tree_cols - 1) as f32 * gap_x);
     let tree_left_margin = ((window_w - pad * 2.0) - total_tree_width) / 2.0;
     let tree_area_left = window_x + pad + tree_left_margin.max(0.0);
     
     // Проверяем клики по узлам
-    for &research_kind in ResearchKind::all() {
-        let (col, row) = research_kind.tree_position();
-        let status = research_system.get_status(research_kind);
-        let info = research_kind.info();
+    for info in crate::research::tree() {
+        let (col, row) = (info.column, info.row);
+        let status = research_system.get_status(&info.key);
         
         // Пропускаем завершенные базовые исследования
-        if status == ResearchStatus::Completed && info.days_required == 0 {
+        if status == ResearchStatus::Completed && info.points == 0 {
             continue;
         }
         
         let node_x = tree_area_left + (col as f32) * (node_w + gap_x);
         let node_y = tree_start_y + (row as f32) * (node_h + gap_y) - scroll_offset;
         
-        // Проверка клика
+        // Клик по узлу: поставить в очередь (стоимость списывается при старте) или убрать из неё
         if ui::point_in_rect(
             cursor_xy.x, cursor_xy.y,
             node_x as i32, node_y as i32,
             node_w as i32, node_h as i32
-        ) && status == ResearchStatus::Available {
-            // Проверяем ресурсы
-            let total_res = types::total_resources(warehouses, resources);
-            let can_afford = total_res.wood >= info.cost.wood 
-                && total_res.gold >= info.cost.gold
-                && total_res.stone >= info.cost.stone;
-            
-            if can_afford {
-                // Списываем ресурсы
-                let _ = types::spend_building_cost(warehouses, resources, &info.cost);
-                // Начинаем исследование
-                research_system.start_research(research_kind);
+        ) {
+            if !research_system.dequeue(&info.key) {
+                research_system.enqueue(&info.key, resources, warehouses);
             }
-            
-            return true;
+            return false;
         }
     }
     
     false
 }
 
+
+/// Обработка кликов в окне торговли (пороги приказов)
+/// Возвращает true если нужно закрыть окно
+pub fn handle_trade_panel_click(
+    cursor_xy: IVec2,
+    fw: i32,
+    fh: i32,
+    base_scale_k: f32,
+    trade: &mut crate::trade::TradeSystem,
+    visible: &Resources,
+) -> bool {
+    let s = ui::ui_scale(fh, base_scale_k);
+    let l = ui::layout_trade_panel(fw, fh, s);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.close_x, l.close_y, l.close_size, l.close_size) {
+        return true;
+    }
+    let btn_h = ui::ui_item_h(s);
+    let rows = crate::ui_gpu::trade_panel_rows(trade, visible);
+    for (row, &i) in rows.iter().take(l.max_rows.max(0) as usize).enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let e = &mut trade.market[i];
+        for (col_x, order) in [(l.sell_x, &mut e.sell_above), (l.buy_x, &mut e.buy_below)] {
+            let plus_x = col_x + l.btn_w + l.value_w + 4 * s;
+            if ui::point_in_rect(cursor_xy.x, cursor_xy.y, col_x, ry, l.btn_w, btn_h) {
+                crate::trade::lower_order(order);
+                return false;
+            }
+            if ui::point_in_rect(cursor_xy.x, cursor_xy.y, plus_x, ry, l.btn_w, btn_h) {
+                crate::trade::raise_order(order);
+                return false;
+            }
+        }
+    }
+    false
+}
+
+/// Клик в журнале квестов: (закрыть окно, id квеста, награду за который забрали)
+pub fn handle_quest_journal_click(
+    cursor_xy: IVec2,
+    fw: i32,
+    fh: i32,
+    base_scale_k: f32,
+    quests: &crate::quests::QuestSystem,
+) -> (bool, Option<u32>) {
+    let s = ui::ui_scale(fh, base_scale_k);
+    let l = ui::layout_quest_journal(fw, fh, s);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.close_x, l.close_y, l.close_size, l.close_size) {
+        return (true, None);
+    }
+    let btn_h = ui::ui_item_h(s);
+    for (row, q) in quests.active_quests.iter().take(l.max_rows.max(0) as usize).enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        if q.completed && ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.claim_x, ry, l.claim_w, btn_h) {
+            return (false, Some(q.id));
+        }
+    }
+    (false, None)
+}
+
+/// Клики в журнале событий
+pub enum EventLogAction {
+    Close,
+    Filter(Option<crate::notifications::LogCategory>),
+    // навести камеру на место события
+    GoTo(IVec2),
+}
+
+/// Клик в журнале событий: вкладка раздела, кнопка перехода к месту или закрытие
+pub fn handle_event_log_click(
+    cursor_xy: IVec2,
+    fw: i32,
+    fh: i32,
+    base_scale_k: f32,
+    notifications: &crate::notifications::NotificationSystem,
+    view: crate::notifications::LogView,
+) -> Option<EventLogAction> {
+    use crate::notifications::LogCategory;
+    let s = ui::ui_scale(fh, base_scale_k);
+    let l = ui::layout_event_log(fw, fh, s);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.close_x, l.close_y, l.close_size, l.close_size) {
+        return Some(EventLogAction::Close);
+    }
+    let btn_h = ui::ui_item_h(s);
+    let tabs = std::iter::once(None).chain(LogCategory::ALL.iter().copied().map(Some));
+    for (&(tx, tw), category) in l.tabs.iter().zip(tabs) {
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, tx, l.tabs_y, tw, btn_h) {
+            return Some(EventLogAction::Filter(category));
+        }
+    }
+    let entries = notifications.entries(view.filter).skip(view.scroll).take(l.max_rows.max(0) as usize);
+    for (row, e) in entries.enumerate() {
+        let ry = l.rows_y + row as i32 * l.row_h;
+        if let Some(pos) = e.pos
+            && ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.go_x, ry, l.go_w, btn_h) {
+            return Some(EventLogAction::GoTo(pos));
+        }
+    }
+    None
+}
+
+/// Кнопки экрана итога сценария
+pub enum ScenarioResultAction {
+    Continue,
+    MainMenu,
+}
+
+/// Клик на экране итога сценария
+pub fn handle_scenario_result_click(cursor_xy: IVec2, fw: i32, fh: i32, base_scale_k: f32) -> Option<ScenarioResultAction> {
+    let s = ui::ui_scale(fh, base_scale_k);
+    let l = ui::layout_scenario_result(fw, fh, s);
+    let btn_h = ui::ui_item_h(s);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.continue_x, l.btn_y, l.btn_w, btn_h) {
+        return Some(ScenarioResultAction::Continue);
+    }
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.menu_x, l.btn_y, l.btn_w, btn_h) {
+        return Some(ScenarioResultAction::MainMenu);
+    }
+    None
+}
+
+/// Клик в окне контрактов: принять или отклонить предложение. Возвращает true, если окно нужно закрыть
+pub fn handle_contracts_panel_click(
+    cursor_xy: IVec2,
+    fw: i32,
+    fh: i32,
+    base_scale_k: f32,
+    contracts: &mut crate::contracts::ContractSystem,
+    quest_system: &mut crate::quests::QuestSystem,
+) -> bool {
+    let s = ui::ui_scale(fh, base_scale_k);
+    let l = ui::layout_contracts_panel(fw, fh, s, contracts.settlements.len() as i32);
+    if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.close_x, l.close_y, l.close_size, l.close_size) {
+        return true;
+    }
+    let btn_h = ui::ui_item_h(s);
+    for (row, c) in contracts.contracts.iter().take(l.max_rows.max(0) as usize).enumerate() {
+        if c.accepted { continue; }
+        let ry = l.rows_y + row as i32 * l.row_h;
+        let id = c.id;
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.accept_x, ry, l.accept_w, btn_h) {
+            contracts.accept(id, quest_system);
+            return false;
+        }
+        if ui::point_in_rect(cursor_xy.x, cursor_xy.y, l.decline_x, ry, l.decline_w, btn_h) {
+            contracts.decline(id);
+            return false;
+        }
+    }
+    false
+}
@@ -1,11 +1,75 @@
 use rand::{rngs::StdRng, Rng};
 use crate::types::WeatherKind;
 
+// длина сезона в игровых днях (год — четыре сезона)
+pub const DAYS_PER_SEASON: i32 = 6;
+
+/// Время года, задаётся счётчиком игровых дней
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn from_day(day: i32) -> Self {
+        match (day / DAYS_PER_SEASON).rem_euclid(4) {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Season::Spring => crate::i18n::tr("season.spring"),
+            Season::Summer => crate::i18n::tr("season.summer"),
+            Season::Autumn => crate::i18n::tr("season.autumn"),
+            Season::Winter => crate::i18n::tr("season.winter"),
+        }
+    }
+
+    /// Растёт ли пшеница (весна–осень)
+    pub fn crops_grow(self) -> bool {
+        self != Season::Winter
+    }
+
+    /// Множитель скорости роста деревьев
+    pub fn tree_growth_mul(self) -> f32 {
+        match self {
+            Season::Spring => 1.6,
+            Season::Summer => 1.0,
+            Season::Autumn => 0.6,
+            Season::Winter => 0.25,
+        }
+    }
+
+    /// Холодный сезон: дома топят дровами
+    pub fn is_cold(self) -> bool {
+        self == Season::Winter
+    }
+
+    // множители весов погоды [Clear, Rain, Fog, Snow]; снег бывает только зимой
+    fn weather_bias(self) -> [f32; 4] {
+        match self {
+            Season::Spring => [1.0, 1.4, 1.0, 0.0],
+            Season::Summer => [1.6, 0.7, 0.5, 0.0],
+            Season::Autumn => [0.8, 1.3, 1.6, 0.0],
+            Season::Winter => [0.8, 0.3, 1.0, 3.0],
+        }
+    }
+}
+
 /// Система управления погодой с автоматической сменой
 pub struct WeatherSystem {
     current: WeatherKind,
     timer_ms: f32,
     next_change_ms: f32,
+    // счётчик игровых дней (календарь сезонов)
+    day: i32,
 }
 
 impl WeatherSystem {
@@ -16,6 +80,7 @@ impl WeatherSystem {
             current: initial,
             timer_ms: 0.0,
             next_change_ms: next_change,
+            day: 0,
         }
     }
 
@@ -24,7 +89,7 @@ impl WeatherSystem {
         self.timer_ms += dt_ms;
         if self.timer_ms >= self.next_change_ms {
             self.timer_ms = 0.0;
-            self.current = pick_next_weather(self.current, rng);
+            self.current = pick_next_weather(self.current, self.season(), rng);
             self.next_change_ms = choose_weather_duration_ms(self.current, rng);
         }
     }
@@ -41,6 +106,40 @@ impl WeatherSystem {
         self.next_change_ms = choose_weather_duration_ms(new_weather, rng);
     }
 
+    pub fn day(&self) -> i32 {
+        self.day
+    }
+
+    pub fn season(&self) -> Season {
+        Season::from_day(self.day)
+    }
+
+    /// Выставить день (при загрузке сохранения)
+    pub fn set_day(&mut self, day: i32) {
+        self.day = day.max(0);
+    }
+
+    /// Вызывается на рассвете; возвращает true, если начался новый сезон
+    pub fn new_day(&mut self) -> bool {
+        let before = self.season();
+        self.day += 1;
+        // снег не переживает зиму: погода сменится на следующем обновлении
+        if self.current == WeatherKind::Snow && !self.season().is_cold() {
+            self.next_change_ms = self.timer_ms;
+        }
+        self.season() != before
+    }
+
+    /// Холодно: зима или снегопад (нужно топить дома)
+    pub fn is_cold(&self) -> bool {
+        self.season().is_cold() || self.current == WeatherKind::Snow
+    }
+
+    /// Метка календаря для верхней панели, например "SPRING DAY 3"
+    pub fn calendar_label(&self) -> String {
+        format!("{} {} {}", self.season().label(), crate::i18n::tr("hud.day"), self.day.rem_euclid(DAYS_PER_SEASON) + 1)
+    }
+
     /// Получить интенсивность погоды для эффектов рендеринга
     pub fn intensity(&self) -> f32 {
         match self.current {
@@ -52,12 +151,12 @@ impl WeatherSystem {
     }
 
     /// Получить метку и цвет погоды для UI
-    pub fn ui_label_and_color(&self) -> (&'static [u8], [u8; 4]) {
+    pub fn ui_label_and_color(&self) -> (&'static str, [u8; 4]) {
         match self.current {
-            WeatherKind::Clear => (b"CLEAR", [180, 200, 120, 255]),
-            WeatherKind::Rain => (b"RAIN", [90, 120, 200, 255]),
-            WeatherKind::Fog => (b"FOG", [160, 160, 160, 255]),
-            WeatherKind::Snow => (b"SNOW", [220, 230, 255, 255]),
+            WeatherKind::Clear => (crate::i18n::tr("weather.clear"), [180, 200, 120, 255]),
+            WeatherKind::Rain => (crate::i18n::tr("weather.rain"), [90, 120, 200, 255]),
+            WeatherKind::Fog => (crate::i18n::tr("weather.fog"), [160, 160, 160, 255]),
+            WeatherKind::Snow => (crate::i18n::tr("weather.snow"), [220, 230, 255, 255]),
         }
     }
 }
@@ -75,8 +174,8 @@ fn choose_weather_duration_ms(current: WeatherKind, rng: &mut StdRng) -> f32 {
     sec * 1000.0
 }
 
-/// Выбрать следующую погоду на основе вероятностных переходов
-fn pick_next_weather(current: WeatherKind, rng: &mut StdRng) -> WeatherKind {
+/// Выбрать следующую погоду на основе вероятностных переходов и сезона
+fn pick_next_weather(current: WeatherKind, season: Season, rng: &mut StdRng) -> WeatherKind {
     // Вероятности переходов зависят от текущей погоды
     // Значения — веса; нормализуем автоматически
     let (opts, weights): (&[WeatherKind], &[f32]) = match current {
@@ -97,6 +196,8 @@ fn pick_next_weather(current: WeatherKind, rng: &mut StdRng) -> WeatherKind {
             &[0.30, 0.20, 0.10, 0.40],
         ),
     };
+    let bias = season.weather_bias();
+    let weights: Vec<f32> = weights.iter().zip(bias).map(|(w, b)| w * b).collect();
     let total: f32 = weights.iter().copied().sum();
     let mut r = rng.random_range(0.0..total);
     for (w, &p) in opts.iter().zip(weights.iter()) {
@@ -105,6 +206,7 @@ fn pick_next_weather(current: WeatherKind, rng: &mut StdRng) -> WeatherKind {
         }
         r -= p;
     }
-    *opts.last().unwrap_or(&current)
+    // погрешность округления: последний допустимый вариант
+    opts.iter().zip(weights.iter()).rev().find(|(_, p)| **p > 0.0).map(|(w, _)| *w).unwrap_or(current)
 }
 
@@ -1,73 +1,297 @@
-use std::{collections::{HashMap, HashSet}, sync::mpsc::{Sender, Receiver, channel}, thread};
+use std::{collections::{HashMap, HashSet, VecDeque}, sync::{Arc, Condvar, Mutex, mpsc::{Receiver, channel}}, thread};
 use glam::IVec2;
 use noise::{Fbm, NoiseFn, Seedable, MultiFractal};
+use serde::{Serialize, Deserialize};
 
 use crate::types::{TileKind, BiomeKind};
+use crate::i18n::tr;
 
 pub const CHUNK_W: i32 = 32;
 pub const CHUNK_H: i32 = 32;
 
+const CHUNK_AREA: usize = (CHUNK_W * CHUNK_H) as usize;
+// за сколько тиков симуляции обходим все чанки при росте деревьев
+const TREE_GROWTH_SLICES: usize = 16;
+// запас (в чанках) вокруг видимой области, вне которого заявки на генерацию отменяются
+const CANCEL_MARGIN_CHUNKS: i32 = 2;
+
+// Уровни высоты (0..=31): с какого начинаются холмы и горы
+pub const HILLS_HEIGHT: u8 = 16;
+pub const MOUNTAIN_HEIGHT: u8 = 22;
+// перепад высот с соседом, на котором уже нельзя ставить большинство зданий
+pub const STEEP_SLOPE: u8 = 2;
+// Запас месторождения в клетке (единиц сырья)
+pub const DEPOSIT_MAX: u16 = 200;
+const DEPOSIT_MIN: u16 = 40;
+// глина понемногу восстанавливается (за день), но не выше исходного запаса клетки и порога
+const CLAY_REGEN_PER_DAY: u16 = 3;
+const CLAY_REGEN_CAP: u16 = 80;
+// как часто пересчитывается видимость и радиус обзора отряда разведчиков
+const VISION_REFRESH_MS: i32 = 250;
+pub const SCOUT_VISION_RADIUS: i32 = 6;
+
+/// Параметры генерации карты (задаются пресетом при создании игры)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapGenParams {
+    // порог мелководья по шуму суши: выше — больше воды
+    pub sea_level: f32,
+    // с какого значения шума суша покрыта лесом
+    pub forest_level: f32,
+    // множитель ширины рек (0 — без рек)
+    pub river_width: f32,
+    // множитель частоты шума суши: больше — мельче острова и озёра
+    pub land_scale: f64,
+    // подъём суши вокруг стартовой точки (гарантирует место для поселения)
+    pub start_lift: f32,
+    // снижение порогов месторождений: больше — богаче недра
+    pub deposit_bonus: f32,
+}
+
+impl Default for MapGenParams {
+    fn default() -> Self {
+        Self { sea_level: -0.2, forest_level: 0.2, river_width: 1.0, land_scale: 1.0, start_lift: 0.0, deposit_bonus: 0.0 }
+    }
+}
+
+/// Плотный битовый слой чанка: по биту на тайл
+#[derive(Clone, Copy, Default)]
+pub struct BitLayer([u64; CHUNK_AREA / 64]);
+
+impl BitLayer {
+    pub fn get(&self, i: usize) -> bool { self.0[i >> 6] & (1u64 << (i & 63)) != 0 }
+    pub fn set(&mut self, i: usize, on: bool) {
+        if on { self.0[i >> 6] |= 1u64 << (i & 63); } else { self.0[i >> 6] &= !(1u64 << (i & 63)); }
+    }
+    pub fn any(&self) -> bool { self.0.iter().any(|&w| w != 0) }
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ { (0..CHUNK_AREA).filter(move |&i| self.get(i)) }
+}
+
+/// Вид месторождения
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositKind {
+    Clay,
+    Stone,
+    Iron,
+    // редкие
+    Coal,
+    Gold,
+    Gems,
+    Salt,
+}
+
+const DEPOSIT_KINDS: usize = 7;
+
+impl DepositKind {
+    pub const ALL: [DepositKind; DEPOSIT_KINDS] = [
+        DepositKind::Clay, DepositKind::Stone, DepositKind::Iron,
+        DepositKind::Coal, DepositKind::Gold, DepositKind::Gems, DepositKind::Salt,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DepositKind::Clay => tr("deposit.clay"),
+            DepositKind::Stone => tr("deposit.stone"),
+            DepositKind::Iron => tr("deposit.iron"),
+            DepositKind::Coal => tr("deposit.coal"),
+            DepositKind::Gold => tr("deposit.gold"),
+            DepositKind::Gems => tr("deposit.gems"),
+            DepositKind::Salt => tr("deposit.salt"),
+        }
+    }
+}
+
+/// Слой месторождения: где оно было при генерации, исходный запас и сколько осталось.
+/// Запасы выделяются только под чанки, где генерация положила это месторождение.
+#[derive(Clone, Default)]
+struct DepositLayer {
+    present: BitLayer,
+    // пары (исходный запас, остаток) по тайлам
+    amounts: Option<Box<[(u16, u16)]>>,
+}
+
+impl DepositLayer {
+    fn put(&mut self, i: usize, amount: u16) {
+        self.present.set(i, true);
+        self.amounts.get_or_insert_with(|| vec![(0, 0); CHUNK_AREA].into_boxed_slice())[i] = (amount, amount);
+    }
+    fn initial(&self, i: usize) -> u16 { self.amounts.as_ref().map_or(0, |a| a[i].0) }
+    fn left(&self, i: usize) -> u16 { self.amounts.as_ref().map_or(0, |a| a[i].1) }
+    // меняем остаток только там, где месторождение было при генерации
+    fn set_left(&mut self, i: usize, left: u16) {
+        if !self.present.get(i) { return; }
+        if let Some(a) = self.amounts.as_mut() { a[i].1 = left; }
+    }
+}
+
+/// Остатки месторождений по клеткам выгруженного чанка (индекс — `DepositKind as usize`)
+pub type ChunkDeposits = Vec<((i32, i32), Vec<u16>)>;
+
+/// Слои изменений игрока (дороги, занятые зданиями клетки)
+#[derive(Clone, Copy, Default)]
+pub struct PlayerLayers {
+    pub roads: BitLayer,
+    pub occupied: BitLayer,
+}
+
+impl PlayerLayers {
+    pub fn any(&self) -> bool { self.roads.any() || self.occupied.any() }
+}
+
 #[derive(Clone)]
-pub struct Chunk { pub tiles: Vec<TileKind> }
+pub struct Chunk {
+    pub tiles: Vec<TileKind>,
+    // кадр, в котором чанк последний раз попадал в видимую область (для LRU)
+    pub last_seen: u64,
+    // слои по тайлам чанка; деревья — только в чанках, где они есть
+    trees: Option<Box<[Option<Tree>]>>,
+    tree_count: u32,
+    biomes: Vec<BiomeKind>,
+    heights: Vec<u8>,
+    rivers: BitLayer,
+    deposits: [DepositLayer; DEPOSIT_KINDS],
+    // добыча или восстановление меняли остатки (нужно сохранять при выгрузке)
+    deposits_touched: bool,
+    player: PlayerLayers,
+    // до какого момента часов роста деревья чанка уже обновлены
+    grown_until_ms: i64,
+}
+
+impl Chunk {
+    fn new(tiles: Vec<TileKind>, last_seen: u64, grown_until_ms: i64) -> Self {
+        Self {
+            tiles,
+            last_seen,
+            trees: None,
+            tree_count: 0,
+            biomes: vec![BiomeKind::Meadow; CHUNK_AREA],
+            heights: vec![0; CHUNK_AREA],
+            rivers: BitLayer::default(),
+            deposits: Default::default(),
+            deposits_touched: false,
+            player: PlayerLayers::default(),
+            grown_until_ms,
+        }
+    }
+
+    fn tree(&self, i: usize) -> Option<Tree> { self.trees.as_ref().and_then(|t| t[i]) }
+
+    fn set_tree(&mut self, i: usize, tree: Option<Tree>) {
+        if tree.is_none() && self.trees.is_none() { return; }
+        let trees = self.trees.get_or_insert_with(|| vec![None; CHUNK_AREA].into_boxed_slice());
+        match (trees[i].is_some(), tree.is_some()) {
+            (false, true) => self.tree_count += 1,
+            (true, false) => self.tree_count -= 1,
+            _ => {}
+        }
+        trees[i] = tree;
+    }
+
+    fn clear_trees(&mut self) {
+        self.trees = None;
+        self.tree_count = 0;
+    }
+
+    fn tree_list(&self) -> impl Iterator<Item = (usize, Tree)> + '_ {
+        self.trees.iter().flat_map(|t| t.iter().enumerate().filter_map(|(i, t)| t.map(|tree| (i, tree))))
+    }
+
+    fn deposit_amounts(&self, i: usize) -> Vec<u16> {
+        self.deposits.iter().map(|l| l.left(i)).collect()
+    }
+}
+
+/// Что остаётся от выгруженного чанка с изменениями игрока
+#[derive(Clone, Default)]
+pub struct PersistedChunk {
+    pub trees: ChunkTrees,
+    pub player: PlayerLayers,
+    pub deposits: ChunkDeposits,
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct Tree { pub stage: u8, pub age_ms: i32 }
 
+// битовые слои по чанкам (разведанная область в сохранении): по 16 слов на чанк
+pub type ChunkBits = Vec<((i32, i32), Vec<u64>)>;
+
+// деревья одного чанка с координатами тайлов
+pub type ChunkTrees = Vec<((i32, i32), Tree)>;
+
 pub struct World {
     pub seed: u64,
     pub fbm: Fbm<noise::OpenSimplex>,
-    pub chunks: HashMap<(i32, i32), Chunk>,
-    pub occupied: HashSet<(i32, i32)>,
-    pub roads: HashSet<(i32, i32)>,
-    pub trees: HashMap<(i32, i32), Tree>,
-    pub tx: Sender<(i32, i32)>,
-    pub rx: Receiver<ChunkResult>,
+    chunks: HashMap<(i32, i32), Chunk>,
+    // пул потоков генерации и число потоков (для пересоздания при смене seed)
+    workers: ChunkWorkerPool,
+    worker_threads: usize,
     pub pending: HashSet<(i32, i32)>,
     pub max_chunks: usize,
     // деревья, которые были вырублены (не восстанавливать при догрузке чанков)
     pub removed_trees: HashSet<(i32, i32)>,
-    // кэш месторождений по тайлам (уменьшаем вызовы шума в рендер-цикле)
-    pub clay_deposits: HashSet<(i32, i32)>,
-    pub stone_deposits: HashSet<(i32, i32)>,
-    pub iron_deposits: HashSet<(i32, i32)>,
-    pub rivers: HashSet<(i32, i32)>,
-    // биом по тайлам (кэш)
-    pub biomes: HashMap<(i32,i32), BiomeKind>,
     // настройки биомов из конфига (runtime)
     pub biome_swamp_thr: f32,
     pub biome_rocky_thr: f32,
     pub biome_swamp_tree_growth_wmul: f32,
     pub biome_rocky_tree_growth_wmul: f32,
-    // область строительства (разблокированные тайлы)
-    pub explored_tiles: HashSet<(i32, i32)>,
+    // параметры генерации (пресет карты, обилие ресурсов)
+    pub map_gen: MapGenParams,
+    // туман войны: разведанные тайлы (область строительства) и видимые сейчас
+    explored: HashMap<(i32, i32), BitLayer>,
+    visible: HashMap<(i32, i32), BitLayer>,
+    vision_clock_ms: i32,
+    // паромные линии между причалами и метки островов (для складских сетей)
+    pub ferries: Vec<(IVec2, IVec2)>,
+    islands: HashMap<(i32, i32), u32>,
+    // растёт при каждом изменении суши (дороги, загрузка и выгрузка чанков) — для кэша островов
+    land_revision: u64,
+    // --- выгрузка чанков (LRU по расстоянию от камеры) ---
+    // счётчик кадров планирования (schedule_ring)
+    pub frame_tick: u64,
+    // чанк под центром камеры и видимый прямоугольник чанков (не выгружаем)
+    pub focus_chunk: (i32, i32),
+    pub view_chunks: (i32, i32, i32, i32),
+    // рабочие области зданий (центр и радиус в тайлах): их чанки не выгружаем,
+    // иначе добытчики и лесорубы не видят месторождений и деревьев соседнего чанка
+    pub work_areas: Vec<(IVec2, i32)>,
+    // чанки с изменениями игрока (вырубка/посадка деревьев)
+    pub modified_chunks: HashSet<(i32, i32)>,
+    // изменения игрока в выгруженных (или ещё не загруженных) чанках
+    persisted: HashMap<(i32, i32), PersistedChunk>,
+    // выработанные за шаг месторождения (для уведомлений)
+    pub depleted_events: Vec<(IVec2, DepositKind)>,
+    // --- рост деревьев порциями чанков ---
+    growth_clock_ms: i64,
+    growth_queue: VecDeque<(i32, i32)>,
+    // ключи, стоящие в growth_queue (проверка без прохода по очереди)
+    growth_queued: HashSet<(i32, i32)>,
 }
 
 impl World {
-    pub fn new(seed: u64) -> Self {
-        let mut fbm = Fbm::<noise::OpenSimplex>::new(0);
-        fbm = fbm.set_seed(seed as u32).set_octaves(5).set_frequency(0.03).set_lacunarity(2.0).set_persistence(0.5);
-        let (tx, rx) = spawn_chunk_worker(seed);
-        Self { seed, fbm, chunks: HashMap::new(), occupied: HashSet::new(), roads: HashSet::new(), trees: HashMap::new(), tx, rx, pending: HashSet::new(), max_chunks: 512, removed_trees: HashSet::new(), clay_deposits: HashSet::new(), stone_deposits: HashSet::new(), iron_deposits: HashSet::new(), rivers: HashSet::new(), biomes: HashMap::new(), biome_swamp_thr: 0.10, biome_rocky_thr: 0.10, biome_swamp_tree_growth_wmul: 0.85, biome_rocky_tree_growth_wmul: 1.20, explored_tiles: HashSet::new() }
+    pub fn new(seed: u64, worker_threads: usize) -> Self {
+        let fbm = make_fbm(seed);
+        let workers = ChunkWorkerPool::new(seed, worker_threads);
+        Self { seed, fbm, chunks: HashMap::new(), workers, worker_threads, pending: HashSet::new(), max_chunks: 512, removed_trees: HashSet::new(), biome_swamp_thr: 0.10, biome_rocky_thr: 0.10, biome_swamp_tree_growth_wmul: 0.85, biome_rocky_tree_growth_wmul: 1.20, map_gen: MapGenParams::default(), explored: HashMap::new(), visible: HashMap::new(), vision_clock_ms: 0, ferries: Vec::new(), islands: HashMap::new(), land_revision: 0, frame_tick: 0, focus_chunk: (0, 0), view_chunks: (0, 0, 0, 0), work_areas: Vec::new(), modified_chunks: HashSet::new(), persisted: HashMap::new(), depleted_events: Vec::new(), growth_clock_ms: 0, growth_queue: VecDeque::new(), growth_queued: HashSet::new() }
     }
 
     pub fn reset_noise(&mut self, seed: u64) {
         self.seed = seed;
-        let mut fbm = Fbm::<noise::OpenSimplex>::new(0);
-        fbm = fbm.set_seed(seed as u32).set_octaves(5).set_frequency(0.03).set_lacunarity(2.0).set_persistence(0.5);
-        self.fbm = fbm;
+        self.fbm = make_fbm(seed);
         self.chunks.clear();
-        self.occupied.clear();
-        self.roads.clear();
-        self.trees.clear();
         self.removed_trees.clear();
-        self.clay_deposits.clear();
-        self.stone_deposits.clear();
-        self.iron_deposits.clear();
-        self.rivers.clear();
-        self.biomes.clear();
-        self.explored_tiles.clear();
-        let (tx, rx) = spawn_chunk_worker(seed);
-        self.tx = tx; self.rx = rx; self.pending.clear();
+        self.explored.clear();
+        self.visible.clear();
+        self.ferries.clear();
+        self.islands.clear();
+        self.land_revision += 1;
+        self.modified_chunks.clear();
+        self.persisted.clear();
+        self.depleted_events.clear();
+        self.growth_queue.clear();
+        self.growth_queued.clear();
+        // старый пул останавливается при drop
+        self.workers = ChunkWorkerPool::new(seed, self.worker_threads);
+        self.pending.clear();
     }
 
     pub fn get_tile(&mut self, tx_t: i32, ty_t: i32) -> TileKind {
@@ -76,8 +300,7 @@ impl World {
         let lx = tx_t.rem_euclid(CHUNK_W);
         let ly = ty_t.rem_euclid(CHUNK_H);
         if !self.chunks.contains_key(&(cx, cy)) && !self.pending.contains(&(cx, cy)) {
-            let _ = self.tx.send((cx, cy));
-            self.pending.insert((cx, cy));
+            self.request_chunk((cx, cy));
         }
         if let Some(chunk) = self.chunks.get(&(cx, cy)) {
             return chunk.tiles[(ly * CHUNK_W + lx) as usize];
@@ -85,134 +308,202 @@ impl World {
         self.tile_by_noise(tx_t, ty_t)
     }
 
-    pub fn is_occupied(&self, t: IVec2) -> bool { self.occupied.contains(&(t.x, t.y)) }
-    pub fn occupy(&mut self, t: IVec2) { self.occupied.insert((t.x, t.y)); }
-    pub fn set_road(&mut self, t: IVec2, on: bool) { if on { self.roads.insert((t.x, t.y)); } else { self.roads.remove(&(t.x, t.y)); } }
-    pub fn is_road(&self, t: IVec2) -> bool { self.roads.contains(&(t.x, t.y)) }
+    fn request_chunk(&mut self, key: (i32, i32)) {
+        self.workers.request(self.chunk_request(key));
+        self.pending.insert(key);
+    }
+
+    // заявка на генерацию с текущими порогами биомов и параметрами карты
+    fn chunk_request(&self, key: (i32, i32)) -> ChunkRequest {
+        ChunkRequest { cx: key.0, cy: key.1, swamp_thr: self.biome_swamp_thr, rocky_thr: self.biome_rocky_thr, map_gen: self.map_gen }
+    }
+
+    /// Тайл только из загруженного чанка (без запроса генерации)
+    pub fn loaded_tile(&self, p: IVec2) -> Option<TileKind> {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).map(|ch| ch.tiles[i])
+    }
+
+    /// Тайл без запроса генерации: из загруженного чанка или по шуму
+    pub fn tile_at(&self, p: IVec2) -> TileKind {
+        self.loaded_tile(p).unwrap_or_else(|| self.tile_by_noise(p.x, p.y))
+    }
+
+    /// Причал на другом конце паромной линии
+    pub fn ferry_partner(&self, p: IVec2) -> Option<IVec2> {
+        self.ferries.iter().find_map(|&(a, b)| if a == p { Some(b) } else if b == p { Some(a) } else { None })
+    }
+
+    /// Номер острова тайла (None — не размечен)
+    pub fn island_at(&self, p: IVec2) -> Option<u32> {
+        self.islands.get(&(p.x, p.y)).copied()
+    }
+
+    pub fn land_revision(&self) -> u64 { self.land_revision }
 
+    pub fn set_islands(&mut self, islands: HashMap<(i32, i32), u32>) {
+        self.islands = islands;
+    }
+
+    pub fn chunk_of(tx: i32, ty: i32) -> (i32, i32) { (tx.div_euclid(CHUNK_W), ty.div_euclid(CHUNK_H)) }
+
+    // чанк и индекс тайла внутри него
+    fn locate(tx: i32, ty: i32) -> ((i32, i32), usize) {
+        let lx = tx.rem_euclid(CHUNK_W);
+        let ly = ty.rem_euclid(CHUNK_H);
+        (Self::chunk_of(tx, ty), (ly * CHUNK_W + lx) as usize)
+    }
+
+    fn tile_pos(key: (i32, i32), i: usize) -> (i32, i32) {
+        (key.0 * CHUNK_W + (i as i32) % CHUNK_W, key.1 * CHUNK_H + (i as i32) / CHUNK_W)
+    }
+
+    // --- Дороги и занятые клетки (слои игрока) ---
+    fn player_layers(&self, key: (i32, i32)) -> Option<&PlayerLayers> {
+        self.chunks.get(&key).map(|ch| &ch.player).or_else(|| self.persisted.get(&key).map(|pc| &pc.player))
+    }
+
+    fn player_layers_mut(&mut self, key: (i32, i32)) -> &mut PlayerLayers {
+        if self.chunks.contains_key(&key) {
+            return &mut self.chunks.get_mut(&key).unwrap().player;
+        }
+        // чанк ещё не загружен (например, при загрузке сейва) — копим изменения до интеграции
+        &mut self.persisted.entry(key).or_default().player
+    }
+
+    pub fn is_occupied(&self, t: IVec2) -> bool {
+        let (key, i) = Self::locate(t.x, t.y);
+        self.player_layers(key).is_some_and(|pl| pl.occupied.get(i))
+    }
+    pub fn occupy(&mut self, t: IVec2) { self.set_occupied(t, true); }
+    pub fn vacate(&mut self, t: IVec2) { self.set_occupied(t, false); }
+    fn set_occupied(&mut self, t: IVec2, on: bool) {
+        let (key, i) = Self::locate(t.x, t.y);
+        self.player_layers_mut(key).occupied.set(i, on);
+    }
+    pub fn clear_occupied(&mut self) {
+        for ch in self.chunks.values_mut() { ch.player.occupied = BitLayer::default(); }
+        for pc in self.persisted.values_mut() { pc.player.occupied = BitLayer::default(); }
+    }
+
+    pub fn set_road(&mut self, t: IVec2, on: bool) {
+        let (key, i) = Self::locate(t.x, t.y);
+        self.player_layers_mut(key).roads.set(i, on);
+        self.land_revision += 1;
+    }
+    pub fn is_road(&self, t: IVec2) -> bool {
+        let (key, i) = Self::locate(t.x, t.y);
+        self.player_layers(key).is_some_and(|pl| pl.roads.get(i))
+    }
+
+    /// Все клетки дорог (для сохранения)
+    pub fn all_roads(&self) -> Vec<(i32, i32)> {
+        let loaded = self.chunks.iter().map(|(&key, ch)| (key, &ch.player));
+        let stored = self.persisted.iter().map(|(&key, pc)| (key, &pc.player));
+        loaded.chain(stored)
+            .flat_map(|(key, pl)| pl.roads.ones().map(move |i| Self::tile_pos(key, i)))
+            .collect()
+    }
+
+    pub fn restore_roads(&mut self, roads: impl IntoIterator<Item = (i32, i32)>) {
+        for ch in self.chunks.values_mut() { ch.player.roads = BitLayer::default(); }
+        for pc in self.persisted.values_mut() { pc.player.roads = BitLayer::default(); }
+        for (x, y) in roads { self.set_road(IVec2::new(x, y), true); }
+    }
+
+    /// Принять готовые чанки от пула генерации (non-blocking).
+    /// Воркеры присылают чанк целиком; здесь только накладываем изменения игрока.
     pub fn integrate_ready_chunks(&mut self) {
-        for res in self.rx.try_iter() {
-            self.chunks.insert((res.cx, res.cy), Chunk { tiles: res.tiles });
-            self.pending.remove(&(res.cx, res.cy));
-            // Заполним набор деревьев по лесным тайлам в чанке
-            if let Some(chunk) = self.chunks.get_mut(&(res.cx, res.cy)) {
-                for ly in 0..CHUNK_H { for lx in 0..CHUNK_W {
-                    let idx = (ly * CHUNK_W + lx) as usize;
-                    let tx = res.cx * CHUNK_W + lx;
-                    let ty = res.cy * CHUNK_H + ly;
-                    let p = IVec2::new(tx, ty);
-                    // Преобразуем «речные» клетки в воду на этапе интеграции (без доп. заимствования self)
-                    // Локальные параметры/шумы
-                    let fbm = &self.fbm;
-                    let is_river = {
-                        // маска 1
-                        let v1 = fbm.get([p.x as f64 * 0.035 + 1234.0, p.y as f64 * 0.035 - 987.0]) as f32;
-                        let u1 = fbm.get([p.x as f64 * 0.018 - 777.0, p.y as f64 * 0.022 + 444.0]) as f32;
-                        let w1 = fbm.get([p.x as f64 * 0.10 - 222.0, p.y as f64 * 0.10 + 333.0]) as f32;
-                        let s1 = ((v1 + u1 * 0.5) * 3.14159).sin().abs();
-                        let width1 = 0.028 + 0.018 * (w1 * 0.5 + 0.5); // 0.028..0.046
-                        // маска 2 (смещённые фазы) — добавляет дополнительных рек, почти не увеличивая ширину
-                        let v2 = fbm.get([p.x as f64 * 0.032 - 321.0, p.y as f64 * 0.032 + 654.0]) as f32;
-                        let u2 = fbm.get([p.x as f64 * 0.020 + 999.0, p.y as f64 * 0.017 - 888.0]) as f32;
-                        let w2 = fbm.get([p.x as f64 * 0.12 + 111.0, p.y as f64 * 0.12 - 222.0]) as f32;
-                        let s2 = ((v2 + u2 * 0.5) * 3.14159).sin().abs();
-                        let width2 = 0.026 + 0.016 * (w2 * 0.5 + 0.5); // 0.026..0.042
-                        (s1 < width1) || (s2 < width2)
-                    };
-                    if chunk.tiles[idx] != TileKind::Water && is_river {
-                        chunk.tiles[idx] = TileKind::Water;
-                        self.rivers.insert((tx, ty));
-                    }
-                    if chunk.tiles[idx] == TileKind::Forest {
-                        if !self.removed_trees.contains(&(tx, ty)) {
-                            // Детерминированное распределение зрелости по координатам и seed:
-                            // ~15% stage0, ~30% stage1, ~55% stage2
-                            let mut v = (tx as i64).wrapping_mul(0x9E3779B97F4A7C15u64 as i64)
-                                ^ (ty as i64).wrapping_mul(0xC2B2AE3D27D4EB4Fu64 as i64)
-                                ^ (self.seed as i64);
-                            // xorshift64*
-                            v ^= v >> 12; v ^= v << 25; v ^= v >> 27;
-                            let u = ((v.wrapping_mul(0x2545F4914F6CDD1D) >> 11) & 0xFFFF_FFFF) as u32;
-                            let r = (u as f32) / (u32::MAX as f32); // 0..1
-                            let stage = if r < 0.15 { 0 } else if r < 0.45 { 1 } else { 2 };
-                            self.trees.insert((tx, ty), Tree { stage, age_ms: 0 });
-                        }
-                    }
-                    // кэш: месторождений и биомы (на неводных)
-                    if chunk.tiles[idx] != TileKind::Water {
-                        // Локальный расчёт биома и месторождений для избежания конфликта заимствований
-                        let fbm = &self.fbm;
-                        let swamp_thr = self.biome_swamp_thr;
-                        let rocky_thr = self.biome_rocky_thr;
-                        let moisture = fbm.get([tx as f64 * 0.18 + 311.0, ty as f64 * 0.18 - 211.0]) as f32;
-                        let rocky_v  = fbm.get([tx as f64 * 0.22 - 157.0, ty as f64 * 0.22 +  97.0]) as f32;
-                        let bm = if moisture > swamp_thr { BiomeKind::Swamp } else if rocky_v > rocky_thr { BiomeKind::Rocky } else { BiomeKind::Meadow };
-                        // базовые вероятности
-                        let has_clay_base = {
-                            let base = fbm.get([tx as f64 * 0.30 + 31.0, ty as f64 * 0.30 - 77.0]) as f32;
-                            let thr = 0.22_f32; let margin = 0.05_f32;
-                            if base > thr { true } else if base > thr - margin {
-                                const NB: [(i32,i32);8] = [(1,0),(-1,0),(0,1),(0,-1),(1,1),(1,-1),(-1,1),(-1,-1)];
-                                let mut hits = 0; for (dx,dy) in NB { let v = fbm.get([(tx+dx) as f64 * 0.30 + 31.0, (ty+dy) as f64 * 0.30 - 77.0]) as f32; if v > thr { hits += 1; } }
-                                hits >= 2
-                            } else { false }
-                        };
-                        let has_stone_base = {
-                            let base = fbm.get([tx as f64 * 0.38 - 123.0, ty as f64 * 0.38 + 19.0]) as f32;
-                            let thr = 0.26_f32; let margin = 0.06_f32;
-                            if base > thr { true } else if base > thr - margin {
-                                const NB: [(i32,i32);8] = [(1,0),(-1,0),(0,1),(0,-1),(1,1),(1,-1),(-1,1),(-1,-1)];
-                                let mut hits = 0; for (dx,dy) in NB { let v = fbm.get([(tx+dx) as f64 * 0.38 - 123.0, (ty+dy) as f64 * 0.38 + 19.0]) as f32; if v > thr { hits += 1; } }
-                                hits >= 2
-                            } else { false }
-                        };
-                        let has_iron_base = {
-                            let base = fbm.get([tx as f64 * 0.34 + 211.0, ty as f64 * 0.34 + 87.0]) as f32;
-                            let thr = 0.30_f32; let margin = 0.05_f32;
-                            if base > thr { true } else if base > thr - margin {
-                                const NB: [(i32,i32);8] = [(1,0),(-1,0),(0,1),(0,-1),(1,1),(1,-1),(-1,1),(-1,-1)];
-                                let mut hits = 0; for (dx,dy) in NB { let v = fbm.get([(tx+dx) as f64 * 0.34 + 211.0, (ty+dy) as f64 * 0.34 + 87.0]) as f32; if v > thr { hits += 1; } }
-                                hits >= 2
-                            } else { false }
-                        };
-                        // биомные довески
-                        let mut has_clay = has_clay_base;
-                        if !has_clay && matches!(bm, BiomeKind::Swamp) {
-                            let n = fbm.get([tx as f64 * 0.55 - 13.0, ty as f64 * 0.55 + 21.0]) as f32; if n > 0.60 { has_clay = true; }
-                        }
-                        let mut has_stone = has_stone_base;
-                        if !has_stone && matches!(bm, BiomeKind::Rocky) {
-                            let n = fbm.get([tx as f64 * 0.52 + 77.0, ty as f64 * 0.52 - 41.0]) as f32; if n > 0.62 { has_stone = true; }
-                        }
-                        let mut has_iron = has_iron_base;
-                        if !has_iron && matches!(bm, BiomeKind::Rocky) {
-                            let n = fbm.get([tx as f64 * 0.59 - 91.0, ty as f64 * 0.59 + 63.0]) as f32; if n > 0.64 { has_iron = true; }
-                        }
-                        if has_clay { self.clay_deposits.insert((tx, ty)); }
-                        if has_stone { self.stone_deposits.insert((tx, ty)); }
-                        if has_iron { self.iron_deposits.insert((tx, ty)); }
-                        self.biomes.insert((tx, ty), bm);
-                    }
-                }}
+        while let Some(res) = self.workers.try_recv() {
+            let key = (res.cx, res.cy);
+            self.pending.remove(&key);
+            // повторный результат для уже загруженного чанка не должен затирать его слои
+            if self.chunks.contains_key(&key) { continue; }
+            self.integrate_chunk(key, res.chunk);
+        }
+        self.evict_far_chunks();
+    }
+
+    // наложить изменения игрока на сгенерированный чанк и поставить его в мир
+    fn integrate_chunk(&mut self, key: (i32, i32), mut chunk: Chunk) {
+        chunk.last_seen = self.frame_tick;
+        chunk.grown_until_ms = self.growth_clock_ms;
+        // Изменённый игроком чанк возвращается со своими деревьями и дорогами, а не с посевом по seed
+        let modified = self.modified_chunks.contains(&key);
+        let persisted = self.persisted.remove(&key);
+        if let Some(pc) = &persisted {
+            chunk.player = pc.player;
+            // выработанные месторождения не восстанавливаются при догрузке
+            for ((x, y), amounts) in &pc.deposits {
+                let (_, i) = Self::locate(*x, *y);
+                for (layer, &left) in chunk.deposits.iter_mut().zip(amounts) {
+                    layer.set_left(i, left);
+                }
+                chunk.deposits_touched = true;
             }
         }
-        if self.chunks.len() > self.max_chunks {
-            while self.chunks.len() > self.max_chunks {
-                if let Some((&key, _)) = self.chunks.iter().next() {
-                    // Удалим деревья, принадлежащие этому чанку
-                    let (cx, cy) = key;
-                    let min_tx = cx * CHUNK_W; let max_tx = min_tx + CHUNK_W - 1;
-                    let min_ty = cy * CHUNK_H; let max_ty = min_ty + CHUNK_H - 1;
-                    self.trees.retain(|&(tx, ty), _| !(tx >= min_tx && tx <= max_tx && ty >= min_ty && ty <= max_ty));
-                    // очистим кэш месторождений в пределах чанка
-                    self.clay_deposits.retain(|&(tx, ty)| !(tx >= min_tx && tx <= max_tx && ty >= min_ty && ty <= max_ty));
-                    self.stone_deposits.retain(|&(tx, ty)| !(tx >= min_tx && tx <= max_tx && ty >= min_ty && ty <= max_ty));
-                    self.iron_deposits.retain(|&(tx, ty)| !(tx >= min_tx && tx <= max_tx && ty >= min_ty && ty <= max_ty));
-                    self.rivers.retain(|&(tx, ty)| !(tx >= min_tx && tx <= max_tx && ty >= min_ty && ty <= max_ty));
-                    self.chunks.remove(&key);
-                } else { break; }
+        match persisted {
+            Some(pc) if modified => {
+                chunk.clear_trees();
+                for &((x, y), tree) in &pc.trees {
+                    let (_, i) = Self::locate(x, y);
+                    chunk.set_tree(i, Some(tree));
+                }
+            }
+            Some(_) => {}
+            None if modified => {
+                for i in 0..CHUNK_AREA {
+                    if self.removed_trees.contains(&Self::tile_pos(key, i)) { chunk.set_tree(i, None); }
+                }
             }
+            None => {}
         }
+        self.chunks.insert(key, chunk);
+        self.land_revision += 1;
+        if self.growth_queued.insert(key) { self.growth_queue.push_back(key); }
+    }
+
+    /// Выгрузить дальние чанки, когда их больше `max_chunks`.
+    /// Кандидаты — вне видимой области, сначала самые далёкие от камеры, затем давно не виденные.
+    /// Чанки с дорогами и зданиями остаются в памяти (по ним ходят жители),
+    /// как и чанки рабочих областей зданий (`work_areas`);
+    /// деревья остальных изменённых чанков сохраняются и вернутся при догрузке.
+    fn evict_far_chunks(&mut self) {
+        if self.chunks.len() <= self.max_chunks { return; }
+        let pinned: HashSet<(i32, i32)> = self.work_areas.iter().flat_map(|&(c, r)| {
+            let (x0, y0) = Self::chunk_of(c.x - r, c.y - r);
+            let (x1, y1) = Self::chunk_of(c.x + r, c.y + r);
+            (y0..=y1).flat_map(move |cy| (x0..=x1).map(move |cx| (cx, cy)))
+        }).collect();
+        // гистерезис: выгружаем с запасом, чтобы не сортировать на каждом новом чанке
+        let target = self.max_chunks - self.max_chunks / 8;
+        let (vx0, vy0, vx1, vy1) = self.view_chunks;
+        let (fx, fy) = self.focus_chunk;
+        let mut candidates: Vec<((i32, i32), i32, u64)> = self.chunks.iter()
+            .filter(|&(&(cx, cy), _)| !(cx >= vx0 && cx <= vx1 && cy >= vy0 && cy <= vy1))
+            .map(|(&key, ch)| (key, (key.0 - fx).abs().max((key.1 - fy).abs()), ch.last_seen))
+            .collect();
+        candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+        for (key, _, _) in candidates {
+            if self.chunks.len() <= target { break; }
+            if self.chunks[&key].player.any() || pinned.contains(&key) { continue; }
+            self.unload_chunk(key);
+        }
+    }
+
+    fn unload_chunk(&mut self, key: (i32, i32)) {
+        let Some(chunk) = self.chunks.remove(&key) else { return; };
+        self.land_revision += 1;
+        // немодифицированный чанк просто пересоздаётся из seed при возврате
+        if !self.modified_chunks.contains(&key) && !chunk.player.any() && !chunk.deposits_touched { return; }
+        let trees = chunk.tree_list().map(|(i, tree)| (Self::tile_pos(key, i), tree)).collect();
+        let deposits = if chunk.deposits_touched {
+            (0..CHUNK_AREA)
+                .filter(|&i| chunk.deposits.iter().any(|l| l.present.get(i)))
+                .map(|i| (Self::tile_pos(key, i), chunk.deposit_amounts(i)))
+                .collect()
+        } else { Vec::new() };
+        self.persisted.insert(key, PersistedChunk { trees, player: chunk.player, deposits });
     }
 
     pub fn schedule_ring(&mut self, min_tx: i32, min_ty: i32, max_tx: i32, max_ty: i32) {
@@ -220,43 +511,164 @@ impl World {
         let cmin_y = (min_ty.div_euclid(CHUNK_H)) - 1;
         let cmax_x = (max_tx.div_euclid(CHUNK_W)) + 1;
         let cmax_y = (max_ty.div_euclid(CHUNK_H)) + 1;
+        self.frame_tick += 1;
+        self.view_chunks = (cmin_x, cmin_y, cmax_x, cmax_y);
+        let focus = ((cmin_x + cmax_x).div_euclid(2), (cmin_y + cmax_y).div_euclid(2));
+        if focus != self.focus_chunk || self.frame_tick == 1 {
+            self.focus_chunk = focus;
+            self.workers.set_focus(focus);
+            // запросы, ушедшие далеко за пределы экрана, отменяем (при возврате закажем заново)
+            let keep = (cmin_x - CANCEL_MARGIN_CHUNKS, cmin_y - CANCEL_MARGIN_CHUNKS, cmax_x + CANCEL_MARGIN_CHUNKS, cmax_y + CANCEL_MARGIN_CHUNKS);
+            for key in self.workers.cancel_outside(keep) { self.pending.remove(&key); }
+        }
         for cy in cmin_y..=cmax_y { for cx in cmin_x..=cmax_x {
-            if !self.chunks.contains_key(&(cx, cy)) && !self.pending.contains(&(cx, cy)) {
-                let _ = self.tx.send((cx, cy)); self.pending.insert((cx, cy));
+            if let Some(chunk) = self.chunks.get_mut(&(cx, cy)) {
+                chunk.last_seen = self.frame_tick;
+            } else if !self.pending.contains(&(cx, cy)) {
+                self.request_chunk((cx, cy));
             }
         }}
     }
 
     fn tile_by_noise(&self, tx: i32, ty: i32) -> TileKind {
-        let n = self.fbm.get([tx as f64, ty as f64]) as f32;
-        // Дополнительные вариации ресурсов через смещённые октавы шума
-        let n2 = self.fbm.get([tx as f64 * 0.7 + 100.0, ty as f64 * 0.7 - 50.0]) as f32;
-        let _n3 = self.fbm.get([tx as f64 * 0.9 - 200.0, ty as f64 * 0.9 + 80.0]) as f32;
-        if n < -0.2 { TileKind::Water }
-        else if n > 0.6 && n2 > 0.5 { TileKind::Forest }
-        else { TileKind::Grass }
+        let req = self.chunk_request(Self::chunk_of(tx, ty));
+        terrain_at(land_noise(&self.fbm, tx, ty, &self.map_gen), height_at(&self.fbm, tx, ty, &req), &self.map_gen)
+    }
+
+    /// Уровень высоты клетки (0..=31)
+    pub fn height(&self, p: IVec2) -> u8 {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).map(|ch| ch.heights[i])
+            .unwrap_or_else(|| height_at(&self.fbm, p.x, p.y, &self.chunk_request(key)))
+    }
+
+    /// Крутизна склона: наибольший перепад высот с соседями по 4 сторонам
+    pub fn slope(&self, p: IVec2) -> u8 {
+        let h = self.height(p);
+        [(1, 0), (-1, 0), (0, 1), (0, -1)].iter()
+            .map(|&(dx, dy)| h.abs_diff(self.height(IVec2::new(p.x + dx, p.y + dy))))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn has_clay_deposit(&self, p: IVec2) -> bool { self.deposit_left(p, DepositKind::Clay) > 0 }
+
+    pub fn has_stone_deposit(&self, p: IVec2) -> bool { self.deposit_left(p, DepositKind::Stone) > 0 }
+
+    pub fn has_iron_deposit(&self, p: IVec2) -> bool { self.deposit_left(p, DepositKind::Iron) > 0 }
+
+    // --- Месторождения: запасы, добыча, восстановление ---
+    /// Остаток месторождения в клетке (0 — нет или выработано)
+    pub fn deposit_left(&self, p: IVec2, kind: DepositKind) -> u16 {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).map_or(0, |ch| ch.deposits[kind as usize].left(i))
     }
 
-    pub fn has_clay_deposit(&self, p: IVec2) -> bool { self.clay_deposits.contains(&(p.x, p.y)) }
+    /// Месторождение было, но выработано
+    pub fn is_depleted(&self, p: IVec2, kind: DepositKind) -> bool {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).is_some_and(|ch| {
+            let layer = &ch.deposits[kind as usize];
+            layer.present.get(i) && layer.left(i) == 0
+        })
+    }
 
-    pub fn has_stone_deposit(&self, p: IVec2) -> bool { self.stone_deposits.contains(&(p.x, p.y)) }
+    /// Суммарный остаток в квадрате радиуса `radius` вокруг `center`
+    pub fn deposit_left_around(&self, center: IVec2, radius: i32, kind: DepositKind) -> u32 {
+        let mut total = 0;
+        for dy in -radius..=radius { for dx in -radius..=radius {
+            total += self.deposit_left(center + IVec2::new(dx, dy), kind) as u32;
+        }}
+        total
+    }
 
-    pub fn has_iron_deposit(&self, p: IVec2) -> bool { self.iron_deposits.contains(&(p.x, p.y)) }
+    /// Добыть единицу сырья из ближайшей к `center` клетки; false — вокруг всё выработано
+    pub fn extract_deposit(&mut self, center: IVec2, radius: i32, kind: DepositKind) -> bool {
+        let mut best: Option<(i32, IVec2)> = None;
+        for dy in -radius..=radius { for dx in -radius..=radius {
+            let p = center + IVec2::new(dx, dy);
+            let d = dx.abs() + dy.abs();
+            if self.deposit_left(p, kind) > 0 && best.is_none_or(|(bd, _)| d < bd) { best = Some((d, p)); }
+        }}
+        let Some((_, p)) = best else { return false; };
+        let (key, i) = Self::locate(p.x, p.y);
+        let ch = self.chunks.get_mut(&key).unwrap();
+        let layer = &mut ch.deposits[kind as usize];
+        let left = layer.left(i) - 1;
+        layer.set_left(i, left);
+        ch.deposits_touched = true;
+        if left == 0 && self.deposit_left_around(center, radius, kind) == 0 {
+            self.depleted_events.push((center, kind));
+        }
+        true
+    }
+
+    /// Ежедневное восстановление глины в разработанных чанках (до исходного запаса клетки)
+    pub fn regenerate_clay(&mut self) {
+        for ch in self.chunks.values_mut() {
+            if !ch.deposits_touched { continue; }
+            let layer = &mut ch.deposits[DepositKind::Clay as usize];
+            for i in 0..CHUNK_AREA {
+                let (left, cap) = (layer.left(i), layer.initial(i).min(CLAY_REGEN_CAP));
+                if layer.present.get(i) && left < cap {
+                    layer.set_left(i, (left + CLAY_REGEN_PER_DAY).min(cap));
+                }
+            }
+        }
+    }
+
+    /// Остатки месторождений в разработанных чанках (для сохранения)
+    pub fn all_deposits(&self) -> ChunkDeposits {
+        let loaded = self.chunks.iter().filter(|(_, ch)| ch.deposits_touched).flat_map(|(&key, ch)| {
+            (0..CHUNK_AREA)
+                .filter(|&i| ch.deposits.iter().any(|l| l.present.get(i)))
+                .map(move |i| (Self::tile_pos(key, i), ch.deposit_amounts(i)))
+        });
+        let stored = self.persisted.values().flat_map(|pc| pc.deposits.iter().cloned());
+        loaded.chain(stored).collect()
+    }
+
+    /// Восстановить остатки месторождений из сохранения
+    pub fn restore_deposits(&mut self, deposits: impl IntoIterator<Item = ((i32, i32), Vec<u16>)>) {
+        for ((x, y), amounts) in deposits {
+            let (key, i) = Self::locate(x, y);
+            if let Some(ch) = self.chunks.get_mut(&key) {
+                for (layer, &left) in ch.deposits.iter_mut().zip(&amounts) {
+                    layer.set_left(i, left);
+                }
+                ch.deposits_touched = true;
+            } else {
+                self.persisted.entry(key).or_default().deposits.push(((x, y), amounts));
+            }
+        }
+    }
+
+    #[allow(dead_code)] // Может быть полезно для мостов/мелководья
+    pub fn is_river(&self, p: IVec2) -> bool { self.chunk_bit(p, |ch| &ch.rivers) }
+
+    fn chunk_bit(&self, p: IVec2, layer: impl Fn(&Chunk) -> &BitLayer) -> bool {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).is_some_and(|ch| layer(ch).get(i))
+    }
 
     pub fn biome(&self, p: IVec2) -> BiomeKind {
-        self.biomes.get(&(p.x, p.y)).cloned().unwrap_or_else(|| self.compute_biome(p))
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).map(|ch| ch.biomes[i]).unwrap_or_else(|| self.compute_biome(p))
+    }
+
+    /// Пересчитать слой биомов загруженных чанков (после смены порогов)
+    pub fn recompute_biomes(&mut self) {
+        let keys: Vec<(i32, i32)> = self.chunks.keys().copied().collect();
+        for key in keys {
+            let biomes: Vec<BiomeKind> = (0..CHUNK_AREA)
+                .map(|i| { let (x, y) = Self::tile_pos(key, i); self.compute_biome(IVec2::new(x, y)) })
+                .collect();
+            if let Some(ch) = self.chunks.get_mut(&key) { ch.biomes = biomes; }
+        }
     }
 
     fn compute_biome(&self, p: IVec2) -> BiomeKind {
-        // базовый шум для «влажности/болотистости» и «каменистости»
-        let moisture = self.fbm.get([p.x as f64 * 0.18 + 311.0, p.y as f64 * 0.18 - 211.0]) as f32; // -1..1
-        let rocky    = self.fbm.get([p.x as f64 * 0.22 - 157.0, p.y as f64 * 0.22 +  97.0]) as f32; // -1..1
-        let swamp_thr = self.biome_swamp_thr;
-        let rocky_thr = self.biome_rocky_thr;
-        // простое разбиение: высокий moisture -> болото; высокий rocky -> скалы; иначе — луг
-        if moisture > swamp_thr { BiomeKind::Swamp }
-        else if rocky > rocky_thr { BiomeKind::Rocky }
-        else { BiomeKind::Meadow }
+        biome_at(&self.fbm, p.x, p.y, self.biome_swamp_thr, self.biome_rocky_thr)
     }
 
     pub fn apply_biome_config(&mut self, cfg: &crate::input::Config) {
@@ -267,84 +679,566 @@ impl World {
     }
 
     // --- Деревья ---
-    pub fn has_tree(&self, p: IVec2) -> bool { self.trees.contains_key(&(p.x, p.y)) }
-    pub fn tree_stage(&self, p: IVec2) -> Option<u8> { self.trees.get(&(p.x, p.y)).map(|t| t.stage) }
-    pub fn plant_tree(&mut self, p: IVec2) { self.trees.insert((p.x, p.y), Tree { stage: 0, age_ms: 0 }); self.removed_trees.remove(&(p.x, p.y)); }
-    pub fn remove_tree(&mut self, p: IVec2) { self.trees.remove(&(p.x, p.y)); self.removed_trees.insert((p.x, p.y)); }
+    pub fn has_tree(&self, p: IVec2) -> bool { self.tree(p).is_some() }
+    pub fn tree_stage(&self, p: IVec2) -> Option<u8> { self.tree(p).map(|t| t.stage) }
+    fn tree(&self, p: IVec2) -> Option<Tree> {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.chunks.get(&key).and_then(|ch| ch.tree(i))
+    }
+
+    pub fn plant_tree(&mut self, p: IVec2) {
+        self.set_tree(p, Some(Tree { stage: 0, age_ms: 0 }));
+        self.removed_trees.remove(&(p.x, p.y));
+    }
+    pub fn remove_tree(&mut self, p: IVec2) {
+        self.set_tree(p, None);
+        self.removed_trees.insert((p.x, p.y));
+    }
+
+    fn set_tree(&mut self, p: IVec2, tree: Option<Tree>) {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.modified_chunks.insert(key);
+        if let Some(ch) = self.chunks.get_mut(&key) {
+            ch.set_tree(i, tree);
+            return;
+        }
+        let pc = self.persisted.entry(key).or_default();
+        pc.trees.retain(|&(tp, _)| tp != (p.x, p.y));
+        if let Some(tree) = tree { pc.trees.push(((p.x, p.y), tree)); }
+    }
 
+    /// Все деревья мира: загруженные и сохранённые в выгруженных чанках
+    pub fn all_trees(&self) -> Vec<((i32, i32), Tree)> {
+        let loaded = self.chunks.iter().flat_map(|(&key, ch)| {
+            ch.tree_list().map(move |(i, tree)| (Self::tile_pos(key, i), tree))
+        });
+        let stored = self.persisted.values().flat_map(|pc| pc.trees.iter().copied());
+        loaded.chain(stored).collect()
+    }
+
+    /// Восстановить деревья из сохранения: раскладываем по чанкам,
+    /// чтобы догрузка чанка не перезаписала их посевом по seed.
+    /// Изменёнными считаются только чанки, где деревья стоят не так, как при посеве.
+    pub fn restore_trees(&mut self, trees: impl IntoIterator<Item = ((i32, i32), Tree)>) {
+        self.removed_trees.clear();
+        self.modified_chunks.clear();
+        for ch in self.chunks.values_mut() { ch.clear_trees(); }
+        for pc in self.persisted.values_mut() { pc.trees.clear(); }
+        let mut by_chunk: HashMap<(i32, i32), ChunkTrees> = HashMap::new();
+        for ((x, y), tree) in trees { by_chunk.entry(Self::chunk_of(x, y)).or_default().push(((x, y), tree)); }
+        // загруженные чанки, где все деревья вырублены
+        let cleared: Vec<(i32, i32)> = self.chunks.keys().filter(|k| !by_chunk.contains_key(k)).copied().collect();
+        for key in cleared {
+            if self.seeded_trees(key).any() { self.modified_chunks.insert(key); }
+        }
+        for (key, list) in by_chunk {
+            let seeded = self.seeded_trees(key);
+            let as_seeded = list.len() == seeded.ones().count()
+                && list.iter().all(|&((x, y), _)| seeded.get(Self::locate(x, y).1));
+            if !as_seeded {
+                for ((x, y), tree) in list { self.set_tree(IVec2::new(x, y), Some(tree)); }
+                continue;
+            }
+            // как при посеве: выгруженный чанк пересоздастся из seed, загруженному возвращаем стадии роста
+            if let Some(ch) = self.chunks.get_mut(&key) {
+                for ((x, y), tree) in list { ch.set_tree(Self::locate(x, y).1, Some(tree)); }
+            }
+        }
+    }
+
+    // где стоят деревья чанка при посеве по seed (лес, не занятый рекой)
+    fn seeded_trees(&self, key: (i32, i32)) -> BitLayer {
+        let mut layer = BitLayer::default();
+        let river_width = self.map_gen.river_width;
+        for i in 0..CHUNK_AREA {
+            let (tx, ty) = Self::tile_pos(key, i);
+            let forest = self.tile_by_noise(tx, ty) == TileKind::Forest
+                && !(river_width > 0.0 && is_river_at(&self.fbm, tx, ty, river_width));
+            layer.set(i, forest);
+        }
+        layer
+    }
+
+    /// Рост деревьев порциями: за тик обновляется часть чанков,
+    /// каждый чанк получает всё время, прошедшее с его прошлого обновления
     pub fn grow_trees(&mut self, dt_ms: i32) {
-        // простая модель роста: 0->1 за 20с, 1->2 за ещё 40с, скорректированные биомом
-        // Swamp — быстрее деревья; Rocky — медленнее
-        let swamp_thr = self.biome_swamp_thr;
-        let rocky_thr = self.biome_rocky_thr;
-        let fbm = &self.fbm;
-        let swamp_tree_wmul = self.biome_swamp_tree_growth_wmul.max(0.01);
-        let rocky_tree_wmul = self.biome_rocky_tree_growth_wmul.max(0.01);
-        for (&(tx, ty), tr) in self.trees.iter_mut() {
-            // локально вычислим биом по шумам без повторного заимствования self
-            let moisture = fbm.get([tx as f64 * 0.18 + 311.0, ty as f64 * 0.18 - 211.0]) as f32;
-            let rocky = fbm.get([tx as f64 * 0.22 - 157.0, ty as f64 * 0.22 +  97.0]) as f32;
-            let wmul = if moisture > swamp_thr { swamp_tree_wmul } else if rocky > rocky_thr { rocky_tree_wmul } else { 1.0 };
-            tr.age_ms += ((dt_ms as f32) / wmul) as i32;
-            if tr.stage == 0 && tr.age_ms >= 20000 { tr.stage = 1; tr.age_ms = 0; }
-            else if tr.stage == 1 && tr.age_ms >= 40000 { tr.stage = 2; tr.age_ms = 0; }
-        }
-    }
-
-    // --- Область строительства (fog of war) ---
-    /// Проверить, разблокирован ли тайл для строительства
+        self.growth_clock_ms += dt_ms as i64;
+        let batch = self.growth_queue.len().div_ceil(TREE_GROWTH_SLICES);
+        for _ in 0..batch {
+            let Some(key) = self.growth_queue.pop_front() else { break; };
+            // выгруженный чанк выпадает из очереди; при загрузке встанет снова
+            let Some(chunk) = self.chunks.get_mut(&key) else { self.growth_queued.remove(&key); continue; };
+            grow_chunk_trees(chunk, self.growth_clock_ms, self.biome_swamp_tree_growth_wmul, self.biome_rocky_tree_growth_wmul);
+            self.growth_queue.push_back(key);
+        }
+    }
+
+    // --- Туман войны: разведанные (строить можно) и видимые сейчас тайлы ---
+    /// Проверить, разведан ли тайл (разблокирован для строительства)
     pub fn is_explored(&self, p: IVec2) -> bool {
-        self.explored_tiles.contains(&(p.x, p.y))
+        let (key, i) = Self::locate(p.x, p.y);
+        self.explored.get(&key).is_some_and(|l| l.get(i))
     }
 
-    /// Разблокировать область вокруг точки (круг с радиусом)
+    /// Виден ли тайл сейчас (в радиусе обзора зданий или разведчиков)
+    pub fn is_visible(&self, p: IVec2) -> bool {
+        let (key, i) = Self::locate(p.x, p.y);
+        self.visible.get(&key).is_some_and(|l| l.get(i))
+    }
+
+    /// Разведать область вокруг точки (круг с радиусом)
     pub fn explore_area(&mut self, center: IVec2, radius: i32) {
-        for dy in -radius..=radius {
-            for dx in -radius..=radius {
-                let dist_sq = dx * dx + dy * dy;
-                if dist_sq <= radius * radius {
-                    self.explored_tiles.insert((center.x + dx, center.y + dy));
-                }
-            }
+        mark_circle(&mut self.explored, center, radius);
+    }
+
+    /// Пересчитать видимость по зданиям и разведчикам (раз в VISION_REFRESH_MS);
+    /// всё, что видно, заодно становится разведанным
+    pub fn update_vision(&mut self, dt_ms: i32, buildings: &[crate::types::Building], scouts: &[IVec2]) {
+        self.vision_clock_ms += dt_ms;
+        if self.vision_clock_ms < VISION_REFRESH_MS { return; }
+        self.vision_clock_ms = 0;
+        self.visible.clear();
+        let eyes = buildings.iter().map(|b| (b.pos, b.kind.vision_radius()))
+            .chain(scouts.iter().map(|&p| (p, SCOUT_VISION_RADIUS)));
+        for (pos, r) in eyes {
+            mark_circle(&mut self.visible, pos, r);
+            mark_circle(&mut self.explored, pos, r);
         }
     }
 
-    /// Обновить область строительства на основе населения
-    /// Радиус увеличивается с ростом населения
-    pub fn update_exploration_by_population(&mut self, buildings: &[crate::types::Building], population: i32) {
-        // Начальный радиус: 8 тайлов
-        // Каждые 5 жителей добавляют +1 к радиусу
-        let base_radius = 8;
-        let radius = base_radius + (population / 5).min(20); // максимум радиус 28
-        
-        // Разблокируем область вокруг каждого дома
-        for building in buildings.iter() {
-            if matches!(building.kind, crate::types::BuildingKind::House) {
-                self.explore_area(building.pos, radius);
+    /// Чанки, в которых есть разведанные тайлы
+    pub fn explored_chunks(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.explored.keys().copied()
+    }
+
+    /// Разведанная область по чанкам (для сохранения)
+    pub fn explored_bits(&self) -> ChunkBits {
+        self.explored.iter().map(|(&key, l)| (key, l.0.to_vec())).collect()
+    }
+
+    /// Восстановить разведанную область из сохранения
+    pub fn restore_explored(&mut self, bits: impl IntoIterator<Item = ((i32, i32), Vec<u64>)>) {
+        self.explored.clear();
+        self.visible.clear();
+        for (key, words) in bits {
+            let layer = self.explored.entry(key).or_default();
+            for (dst, w) in layer.0.iter_mut().zip(words) { *dst = w; }
+        }
+        // видимость пересчитается на ближайшем шаге симуляции
+        self.vision_clock_ms = VISION_REFRESH_MS;
+    }
+}
+
+// отметить круг тайлов в послойной (по чанкам) битовой карте
+fn mark_circle(layers: &mut HashMap<(i32, i32), BitLayer>, center: IVec2, radius: i32) {
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy <= radius * radius {
+                let (key, i) = World::locate(center.x + dx, center.y + dy);
+                layers.entry(key).or_default().set(i, true);
             }
         }
     }
 }
 
-pub struct ChunkResult { pub cx: i32, pub cy: i32, pub tiles: Vec<TileKind> }
+// простая модель роста: 0->1 за 20с, 1->2 за ещё 40с, скорректированные биомом
+// Swamp — быстрее деревья; Rocky — медленнее
+fn grow_chunk_trees(chunk: &mut Chunk, clock_ms: i64, swamp_wmul: f32, rocky_wmul: f32) {
+    let dt_ms = (clock_ms - chunk.grown_until_ms) as f32;
+    chunk.grown_until_ms = clock_ms;
+    let Some(trees) = chunk.trees.as_deref_mut() else { return; };
+    if chunk.tree_count == 0 { return; }
+    let swamp_wmul = swamp_wmul.max(0.01);
+    let rocky_wmul = rocky_wmul.max(0.01);
+    for (tr, bm) in trees.iter_mut().zip(chunk.biomes.iter()) {
+        let Some(tr) = tr else { continue; };
+        let wmul = match bm { BiomeKind::Swamp => swamp_wmul, BiomeKind::Rocky => rocky_wmul, BiomeKind::Meadow => 1.0 };
+        tr.age_ms += (dt_ms / wmul) as i32;
+        if tr.stage == 0 && tr.age_ms >= 20000 { tr.stage = 1; tr.age_ms = 0; }
+        else if tr.stage == 1 && tr.age_ms >= 40000 { tr.stage = 2; tr.age_ms = 0; }
+    }
+}
+
+fn make_fbm(seed: u64) -> Fbm<noise::OpenSimplex> {
+    Fbm::<noise::OpenSimplex>::new(0)
+        .set_seed(seed as u32).set_octaves(5).set_frequency(0.03).set_lacunarity(2.0).set_persistence(0.5)
+}
+
+fn biome_at(fbm: &Fbm<noise::OpenSimplex>, tx: i32, ty: i32, swamp_thr: f32, rocky_thr: f32) -> BiomeKind {
+    // базовый шум для «влажности/болотистости» и «каменистости»
+    let moisture = fbm.get([tx as f64 * 0.18 + 311.0, ty as f64 * 0.18 - 211.0]) as f32; // -1..1
+    let rocky    = fbm.get([tx as f64 * 0.22 - 157.0, ty as f64 * 0.22 +  97.0]) as f32; // -1..1
+    // простое разбиение: высокий moisture -> болото; высокий rocky -> скалы; иначе — луг
+    if moisture > swamp_thr { BiomeKind::Swamp }
+    else if rocky > rocky_thr { BiomeKind::Rocky }
+    else { BiomeKind::Meadow }
+}
+
+// Высота из того же fbm: крупные формы рельефа + сам шум суши,
+// в каменистых местах (вне болот) рельеф плавно поднимается
+fn height_at(fbm: &Fbm<noise::OpenSimplex>, tx: i32, ty: i32, req: &ChunkRequest) -> u8 {
+    let n = land_noise(fbm, tx, ty, &req.map_gen);
+    let relief = fbm.get([tx as f64 * 0.35 + 431.0, ty as f64 * 0.35 - 173.0]) as f32;
+    let moisture = fbm.get([tx as f64 * 0.18 + 311.0, ty as f64 * 0.18 - 211.0]) as f32;
+    let rocky = fbm.get([tx as f64 * 0.22 - 157.0, ty as f64 * 0.22 + 97.0]) as f32;
+    let rocky_lift = (rocky - req.rocky_thr).max(0.0) * 1.2 * ((req.swamp_thr - moisture) * 10.0).clamp(0.0, 1.0);
+    let e = n * 0.5 + relief * 0.5 + rocky_lift;
+    ((e + 0.15) * 60.0).clamp(0.0, 31.0) as u8
+}
+
+// Шум суши с учётом масштаба пресета и подъёма вокруг старта
+fn land_noise(fbm: &Fbm<noise::OpenSimplex>, tx: i32, ty: i32, map_gen: &MapGenParams) -> f32 {
+    let n = fbm.get([tx as f64 * map_gen.land_scale, ty as f64 * map_gen.land_scale]) as f32;
+    let d = (tx as f32).hypot(ty as f32);
+    n + map_gen.start_lift * (1.0 - d / 24.0).max(0.0)
+}
+
+// Тип клетки по шуму суши и высоте (без рек)
+fn terrain_at(n: f32, height: u8, map_gen: &MapGenParams) -> TileKind {
+    if n < map_gen.sea_level - 0.05 { TileKind::Water }
+    else if n < map_gen.sea_level { TileKind::ShallowWater }
+    else if n < map_gen.sea_level + 0.04 { TileKind::Sand }
+    else if height >= MOUNTAIN_HEIGHT { TileKind::Mountain }
+    else if n >= map_gen.forest_level { TileKind::Forest }
+    else if height >= HILLS_HEIGHT { TileKind::Hills }
+    else { TileKind::Grass }
+}
+
+fn is_river_at(fbm: &Fbm<noise::OpenSimplex>, tx: i32, ty: i32, width_mul: f32) -> bool {
+    // маска 1
+    let v1 = fbm.get([tx as f64 * 0.035 + 1234.0, ty as f64 * 0.035 - 987.0]) as f32;
+    let u1 = fbm.get([tx as f64 * 0.018 - 777.0, ty as f64 * 0.022 + 444.0]) as f32;
+    let w1 = fbm.get([tx as f64 * 0.10 - 222.0, ty as f64 * 0.10 + 333.0]) as f32;
+    let s1 = ((v1 + u1 * 0.5) * std::f32::consts::PI).sin().abs();
+    let width1 = (0.028 + 0.018 * (w1 * 0.5 + 0.5)) * width_mul; // 0.028..0.046
+    // маска 2 (смещённые фазы) — добавляет дополнительных рек, почти не увеличивая ширину
+    let v2 = fbm.get([tx as f64 * 0.032 - 321.0, ty as f64 * 0.032 + 654.0]) as f32;
+    let u2 = fbm.get([tx as f64 * 0.020 + 999.0, ty as f64 * 0.017 - 888.0]) as f32;
+    let w2 = fbm.get([tx as f64 * 0.12 + 111.0, ty as f64 * 0.12 - 222.0]) as f32;
+    let s2 = ((v2 + u2 * 0.5) * std::f32::consts::PI).sin().abs();
+    let width2 = (0.026 + 0.016 * (w2 * 0.5 + 0.5)) * width_mul; // 0.026..0.042
+    (s1 < width1) || (s2 < width2)
+}
+
+// Детерминированное распределение зрелости по координатам и seed:
+// ~15% stage0, ~30% stage1, ~55% stage2
+fn seeded_tree_stage(seed: u64, tx: i32, ty: i32) -> u8 {
+    let mut v = (tx as i64).wrapping_mul(0x9E3779B97F4A7C15u64 as i64)
+        ^ (ty as i64).wrapping_mul(0xC2B2AE3D27D4EB4Fu64 as i64)
+        ^ (seed as i64);
+    // xorshift64*
+    v ^= v >> 12; v ^= v << 25; v ^= v >> 27;
+    let u = ((v.wrapping_mul(0x2545F4914F6CDD1D) >> 11) & 0xFFFF_FFFF) as u32;
+    let r = (u as f32) / (u32::MAX as f32); // 0..1
+    if r < 0.15 { 0 } else if r < 0.45 { 1 } else { 2 }
+}
 
-pub fn spawn_chunk_worker(seed: u64) -> (Sender<(i32, i32)>, Receiver<ChunkResult>) {
-    let (tx, rx_req) = channel::<(i32, i32)>();
-    let (tx_res, rx_res) = channel::<ChunkResult>();
-    thread::spawn(move || {
-        let mut fbm = Fbm::<noise::OpenSimplex>::new(0);
-        fbm = fbm.set_seed(seed as u32).set_octaves(5).set_frequency(0.03).set_lacunarity(2.0).set_persistence(0.5);
-        while let Ok((cx, cy)) = rx_req.recv() {
-            let mut tiles = vec![TileKind::Water; (CHUNK_W * CHUNK_H) as usize];
-            for ly in 0..CHUNK_H { for lx in 0..CHUNK_W {
-                let tx = cx * CHUNK_W + lx; let ty = cy * CHUNK_H + ly;
-                let n = fbm.get([tx as f64, ty as f64]) as f32;
-                tiles[(ly * CHUNK_W + lx) as usize] = if n < -0.2 { TileKind::Water } else if n < 0.2 { TileKind::Grass } else { TileKind::Forest };
-            }}
-            let _ = tx_res.send(ChunkResult { cx, cy, tiles });
+// Параметры шума месторождения: масштаб, смещения, порог и полоса сглаживания
+struct DepositNoise { scale: f64, ox: f64, oy: f64, thr: f32, margin: f32 }
+
+const CLAY_NOISE: DepositNoise = DepositNoise { scale: 0.30, ox: 31.0, oy: -77.0, thr: 0.22, margin: 0.05 };
+const STONE_NOISE: DepositNoise = DepositNoise { scale: 0.38, ox: -123.0, oy: 19.0, thr: 0.26, margin: 0.06 };
+const IRON_NOISE: DepositNoise = DepositNoise { scale: 0.34, ox: 211.0, oy: 87.0, thr: 0.30, margin: 0.05 };
+// редкие месторождения: узкие пятна без сглаживания краёв
+const COAL_NOISE: DepositNoise = DepositNoise { scale: 0.33, ox: 147.0, oy: -59.0, thr: 0.25, margin: 0.0 };
+const GOLD_NOISE: DepositNoise = DepositNoise { scale: 0.47, ox: -233.0, oy: 171.0, thr: 0.20, margin: 0.0 };
+const GEMS_NOISE: DepositNoise = DepositNoise { scale: 0.71, ox: 89.0, oy: 263.0, thr: 0.15, margin: 0.0 };
+const SALT_NOISE: DepositNoise = DepositNoise { scale: 0.41, ox: -37.0, oy: -301.0, thr: 0.15, margin: 0.0 };
+// самоцветы — только на высоких холмах у гор
+const GEMS_MIN_HEIGHT: u8 = 19;
+
+// Запас растёт с превышением шумом порога: у кромки пятна — минимум, в центре — максимум
+fn deposit_amount(v: f32, thr: f32) -> u16 {
+    let t = ((v - thr) / 0.3).clamp(0.0, 1.0);
+    DEPOSIT_MIN + (t * (DEPOSIT_MAX - DEPOSIT_MIN) as f32) as u16
+}
+
+// Месторождение по шуму: выше порога — точно есть; в полосе margin под порогом —
+// только если у тайла хотя бы 2 соседа выше порога (сглаживает рваные края)
+
+fn deposit_at(fbm: &Fbm<noise::OpenSimplex>, tx: i32, ty: i32, dn: &DepositNoise, bonus: f32) -> Option<u16> {
+    let sample = |x: i32, y: i32| fbm.get([x as f64 * dn.scale + dn.ox, y as f64 * dn.scale + dn.oy]) as f32;
+    let (base, thr) = (sample(tx, ty), dn.thr - bonus);
+    if base > thr { Some(deposit_amount(base, thr)) } else if base > thr - dn.margin {
+        const NB: [(i32,i32);8] = [(1,0),(-1,0),(0,1),(0,-1),(1,1),(1,-1),(-1,1),(-1,-1)];
+        let near = NB.iter().filter(|&&(dx, dy)| sample(tx + dx, ty + dy) > thr).count() >= 2;
+        near.then_some(DEPOSIT_MIN)
+    } else { None }
+}
+
+/// Полная генерация чанка из seed: тайлы, реки, деревья, биомы и месторождения.
+/// Выполняется в потоках пула; изменения игрока накладываются уже в `integrate_ready_chunks`.
+fn generate_chunk(fbm: &Fbm<noise::OpenSimplex>, seed: u64, req: ChunkRequest) -> Chunk {
+    let mut chunk = Chunk::new(vec![TileKind::Water; CHUNK_AREA], 0, 0);
+    for ly in 0..CHUNK_H { for lx in 0..CHUNK_W {
+        let idx = (ly * CHUNK_W + lx) as usize;
+        let tx = req.cx * CHUNK_W + lx;
+        let ty = req.cy * CHUNK_H + ly;
+        let n = land_noise(fbm, tx, ty, &req.map_gen);
+        let height = height_at(fbm, tx, ty, &req);
+        chunk.heights[idx] = height;
+        let mut kind = terrain_at(n, height, &req.map_gen);
+        // «речные» клетки превращаем в воду
+        if !kind.is_water() && req.map_gen.river_width > 0.0 && is_river_at(fbm, tx, ty, req.map_gen.river_width) {
+            kind = TileKind::Water;
+            chunk.rivers.set(idx, true);
+        }
+        chunk.tiles[idx] = kind;
+        if kind == TileKind::Forest {
+            chunk.set_tree(idx, Some(Tree { stage: seeded_tree_stage(seed, tx, ty), age_ms: 0 }));
+        }
+        // слой биомов (на всех тайлах) и месторождений (на неводных)
+        let bm = biome_at(fbm, tx, ty, req.swamp_thr, req.rocky_thr);
+        chunk.biomes[idx] = bm;
+        if kind.is_water() { continue; }
+        // базовые вероятности + биомные довески
+        let bonus = req.map_gen.deposit_bonus;
+        let mut clay = deposit_at(fbm, tx, ty, &CLAY_NOISE, bonus);
+        if clay.is_none() && matches!(bm, BiomeKind::Swamp) {
+            let n = fbm.get([tx as f64 * 0.55 - 13.0, ty as f64 * 0.55 + 21.0]) as f32; if n > 0.60 - bonus { clay = Some(deposit_amount(n, 0.60 - bonus)); }
+        }
+        let mut stone = deposit_at(fbm, tx, ty, &STONE_NOISE, bonus);
+        if stone.is_none() && matches!(bm, BiomeKind::Rocky) {
+            let n = fbm.get([tx as f64 * 0.52 + 77.0, ty as f64 * 0.52 - 41.0]) as f32; if n > 0.62 - bonus { stone = Some(deposit_amount(n, 0.62 - bonus)); }
+        }
+        let mut iron = deposit_at(fbm, tx, ty, &IRON_NOISE, bonus);
+        if iron.is_none() && matches!(bm, BiomeKind::Rocky) {
+            let n = fbm.get([tx as f64 * 0.59 - 91.0, ty as f64 * 0.59 + 63.0]) as f32; if n > 0.64 - bonus { iron = Some(deposit_amount(n, 0.64 - bonus)); }
+        }
+        // руда чаще выходит на поверхность в холмах
+        if iron.is_none() && kind == TileKind::Hills {
+            let n = fbm.get([tx as f64 * 0.61 + 53.0, ty as f64 * 0.61 + 29.0]) as f32; if n > 0.45 - bonus { iron = Some(deposit_amount(n, 0.45 - bonus)); }
+        }
+        // редкие: уголь в низинах, золото и самоцветы в холмах, соль на побережье
+        let coal = if !matches!(kind, TileKind::Sand | TileKind::Mountain) { deposit_at(fbm, tx, ty, &COAL_NOISE, bonus) } else { None };
+        let gold = if kind == TileKind::Hills { deposit_at(fbm, tx, ty, &GOLD_NOISE, bonus) } else { None };
+        let gems = if kind == TileKind::Hills && height >= GEMS_MIN_HEIGHT { deposit_at(fbm, tx, ty, &GEMS_NOISE, bonus) } else { None };
+        let salt = if kind == TileKind::Sand { deposit_at(fbm, tx, ty, &SALT_NOISE, bonus) } else { None };
+        let found = [
+            (DepositKind::Clay, clay), (DepositKind::Stone, stone), (DepositKind::Iron, iron),
+            (DepositKind::Coal, coal), (DepositKind::Gold, gold), (DepositKind::Gems, gems), (DepositKind::Salt, salt),
+        ];
+        for (kind, amount) in found {
+            if let Some(amount) = amount { chunk.deposits[kind as usize].put(idx, amount); }
+        }
+    }}
+    chunk
+}
+
+/// Запрос на генерацию чанка; пороги биомов и параметры карты передаются с запросом,
+/// так как их можно поменять в рантайме (конфиг, консоль, новая игра)
+#[derive(Clone, Copy)]
+pub struct ChunkRequest { pub cx: i32, pub cy: i32, pub swamp_thr: f32, pub rocky_thr: f32, pub map_gen: MapGenParams }
+
+pub struct ChunkResult { pub cx: i32, pub cy: i32, pub chunk: Chunk }
+
+// Очередь заявок, общая для потоков пула
+struct GenQueue {
+    requests: HashMap<(i32, i32), ChunkRequest>,
+    focus: (i32, i32),
+    shutdown: bool,
+}
+
+/// Пул потоков генерации чанков.
+/// Воркер берёт из очереди заявку, ближайшую к чанку под камерой.
+pub struct ChunkWorkerPool {
+    shared: Arc<(Mutex<GenQueue>, Condvar)>,
+    rx: Receiver<ChunkResult>,
+}
+
+impl ChunkWorkerPool {
+    pub fn new(seed: u64, threads: usize) -> Self {
+        let shared = Arc::new((Mutex::new(GenQueue { requests: HashMap::new(), focus: (0, 0), shutdown: false }), Condvar::new()));
+        let (tx_res, rx) = channel::<ChunkResult>();
+        for _ in 0..threads.max(1) {
+            let shared = Arc::clone(&shared);
+            let tx_res = tx_res.clone();
+            thread::spawn(move || {
+                let fbm = make_fbm(seed);
+                loop {
+                    let req = {
+                        let (lock, cvar) = &*shared;
+                        let mut q = lock.lock().unwrap();
+                        while q.requests.is_empty() && !q.shutdown { q = cvar.wait(q).unwrap(); }
+                        if q.shutdown { return; }
+                        let (fx, fy) = q.focus;
+                        let key = *q.requests.keys()
+                            .min_by_key(|&&(cx, cy)| (cx - fx).abs().max((cy - fy).abs()))
+                            .unwrap();
+                        q.requests.remove(&key).unwrap()
+                    };
+                    let chunk = generate_chunk(&fbm, seed, req);
+                    if tx_res.send(ChunkResult { cx: req.cx, cy: req.cy, chunk }).is_err() { return; }
+                }
+            });
         }
-    });
-    (tx, rx_res)
+        Self { shared, rx }
+    }
+
+    pub fn request(&self, req: ChunkRequest) {
+        let (lock, cvar) = &*self.shared;
+        lock.lock().unwrap().requests.insert((req.cx, req.cy), req);
+        cvar.notify_one();
+    }
+
+    pub fn set_focus(&self, focus: (i32, i32)) {
+        self.shared.0.lock().unwrap().focus = focus;
+    }
+
+    /// Отменить заявки вне прямоугольника чанков; возвращает отменённые ключи
+    pub fn cancel_outside(&self, (x0, y0, x1, y1): (i32, i32, i32, i32)) -> Vec<(i32, i32)> {
+        let mut q = self.shared.0.lock().unwrap();
+        let cancelled: Vec<(i32, i32)> = q.requests.keys()
+            .filter(|&&(cx, cy)| cx < x0 || cx > x1 || cy < y0 || cy > y1)
+            .copied()
+            .collect();
+        for key in &cancelled { q.requests.remove(key); }
+        cancelled
+    }
+
+    pub fn try_recv(&self) -> Option<ChunkResult> { self.rx.try_recv().ok() }
+}
+
+impl Drop for ChunkWorkerPool {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*self.shared;
+        if let Ok(mut q) = lock.lock() { q.shutdown = true; }
+        cvar.notify_all();
+    }
+}
+
+/// Число потоков генерации: из конфига или (0) по числу ядер, оставляя одно главному потоку
+pub fn chunk_worker_threads(configured: usize) -> usize {
+    if configured > 0 { return configured; }
+    thread::available_parallelism().map(|n| n.get().saturating_sub(1)).unwrap_or(1).clamp(1, 4)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // сгенерировать чанк синхронно, минуя пул потоков
+    fn load(world: &mut World, key: (i32, i32)) {
+        let chunk = generate_chunk(&world.fbm, world.seed, world.chunk_request(key));
+        world.integrate_chunk(key, chunk);
+    }
+
+    // первый чанк с деревьями по посеву
+    fn forest_chunk(world: &World) -> (i32, i32) {
+        (0..64).map(|i| (i % 8, i / 8)).find(|&k| world.seeded_trees(k).any()).expect("no forest near origin")
+    }
+
+    #[test]
+    fn restored_seed_trees_do_not_mark_chunk_modified() {
+        let mut world = World::new(7, 1);
+        let key = forest_chunk(&world);
+        load(&mut world, key);
+        let saved = world.all_trees();
+        world.restore_trees(saved.iter().copied());
+        assert!(world.modified_chunks.is_empty());
+        assert_eq!(world.all_trees().len(), saved.len());
+
+        // без одного дерева чанк отличается от посева
+        world.restore_trees(saved.iter().skip(1).copied());
+        assert!(world.modified_chunks.contains(&key));
+    }
+
+    // загрузить чанки вокруг начала координат до первой клетки с глиной, подходящей под условие
+    fn clay_tile(world: &mut World, fits: impl Fn(u16) -> bool) -> IVec2 {
+        for k in 0..64 {
+            let key = (k % 8, k / 8);
+            load(world, key);
+            let layer = &world.chunks[&key].deposits[DepositKind::Clay as usize];
+            if let Some(i) = layer.present.ones().find(|&i| fits(layer.initial(i))) {
+                let (x, y) = World::tile_pos(key, i);
+                return IVec2::new(x, y);
+            }
+        }
+        panic!("no clay near origin");
+    }
+
+    #[test]
+    fn extraction_takes_nearest_tile_and_reports_depletion() {
+        let mut world = World::new(7, 1);
+        let p = clay_tile(&mut world, |_| true);
+        let start = world.deposit_left(p, DepositKind::Clay);
+        assert!(world.extract_deposit(p, 0, DepositKind::Clay));
+        assert_eq!(world.deposit_left(p, DepositKind::Clay), start - 1);
+        while world.extract_deposit(p, 0, DepositKind::Clay) {}
+        assert!(world.is_depleted(p, DepositKind::Clay));
+        assert_eq!(world.depleted_events, vec![(p, DepositKind::Clay)]);
+    }
+
+    #[test]
+    fn clay_regrows_only_to_its_initial_amount() {
+        let mut world = World::new(7, 1);
+        let p = clay_tile(&mut world, |amount| amount < CLAY_REGEN_CAP);
+        let start = world.deposit_left(p, DepositKind::Clay);
+        for _ in 0..start { world.extract_deposit(p, 0, DepositKind::Clay); }
+        assert_eq!(world.deposit_left(p, DepositKind::Clay), 0);
+        world.regenerate_clay();
+        assert_eq!(world.deposit_left(p, DepositKind::Clay), CLAY_REGEN_PER_DAY.min(start));
+        for _ in 0..100 { world.regenerate_clay(); }
+        assert_eq!(world.deposit_left(p, DepositKind::Clay), start);
+    }
+
+    #[test]
+    fn evicted_chunk_keeps_player_changes() {
+        let mut world = World::new(7, 1);
+        let key = forest_chunk(&world);
+        load(&mut world, key);
+        let ((x, y), _) = world.all_trees()[0];
+        let cut = IVec2::new(x, y);
+        world.remove_tree(cut);
 
+        let other = (key.0 + 20, key.1);
+        load(&mut world, other);
+        world.max_chunks = 0;
+        world.view_chunks = (100, 100, 100, 100);
+        world.evict_far_chunks();
+        assert!(world.chunks.is_empty());
+        // нетронутый чанк не сохраняется, изменённый — сохраняется
+        assert!(world.persisted.contains_key(&key));
+        assert!(!world.persisted.contains_key(&other));
+
+        load(&mut world, key);
+        assert!(!world.has_tree(cut));
+        assert!(world.modified_chunks.contains(&key));
+    }
+
+    #[test]
+    fn work_area_keeps_neighbour_chunk_loaded() {
+        use crate::building_production::DEPOSIT_RADIUS as R;
+        let mut world = World::new(7, 1);
+        // месторождение у левого края чанка; карьер — в соседнем чанке слева
+        let (key, p, kind) = (0..64).map(|k| (k % 8, k / 8)).find_map(|key| {
+            load(&mut world, key);
+            DepositKind::ALL.into_iter().find_map(|kind| {
+                let layer = &world.chunks[&key].deposits[kind as usize];
+                layer.present.ones().map(|i| World::tile_pos(key, i))
+                    .find(|&(x, _)| x.rem_euclid(CHUNK_W) < R)
+                    .map(|(x, y)| (key, IVec2::new(x, y), kind))
+            })
+        }).expect("no deposit at a chunk edge");
+        let center = IVec2::new(key.0 * CHUNK_W - 1, p.y);
+        let quarry_chunk = World::chunk_of(center.x, center.y);
+        load(&mut world, quarry_chunk);
+        world.occupy(center - IVec2::new(1, 1));
+        let around = world.deposit_left_around(center, R, kind);
+        assert!(around > 0);
+
+        world.max_chunks = 0;
+        world.view_chunks = (100, 100, 100, 100);
+        world.work_areas = vec![(center, R)];
+        world.evict_far_chunks();
+        assert!(world.chunks.contains_key(&key));
+        assert_eq!(world.deposit_left_around(center, R, kind), around);
+
+        // без рабочей области соседний чанк выгружается, и месторождение пропадает
+        world.work_areas.clear();
+        world.evict_far_chunks();
+        assert!(!world.chunks.contains_key(&key));
+        assert!(world.chunks.contains_key(&quarry_chunk));
+        assert!(world.deposit_left_around(center, R, kind) < around);
+    }
+}